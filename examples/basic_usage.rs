@@ -52,7 +52,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     
     // Example 1: Search for items
     println!("\n🔍 Searching for gaming laptops...");
-    match client.search_items("gaming laptop", Some(5)).await {
+    match client.search_items("gaming laptop", Some(5), false).await {
         Ok(items) => {
             let count = items.item_summaries.as_ref().map(|v| v.len()).unwrap_or(0);
             println!("✅ Found {} items", count);
@@ -121,15 +121,17 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     // Example 4: Advanced search
     println!("\n🔍 Advanced search with filters...");
-    match client.search_items_advanced(
-        Some("laptop"),
-        None, // aspect_filter
-        Some("58058"), // Electronics category
-        Some("price:[100..1000]"), // Price filter
-        Some(5),
-        Some(0),
-        Some("price") // Sort by price
-    ).await {
+    match client
+        .search()
+        .query("laptop")
+        .category("58058") // Electronics category
+        .filter("price:[100..1000]") // Price filter
+        .sort("price") // Sort by price
+        .limit(5)
+        .auto_correct(true)
+        .send()
+        .await
+    {
         Ok(items) => {
             let count = items.item_summaries.as_ref().map(|v| v.len()).unwrap_or(0);
             println!("✅ Advanced search found {} items", count);
@@ -1,4 +1,32 @@
+use crate::ebay::api_error::{is_maintenance, send_request, ApiResponseError, RawRequestError};
+use crate::ebay::auth::{Clock, SharedClock, SharedTokenStore, TokenStore};
+use crate::ebay::circuit_breaker::{CircuitBreakerConfig, CircuitBreakers, CircuitProbe};
+use crate::ebay::retry::{with_retries, RetryBudget, RetryBudgetConfig, RetryPolicy, ShutdownSignal};
+use crate::error::{HermesError, HermesResult};
+use crate::ebay::marketplace::Marketplace;
+use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
+use std::future::Future;
+use std::sync::{Arc, RwLock};
+use std::time::Duration;
+
+/// Placeholder for IDs and URLs eBay would have assigned to a call skipped in dry-run mode
+pub const DRY_RUN_ID: &str = "DRY_RUN";
+
+/// Application keys for one eBay environment
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EbayCredentials {
+    pub app_id: String,
+    pub cert_id: String,
+}
+
+/// Keys swapped in at runtime, replacing the configured ones
+#[derive(Debug)]
+struct RotatedCredentials {
+    credentials: EbayCredentials,
+    /// Number of rotations so far, starting at 1
+    generation: u64,
+}
 
 /// Configuration for eBay API
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -7,7 +35,79 @@ pub struct EbayConfig {
     pub cert_id: String,
     pub dev_id: Option<String>,
     pub sandbox: bool,
+    /// Keys used instead of `app_id`/`cert_id` when `sandbox` is true
+    #[serde(default)]
+    pub sandbox_credentials: Option<EbayCredentials>,
+    /// Keys used instead of `app_id`/`cert_id` when `sandbox` is false
+    #[serde(default)]
+    pub production_credentials: Option<EbayCredentials>,
     pub oauth_token: Option<String>,
+    /// eBay redirect URL name (RuName) used for user consent
+    #[serde(default)]
+    pub ru_name: Option<String>,
+    /// Override for the API host (e.g. a proxy or a local mock server)
+    #[serde(default)]
+    pub api_base_url: Option<String>,
+    /// Default marketplace sent as `X-EBAY-C-MARKETPLACE-ID`
+    #[serde(default)]
+    pub marketplace: Marketplace,
+    /// Log outgoing requests as curl commands at debug level
+    #[serde(default)]
+    pub curl_debug: bool,
+    /// Request gzip-compressed Taxonomy responses (category trees and subtrees)
+    #[serde(default)]
+    pub gzip_taxonomy: bool,
+    /// Log mutating calls instead of sending them
+    #[serde(default)]
+    pub dry_run: bool,
+    /// Largest response body read before giving up (unlimited when unset)
+    #[serde(default)]
+    pub max_response_bytes: Option<usize>,
+    /// Keep the raw JSON of typed responses (see `EbayResponse::raw`)
+    #[serde(default)]
+    pub raw_capture: bool,
+    /// Request-scoped ID tying eBay calls to the originating request
+    #[serde(skip)]
+    pub correlation_id: Option<String>,
+    /// Header the correlation ID is sent in, e.g. "X-Request-Id" (not sent when unset)
+    #[serde(default)]
+    pub correlation_header: Option<String>,
+    /// Where OAuth tokens are cached (in memory per client when unset)
+    #[serde(skip)]
+    pub token_store: Option<SharedTokenStore>,
+    /// Clock deciding token expiry (the system clock when unset)
+    #[serde(skip)]
+    pub clock: Option<SharedClock>,
+    /// Per-API circuit breaker settings (disabled when unset)
+    #[serde(default)]
+    pub circuit_breaker: Option<CircuitBreakerConfig>,
+    // Circuit state, shared by every client built from clones of this config
+    #[serde(skip)]
+    circuit_breakers: Arc<CircuitBreakers>,
+    // Rotated keys, shared like the circuit state
+    #[serde(skip)]
+    rotated_credentials: Arc<RwLock<Option<RotatedCredentials>>>,
+    // Held while an application token is fetched, so concurrent callers share one refresh
+    #[serde(skip)]
+    pub(crate) token_refresh: Arc<tokio::sync::Mutex<()>>,
+    /// Retries of transient failures on idempotent calls (none by default)
+    #[serde(default)]
+    pub max_retries: u32,
+    /// Cap on the retry rate across all calls (unlimited when unset)
+    #[serde(default)]
+    pub retry_budget: Option<RetryBudgetConfig>,
+    // Retry tokens, shared like the circuit state
+    #[serde(skip)]
+    retry_tokens: Arc<RetryBudget>,
+    /// Custom decision on which failures are retried (429/5xx and no response when unset)
+    #[serde(skip)]
+    pub retry_policy: Option<RetryPolicy>,
+    /// Time after a call's first attempt past which it is no longer retried (unlimited when unset)
+    #[serde(default)]
+    pub retry_timeout: Option<Duration>,
+    /// Signal cancelling pending retry waits, e.g. on shutdown
+    #[serde(skip)]
+    pub shutdown: Option<ShutdownSignal>,
 }
 
 impl EbayConfig {
@@ -17,7 +117,31 @@ impl EbayConfig {
             cert_id: String::new(),
             dev_id: None,
             sandbox: true,
+            sandbox_credentials: None,
+            production_credentials: None,
             oauth_token: None,
+            ru_name: None,
+            api_base_url: None,
+            marketplace: Marketplace::EbayUs,
+            curl_debug: false,
+            gzip_taxonomy: false,
+            dry_run: false,
+            max_response_bytes: None,
+            raw_capture: false,
+            correlation_id: None,
+            correlation_header: None,
+            token_store: None,
+            clock: None,
+            circuit_breaker: None,
+            circuit_breakers: Arc::new(CircuitBreakers::default()),
+            rotated_credentials: Arc::new(RwLock::new(None)),
+            token_refresh: Arc::new(tokio::sync::Mutex::new(())),
+            max_retries: 0,
+            retry_budget: None,
+            retry_tokens: Arc::new(RetryBudget::default()),
+            retry_policy: None,
+            retry_timeout: None,
+            shutdown: None,
         }
     }
 
@@ -41,18 +165,355 @@ impl EbayConfig {
         self
     }
 
+    /// Keys to use while `sandbox` is true
+    /// 
+    /// With both sandbox and production keys set, switching environments is just
+    /// `with_sandbox(..)`. Takes precedence over `with_app_id`/`with_cert_id`.
+    pub fn with_sandbox_credentials(mut self, app_id: &str, cert_id: &str) -> Self {
+        self.sandbox_credentials = Some(EbayCredentials { app_id: app_id.to_string(), cert_id: cert_id.to_string() });
+        self
+    }
+
+    /// Keys to use while `sandbox` is false
+    pub fn with_production_credentials(mut self, app_id: &str, cert_id: &str) -> Self {
+        self.production_credentials = Some(EbayCredentials { app_id: app_id.to_string(), cert_id: cert_id.to_string() });
+        self
+    }
+
+    /// App ID and cert ID for the selected environment
+    /// 
+    /// These are the configured keys; once keys have been rotated, requests are
+    /// authenticated with the rotated ones instead (see [`Self::rotate_credentials`]).
+    pub fn active_credentials(&self) -> (&str, &str) {
+        let environment = if self.sandbox { &self.sandbox_credentials } else { &self.production_credentials };
+        match environment {
+            Some(credentials) => (&credentials.app_id, &credentials.cert_id),
+            None => (&self.app_id, &self.cert_id),
+        }
+    }
+
+    /// Replace the keys of every client built from clones of this config
+    /// 
+    /// The new keys are used from the next token request on, whichever environment
+    /// is selected. Tokens fetched with the previous keys are not reused.
+    pub(crate) fn rotate_credentials(&self, app_id: &str, cert_id: &str) {
+        let mut rotated = self.rotated_credentials.write().unwrap_or_else(|e| e.into_inner());
+        let generation = rotated.as_ref().map_or(1, |rotated| rotated.generation + 1);
+        let credentials = EbayCredentials { app_id: app_id.to_string(), cert_id: cert_id.to_string() };
+        *rotated = Some(RotatedCredentials { credentials, generation });
+    }
+
+    /// Keys to authenticate with, and how many times they have been rotated
+    pub(crate) fn current_credentials(&self) -> (EbayCredentials, u64) {
+        let rotated = self.rotated_credentials.read().unwrap_or_else(|e| e.into_inner());
+        match rotated.as_ref() {
+            Some(rotated) => (rotated.credentials.clone(), rotated.generation),
+            None => {
+                let (app_id, cert_id) = self.active_credentials();
+                (EbayCredentials { app_id: app_id.to_string(), cert_id: cert_id.to_string() }, 0)
+            }
+        }
+    }
+
     pub fn with_oauth_token(mut self, token: &str) -> Self {
         self.oauth_token = Some(token.to_string());
         self
     }
 
-    pub fn base_url(&self) -> &'static str {
-        if self.sandbox {
+    /// Set the RuName eBay redirects sellers to after they grant consent
+    pub fn with_ru_name(mut self, ru_name: &str) -> Self {
+        self.ru_name = Some(ru_name.to_string());
+        self
+    }
+
+    /// Set the default marketplace for calls that don't take an explicit marketplace ID
+    pub fn with_marketplace(mut self, marketplace: Marketplace) -> Self {
+        self.marketplace = marketplace;
+        self
+    }
+
+    /// Route all API and OAuth requests to a different host instead of eBay's
+    pub fn with_api_base_url(mut self, url: &str) -> Self {
+        self.api_base_url = Some(url.trim_end_matches('/').to_string());
+        self
+    }
+
+    /// Log each outgoing request as an equivalent curl command
+    /// 
+    /// Commands are emitted at debug level under the `hermes_sdk::curl` tracing target,
+    /// with request bodies and with credential headers redacted. Covers every API call;
+    /// OAuth token requests are not logged.
+    pub fn with_curl_debug(mut self, enabled: bool) -> Self {
+        self.curl_debug = enabled;
+        self
+    }
+
+    /// Ask for gzip-compressed category tree downloads
+    /// 
+    /// Sends `Accept-Encoding: application/gzip` on Taxonomy category tree and
+    /// subtree requests that don't set an encoding explicitly. Full trees shrink
+    /// from tens of megabytes to a few; responses are decompressed transparently.
+    pub fn with_gzip_taxonomy(mut self, enabled: bool) -> Self {
+        self.gzip_taxonomy = enabled;
+        self
+    }
+
+    /// `Accept-Encoding` for a Taxonomy tree request, falling back to gzip when enabled
+    pub(crate) fn taxonomy_accept_encoding<'a>(&self, requested: Option<&'a str>) -> Option<&'a str> {
+        requested.or(self.gzip_taxonomy.then_some("application/gzip"))
+    }
+
+    /// Preview mutating calls without sending them
+    /// 
+    /// Creates, updates, deletes, publishes, withdrawals, refunds, shipments, bids,
+    /// guest checkout changes and image uploads log the request they would send (at
+    /// info level) and return an empty success response instead of calling eBay.
+    /// Where the caller needs an ID or URL eBay would have assigned, it is
+    /// [`DRY_RUN_ID`]. Reads still call eBay, so batch jobs can be rehearsed against
+    /// live data.
+    pub fn with_dry_run(mut self, enabled: bool) -> Self {
+        self.dry_run = enabled;
+        self
+    }
+
+    /// Stop reading response bodies larger than `max_bytes`
+    /// 
+    /// Such calls fail with `HermesError::ResponseTooLarge` instead of buffering the
+    /// whole body, protecting memory-constrained services. Applies to the requests
+    /// this crate reads itself: `call_raw`, `rate_limit_status`, the Media API,
+    /// `TaxonomyClient::get_category_tree` and each chunk of a feed download. The
+    /// generated API crates read their other responses internally.
+    pub fn with_max_response_bytes(mut self, max_bytes: usize) -> Self {
+        self.max_response_bytes = Some(max_bytes);
+        self
+    }
+
+    /// Keep the raw JSON alongside typed responses
+    /// 
+    /// Fields eBay returns that the models do not have yet can then be read through
+    /// `EbayResponse::raw`. Applies to `EbayClient::call_typed`; the generated API
+    /// crates parse their responses internally.
+    pub fn with_raw_capture(mut self, enabled: bool) -> Self {
+        self.raw_capture = enabled;
+        self
+    }
+
+    /// In dry-run mode, log a mutating call and return the empty response to use in its place
+    /// 
+    /// Returns `None` when the call should be sent.
+    pub(crate) fn skip_in_dry_run<T: Default>(&self, operation: &str, request: &impl Serialize) -> Option<T> {
+        if !self.dry_run {
+            return None;
+        }
+        let request = serde_json::to_string(request).unwrap_or_default();
+        tracing::info!("Dry run: skipping eBay {} with request {}", operation, request);
+        Some(T::default())
+    }
+
+    /// Cache OAuth tokens in `store`, e.g. to share them between processes
+    pub fn with_token_store(mut self, store: Arc<dyn TokenStore>) -> Self {
+        self.token_store = Some(SharedTokenStore(store));
+        self
+    }
+
+    /// Decide token expiry with `clock`, e.g. a `ManualClock` in tests
+    pub fn with_clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.clock = Some(SharedClock(clock));
+        self
+    }
+
+    /// Tag calls with the ID of the request that triggered them
+    /// 
+    /// Browse calls made by `EbayClient` run inside an `ebay_call` tracing span with a
+    /// `correlation_id` field, so their logs can be joined to the originating request.
+    pub fn with_correlation_id(mut self, correlation_id: &str) -> Self {
+        self.correlation_id = Some(correlation_id.to_string());
+        self
+    }
+
+    /// Also send the correlation ID to eBay in `header`, e.g. "X-Request-Id"
+    pub fn with_correlation_header(mut self, header: &str) -> Self {
+        self.correlation_header = Some(header.to_string());
+        self
+    }
+
+    /// Tracing span for one eBay call, carrying the correlation ID when set
+    pub(crate) fn call_span(&self, api: &'static str, operation: &'static str) -> tracing::Span {
+        tracing::info_span!("ebay_call", api, operation, correlation_id = self.correlation_id.as_deref())
+    }
+
+    /// HTTP client sending the correlation header, when one is configured
+    /// 
+    /// `None` means a plain client is used.
+    pub(crate) fn correlation_client(&self) -> Option<reqwest::Client> {
+        let (header, id) = (self.correlation_header.as_deref()?, self.correlation_id.as_deref()?);
+        let (Ok(name), Ok(value)) =
+            (reqwest::header::HeaderName::try_from(header), reqwest::header::HeaderValue::try_from(id))
+        else {
+            tracing::warn!("Not sending correlation ID: invalid header {:?}: {:?}", header, id);
+            return None;
+        };
+        let headers = reqwest::header::HeaderMap::from_iter([(name, value)]);
+        reqwest::Client::builder().default_headers(headers).build().ok()
+    }
+
+    /// HTTP client for eBay requests, with the correlation header when set
+    pub(crate) fn http_client(&self) -> reqwest::Client {
+        self.correlation_client().unwrap_or_default()
+    }
+
+    /// Send an eBay request, reading at most `max_response_bytes` of the response
+    pub(crate) async fn send(&self, request: reqwest::RequestBuilder) -> Result<String, RawRequestError> {
+        send_request(self, request).await
+    }
+
+    /// Send an eBay request and parse the JSON response, keeping the body if it does not match `T`
+    pub(crate) async fn send_json<T: DeserializeOwned>(&self, request: reqwest::RequestBuilder) -> Result<T, RawRequestError> {
+        let body = self.send(request).await?;
+        serde_json::from_str(&body).map_err(|error| RawRequestError::Deserialize { error, body })
+    }
+
+    /// Stop calling an API family after repeated outages
+    /// 
+    /// After `failure_threshold` consecutive server errors or connection failures of
+    /// one API (e.g. Browse), its calls fail with `HermesError::CircuitOpen` for
+    /// `cooldown`, after which a single probe call decides whether to close it again.
+    pub fn with_circuit_breaker(mut self, failure_threshold: u32, cooldown: Duration) -> Self {
+        self.circuit_breaker = Some(CircuitBreakerConfig { failure_threshold, cooldown });
+        self
+    }
+
+    /// Fail fast if the circuit breaker for `api` is open
+    /// 
+    /// Keep the returned probe alive until the call's outcome is recorded.
+    pub(crate) fn check_circuit(&self, api: &'static str) -> HermesResult<CircuitProbe> {
+        match &self.circuit_breaker {
+            Some(settings) => self.circuit_breakers.check(settings, api),
+            None => Ok(CircuitProbe::none()),
+        }
+    }
+
+    /// Feed the outcome of a call to `api`, made holding `probe`, into its circuit breaker
+    pub(crate) fn record_circuit<T, E: ApiResponseError>(
+        &self,
+        api: &'static str,
+        probe: &CircuitProbe,
+        result: &Result<T, E>,
+    ) {
+        if let Some(settings) = &self.circuit_breaker {
+            let failed = match result {
+                Ok(_) => false,
+                Err(e) => match e.response() {
+                    // Maintenance opens the circuit straight away, for at least the requested delay
+                    Some((status, body)) if is_maintenance(status, body) => {
+                        self.circuit_breakers.trip(settings, api, probe, e.retry_after());
+                        return;
+                    }
+                    Some((status, _)) => status >= 500,
+                    None => e.is_transport_error(),
+                },
+            };
+            self.circuit_breakers.record(settings, api, probe, failed);
+        }
+    }
+
+    /// Retry idempotent calls that fail transiently
+    /// 
+    /// GET requests answered with a server error or 429, or that got no response,
+    /// are retried up to `max_retries` times with exponential backoff.
+    pub fn with_retries(mut self, max_retries: u32) -> Self {
+        self.max_retries = max_retries;
+        self
+    }
+
+    /// Limit retries across all in-flight calls with a token bucket
+    /// 
+    /// Every retry spends one of `capacity` tokens, which refill at
+    /// `refill_per_second`. With the bucket empty, failures are returned without
+    /// retrying, so an outage does not turn into a retry storm. Shared by every
+    /// client built from this config.
+    pub fn with_retry_budget(mut self, capacity: u32, refill_per_second: f64) -> Self {
+        self.retry_budget = Some(RetryBudgetConfig { capacity, refill_per_second });
+        self
+    }
+
+    /// Decide for yourself which failures are retried
+    /// 
+    /// `policy` receives each failure of a retried call and returns whether to try
+    /// again, replacing the default of retrying 429s, server errors and calls that
+    /// got no response. `with_retries` still caps the number of attempts.
+    pub fn with_retry_policy(mut self, policy: Arc<dyn Fn(&HermesError) -> bool + Send + Sync>) -> Self {
+        self.retry_policy = Some(RetryPolicy(policy));
+        self
+    }
+
+    /// Stop retrying a call once `timeout` has passed since its first attempt
+    /// 
+    /// A retry whose backoff or `Retry-After` wait would end past the timeout is not
+    /// attempted; the last failure is returned straight away instead.
+    pub fn with_retry_timeout(mut self, timeout: Duration) -> Self {
+        self.retry_timeout = Some(timeout);
+        self
+    }
+
+    /// Cancel pending retry waits when `signal` is triggered
+    /// 
+    /// Calls sleeping before a retry return their last failure at once, so a long
+    /// `Retry-After` does not hold up shutdown.
+    pub fn with_shutdown_signal(mut self, signal: ShutdownSignal) -> Self {
+        self.shutdown = Some(signal);
+        self
+    }
+
+    /// Run an idempotent call to `api`, retrying transient failures as configured
+    pub(crate) async fn retrying<T, E, F, Fut>(&self, api: &'static str, call: F) -> Result<T, E>
+    where
+        E: ApiResponseError + std::fmt::Debug,
+        F: FnMut() -> Fut,
+        Fut: Future<Output = Result<T, E>>,
+    {
+        let budget = self.retry_budget.as_ref().map(|settings| (settings, self.retry_tokens.as_ref()));
+        with_retries(
+            api,
+            self.max_retries,
+            budget,
+            self.retry_policy.as_ref(),
+            self.retry_timeout,
+            self.shutdown.as_ref(),
+            call,
+        )
+        .await
+    }
+
+    pub fn base_url(&self) -> &str {
+        if let Some(url) = self.api_base_url.as_deref() {
+            url
+        } else if self.sandbox {
             "https://api.sandbox.ebay.com"
         } else {
             "https://api.ebay.com"
         }
     }
+
+    /// Host of eBay's user consent (sign-in) pages
+    pub fn consent_base_url(&self) -> &'static str {
+        if self.sandbox {
+            "https://auth.sandbox.ebay.com"
+        } else {
+            "https://auth.ebay.com"
+        }
+    }
+
+    /// Base URL of the Media API, which eBay serves from a separate host
+    pub fn media_base_url(&self) -> &str {
+        if let Some(url) = self.api_base_url.as_deref() {
+            url
+        } else if self.sandbox {
+            "https://apim.sandbox.ebay.com"
+        } else {
+            "https://apim.ebay.com"
+        }
+    }
 }
 
 impl Default for EbayConfig {
@@ -0,0 +1,352 @@
+//! Mapping of eBay error responses onto [`HermesError`]
+//!
+//! eBay signals "not found" inconsistently: some endpoints return HTTP 404, others
+//! return 400 (or another status) with an API-specific `errorId` in the body. All of
+//! them are normalized into [`HermesError::NotFound`] here so callers have a single
+//! variant to match on. Other error responses become [`HermesError::Api`] carrying
+//! eBay's parsed error messages. Successful (2xx) responses are always parsed into the
+//! endpoint's model, so per-entry errors inside bulk responses are still reported in
+//! those models.
+//!
+//! Requests are built with reqwest and their bodies read here, so only the generated
+//! crates' models are used. None of them use `deny_unknown_fields`, so fields eBay
+//! adds to a response are ignored. A body that still fails to parse (a missing
+//! required field or a changed type) becomes [`HermesError::Deserialize`] carrying it.
+//!
+//! A 503 whose body mentions maintenance becomes [`HermesError::ServiceUnavailable`],
+//! so scheduled downtime can be told apart from a transient server error.
+
+use crate::config::EbayConfig;
+use crate::ebay::debug::send_logged;
+use crate::error::{ApiErrorMessage, HermesError};
+use flate2::read::GzDecoder;
+use serde::de::DeserializeOwned;
+use serde::Deserialize;
+use std::fmt;
+use std::io::Read;
+use std::time::Duration;
+
+/// Known "resource not found" error IDs, keyed by the error `domain` eBay reports
+const NOT_FOUND_ERROR_IDS: &[(&str, &[i64])] = &[
+    // The specified item ID was not found
+    ("API_BROWSE", &[11001]),
+    // SKU not found / resource not found / offer not available
+    ("API_INVENTORY", &[25702, 25710, 25713]),
+];
+
+/// Access to the HTTP response behind a failed eBay request
+pub(crate) trait ApiResponseError {
+    /// Status code and raw body, if the error came from an eBay response
+    fn response(&self) -> Option<(u16, &str)>;
+
+    /// Whether the request failed without getting any HTTP response
+    fn is_transport_error(&self) -> bool;
+
+    /// The parse error and the body, if eBay's successful response did not match the model
+    fn deserialize_error(&self) -> Option<(&serde_json::Error, &str)>;
+
+    /// The response's `Retry-After` delay, if eBay sent one
+    fn retry_after(&self) -> Option<Duration>;
+}
+
+/// Failure of a request to eBay
+#[derive(Debug)]
+pub(crate) enum RawRequestError {
+    /// eBay answered with a non-success status
+    Response { status: u16, body: String, retry_after: Option<Duration> },
+    /// No response was received
+    Transport(reqwest::Error),
+    /// The response body was larger than the configured limit
+    TooLarge { limit: usize },
+    /// The successful response did not match the expected model
+    Deserialize { error: serde_json::Error, body: String },
+}
+
+impl ApiResponseError for RawRequestError {
+    fn response(&self) -> Option<(u16, &str)> {
+        match self {
+            RawRequestError::Response { status, body, .. } => Some((*status, body.as_str())),
+            _ => None,
+        }
+    }
+
+    fn is_transport_error(&self) -> bool {
+        matches!(self, RawRequestError::Transport(_))
+    }
+
+    fn deserialize_error(&self) -> Option<(&serde_json::Error, &str)> {
+        match self {
+            RawRequestError::Deserialize { error, body } => Some((error, body.as_str())),
+            _ => None,
+        }
+    }
+
+    fn retry_after(&self) -> Option<Duration> {
+        match self {
+            RawRequestError::Response { retry_after, .. } => *retry_after,
+            _ => None,
+        }
+    }
+}
+
+/// The `Retry-After` delay of a response, when given in seconds
+pub(crate) fn retry_after(response: &reqwest::Response) -> Option<Duration> {
+    let value = response.headers().get(reqwest::header::RETRY_AFTER)?.to_str().ok()?;
+    value.trim().parse().ok().map(Duration::from_secs)
+}
+
+/// Read a response body, giving up once it grows past `limit` bytes
+pub(crate) async fn read_body(mut response: reqwest::Response, limit: Option<usize>) -> Result<String, RawRequestError> {
+    let body = match limit {
+        None => response.bytes().await.map_err(RawRequestError::Transport)?.to_vec(),
+        Some(limit) => {
+            if response.content_length().is_some_and(|length| length > limit as u64) {
+                return Err(RawRequestError::TooLarge { limit });
+            }
+            let mut body = Vec::new();
+            while let Some(chunk) = response.chunk().await.map_err(RawRequestError::Transport)? {
+                if body.len() + chunk.len() > limit {
+                    return Err(RawRequestError::TooLarge { limit });
+                }
+                body.extend_from_slice(&chunk);
+            }
+            body
+        }
+    };
+    // Taxonomy trees requested with `Accept-Encoding: application/gzip` may arrive as a
+    // gzip file without a `Content-Encoding` header, so reqwest leaves them compressed
+    let body = if body.starts_with(&GZIP_MAGIC) { gunzip(body, limit)? } else { body };
+    Ok(String::from_utf8_lossy(&body).into_owned())
+}
+
+/// First two bytes of every gzip stream
+const GZIP_MAGIC: [u8; 2] = [0x1f, 0x8b];
+
+/// Inflate a gzip body, keeping it within `limit` bytes once inflated
+///
+/// A body that fails to inflate is returned as sent.
+fn gunzip(body: Vec<u8>, limit: Option<usize>) -> Result<Vec<u8>, RawRequestError> {
+    let max = limit.map_or(u64::MAX, |limit| limit as u64 + 1);
+    let mut inflated = Vec::new();
+    match GzDecoder::new(body.as_slice()).take(max).read_to_end(&mut inflated) {
+        Err(_) => Ok(body),
+        Ok(_) => match limit {
+            Some(limit) if inflated.len() > limit => Err(RawRequestError::TooLarge { limit }),
+            _ => Ok(inflated),
+        },
+    }
+}
+
+/// Send a request, returning the body on success
+pub(crate) async fn send_request(config: &EbayConfig, request: reqwest::RequestBuilder) -> Result<String, RawRequestError> {
+    let response = send_logged(config, request).await.map_err(RawRequestError::Transport)?;
+    let status = response.status();
+    let retry_after = retry_after(&response);
+    let body = read_body(response, config.max_response_bytes).await?;
+    if status.is_success() {
+        Ok(body)
+    } else {
+        Err(RawRequestError::Response { status: status.as_u16(), body, retry_after })
+    }
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct ErrorBody {
+    #[serde(default)]
+    errors: Vec<ApiErrorMessage>,
+}
+
+/// Convert the error of an eBay request into a [`HermesError`]
+///
+/// # Arguments
+/// * `operation` - The wrapper method name, used in the error message
+/// * `resource` - Description of what was requested, reported by `NotFound`
+/// * `error` - The error returned by the request
+pub(crate) fn api_error<E>(operation: &str, resource: &str, error: E) -> HermesError
+where
+    E: ApiResponseError + fmt::Debug,
+{
+    api_error_ref(operation, resource, &error)
+}
+
+/// [`api_error`] for an error that is still needed afterwards, e.g. to be retried
+pub(crate) fn api_error_ref<E>(operation: &str, resource: &str, error: &E) -> HermesError
+where
+    E: ApiResponseError + fmt::Debug,
+{
+    if let Some((status, body)) = error.response() {
+        if is_not_found(status, body) {
+            return HermesError::NotFound { resource: resource.to_string() };
+        }
+        if is_maintenance(status, body) {
+            return HermesError::ServiceUnavailable { retry_after: error.retry_after() };
+        }
+        let mut parsed: ErrorBody = serde_json::from_str(body).unwrap_or_default();
+        if is_marketplace_mismatch(status, &parsed.errors) {
+            parsed.errors.insert(0, marketplace_mismatch_message());
+        }
+        return HermesError::Api {
+            operation: operation.to_string(),
+            status,
+            errors: parsed.errors,
+            body: body.to_string(),
+        };
+    }
+    if let Some((e, body)) = error.deserialize_error() {
+        return HermesError::Deserialize { reason: format!("{}: {}", operation, e), body: body.to_string() };
+    }
+    HermesError::ApiRequest(format!("eBay {} failed: {:?}", operation, error))
+}
+
+/// [`api_error`] taking ownership of the error, keeping transport errors and bodies as-is
+pub(crate) fn raw_error(operation: &str, resource: &str, error: RawRequestError) -> HermesError {
+    match error {
+        RawRequestError::Transport(e) => HermesError::Http(e),
+        RawRequestError::TooLarge { limit } => HermesError::ResponseTooLarge { limit },
+        RawRequestError::Deserialize { error, body } => {
+            HermesError::Deserialize { reason: format!("{}: {}", operation, error), body }
+        }
+        e => api_error(operation, resource, e),
+    }
+}
+
+/// Parse a successful response body, keeping the body if parsing fails
+pub(crate) fn parse_response<T: DeserializeOwned>(body: &str) -> Result<T, HermesError> {
+    serde_json::from_str(body).map_err(|e| HermesError::Deserialize { reason: e.to_string(), body: body.to_string() })
+}
+
+/// Whether a response means the requested resource does not exist
+///
+/// True for HTTP 404, and for any status whose error body carries a known
+/// not-found `errorId` (matched per `domain` when eBay reports one).
+pub(crate) fn is_not_found(status: u16, body: &str) -> bool {
+    if status == 404 {
+        return true;
+    }
+    let body: ErrorBody = serde_json::from_str(body).unwrap_or_default();
+    body.errors.iter().any(|error| {
+        let Some(error_id) = error.error_id else {
+            return false;
+        };
+        NOT_FOUND_ERROR_IDS.iter().any(|(domain, ids)| {
+            error.domain.as_deref().is_none_or(|d| d == *domain) && ids.contains(&error_id)
+        })
+    })
+}
+
+/// Whether a response is eBay's maintenance notice rather than a transient outage
+///
+/// eBay answers with 503 during maintenance windows, with a body (JSON errors or an
+/// HTML page) announcing the maintenance.
+pub(crate) fn is_maintenance(status: u16, body: &str) -> bool {
+    status == 503 && body.to_ascii_lowercase().contains("maintenance")
+}
+
+/// Whether a 403 was caused by the `X-EBAY-C-MARKETPLACE-ID` header naming a
+/// marketplace the access token is not authorized for
+///
+/// eBay reports this as an access error that names the marketplace, so any 403
+/// error mentioning it is treated as a mismatch.
+fn is_marketplace_mismatch(status: u16, errors: &[ApiErrorMessage]) -> bool {
+    status == 403
+        && errors.iter().any(|error| {
+            [error.message.as_deref(), error.long_message.as_deref()]
+                .into_iter()
+                .flatten()
+                .any(|text| text.to_ascii_lowercase().contains("marketplace"))
+        })
+}
+
+fn marketplace_mismatch_message() -> ApiErrorMessage {
+    ApiErrorMessage {
+        message: Some(
+            "The access token is not authorized for the marketplace in X-EBAY-C-MARKETPLACE-ID; \
+             the seller must be registered on that eBay site and the token granted for it"
+                .to_string(),
+        ),
+        ..Default::default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn status_404_is_not_found() {
+        assert!(is_not_found(404, ""));
+    }
+
+    #[test]
+    fn known_error_id_is_not_found_regardless_of_status() {
+        let body = r#"{"errors":[{"errorId":25702,"domain":"API_INVENTORY","message":"SKU MUG-001 is not available"}]}"#;
+        assert!(is_not_found(400, body));
+        // Same ID from another API means something else
+        let other_domain = r#"{"errors":[{"errorId":25702,"domain":"API_ACCOUNT"}]}"#;
+        assert!(!is_not_found(400, other_domain));
+        assert!(!is_not_found(400, r#"{"errors":[{"errorId":25001,"domain":"API_INVENTORY"}]}"#));
+        assert!(!is_not_found(500, "not json"));
+    }
+
+    #[test]
+    fn unknown_fields_are_ignored_and_bad_bodies_are_kept() {
+        let body = r#"{"itemId":"123","title":"Mug","newEbayField":{"nested":[1,2]}}"#;
+        let item: hermes_ebay_buy_browse::models::Item = parse_response(body).unwrap();
+        assert_eq!(item.item_id.as_deref(), Some("123"));
+
+        let drifted = r#"{"itemId":123}"#;
+        let err = parse_response::<hermes_ebay_buy_browse::models::Item>(drifted).unwrap_err();
+        assert!(matches!(err, HermesError::Deserialize { ref body, .. } if body == drifted));
+    }
+
+    #[test]
+    fn api_error_messages_are_parsed() {
+        let error = HermesError::Api {
+            operation: "create_offer".to_string(),
+            status: 400,
+            errors: serde_json::from_str::<ErrorBody>(
+                r#"{"errors":[{"errorId":25709,"domain":"API_INVENTORY","message":"Invalid value","longMessage":"Invalid value for price"}]}"#,
+            )
+            .unwrap()
+            .errors,
+            body: String::new(),
+        };
+        assert_eq!(error.api_messages(), ["Invalid value for price"]);
+        assert_eq!(error.to_string(), "eBay create_offer failed with HTTP 400: Invalid value for price");
+    }
+
+    #[test]
+    fn marketplace_scope_mismatch_is_explained() {
+        let body = r#"{"errors":[{"errorId":1100,"domain":"ACCESS","category":"REQUEST","message":"Access denied","longMessage":"Insufficient permissions to fulfill the request for marketplace EBAY_DE."}]}"#;
+        let err = api_error("get_offers", "sku MUG-1", RawRequestError::Response { status: 403, body: body.to_string(), retry_after: None });
+
+        let HermesError::Api { status, ref errors, .. } = err else {
+            panic!("expected HermesError::Api, got {:?}", err);
+        };
+        assert_eq!(status, 403);
+        assert_eq!(errors.len(), 2);
+        assert_eq!(errors[1].error_id, Some(1100));
+        assert!(err.to_string().contains("not authorized for the marketplace in X-EBAY-C-MARKETPLACE-ID"));
+
+        // Other access errors are reported as eBay sent them
+        let other = r#"{"errors":[{"errorId":1100,"message":"Access denied"}]}"#;
+        let err = api_error("get_offers", "sku MUG-1", RawRequestError::Response { status: 403, body: other.to_string(), retry_after: None });
+        assert_eq!(err.api_messages(), ["Access denied"]);
+    }
+
+    #[test]
+    fn maintenance_503_is_service_unavailable() {
+        let body = r#"{"errors":[{"errorId":10001,"domain":"API_BROWSE","category":"APPLICATION","message":"Service unavailable","longMessage":"The service is temporarily unavailable due to scheduled maintenance. Please try again later."}]}"#;
+        let maintenance = RawRequestError::Response {
+            status: 503,
+            body: body.to_string(),
+            retry_after: Some(Duration::from_secs(1800)),
+        };
+        let err = api_error("get_item", "item_id 123", maintenance);
+        assert!(matches!(err, HermesError::ServiceUnavailable { retry_after: Some(delay) } if delay.as_secs() == 1800));
+
+        // A plain 503 stays a transient API error
+        let transient = RawRequestError::Response { status: 503, body: String::new(), retry_after: None };
+        assert!(matches!(api_error("get_item", "item_id 123", transient), HermesError::Api { status: 503, .. }));
+    }
+}
@@ -1,11 +1,17 @@
-use crate::config::EbayConfig;
+use crate::config::{EbayConfig, EbayCredentials};
 use crate::error::{HermesError, HermesResult};
+use async_trait::async_trait;
 use reqwest::Client;
 use serde::{Deserialize, Serialize};
-use std::sync::Arc;
-use std::time::{Duration, Instant};
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::{Arc, Mutex as StdMutex};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
 use tokio::sync::Mutex;
 
+/// Tokens are refreshed this long before they actually expire
+const TOKEN_REFRESH_MARGIN: Duration = Duration::from_secs(60);
+
 /// eBay OAuth token response
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct EbayToken {
@@ -14,51 +20,221 @@ pub struct EbayToken {
     pub expires_in: u64,
     #[serde(default)]
     pub scope: Option<String>,
+    /// Long-lived token for `refresh_user_token`, issued with user tokens only
+    #[serde(default)]
+    pub refresh_token: Option<String>,
+    /// When the token expires, in seconds since the Unix epoch
+    /// 
+    /// Set when the token is fetched so that stored tokens stay meaningful across
+    /// processes.
+    #[serde(default)]
+    pub expires_at: Option<u64>,
+}
+
+impl EbayToken {
+    /// Whether the token can still be used (it is not within a minute of expiring)
+    pub fn is_valid(&self) -> bool {
+        self.is_valid_at(SystemTime::now())
+    }
+
+    /// Whether the token can still be used at `now`
+    pub fn is_valid_at(&self, now: SystemTime) -> bool {
+        let Some(expires_at) = self.expires_at else {
+            return false;
+        };
+        let now = now.duration_since(UNIX_EPOCH).unwrap_or_default();
+        now + TOKEN_REFRESH_MARGIN < Duration::from_secs(expires_at)
+    }
+}
+
+/// Source of the current time for token expiry
+/// 
+/// Token expiry is wall-clock time (tokens can be shared between processes through a
+/// [`TokenStore`]), so clocks report `SystemTime`. Replace the [`SystemClock`] with a
+/// [`ManualClock`] to test expiry and refresh without waiting.
+pub trait Clock: Send + Sync {
+    fn now(&self) -> SystemTime;
+}
+
+/// The real system clock, used when no other clock is configured
+#[derive(Debug, Default, Clone, Copy)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> SystemTime {
+        SystemTime::now()
+    }
+}
+
+/// A clock that only moves when told to, for tests
+#[derive(Debug)]
+pub struct ManualClock {
+    now: StdMutex<SystemTime>,
+}
+
+impl ManualClock {
+    /// Start the clock at the current system time
+    pub fn new() -> Self {
+        Self { now: StdMutex::new(SystemTime::now()) }
+    }
+
+    /// Move the clock forward by `duration`
+    pub fn advance(&self, duration: Duration) {
+        *self.now.lock().unwrap_or_else(|e| e.into_inner()) += duration;
+    }
+}
+
+impl Default for ManualClock {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Clock for ManualClock {
+    fn now(&self) -> SystemTime {
+        *self.now.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+/// A [`Clock`] that can be held in an [`EbayConfig`]
+#[derive(Clone)]
+pub struct SharedClock(pub Arc<dyn Clock>);
+
+impl fmt::Debug for SharedClock {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("SharedClock")
+    }
+}
+
+/// Storage for OAuth tokens
+/// 
+/// Implement this over a shared cache (e.g. Redis) so that several processes reuse
+/// one application token instead of each fetching their own.
+#[async_trait]
+pub trait TokenStore: Send + Sync {
+    /// Get the token stored under `key`, if any
+    async fn get(&self, key: &str) -> Option<EbayToken>;
+
+    /// Store `token` under `key`, replacing any previous token
+    async fn set(&self, key: &str, token: EbayToken);
+}
+
+/// Process-local [`TokenStore`], used when no other store is configured
+#[derive(Debug, Default)]
+pub struct InMemoryTokenStore {
+    tokens: Mutex<HashMap<String, EbayToken>>,
+}
+
+impl InMemoryTokenStore {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl TokenStore for InMemoryTokenStore {
+    async fn get(&self, key: &str) -> Option<EbayToken> {
+        self.tokens.lock().await.get(key).cloned()
+    }
+
+    async fn set(&self, key: &str, token: EbayToken) {
+        self.tokens.lock().await.insert(key.to_string(), token);
+    }
+}
+
+/// A [`TokenStore`] that can be held in an [`EbayConfig`]
+#[derive(Clone)]
+pub struct SharedTokenStore(pub Arc<dyn TokenStore>);
+
+impl fmt::Debug for SharedTokenStore {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("SharedTokenStore")
+    }
+}
+
+/// Error body of a failed OAuth token request
+#[derive(Debug, Default, Deserialize)]
+struct OAuthErrorBody {
+    error: Option<String>,
+    error_description: Option<String>,
 }
 
 /// eBay authentication handler
 pub struct EbayAuth {
     config: EbayConfig,
     client: Client,
-    token: Arc<Mutex<Option<EbayToken>>>,
-    token_expires_at: Arc<Mutex<Option<Instant>>>,
+    store: Arc<dyn TokenStore>,
+    clock: Arc<dyn Clock>,
 }
 
 impl EbayAuth {
     /// Create a new eBay authentication handler
+    /// 
+    /// Uses the config's token store if one is set, otherwise an in-memory store,
+    /// and likewise the config's clock or the system clock.
     pub fn new(config: EbayConfig) -> HermesResult<Self> {
         let client = Client::new();
+        let store = match &config.token_store {
+            Some(SharedTokenStore(store)) => Arc::clone(store),
+            None => Arc::new(InMemoryTokenStore::new()),
+        };
+        let clock = match &config.clock {
+            Some(SharedClock(clock)) => Arc::clone(clock),
+            None => Arc::new(SystemClock),
+        };
         Ok(Self {
             config,
             client,
-            token: Arc::new(Mutex::new(None)),
-            token_expires_at: Arc::new(Mutex::new(None)),
+            store,
+            clock,
         })
     }
 
     /// Get a valid access token, refreshing if necessary
+    /// 
+    /// Only one refresh runs at a time across the clients built from one config;
+    /// callers that waited for it use the token it stored.
     pub async fn get_access_token(&self) -> HermesResult<String> {
         // Check if we have a valid token
-        {
-            let token_guard = self.token.lock().await;
-            let expires_guard = self.token_expires_at.lock().await;
-            
-            if let (Some(token), Some(expires_at)) = (token_guard.as_ref(), *expires_guard) {
-                if Instant::now() < expires_at {
-                    return Ok(token.access_token.clone());
-                }
-            }
+        let (credentials, generation) = self.config.current_credentials();
+        let key = self.store_key(&credentials, generation);
+        if let Some(token) = self.valid_token(&key).await {
+            return Ok(token);
+        }
+
+        // Another caller may have refreshed the token while we waited for the lock
+        let _refresh = self.config.token_refresh.lock().await;
+        if let Some(token) = self.valid_token(&key).await {
+            return Ok(token);
         }
 
         // Get a new token
-        self.refresh_token().await?;
-        
-        let token_guard = self.token.lock().await;
-        Ok(token_guard.as_ref().unwrap().access_token.clone())
+        let token = self.refresh_token(&credentials).await?;
+        self.store.set(&key, token.clone()).await;
+        Ok(token.access_token)
+    }
+
+    /// The stored access token under `key`, unless it is missing or about to expire
+    async fn valid_token(&self, key: &str) -> Option<String> {
+        let token = self.store.get(key).await?;
+        token.is_valid_at(self.clock.now()).then_some(token.access_token)
+    }
+
+    /// Key tokens are stored under; tokens are shared per application and environment
+    /// 
+    /// Rotated keys get a key of their own, so tokens fetched with the previous keys
+    /// are not reused.
+    fn store_key(&self, credentials: &EbayCredentials, generation: u64) -> String {
+        let environment = if self.config.sandbox { "sandbox" } else { "production" };
+        if generation == 0 {
+            format!("ebay:{}:{}", environment, credentials.app_id)
+        } else {
+            format!("ebay:{}:{}:{}", environment, credentials.app_id, generation)
+        }
     }
 
-    /// Refresh the OAuth token
-    async fn refresh_token(&self) -> HermesResult<()> {
+    /// Fetch a new OAuth token
+    async fn refresh_token(&self, credentials: &EbayCredentials) -> HermesResult<EbayToken> {
         let url = format!("{}/identity/v1/oauth2/token", self.config.base_url());
         
         // Comprehensive eBay OAuth scopes
@@ -72,7 +248,7 @@ impl EbayAuth {
 
         let response = self.client
             .post(&url)
-            .basic_auth(&self.config.app_id, Some(&self.config.cert_id))
+            .basic_auth(&credentials.app_id, Some(&credentials.cert_id))
             .form(&params)
             .send()
             .await
@@ -88,19 +264,143 @@ impl EbayAuth {
             )));
         }
 
-        let token: EbayToken = response.json().await
+        let mut token: EbayToken = response.json().await
+            .map_err(|e| HermesError::Authentication(format!("Failed to parse token response: {}", e)))?;
+
+        // Record the absolute expiration time
+        let now = self.clock.now().duration_since(UNIX_EPOCH).unwrap_or_default();
+        token.expires_at = Some(now.as_secs() + token.expires_in);
+
+        Ok(token)
+    }
+
+    /// URL of the consent page where a seller grants this application `scopes`
+    /// 
+    /// eBay redirects back to the configured RuName with an authorization code.
+    pub fn consent_url(&self, scopes: &[&str]) -> HermesResult<String> {
+        self.build_consent_url(scopes, None)
+    }
+
+    /// [`consent_url`](Self::consent_url) carrying an OAuth `state` value
+    /// 
+    /// eBay echoes `state` back on the redirect; reject redirects whose `state`
+    /// does not match the one sent.
+    pub fn consent_url_with_state(&self, scopes: &[&str], state: &str) -> HermesResult<String> {
+        self.build_consent_url(scopes, Some(state))
+    }
+
+    fn build_consent_url(&self, scopes: &[&str], state: Option<&str>) -> HermesResult<String> {
+        let ru_name = self.config.ru_name.as_deref().ok_or_else(|| {
+            HermesError::Configuration("an RuName is required for user consent (EbayConfig::with_ru_name)".to_string())
+        })?;
+        let scope = scopes.join(" ");
+        let (credentials, _) = self.config.current_credentials();
+        let mut url = reqwest::Url::parse_with_params(
+            &format!("{}/oauth2/authorize", self.config.consent_base_url()),
+            [
+                ("client_id", credentials.app_id.as_str()),
+                ("redirect_uri", ru_name),
+                ("response_type", "code"),
+                ("scope", scope.as_str()),
+            ],
+        )
+        .map_err(|e| HermesError::Configuration(e.to_string()))?;
+        if let Some(state) = state {
+            url.query_pairs_mut().append_pair("state", state);
+        }
+        Ok(url.to_string())
+    }
+
+    /// Exchange the authorization code from a consent redirect for user tokens
+    /// 
+    /// The returned token carries the seller's `refresh_token`; store it to get new
+    /// access tokens later with [`refresh_user_token`](Self::refresh_user_token).
+    /// 
+    /// # Arguments
+    /// * `code` - The `code` query parameter eBay appended to the RuName redirect
+    pub async fn exchange_authorization_code(&self, code: &str) -> HermesResult<EbayToken> {
+        let ru_name = self.config.ru_name.as_deref().ok_or_else(|| {
+            HermesError::Configuration("an RuName is required for user consent (EbayConfig::with_ru_name)".to_string())
+        })?;
+        let url = format!("{}/identity/v1/oauth2/token", self.config.base_url());
+        let params = [("grant_type", "authorization_code"), ("code", code), ("redirect_uri", ru_name)];
+
+        let (credentials, _) = self.config.current_credentials();
+        let response = self.client
+            .post(&url)
+            .basic_auth(&credentials.app_id, Some(&credentials.cert_id))
+            .form(&params)
+            .send()
+            .await
+            .map_err(|e| HermesError::Authentication(e.to_string()))?;
+
+        if !response.status().is_success() {
+            let status = response.status();
+            let error_text = response.text().await.unwrap_or_default();
+            return Err(HermesError::Authentication(format!(
+                "Failed to exchange authorization code: {} - {}",
+                status,
+                error_text
+            )));
+        }
+
+        let mut token: EbayToken = response.json().await
             .map_err(|e| HermesError::Authentication(format!("Failed to parse token response: {}", e)))?;
 
-        // Store the token and expiration time
-        {
-            let mut token_guard = self.token.lock().await;
-            let mut expires_guard = self.token_expires_at.lock().await;
-            
-            *token_guard = Some(token.clone());
-            *expires_guard = Some(Instant::now() + Duration::from_secs(token.expires_in.saturating_sub(60))); // Refresh 1 minute early
+        let now = self.clock.now().duration_since(UNIX_EPOCH).unwrap_or_default();
+        token.expires_at = Some(now.as_secs() + token.expires_in);
+
+        Ok(token)
+    }
+
+    /// Exchange a seller's refresh token for a new user access token
+    /// 
+    /// When eBay rejects the refresh token (`invalid_grant`: revoked, expired, or
+    /// consent withdrawn), fails with [`HermesError::ReauthorizationRequired`]
+    /// carrying the consent URL for `scopes`, so the seller can be asked to reconnect.
+    /// 
+    /// # Arguments
+    /// * `refresh_token` - The seller's refresh token
+    /// * `scopes` - Scopes to request; must be a subset of those originally granted
+    pub async fn refresh_user_token(&self, refresh_token: &str, scopes: &[&str]) -> HermesResult<EbayToken> {
+        let url = format!("{}/identity/v1/oauth2/token", self.config.base_url());
+        let scope = scopes.join(" ");
+        let mut params = vec![("grant_type", "refresh_token"), ("refresh_token", refresh_token)];
+        if !scope.is_empty() {
+            params.push(("scope", &scope));
+        }
+
+        let (credentials, _) = self.config.current_credentials();
+        let response = self.client
+            .post(&url)
+            .basic_auth(&credentials.app_id, Some(&credentials.cert_id))
+            .form(&params)
+            .send()
+            .await
+            .map_err(|e| HermesError::Authentication(e.to_string()))?;
+
+        if !response.status().is_success() {
+            let status = response.status();
+            let error_text = response.text().await.unwrap_or_default();
+            let error: OAuthErrorBody = serde_json::from_str(&error_text).unwrap_or_default();
+            if error.error.as_deref() == Some("invalid_grant") {
+                tracing::warn!("eBay refresh token rejected: {}", error.error_description.unwrap_or_default());
+                return Err(HermesError::ReauthorizationRequired { auth_url: self.consent_url(scopes).ok() });
+            }
+            return Err(HermesError::Authentication(format!(
+                "Failed to refresh user token: {} - {}",
+                status,
+                error_text
+            )));
         }
 
-        Ok(())
+        let mut token: EbayToken = response.json().await
+            .map_err(|e| HermesError::Authentication(format!("Failed to parse token response: {}", e)))?;
+
+        let now = self.clock.now().duration_since(UNIX_EPOCH).unwrap_or_default();
+        token.expires_at = Some(now.as_secs() + token.expires_in);
+
+        Ok(token)
     }
 
     /// Get the authorization header for API requests
@@ -108,4 +408,197 @@ impl EbayAuth {
         let token = self.get_access_token().await?;
         Ok(format!("Bearer {}", token))
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use wiremock::matchers::{header, method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    /// In-memory store that counts reads, standing in for a shared cache
+    #[derive(Default)]
+    struct CountingStore {
+        inner: InMemoryTokenStore,
+        gets: AtomicUsize,
+    }
+
+    #[async_trait]
+    impl TokenStore for CountingStore {
+        async fn get(&self, key: &str) -> Option<EbayToken> {
+            self.gets.fetch_add(1, Ordering::SeqCst);
+            self.inner.get(key).await
+        }
+
+        async fn set(&self, key: &str, token: EbayToken) {
+            self.inner.set(key, token).await
+        }
+    }
+
+    async fn mount_token_endpoint(server: &MockServer, expected_calls: u64) {
+        Mock::given(method("POST"))
+            .and(path("/identity/v1/oauth2/token"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "access_token": "fetched-token",
+                "token_type": "Application Access Token",
+                "expires_in": 7200
+            })))
+            .expect(expected_calls)
+            .mount(server)
+            .await;
+    }
+
+    fn config(server: &MockServer, store: Arc<CountingStore>) -> EbayConfig {
+        EbayConfig::new()
+            .with_app_id("test-app-id")
+            .with_cert_id("test-cert-id")
+            .with_api_base_url(&server.uri())
+            .with_token_store(store)
+    }
+
+    #[tokio::test]
+    async fn valid_token_is_read_from_store_instead_of_fetched() {
+        let server = MockServer::start().await;
+        mount_token_endpoint(&server, 0).await;
+
+        let store = Arc::new(CountingStore::default());
+        let expires_at = SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_secs() + 3600;
+        store.set("ebay:sandbox:test-app-id", EbayToken {
+            access_token: "stored-token".to_string(),
+            token_type: "Application Access Token".to_string(),
+            expires_in: 7200,
+            scope: None,
+            refresh_token: None,
+            expires_at: Some(expires_at),
+        }).await;
+
+        let auth = EbayAuth::new(config(&server, Arc::clone(&store))).unwrap();
+        assert_eq!(auth.get_access_token().await.unwrap(), "stored-token");
+        assert_eq!(store.gets.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn credentials_follow_sandbox_flag() {
+        let server = MockServer::start().await;
+        // Basic auth of "sandbox-app:sandbox-cert" and "prod-app:prod-cert"
+        for (credentials, access_token) in [
+            ("Basic c2FuZGJveC1hcHA6c2FuZGJveC1jZXJ0", "sandbox-token"),
+            ("Basic cHJvZC1hcHA6cHJvZC1jZXJ0", "production-token"),
+        ] {
+            Mock::given(method("POST"))
+                .and(path("/identity/v1/oauth2/token"))
+                .and(header("Authorization", credentials))
+                .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                    "access_token": access_token,
+                    "token_type": "Application Access Token",
+                    "expires_in": 7200
+                })))
+                .expect(1)
+                .mount(&server)
+                .await;
+        }
+
+        let config = EbayConfig::new()
+            .with_sandbox_credentials("sandbox-app", "sandbox-cert")
+            .with_production_credentials("prod-app", "prod-cert")
+            .with_api_base_url(&server.uri());
+        assert_eq!(config.active_credentials(), ("sandbox-app", "sandbox-cert"));
+
+        let sandbox = EbayAuth::new(config.clone()).unwrap();
+        assert_eq!(sandbox.get_access_token().await.unwrap(), "sandbox-token");
+        let production = EbayAuth::new(config.with_sandbox(false)).unwrap();
+        assert_eq!(production.get_access_token().await.unwrap(), "production-token");
+    }
+
+    #[tokio::test]
+    async fn revoked_refresh_token_requires_reauthorization() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/identity/v1/oauth2/token"))
+            .respond_with(ResponseTemplate::new(400).set_body_json(serde_json::json!({
+                "error": "invalid_grant",
+                "error_description": "the provided authorization refresh token is invalid or was issued to another client"
+            })))
+            .mount(&server)
+            .await;
+
+        let config = EbayConfig::new()
+            .with_app_id("test-app-id")
+            .with_cert_id("test-cert-id")
+            .with_ru_name("Test_Seller-TestApp-PRD-abc")
+            .with_api_base_url(&server.uri());
+        let auth = EbayAuth::new(config).unwrap();
+        let err = auth
+            .refresh_user_token("v^1.1#revoked", &["https://api.ebay.com/oauth/api_scope/sell.inventory"])
+            .await
+            .unwrap_err();
+
+        let HermesError::ReauthorizationRequired { auth_url: Some(auth_url) } = err else {
+            panic!("expected ReauthorizationRequired, got {:?}", err);
+        };
+        assert!(auth_url.starts_with("https://auth.sandbox.ebay.com/oauth2/authorize?client_id=test-app-id"));
+        assert!(auth_url.contains("redirect_uri=Test_Seller-TestApp-PRD-abc"));
+        assert!(auth_url.contains("scope=https%3A%2F%2Fapi.ebay.com%2Foauth%2Fapi_scope%2Fsell.inventory"));
+    }
+
+    #[tokio::test]
+    async fn expired_token_is_refreshed_on_manual_clock() {
+        let server = MockServer::start().await;
+        mount_token_endpoint(&server, 2).await;
+
+        let clock = Arc::new(ManualClock::new());
+        let config = config(&server, Arc::new(CountingStore::default())).with_clock(clock.clone());
+        let auth = EbayAuth::new(config).unwrap();
+        auth.get_access_token().await.unwrap();
+
+        // Still valid just outside the refresh margin
+        clock.advance(Duration::from_secs(7200) - TOKEN_REFRESH_MARGIN - Duration::from_secs(1));
+        auth.get_access_token().await.unwrap();
+
+        // Inside the margin: refreshed without waiting
+        clock.advance(Duration::from_secs(2));
+        auth.get_access_token().await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn fetched_token_is_shared_through_store() {
+        let server = MockServer::start().await;
+        mount_token_endpoint(&server, 1).await;
+
+        let store = Arc::new(CountingStore::default());
+        let first = EbayAuth::new(config(&server, Arc::clone(&store))).unwrap();
+        let second = EbayAuth::new(config(&server, Arc::clone(&store))).unwrap();
+        assert_eq!(first.get_access_token().await.unwrap(), "fetched-token");
+        assert_eq!(second.get_access_token().await.unwrap(), "fetched-token");
+    }
+
+    #[tokio::test]
+    async fn concurrent_callers_share_one_refresh() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/identity/v1/oauth2/token"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(serde_json::json!({
+                        "access_token": "fetched-token",
+                        "token_type": "Application Access Token",
+                        "expires_in": 7200
+                    }))
+                    .set_delay(Duration::from_millis(100)),
+            )
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        // Two handlers built from one config, as the clients of an `EbayClient` are
+        let config = config(&server, Arc::new(CountingStore::default()));
+        let first = EbayAuth::new(config.clone()).unwrap();
+        let second = EbayAuth::new(config).unwrap();
+        let tokens = futures::future::join_all(
+            (0..4).map(|i| if i % 2 == 0 { first.get_access_token() } else { second.get_access_token() }),
+        )
+        .await;
+        assert!(tokens.iter().all(|token| token.as_deref().ok() == Some("fetched-token")));
+    }
+}
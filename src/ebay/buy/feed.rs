@@ -1,13 +1,24 @@
 use crate::config::EbayConfig;
 use crate::error::{HermesError, HermesResult};
+use crate::ebay::api_error::{raw_error, read_body, retry_after, RawRequestError};
+use crate::ebay::telemetry::record_api_call;
 use crate::ebay::auth::EbayAuth;
+use crate::ebay::debug::send_logged;
+use crate::ebay::marketplace::{normalize_marketplace, MarketplaceStyle};
 use std::sync::Arc;
 
-// Import eBay Feed SDK models and APIs
+// Import eBay Feed SDK models
 use hermes_ebay_buy_feed::models::{ItemResponse, ItemGroupResponse, ItemPriorityResponse, ItemSnapshotResponse};
-use hermes_ebay_buy_feed::apis::configuration::Configuration as FeedConfiguration;
+
+/// Bytes requested per range request by a [`FeedSession`] (eBay's maximum chunk size)
+pub const FEED_CHUNK_BYTES: u64 = 100 * 1024 * 1024;
 
 /// eBay Feed API client for bulk item data feeds
+/// 
+/// Feed files are returned as eBay serves them, without parsing. eBay does not
+/// declare a schema version for them (neither in the response headers nor in the
+/// gzipped TSV), so there is nothing to check a file against; consumers should check
+/// the TSV header row for the columns they read before relying on their positions.
 pub struct FeedClient {
     config: EbayConfig,
     auth: Arc<EbayAuth>,
@@ -20,6 +31,13 @@ impl FeedClient {
         Ok(Self { config, auth })
     }
 
+    /// Start a download session capped at `max_bytes` in total
+    /// 
+    /// See [`FeedSession`]; the cap protects deployments on metered bandwidth.
+    pub fn session(&self, max_bytes: u64) -> FeedSession<'_> {
+        FeedSession { client: self, max_bytes, chunk_bytes: FEED_CHUNK_BYTES, downloaded: 0 }
+    }
+
     /// Get item feed - bulk item data
     pub async fn get_item_feed(
         &self,
@@ -37,27 +55,29 @@ impl FeedClient {
         let token_duration = token_start.elapsed();
         tracing::info!("OAuth token request for get_item_feed: {:?}", token_duration);
         
-        // Set up configuration
-        let mut config = FeedConfiguration::new();
-        config.base_path = if self.config.sandbox {
-            "https://api.sandbox.ebay.com/buy/feed/v1".to_string()
-        } else {
-            "https://api.ebay.com/buy/feed/v1".to_string()
-        };
-        config.oauth_access_token = Some(token);
+        let url = format!("{}/buy/feed/v1/item", self.config.base_url());
+        let http = self.config.http_client();
+        
+        // Fail fast while this API's circuit breaker is open
+        let probe = self.config.check_circuit("buy.feed")?;
         
-        // Call the eBay SDK
+        let marketplace_id = normalize_marketplace(marketplace_id, MarketplaceStyle::Underscore);
+        
+        // Call the eBay API
         let ebay_start = std::time::Instant::now();
-        let result = hermes_ebay_buy_feed::apis::item_api::get_item_feed(
-            &config,
-            "application/gzip", // accept
-            marketplace_id,     // x_ebay_c_marketplace_id
-            range,             // range
-            feed_scope,        // feed_scope
-            category_id,       // category_id
-            date,              // date
-        ).await;
+        let result = self.config.retrying("buy.feed", || {
+            self.config.send_json(
+                http.get(&url)
+                    .bearer_auth(&token)
+                    .query(&[("feed_scope", Some(feed_scope)), ("category_id", Some(category_id)), ("date", date)])
+                    .header(reqwest::header::ACCEPT, "application/gzip")
+                    .header("X-EBAY-C-MARKETPLACE-ID", &marketplace_id)
+                    .header(reqwest::header::RANGE, range),
+            )
+        }).await;
         let ebay_duration = ebay_start.elapsed();
+        record_api_call("buy.feed", "get_item_feed", &result, ebay_duration);
+        self.config.record_circuit("buy.feed", &probe, &result);
         tracing::info!("eBay get_item_feed API call: {:?}", ebay_duration);
         
         match result {
@@ -70,7 +90,7 @@ impl FeedClient {
             Err(e) => {
                 let total_duration = start_time.elapsed();
                 tracing::error!("eBay get_item_feed error after {:?}: {:?}", total_duration, e);
-                Err(HermesError::ApiRequest(format!("eBay get_item_feed failed: {:?}", e)))
+                Err(raw_error("get_item_feed", &format!("category_id {}", category_id), e))
             }
         }
     }
@@ -92,27 +112,30 @@ impl FeedClient {
         let token_duration = token_start.elapsed();
         tracing::info!("OAuth token request for get_item_group_feed: {:?}", token_duration);
         
-        // Set up configuration
-        let mut config = FeedConfiguration::new();
-        config.base_path = if self.config.sandbox {
-            "https://api.sandbox.ebay.com/buy/feed/v1".to_string()
-        } else {
-            "https://api.ebay.com/buy/feed/v1".to_string()
-        };
-        config.oauth_access_token = Some(token);
+        let url = format!("{}/buy/feed/v1/item_group", self.config.base_url());
+        let http = self.config.http_client();
+        
+        // Fail fast while this API's circuit breaker is open
+        let probe = self.config.check_circuit("buy.feed")?;
         
-        // Call the eBay SDK
+        let marketplace_id = normalize_marketplace(marketplace_id, MarketplaceStyle::Underscore);
+        
+        // Call the eBay API
         let ebay_start = std::time::Instant::now();
-        let result = hermes_ebay_buy_feed::apis::item_group_api::get_item_group_feed(
-            &config,
-            "application/gzip", // accept
-            marketplace_id,     // x_ebay_c_marketplace_id
-            feed_scope,        // feed_scope
-            category_id,       // category_id
-            range,             // range
-            date,              // date
-        ).await;
+        let result = self.config.retrying("buy.feed", || {
+            let mut request = http.get(&url)
+                .bearer_auth(&token)
+                .query(&[("feed_scope", Some(feed_scope)), ("category_id", Some(category_id)), ("date", date)])
+                .header(reqwest::header::ACCEPT, "application/gzip")
+                .header("X-EBAY-C-MARKETPLACE-ID", &marketplace_id);
+            if let Some(range) = range {
+                request = request.header(reqwest::header::RANGE, range);
+            }
+            self.config.send_json(request)
+        }).await;
         let ebay_duration = ebay_start.elapsed();
+        record_api_call("buy.feed", "get_item_group_feed", &result, ebay_duration);
+        self.config.record_circuit("buy.feed", &probe, &result);
         tracing::info!("eBay get_item_group_feed API call: {:?}", ebay_duration);
         
         match result {
@@ -125,7 +148,7 @@ impl FeedClient {
             Err(e) => {
                 let total_duration = start_time.elapsed();
                 tracing::error!("eBay get_item_group_feed error after {:?}: {:?}", total_duration, e);
-                Err(HermesError::ApiRequest(format!("eBay get_item_group_feed failed: {:?}", e)))
+                Err(raw_error("get_item_group_feed", &format!("category_id {}", category_id), e))
             }
         }
     }
@@ -146,26 +169,29 @@ impl FeedClient {
         let token_duration = token_start.elapsed();
         tracing::info!("OAuth token request for get_item_priority_feed: {:?}", token_duration);
         
-        // Set up configuration
-        let mut config = FeedConfiguration::new();
-        config.base_path = if self.config.sandbox {
-            "https://api.sandbox.ebay.com/buy/feed/v1".to_string()
-        } else {
-            "https://api.ebay.com/buy/feed/v1".to_string()
-        };
-        config.oauth_access_token = Some(token);
+        let url = format!("{}/buy/feed/v1/item_priority", self.config.base_url());
+        let http = self.config.http_client();
         
-        // Call the eBay SDK
+        // Fail fast while this API's circuit breaker is open
+        let probe = self.config.check_circuit("buy.feed")?;
+        
+        let marketplace_id = normalize_marketplace(marketplace_id, MarketplaceStyle::Underscore);
+        
+        // Call the eBay API
         let ebay_start = std::time::Instant::now();
-        let result = hermes_ebay_buy_feed::apis::item_priority_api::get_item_priority_feed(
-            &config,
-            "application/gzip", // accept
-            marketplace_id,     // x_ebay_c_marketplace_id
-            range,             // range
-            category_id,       // category_id
-            date,              // date
-        ).await;
+        let result = self.config.retrying("buy.feed", || {
+            self.config.send_json(
+                http.get(&url)
+                    .bearer_auth(&token)
+                    .query(&[("category_id", category_id), ("date", date)])
+                    .header(reqwest::header::ACCEPT, "application/gzip")
+                    .header("X-EBAY-C-MARKETPLACE-ID", &marketplace_id)
+                    .header(reqwest::header::RANGE, range),
+            )
+        }).await;
         let ebay_duration = ebay_start.elapsed();
+        record_api_call("buy.feed", "get_item_priority_feed", &result, ebay_duration);
+        self.config.record_circuit("buy.feed", &probe, &result);
         tracing::info!("eBay get_item_priority_feed API call: {:?}", ebay_duration);
         
         match result {
@@ -178,7 +204,7 @@ impl FeedClient {
             Err(e) => {
                 let total_duration = start_time.elapsed();
                 tracing::error!("eBay get_item_priority_feed error after {:?}: {:?}", total_duration, e);
-                Err(HermesError::ApiRequest(format!("eBay get_item_priority_feed failed: {:?}", e)))
+                Err(raw_error("get_item_priority_feed", &format!("category_id {}", category_id), e))
             }
         }
     }
@@ -199,26 +225,29 @@ impl FeedClient {
         let token_duration = token_start.elapsed();
         tracing::info!("OAuth token request for get_item_snapshot_feed: {:?}", token_duration);
         
-        // Set up configuration
-        let mut config = FeedConfiguration::new();
-        config.base_path = if self.config.sandbox {
-            "https://api.sandbox.ebay.com/buy/feed/v1".to_string()
-        } else {
-            "https://api.ebay.com/buy/feed/v1".to_string()
-        };
-        config.oauth_access_token = Some(token);
+        let url = format!("{}/buy/feed/v1/item_snapshot", self.config.base_url());
+        let http = self.config.http_client();
+        
+        // Fail fast while this API's circuit breaker is open
+        let probe = self.config.check_circuit("buy.feed")?;
+        
+        let marketplace_id = normalize_marketplace(marketplace_id, MarketplaceStyle::Underscore);
         
-        // Call the eBay SDK
+        // Call the eBay API
         let ebay_start = std::time::Instant::now();
-        let result = hermes_ebay_buy_feed::apis::item_snapshot_api::get_item_snapshot_feed(
-            &config,
-            "application/gzip", // accept
-            marketplace_id,     // x_ebay_c_marketplace_id
-            range,             // range
-            category_id,       // category_id
-            snapshot_date,     // snapshot_date
-        ).await;
+        let result = self.config.retrying("buy.feed", || {
+            self.config.send_json(
+                http.get(&url)
+                    .bearer_auth(&token)
+                    .query(&[("category_id", category_id), ("snapshot_date", snapshot_date)])
+                    .header(reqwest::header::ACCEPT, "application/gzip")
+                    .header("X-EBAY-C-MARKETPLACE-ID", &marketplace_id)
+                    .header(reqwest::header::RANGE, range),
+            )
+        }).await;
         let ebay_duration = ebay_start.elapsed();
+        record_api_call("buy.feed", "get_item_snapshot_feed", &result, ebay_duration);
+        self.config.record_circuit("buy.feed", &probe, &result);
         tracing::info!("eBay get_item_snapshot_feed API call: {:?}", ebay_duration);
         
         match result {
@@ -231,8 +260,204 @@ impl FeedClient {
             Err(e) => {
                 let total_duration = start_time.elapsed();
                 tracing::error!("eBay get_item_snapshot_feed error after {:?}: {:?}", total_duration, e);
-                Err(HermesError::ApiRequest(format!("eBay get_item_snapshot_feed failed: {:?}", e)))
+                Err(raw_error("get_item_snapshot_feed", &format!("category_id {}", category_id), e))
+            }
+        }
+    }
+}
+
+/// A series of feed downloads sharing one byte cap
+/// 
+/// Feed files are fetched in chunks with HTTP range requests. The session counts
+/// every byte received, across chunks and files, and fails with
+/// [`HermesError::QuotaExceeded`] as soon as the count passes the cap, without
+/// waiting for the current chunk to finish.
+pub struct FeedSession<'a> {
+    client: &'a FeedClient,
+    max_bytes: u64,
+    chunk_bytes: u64,
+    downloaded: u64,
+}
+
+impl FeedSession<'_> {
+    /// Request `chunk_bytes` per range request instead of [`FEED_CHUNK_BYTES`]
+    pub fn with_chunk_bytes(mut self, chunk_bytes: u64) -> Self {
+        self.chunk_bytes = chunk_bytes.max(1);
+        self
+    }
+
+    /// Bytes received so far in this session
+    pub fn downloaded_bytes(&self) -> u64 {
+        self.downloaded
+    }
+
+    /// Bytes left before the cap is reached
+    pub fn remaining_bytes(&self) -> u64 {
+        self.max_bytes.saturating_sub(self.downloaded)
+    }
+
+    /// Download a whole item feed file
+    /// 
+    /// Returns the file as eBay serves it (a gzipped TSV file).
+    /// 
+    /// # Arguments
+    /// * `marketplace_id` - Marketplace of the feed, e.g. "EBAY_US"
+    /// * `feed_scope` - "NEWLY_LISTED" (daily file) or "ALL_ACTIVE" (weekly file)
+    /// * `category_id` - Top-level category of the feed
+    /// * `date` - Day of a daily file, as "yyyyMMdd"
+    pub async fn download_item_feed(
+        &mut self,
+        marketplace_id: &str,
+        feed_scope: &str,
+        category_id: &str,
+        date: Option<&str>,
+    ) -> HermesResult<Vec<u8>> {
+        let start_time = std::time::Instant::now();
+        let config = &self.client.config;
+
+        // Get access token
+        let token_start = std::time::Instant::now();
+        let token = self.client.auth.get_access_token().await?;
+        let token_duration = token_start.elapsed();
+        tracing::info!("OAuth token request for download_item_feed: {:?}", token_duration);
+
+        let marketplace_id = normalize_marketplace(marketplace_id, MarketplaceStyle::Underscore);
+        let url = format!("{}/buy/feed/v1/item", config.base_url());
+        let mut query = vec![("feed_scope", feed_scope), ("category_id", category_id)];
+        if let Some(date) = date {
+            query.push(("date", date));
+        }
+        let http = config.http_client();
+
+        let mut file = Vec::new();
+        loop {
+            let offset = file.len() as u64;
+            let range = format!("bytes={}-{}", offset, offset + self.chunk_bytes - 1);
+
+            // Fail fast while this API's circuit breaker is open
+            let probe = config.check_circuit("buy.feed")?;
+
+            let ebay_start = std::time::Instant::now();
+            let result = config
+                .retrying("buy.feed", || {
+                    send(
+                        http.get(&url)
+                            .bearer_auth(&token)
+                            .query(&query)
+                            .header("X-EBAY-C-MARKETPLACE-ID", marketplace_id.as_str())
+                            .header(reqwest::header::ACCEPT, "application/gzip")
+                            .header(reqwest::header::RANGE, range.as_str()),
+                        config,
+                    )
+                })
+                .await;
+            let ebay_duration = ebay_start.elapsed();
+            record_api_call("buy.feed", "download_item_feed", &result, ebay_duration);
+            config.record_circuit("buy.feed", &probe, &result);
+            tracing::info!("eBay download_item_feed chunk {}: {:?}", range, ebay_duration);
+
+            let response = match result {
+                Ok(response) => response,
+                Err(e) => {
+                    tracing::error!("eBay download_item_feed error after {:?}: {:?}", start_time.elapsed(), e);
+                    return Err(raw_error("download_item_feed", &format!("category_id {}", category_id), e));
+                }
+            };
+            let partial = response.status() == reqwest::StatusCode::PARTIAL_CONTENT;
+            let total = content_range_total(&response);
+            self.read_chunk(response, &mut file).await?;
+
+            // A 200 carries the whole file; stop on an empty chunk rather than loop forever
+            let done = !partial || file.len() as u64 == offset || total.is_none_or(|total| file.len() as u64 >= total);
+            if done {
+                break;
+            }
+        }
+
+        tracing::info!("download_item_feed total: {:?} ({} bytes)", start_time.elapsed(), file.len());
+        Ok(file)
+    }
+
+    /// Append a response body to `file`, failing once the session's cap is passed
+    /// 
+    /// A single response longer than `max_response_bytes` fails as well.
+    async fn read_chunk(&mut self, mut response: reqwest::Response, file: &mut Vec<u8>) -> HermesResult<()> {
+        let limit = self.client.config.max_response_bytes;
+        let mut received = 0;
+        while let Some(chunk) = response.chunk().await? {
+            received += chunk.len();
+            if let Some(limit) = limit.filter(|&limit| received > limit) {
+                tracing::warn!("Feed chunk passed max_response_bytes of {}; aborting download", limit);
+                return Err(HermesError::ResponseTooLarge { limit });
+            }
+            self.downloaded += chunk.len() as u64;
+            if self.downloaded > self.max_bytes {
+                tracing::warn!("Feed session passed its cap of {} bytes; aborting download", self.max_bytes);
+                return Err(HermesError::QuotaExceeded { limit: self.max_bytes });
             }
+            file.extend_from_slice(&chunk);
         }
+        Ok(())
+    }
+}
+
+/// Send a feed request, returning the response unread on success
+async fn send(
+    request: reqwest::RequestBuilder,
+    config: &EbayConfig,
+) -> Result<reqwest::Response, RawRequestError> {
+    let response = send_logged(config, request).await.map_err(RawRequestError::Transport)?;
+    let status = response.status();
+    if status.is_success() {
+        return Ok(response);
     }
-}
\ No newline at end of file
+    let retry_after = retry_after(&response);
+    let body = read_body(response, config.max_response_bytes).await?;
+    Err(RawRequestError::Response { status: status.as_u16(), body, retry_after })
+}
+
+/// Size of the whole file from a `Content-Range: bytes 0-99/1000` header
+fn content_range_total(response: &reqwest::Response) -> Option<u64> {
+    let value = response.headers().get(reqwest::header::CONTENT_RANGE)?.to_str().ok()?;
+    value.rsplit('/').next()?.trim().parse().ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ebay::test_support::mock_ebay;
+    use wiremock::matchers::{header, method, path, query_param};
+    use wiremock::{Mock, ResponseTemplate};
+
+    #[tokio::test]
+    async fn feed_session_aborts_once_cap_is_crossed() {
+        let (server, config) = mock_ebay().await;
+        let file: Vec<u8> = (0..25).collect();
+        for (range, start, end) in [("bytes=0-9", 0, 10), ("bytes=10-19", 10, 20), ("bytes=20-29", 20, 25)] {
+            Mock::given(method("GET"))
+                .and(path("/buy/feed/v1/item"))
+                .and(query_param("feed_scope", "NEWLY_LISTED"))
+                .and(query_param("category_id", "1"))
+                .and(header("Range", range))
+                .respond_with(
+                    ResponseTemplate::new(206)
+                        .insert_header("Content-Range", format!("bytes {}-{}/25", start, end - 1))
+                        .set_body_bytes(file[start..end].to_vec()),
+                )
+                .mount(&server)
+                .await;
+        }
+
+        let client = FeedClient::new(config).unwrap();
+        let mut session = client.session(100).with_chunk_bytes(10);
+        let downloaded = session.download_item_feed("EBAY_US", "NEWLY_LISTED", "1", None).await.unwrap();
+        assert_eq!(downloaded, file);
+        assert_eq!(session.downloaded_bytes(), 25);
+
+        // The cap is crossed partway through the second chunk
+        let mut capped = client.session(15).with_chunk_bytes(10);
+        let err = capped.download_item_feed("EBAY_US", "NEWLY_LISTED", "1", None).await.unwrap_err();
+        assert!(matches!(err, HermesError::QuotaExceeded { limit: 15 }));
+        assert_eq!(capped.remaining_bytes(), 0);
+    }
+}
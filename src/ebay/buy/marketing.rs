@@ -1,11 +1,12 @@
 use crate::config::EbayConfig;
-use crate::error::{HermesError, HermesResult};
+use crate::error::HermesResult;
+use crate::ebay::api_error::raw_error;
+use crate::ebay::telemetry::record_api_call;
 use crate::ebay::auth::EbayAuth;
 use std::sync::Arc;
 
-// Import eBay Buy Marketing SDK models and APIs
+// Import eBay Buy Marketing SDK models
 use hermes_ebay_buy_marketing::models::BestSellingProductResponse;
-use hermes_ebay_buy_marketing::apis::configuration::Configuration as MarketingConfiguration;
 
 /// eBay Buy Marketing API client for merchandised products and promotions
 /// 
@@ -50,25 +51,25 @@ impl MarketingClient {
         let token_duration = token_start.elapsed();
         tracing::info!("OAuth token request for get_merchandised_products: {:?}", token_duration);
         
-        // Set up configuration
-        let mut config = MarketingConfiguration::new();
-        config.base_path = if self.config.sandbox {
-            "https://api.sandbox.ebay.com/buy/marketing/v1".to_string()
-        } else {
-            "https://api.ebay.com/buy/marketing/v1".to_string()
-        };
-        config.oauth_access_token = Some(token);
+        let url = format!("{}/buy/marketing/v1/merchandised_product", self.config.base_url());
+        let http = self.config.http_client();
         
-        // Call the eBay SDK
+        // Fail fast while this API's circuit breaker is open
+        let probe = self.config.check_circuit("buy.marketing")?;
+        
+        // Call the eBay API
         let ebay_start = std::time::Instant::now();
-        let result = hermes_ebay_buy_marketing::apis::merchandised_product_api::get_merchandised_products(
-            &config,
-            category_id,
-            metric_name,
-            aspect_filter,
-            limit,
-        ).await;
+        let result = self.config.retrying("buy.marketing", || {
+            self.config.send_json(http.get(&url).bearer_auth(&token).query(&[
+                ("aspect_filter", aspect_filter),
+                ("category_id", Some(category_id)),
+                ("limit", limit),
+                ("metric_name", Some(metric_name)),
+            ]))
+        }).await;
         let ebay_duration = ebay_start.elapsed();
+        record_api_call("buy.marketing", "get_merchandised_products", &result, ebay_duration);
+        self.config.record_circuit("buy.marketing", &probe, &result);
         tracing::info!("eBay get_merchandised_products API call: {:?}", ebay_duration);
         
         match result {
@@ -81,7 +82,7 @@ impl MarketingClient {
             Err(e) => {
                 let total_duration = start_time.elapsed();
                 tracing::error!("eBay get_merchandised_products error after {:?}: {:?}", total_duration, e);
-                Err(HermesError::ApiRequest(format!("eBay get_merchandised_products failed: {:?}", e)))
+                Err(raw_error("get_merchandised_products", &format!("category_id {}", category_id), e))
             }
         }
     }
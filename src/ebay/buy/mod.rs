@@ -6,7 +6,7 @@ pub mod offer;
 pub mod order;
 
 // Re-export commonly used types
-pub use feed::FeedClient;
+pub use feed::{FeedClient, FeedSession, FEED_CHUNK_BYTES};
 pub use marketing::MarketingClient;
 pub use offer::OfferClient;
-pub use order::OrderClient;
\ No newline at end of file
+pub use order::{CheckoutSessionExt, GuestPurchaseOrderExt, OrderClient, PricingBreakdown, PurchaseLineItem};
\ No newline at end of file
@@ -1,11 +1,14 @@
 use crate::config::EbayConfig;
-use crate::error::{HermesError, HermesResult};
+use crate::error::HermesResult;
+use crate::ebay::api_error::raw_error;
+use crate::ebay::telemetry::record_api_call;
 use crate::ebay::auth::EbayAuth;
+use crate::ebay::marketplace::{normalize_marketplace, MarketplaceStyle};
 use std::sync::Arc;
 
 // Import eBay Buy Offer SDK models and APIs
 use hermes_ebay_buy_offer::models::{Bidding, PlaceProxyBidRequest, PlaceProxyBidResponse};
-use hermes_ebay_buy_offer::apis::configuration::Configuration as OfferConfiguration;
+use hermes_ebay_buy_offer::apis::urlencode;
 
 /// eBay Buy Offer API client for bidding and auction operations
 /// 
@@ -46,23 +49,22 @@ impl OfferClient {
         let token_duration = token_start.elapsed();
         tracing::info!("OAuth token request for get_bidding: {:?}", token_duration);
         
-        // Set up configuration
-        let mut config = OfferConfiguration::new();
-        config.base_path = if self.config.sandbox {
-            "https://api.sandbox.ebay.com/buy/offer/v1".to_string()
-        } else {
-            "https://api.ebay.com/buy/offer/v1".to_string()
-        };
-        config.oauth_access_token = Some(token);
+        let url = format!("{}/buy/offer/v1/bidding/{}", self.config.base_url(), urlencode(item_id));
+        let http = self.config.http_client();
         
-        // Call the eBay SDK
+        // Fail fast while this API's circuit breaker is open
+        let probe = self.config.check_circuit("buy.offer")?;
+        
+        let marketplace_id = normalize_marketplace(marketplace_id, MarketplaceStyle::Underscore);
+        
+        // Call the eBay API
         let ebay_start = std::time::Instant::now();
-        let result = hermes_ebay_buy_offer::apis::bidding_api::get_bidding(
-            &config,
-            item_id,
-            marketplace_id,
-        ).await;
+        let result = self.config.retrying("buy.offer", || {
+            self.config.send_json(http.get(&url).bearer_auth(&token).header("X-EBAY-C-MARKETPLACE-ID", &marketplace_id))
+        }).await;
         let ebay_duration = ebay_start.elapsed();
+        record_api_call("buy.offer", "get_bidding", &result, ebay_duration);
+        self.config.record_circuit("buy.offer", &probe, &result);
         tracing::info!("eBay get_bidding API call: {:?}", ebay_duration);
         
         match result {
@@ -75,7 +77,7 @@ impl OfferClient {
             Err(e) => {
                 let total_duration = start_time.elapsed();
                 tracing::error!("eBay get_bidding error after {:?}: {:?}", total_duration, e);
-                Err(HermesError::ApiRequest(format!("eBay get_bidding failed: {:?}", e)))
+                Err(raw_error("get_bidding", &format!("item_id {}", item_id), e))
             }
         }
     }
@@ -95,6 +97,11 @@ impl OfferClient {
         marketplace_id: &str,
         bid_request: &PlaceProxyBidRequest,
     ) -> HermesResult<PlaceProxyBidResponse> {
+        // Dry-run mode logs the request instead of sending it
+        if let Some(response) = self.config.skip_in_dry_run("place_proxy_bid", &(item_id, marketplace_id, bid_request)) {
+            return Ok(response);
+        }
+
         let start_time = std::time::Instant::now();
         
         // Get access token
@@ -103,25 +110,23 @@ impl OfferClient {
         let token_duration = token_start.elapsed();
         tracing::info!("OAuth token request for place_proxy_bid: {:?}", token_duration);
         
-        // Set up configuration
-        let mut config = OfferConfiguration::new();
-        config.base_path = if self.config.sandbox {
-            "https://api.sandbox.ebay.com/buy/offer/v1".to_string()
-        } else {
-            "https://api.ebay.com/buy/offer/v1".to_string()
-        };
-        config.oauth_access_token = Some(token);
+        let url = format!("{}/buy/offer/v1/bidding/{}/place_proxy_bid", self.config.base_url(), urlencode(item_id));
+        let http = self.config.http_client();
+        
+        // Fail fast while this API's circuit breaker is open
+        let probe = self.config.check_circuit("buy.offer")?;
         
-        // Call the eBay SDK
+        // Call the eBay API
         let ebay_start = std::time::Instant::now();
-        let result = hermes_ebay_buy_offer::apis::bidding_api::place_proxy_bid(
-            &config,
-            item_id,
-            marketplace_id,
-            "application/json",
-            Some(bid_request.clone()),
+        let result = self.config.send_json(
+            http.post(&url)
+                .bearer_auth(&token)
+                .header("X-EBAY-C-MARKETPLACE-ID", normalize_marketplace(marketplace_id, MarketplaceStyle::Underscore))
+                .json(bid_request),
         ).await;
         let ebay_duration = ebay_start.elapsed();
+        record_api_call("buy.offer", "place_proxy_bid", &result, ebay_duration);
+        self.config.record_circuit("buy.offer", &probe, &result);
         tracing::info!("eBay place_proxy_bid API call: {:?}", ebay_duration);
         
         match result {
@@ -134,7 +139,7 @@ impl OfferClient {
             Err(e) => {
                 let total_duration = start_time.elapsed();
                 tracing::error!("eBay place_proxy_bid error after {:?}: {:?}", total_duration, e);
-                Err(HermesError::ApiRequest(format!("eBay place_proxy_bid failed: {:?}", e)))
+                Err(raw_error("place_proxy_bid", &format!("item_id {}", item_id), e))
             }
         }
     }
@@ -1,14 +1,134 @@
 use crate::config::EbayConfig;
-use crate::error::{HermesError, HermesResult};
+use crate::error::HermesResult;
+use crate::ebay::api_error::raw_error;
+use crate::ebay::telemetry::record_api_call;
 use crate::ebay::auth::EbayAuth;
+use crate::ebay::marketplace::{normalize_marketplace, MarketplaceStyle};
+use crate::money::parse_decimal;
+use rust_decimal::Decimal;
 use std::sync::Arc;
 
 // Import eBay Buy Order SDK models and APIs
 use hermes_ebay_buy_order::models::{
     GuestCheckoutSessionResponseV2, CreateGuestCheckoutSessionRequestV2, CouponRequest,
-    UpdateQuantity, ShippingAddressImpl, UpdateShippingOption, GuestPurchaseOrderV2,
+    UpdateQuantity, ShippingAddressImpl, UpdateShippingOption, GuestPurchaseOrderV2, Amount,
 };
-use hermes_ebay_buy_order::apis::configuration::Configuration as OrderConfiguration;
+use hermes_ebay_buy_order::apis::urlencode;
+
+/// One line of a purchase order, flattened for display
+#[derive(Debug, Clone, PartialEq)]
+pub struct PurchaseLineItem {
+    pub title: String,
+    pub quantity: u32,
+    pub unit_price: Option<Decimal>,
+    /// Price of the line after discounts, for the quantity purchased
+    pub line_total: Option<Decimal>,
+    pub currency: Option<String>,
+}
+
+/// Convenience accessors for a [`GuestPurchaseOrderV2`]
+pub trait GuestPurchaseOrderExt {
+    /// The order's line items with title, quantity, unit price and line total
+    fn line_items_summary(&self) -> Vec<PurchaseLineItem>;
+
+    /// The order total and its currency
+    fn order_total(&self) -> Option<(Decimal, String)>;
+}
+
+impl GuestPurchaseOrderExt for GuestPurchaseOrderV2 {
+    fn line_items_summary(&self) -> Vec<PurchaseLineItem> {
+        self.line_items
+            .iter()
+            .flatten()
+            .map(|line_item| {
+                let quantity = line_item.quantity.unwrap_or(0).max(0) as u32;
+                let unit_price = parse_decimal(line_item.base_unit_price.as_deref().and_then(|price| price.value.as_deref()));
+                // eBay reports the net price when discounts apply; otherwise derive it
+                let line_total = parse_decimal(line_item.net_price.as_deref().and_then(|price| price.value.as_deref()))
+                    .or_else(|| unit_price.map(|price| price * Decimal::from(quantity)));
+                let currency = line_item
+                    .base_unit_price
+                    .as_deref()
+                    .or(line_item.net_price.as_deref())
+                    .and_then(|amount| amount.currency.clone());
+
+                PurchaseLineItem {
+                    title: line_item.title.clone().unwrap_or_default(),
+                    quantity,
+                    unit_price,
+                    line_total,
+                    currency,
+                }
+            })
+            .collect()
+    }
+
+    fn order_total(&self) -> Option<(Decimal, String)> {
+        let total = self.pricing_summary.as_ref()?.total.as_deref()?;
+        Some((parse_decimal(total.value.as_deref())?, total.currency.clone()?))
+    }
+}
+
+/// Price components of a checkout session, for a cart summary
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct PricingBreakdown {
+    /// Item cost before discounts, shipping and tax
+    pub subtotal: Option<Decimal>,
+    pub shipping: Option<Decimal>,
+    pub tax: Option<Decimal>,
+    /// Coupon and other savings, as a positive amount
+    pub discounts: Option<Decimal>,
+    pub total: Option<Decimal>,
+    pub currency: Option<String>,
+}
+
+/// Checkout summary accessors for a [`GuestCheckoutSessionResponseV2`]
+pub trait CheckoutSessionExt {
+    /// Subtotal, shipping, tax, discounts and total of the session
+    fn pricing_breakdown(&self) -> PricingBreakdown;
+
+    /// Redemption codes of the coupons applied to the session
+    fn applied_coupons(&self) -> Vec<String>;
+}
+
+impl CheckoutSessionExt for GuestCheckoutSessionResponseV2 {
+    fn pricing_breakdown(&self) -> PricingBreakdown {
+        let Some(pricing) = self.pricing_summary.as_deref() else {
+            return PricingBreakdown::default();
+        };
+        let value = |amount: &Option<Box<Amount>>| parse_decimal(amount.as_deref().and_then(|amount| amount.value.as_deref()));
+        let discounts = [value(&pricing.price_discount), value(&pricing.additional_savings)]
+            .into_iter()
+            .flatten()
+            .map(|discount| discount.abs())
+            .reduce(|total, discount| total + discount);
+
+        PricingBreakdown {
+            subtotal: value(&pricing.price_subtotal),
+            shipping: value(&pricing.delivery_cost),
+            tax: value(&pricing.tax),
+            discounts,
+            total: value(&pricing.total),
+            currency: pricing.total.as_deref().and_then(|total| total.currency.clone()),
+        }
+    }
+
+    fn applied_coupons(&self) -> Vec<String> {
+        self.applied_coupons
+            .iter()
+            .flatten()
+            .filter_map(|coupon| coupon.redemption_code.clone())
+            .collect()
+    }
+}
+
+/// Add the `X-EBAY-C-ENDUSERCTX` header, when an end-user context is given
+fn with_end_user_ctx(request: reqwest::RequestBuilder, end_user_ctx: Option<&str>) -> reqwest::RequestBuilder {
+    match end_user_ctx {
+        Some(end_user_ctx) => request.header("X-EBAY-C-ENDUSERCTX", end_user_ctx),
+        None => request,
+    }
+}
 
 /// eBay Buy Order API client for guest checkout and order management
 /// 
@@ -44,6 +164,11 @@ impl OrderClient {
         checkout_request: &CreateGuestCheckoutSessionRequestV2,
         end_user_ctx: Option<&str>,
     ) -> HermesResult<GuestCheckoutSessionResponseV2> {
+        // Dry-run mode logs the request instead of sending it
+        if let Some(response) = self.config.skip_in_dry_run("initiate_guest_checkout_session", &(marketplace_id, checkout_request)) {
+            return Ok(response);
+        }
+
         let start_time = std::time::Instant::now();
         
         // Get access token
@@ -52,25 +177,26 @@ impl OrderClient {
         let token_duration = token_start.elapsed();
         tracing::info!("OAuth token request for initiate_guest_checkout_session: {:?}", token_duration);
         
-        // Set up configuration
-        let mut config = OrderConfiguration::new();
-        config.base_path = if self.config.sandbox {
-            "https://api.sandbox.ebay.com/buy/order/v1".to_string()
-        } else {
-            "https://api.ebay.com/buy/order/v1".to_string()
-        };
-        config.oauth_access_token = Some(token);
+        let url = format!("{}/buy/order/v1/guest_checkout_session/initiate", self.config.base_url());
+        let http = self.config.http_client();
         
-        // Call the eBay SDK
+        // Fail fast while this API's circuit breaker is open
+        let probe = self.config.check_circuit("buy.order")?;
+        
+        // Call the eBay API
         let ebay_start = std::time::Instant::now();
-        let result = hermes_ebay_buy_order::apis::guest_checkout_session_api::initiate_guest_checkout_session(
-            &config,
-            marketplace_id,
-            "application/json",
-            end_user_ctx,
-            Some(checkout_request.clone()),
+        let result = self.config.send_json(
+            with_end_user_ctx(
+                http.post(&url)
+                    .bearer_auth(&token)
+                    .header("X-EBAY-C-MARKETPLACE-ID", normalize_marketplace(marketplace_id, MarketplaceStyle::Underscore))
+                    .json(checkout_request),
+                end_user_ctx,
+            ),
         ).await;
         let ebay_duration = ebay_start.elapsed();
+        record_api_call("buy.order", "initiate_guest_checkout_session", &result, ebay_duration);
+        self.config.record_circuit("buy.order", &probe, &result);
         tracing::info!("eBay initiate_guest_checkout_session API call: {:?}", ebay_duration);
         
         match result {
@@ -83,7 +209,7 @@ impl OrderClient {
             Err(e) => {
                 let total_duration = start_time.elapsed();
                 tracing::error!("eBay initiate_guest_checkout_session error after {:?}: {:?}", total_duration, e);
-                Err(HermesError::ApiRequest(format!("eBay initiate_guest_checkout_session failed: {:?}", e)))
+                Err(raw_error("initiate_guest_checkout_session", &format!("marketplace_id {}", marketplace_id), e))
             }
         }
     }
@@ -111,24 +237,27 @@ impl OrderClient {
         let token_duration = token_start.elapsed();
         tracing::info!("OAuth token request for get_guest_checkout_session: {:?}", token_duration);
         
-        // Set up configuration
-        let mut config = OrderConfiguration::new();
-        config.base_path = if self.config.sandbox {
-            "https://api.sandbox.ebay.com/buy/order/v1".to_string()
-        } else {
-            "https://api.ebay.com/buy/order/v1".to_string()
-        };
-        config.oauth_access_token = Some(token);
+        let url = format!("{}/buy/order/v1/guest_checkout_session/{}", self.config.base_url(), urlencode(checkout_session_id));
+        let http = self.config.http_client();
+        
+        // Fail fast while this API's circuit breaker is open
+        let probe = self.config.check_circuit("buy.order")?;
         
-        // Call the eBay SDK
+        let marketplace_id = normalize_marketplace(marketplace_id, MarketplaceStyle::Underscore);
+        
+        // Call the eBay API
         let ebay_start = std::time::Instant::now();
-        let result = hermes_ebay_buy_order::apis::guest_checkout_session_api::get_guest_checkout_session(
-            &config,
-            &checkout_session_id,
-            marketplace_id,
-            end_user_ctx,
-        ).await;
+        let result = self.config.retrying("buy.order", || {
+            self.config.send_json(
+                with_end_user_ctx(
+                    http.get(&url).bearer_auth(&token).header("X-EBAY-C-MARKETPLACE-ID", &marketplace_id),
+                    end_user_ctx,
+                ),
+            )
+        }).await;
         let ebay_duration = ebay_start.elapsed();
+        record_api_call("buy.order", "get_guest_checkout_session", &result, ebay_duration);
+        self.config.record_circuit("buy.order", &probe, &result);
         tracing::info!("eBay get_guest_checkout_session API call: {:?}", ebay_duration);
         
         match result {
@@ -141,7 +270,7 @@ impl OrderClient {
             Err(e) => {
                 let total_duration = start_time.elapsed();
                 tracing::error!("eBay get_guest_checkout_session error after {:?}: {:?}", total_duration, e);
-                Err(HermesError::ApiRequest(format!("eBay get_guest_checkout_session failed: {:?}", e)))
+                Err(raw_error("get_guest_checkout_session", &format!("checkout_session_id {}", checkout_session_id), e))
             }
         }
     }
@@ -162,6 +291,11 @@ impl OrderClient {
         coupon_request: &CouponRequest,
         end_user_ctx: Option<&str>,
     ) -> HermesResult<GuestCheckoutSessionResponseV2> {
+        // Dry-run mode logs the request instead of sending it
+        if let Some(response) = self.config.skip_in_dry_run("apply_guest_coupon", &(checkout_session_id, coupon_request)) {
+            return Ok(response);
+        }
+
         let start_time = std::time::Instant::now();
         
         // Get access token
@@ -170,26 +304,26 @@ impl OrderClient {
         let token_duration = token_start.elapsed();
         tracing::info!("OAuth token request for apply_guest_coupon: {:?}", token_duration);
         
-        // Set up configuration
-        let mut config = OrderConfiguration::new();
-        config.base_path = if self.config.sandbox {
-            "https://api.sandbox.ebay.com/buy/order/v1".to_string()
-        } else {
-            "https://api.ebay.com/buy/order/v1".to_string()
-        };
-        config.oauth_access_token = Some(token);
+        let url = format!("{}/buy/order/v1/guest_checkout_session/{}/apply_coupon", self.config.base_url(), urlencode(checkout_session_id));
+        let http = self.config.http_client();
+        
+        // Fail fast while this API's circuit breaker is open
+        let probe = self.config.check_circuit("buy.order")?;
         
-        // Call the eBay SDK
+        // Call the eBay API
         let ebay_start = std::time::Instant::now();
-        let result = hermes_ebay_buy_order::apis::guest_checkout_session_api::apply_guest_coupon(
-            &config,
-            &checkout_session_id,
-            marketplace_id,
-            "application/json",
-            end_user_ctx,
-            Some(coupon_request.clone()),
+        let result = self.config.send_json(
+            with_end_user_ctx(
+                http.post(&url)
+                    .bearer_auth(&token)
+                    .header("X-EBAY-C-MARKETPLACE-ID", normalize_marketplace(marketplace_id, MarketplaceStyle::Underscore))
+                    .json(coupon_request),
+                end_user_ctx,
+            ),
         ).await;
         let ebay_duration = ebay_start.elapsed();
+        record_api_call("buy.order", "apply_guest_coupon", &result, ebay_duration);
+        self.config.record_circuit("buy.order", &probe, &result);
         tracing::info!("eBay apply_guest_coupon API call: {:?}", ebay_duration);
         
         match result {
@@ -202,7 +336,7 @@ impl OrderClient {
             Err(e) => {
                 let total_duration = start_time.elapsed();
                 tracing::error!("eBay apply_guest_coupon error after {:?}: {:?}", total_duration, e);
-                Err(HermesError::ApiRequest(format!("eBay apply_guest_coupon failed: {:?}", e)))
+                Err(raw_error("apply_guest_coupon", &format!("checkout_session_id {}", checkout_session_id), e))
             }
         }
     }
@@ -223,6 +357,11 @@ impl OrderClient {
         coupon_request: &CouponRequest,
         end_user_ctx: Option<&str>,
     ) -> HermesResult<GuestCheckoutSessionResponseV2> {
+        // Dry-run mode logs the request instead of sending it
+        if let Some(response) = self.config.skip_in_dry_run("remove_guest_coupon", &(checkout_session_id, coupon_request)) {
+            return Ok(response);
+        }
+
         let start_time = std::time::Instant::now();
         
         // Get access token
@@ -231,26 +370,26 @@ impl OrderClient {
         let token_duration = token_start.elapsed();
         tracing::info!("OAuth token request for remove_guest_coupon: {:?}", token_duration);
         
-        // Set up configuration
-        let mut config = OrderConfiguration::new();
-        config.base_path = if self.config.sandbox {
-            "https://api.sandbox.ebay.com/buy/order/v1".to_string()
-        } else {
-            "https://api.ebay.com/buy/order/v1".to_string()
-        };
-        config.oauth_access_token = Some(token);
+        let url = format!("{}/buy/order/v1/guest_checkout_session/{}/remove_coupon", self.config.base_url(), urlencode(checkout_session_id));
+        let http = self.config.http_client();
         
-        // Call the eBay SDK
+        // Fail fast while this API's circuit breaker is open
+        let probe = self.config.check_circuit("buy.order")?;
+        
+        // Call the eBay API
         let ebay_start = std::time::Instant::now();
-        let result = hermes_ebay_buy_order::apis::guest_checkout_session_api::remove_guest_coupon(
-            &config,
-            &checkout_session_id,
-            marketplace_id,
-            "application/json",
-            end_user_ctx,
-            Some(coupon_request.clone()),
+        let result = self.config.send_json(
+            with_end_user_ctx(
+                http.post(&url)
+                    .bearer_auth(&token)
+                    .header("X-EBAY-C-MARKETPLACE-ID", normalize_marketplace(marketplace_id, MarketplaceStyle::Underscore))
+                    .json(coupon_request),
+                end_user_ctx,
+            ),
         ).await;
         let ebay_duration = ebay_start.elapsed();
+        record_api_call("buy.order", "remove_guest_coupon", &result, ebay_duration);
+        self.config.record_circuit("buy.order", &probe, &result);
         tracing::info!("eBay remove_guest_coupon API call: {:?}", ebay_duration);
         
         match result {
@@ -263,7 +402,7 @@ impl OrderClient {
             Err(e) => {
                 let total_duration = start_time.elapsed();
                 tracing::error!("eBay remove_guest_coupon error after {:?}: {:?}", total_duration, e);
-                Err(HermesError::ApiRequest(format!("eBay remove_guest_coupon failed: {:?}", e)))
+                Err(raw_error("remove_guest_coupon", &format!("checkout_session_id {}", checkout_session_id), e))
             }
         }
     }
@@ -284,6 +423,11 @@ impl OrderClient {
         update_quantity: &UpdateQuantity,
         end_user_ctx: Option<&str>,
     ) -> HermesResult<GuestCheckoutSessionResponseV2> {
+        // Dry-run mode logs the request instead of sending it
+        if let Some(response) = self.config.skip_in_dry_run("update_guest_quantity", &(checkout_session_id, update_quantity)) {
+            return Ok(response);
+        }
+
         let start_time = std::time::Instant::now();
         
         // Get access token
@@ -292,26 +436,26 @@ impl OrderClient {
         let token_duration = token_start.elapsed();
         tracing::info!("OAuth token request for update_guest_quantity: {:?}", token_duration);
         
-        // Set up configuration
-        let mut config = OrderConfiguration::new();
-        config.base_path = if self.config.sandbox {
-            "https://api.sandbox.ebay.com/buy/order/v1".to_string()
-        } else {
-            "https://api.ebay.com/buy/order/v1".to_string()
-        };
-        config.oauth_access_token = Some(token);
+        let url = format!("{}/buy/order/v1/guest_checkout_session/{}/update_quantity", self.config.base_url(), urlencode(checkout_session_id));
+        let http = self.config.http_client();
+        
+        // Fail fast while this API's circuit breaker is open
+        let probe = self.config.check_circuit("buy.order")?;
         
-        // Call the eBay SDK
+        // Call the eBay API
         let ebay_start = std::time::Instant::now();
-        let result = hermes_ebay_buy_order::apis::guest_checkout_session_api::update_guest_quantity(
-            &config,
-            &checkout_session_id,
-            marketplace_id,
-            "application/json",
-            end_user_ctx,
-            Some(update_quantity.clone()),
+        let result = self.config.send_json(
+            with_end_user_ctx(
+                http.post(&url)
+                    .bearer_auth(&token)
+                    .header("X-EBAY-C-MARKETPLACE-ID", normalize_marketplace(marketplace_id, MarketplaceStyle::Underscore))
+                    .json(update_quantity),
+                end_user_ctx,
+            ),
         ).await;
         let ebay_duration = ebay_start.elapsed();
+        record_api_call("buy.order", "update_guest_quantity", &result, ebay_duration);
+        self.config.record_circuit("buy.order", &probe, &result);
         tracing::info!("eBay update_guest_quantity API call: {:?}", ebay_duration);
         
         match result {
@@ -324,7 +468,7 @@ impl OrderClient {
             Err(e) => {
                 let total_duration = start_time.elapsed();
                 tracing::error!("eBay update_guest_quantity error after {:?}: {:?}", total_duration, e);
-                Err(HermesError::ApiRequest(format!("eBay update_guest_quantity failed: {:?}", e)))
+                Err(raw_error("update_guest_quantity", &format!("checkout_session_id {}", checkout_session_id), e))
             }
         }
     }
@@ -345,6 +489,11 @@ impl OrderClient {
         shipping_address: &ShippingAddressImpl,
         end_user_ctx: Option<&str>,
     ) -> HermesResult<GuestCheckoutSessionResponseV2> {
+        // Dry-run mode logs the request instead of sending it
+        if let Some(response) = self.config.skip_in_dry_run("update_guest_shipping_address", &(checkout_session_id, shipping_address)) {
+            return Ok(response);
+        }
+
         let start_time = std::time::Instant::now();
         
         // Get access token
@@ -353,26 +502,26 @@ impl OrderClient {
         let token_duration = token_start.elapsed();
         tracing::info!("OAuth token request for update_guest_shipping_address: {:?}", token_duration);
         
-        // Set up configuration
-        let mut config = OrderConfiguration::new();
-        config.base_path = if self.config.sandbox {
-            "https://api.sandbox.ebay.com/buy/order/v1".to_string()
-        } else {
-            "https://api.ebay.com/buy/order/v1".to_string()
-        };
-        config.oauth_access_token = Some(token);
+        let url = format!("{}/buy/order/v1/guest_checkout_session/{}/update_shipping_address", self.config.base_url(), urlencode(checkout_session_id));
+        let http = self.config.http_client();
+        
+        // Fail fast while this API's circuit breaker is open
+        let probe = self.config.check_circuit("buy.order")?;
         
-        // Call the eBay SDK
+        // Call the eBay API
         let ebay_start = std::time::Instant::now();
-        let result = hermes_ebay_buy_order::apis::guest_checkout_session_api::update_guest_shipping_address(
-            &config,
-            &checkout_session_id,
-            marketplace_id,
-            "application/json",
-            end_user_ctx,
-            Some(shipping_address.clone()),
+        let result = self.config.send_json(
+            with_end_user_ctx(
+                http.post(&url)
+                    .bearer_auth(&token)
+                    .header("X-EBAY-C-MARKETPLACE-ID", normalize_marketplace(marketplace_id, MarketplaceStyle::Underscore))
+                    .json(shipping_address),
+                end_user_ctx,
+            ),
         ).await;
         let ebay_duration = ebay_start.elapsed();
+        record_api_call("buy.order", "update_guest_shipping_address", &result, ebay_duration);
+        self.config.record_circuit("buy.order", &probe, &result);
         tracing::info!("eBay update_guest_shipping_address API call: {:?}", ebay_duration);
         
         match result {
@@ -385,7 +534,7 @@ impl OrderClient {
             Err(e) => {
                 let total_duration = start_time.elapsed();
                 tracing::error!("eBay update_guest_shipping_address error after {:?}: {:?}", total_duration, e);
-                Err(HermesError::ApiRequest(format!("eBay update_guest_shipping_address failed: {:?}", e)))
+                Err(raw_error("update_guest_shipping_address", &format!("checkout_session_id {}", checkout_session_id), e))
             }
         }
     }
@@ -406,6 +555,11 @@ impl OrderClient {
         shipping_option: &UpdateShippingOption,
         end_user_ctx: Option<&str>,
     ) -> HermesResult<GuestCheckoutSessionResponseV2> {
+        // Dry-run mode logs the request instead of sending it
+        if let Some(response) = self.config.skip_in_dry_run("update_guest_shipping_option", &(checkout_session_id, shipping_option)) {
+            return Ok(response);
+        }
+
         let start_time = std::time::Instant::now();
         
         // Get access token
@@ -414,26 +568,26 @@ impl OrderClient {
         let token_duration = token_start.elapsed();
         tracing::info!("OAuth token request for update_guest_shipping_option: {:?}", token_duration);
         
-        // Set up configuration
-        let mut config = OrderConfiguration::new();
-        config.base_path = if self.config.sandbox {
-            "https://api.sandbox.ebay.com/buy/order/v1".to_string()
-        } else {
-            "https://api.ebay.com/buy/order/v1".to_string()
-        };
-        config.oauth_access_token = Some(token);
+        let url = format!("{}/buy/order/v1/guest_checkout_session/{}/update_shipping_option", self.config.base_url(), urlencode(checkout_session_id));
+        let http = self.config.http_client();
         
-        // Call the eBay SDK
+        // Fail fast while this API's circuit breaker is open
+        let probe = self.config.check_circuit("buy.order")?;
+        
+        // Call the eBay API
         let ebay_start = std::time::Instant::now();
-        let result = hermes_ebay_buy_order::apis::guest_checkout_session_api::update_guest_shipping_option(
-            &config,
-            &checkout_session_id,
-            marketplace_id,
-            "application/json",
-            end_user_ctx,
-            Some(shipping_option.clone()),
+        let result = self.config.send_json(
+            with_end_user_ctx(
+                http.post(&url)
+                    .bearer_auth(&token)
+                    .header("X-EBAY-C-MARKETPLACE-ID", normalize_marketplace(marketplace_id, MarketplaceStyle::Underscore))
+                    .json(shipping_option),
+                end_user_ctx,
+            ),
         ).await;
         let ebay_duration = ebay_start.elapsed();
+        record_api_call("buy.order", "update_guest_shipping_option", &result, ebay_duration);
+        self.config.record_circuit("buy.order", &probe, &result);
         tracing::info!("eBay update_guest_shipping_option API call: {:?}", ebay_duration);
         
         match result {
@@ -446,7 +600,7 @@ impl OrderClient {
             Err(e) => {
                 let total_duration = start_time.elapsed();
                 tracing::error!("eBay update_guest_shipping_option error after {:?}: {:?}", total_duration, e);
-                Err(HermesError::ApiRequest(format!("eBay update_guest_shipping_option failed: {:?}", e)))
+                Err(raw_error("update_guest_shipping_option", &format!("checkout_session_id {}", checkout_session_id), e))
             }
         }
     }
@@ -474,24 +628,26 @@ impl OrderClient {
         let token_duration = token_start.elapsed();
         tracing::info!("OAuth token request for get_guest_purchase_order: {:?}", token_duration);
         
-        // Set up configuration
-        let mut config = OrderConfiguration::new();
-        config.base_path = if self.config.sandbox {
-            "https://api.sandbox.ebay.com/buy/order/v1".to_string()
-        } else {
-            "https://api.ebay.com/buy/order/v1".to_string()
-        };
-        config.oauth_access_token = Some(token);
+        let url = format!("{}/buy/order/v1/guest_purchase_order/{}", self.config.base_url(), urlencode(purchase_order_id));
+        let http = self.config.http_client();
+        
+        // Fail fast while this API's circuit breaker is open
+        let probe = self.config.check_circuit("buy.order")?;
         
-        // Call the eBay SDK
+        let marketplace_id = marketplace_id.map(|id| normalize_marketplace(id, MarketplaceStyle::Underscore));
+        
+        // Call the eBay API
         let ebay_start = std::time::Instant::now();
-        let result = hermes_ebay_buy_order::apis::guest_purchase_order_api::get_guest_purchase_order(
-            &config,
-            purchase_order_id,
-            marketplace_id,
-            end_user_ctx,
-        ).await;
+        let result = self.config.retrying("buy.order", || {
+            let mut request = with_end_user_ctx(http.get(&url).bearer_auth(&token), end_user_ctx);
+            if let Some(marketplace_id) = &marketplace_id {
+                request = request.header("X-EBAY-C-MARKETPLACE-ID", marketplace_id);
+            }
+            self.config.send_json(request)
+        }).await;
         let ebay_duration = ebay_start.elapsed();
+        record_api_call("buy.order", "get_guest_purchase_order", &result, ebay_duration);
+        self.config.record_circuit("buy.order", &probe, &result);
         tracing::info!("eBay get_guest_purchase_order API call: {:?}", ebay_duration);
         
         match result {
@@ -504,8 +660,109 @@ impl OrderClient {
             Err(e) => {
                 let total_duration = start_time.elapsed();
                 tracing::error!("eBay get_guest_purchase_order error after {:?}: {:?}", total_duration, e);
-                Err(HermesError::ApiRequest(format!("eBay get_guest_purchase_order failed: {:?}", e)))
+                Err(raw_error("get_guest_purchase_order", &format!("purchase_order_id {}", purchase_order_id), e))
             }
         }
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fixture_order() -> GuestPurchaseOrderV2 {
+        serde_json::from_value(serde_json::json!({
+            "purchaseOrderId": "1234567890",
+            "lineItems": [
+                {
+                    "title": "Wireless mouse",
+                    "quantity": 2,
+                    "baseUnitPrice": { "value": "19.99", "currency": "USD" },
+                    "netPrice": { "value": "35.98", "currency": "USD" }
+                },
+                {
+                    "title": "USB-C cable",
+                    "quantity": 3,
+                    "baseUnitPrice": { "value": "4.50", "currency": "USD" }
+                }
+            ],
+            "pricingSummary": { "total": { "value": "56.76", "currency": "USD" } }
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn line_items_summary_flattens_prices() {
+        let lines = fixture_order().line_items_summary();
+        assert_eq!(
+            lines,
+            [
+                PurchaseLineItem {
+                    title: "Wireless mouse".to_string(),
+                    quantity: 2,
+                    unit_price: Some(Decimal::new(1999, 2)),
+                    line_total: Some(Decimal::new(3598, 2)),
+                    currency: Some("USD".to_string()),
+                },
+                PurchaseLineItem {
+                    title: "USB-C cable".to_string(),
+                    quantity: 3,
+                    unit_price: Some(Decimal::new(450, 2)),
+                    line_total: Some(Decimal::new(1350, 2)),
+                    currency: Some("USD".to_string()),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn checkout_session_reports_coupon_and_pricing_breakdown() {
+        let session: GuestCheckoutSessionResponseV2 = serde_json::from_value(serde_json::json!({
+            "checkoutSessionId": "session-1",
+            "appliedCoupons": [{ "redemptionCode": "SAVE10" }],
+            "pricingSummary": {
+                "priceSubtotal": { "value": "50.00", "currency": "USD" },
+                "deliveryCost": { "value": "5.00", "currency": "USD" },
+                "tax": { "value": "3.60", "currency": "USD" },
+                "priceDiscount": { "value": "-5.00", "currency": "USD" },
+                "total": { "value": "53.60", "currency": "USD" }
+            }
+        }))
+        .unwrap();
+
+        assert_eq!(session.applied_coupons(), ["SAVE10"]);
+        assert_eq!(
+            session.pricing_breakdown(),
+            PricingBreakdown {
+                subtotal: Some(Decimal::new(5000, 2)),
+                shipping: Some(Decimal::new(500, 2)),
+                tax: Some(Decimal::new(360, 2)),
+                discounts: Some(Decimal::new(500, 2)),
+                total: Some(Decimal::new(5360, 2)),
+                currency: Some("USD".to_string()),
+            }
+        );
+    }
+
+    #[test]
+    fn order_total_parses_decimal_and_currency() {
+        assert_eq!(fixture_order().order_total(), Some((Decimal::new(5676, 2), "USD".to_string())));
+        assert_eq!(GuestPurchaseOrderV2::default().order_total(), None);
+    }
+
+    #[tokio::test]
+    async fn dry_run_skips_guest_checkout_changes() {
+        let (server, config) = crate::ebay::test_support::mock_ebay().await;
+        let client = OrderClient::new(config.with_dry_run(true)).unwrap();
+
+        let session = client
+            .initiate_guest_checkout_session("EBAY_US", &CreateGuestCheckoutSessionRequestV2::default(), None)
+            .await
+            .unwrap();
+        assert_eq!(session, GuestCheckoutSessionResponseV2::default());
+        client.apply_guest_coupon("session-1", "EBAY_US", &CouponRequest::default(), None).await.unwrap();
+        client.update_guest_quantity("session-1", "EBAY_US", &UpdateQuantity::default(), None).await.unwrap();
+
+        assert!(server.received_requests().await.unwrap().is_empty());
+    }
+}
@@ -0,0 +1,89 @@
+//! Persistable search results
+//!
+//! [`CachedSearch`] wraps a Browse search response with the query that produced it
+//! and when it was fetched, giving caching layers a stable envelope to store.
+
+use crate::error::HermesResult;
+use hermes_ebay_buy_browse::models::SearchPagedCollection;
+use serde::{Deserialize, Serialize};
+use std::time::{Duration, SystemTime};
+
+/// A search response together with the query metadata needed to reuse it
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CachedSearch {
+    /// Keyword query the results were fetched for
+    pub query: String,
+    /// Browse `filter` expression, if one was used
+    #[serde(default)]
+    pub filter: Option<String>,
+    pub fetched_at: SystemTime,
+    pub results: SearchPagedCollection,
+}
+
+impl CachedSearch {
+    /// Wrap freshly fetched results, stamped with the current time
+    pub fn new(query: &str, filter: Option<&str>, results: SearchPagedCollection) -> Self {
+        Self {
+            query: query.to_string(),
+            filter: filter.map(str::to_string),
+            fetched_at: SystemTime::now(),
+            results,
+        }
+    }
+
+    /// Whether the results are older than `ttl`
+    ///
+    /// A `fetched_at` in the future (e.g. after a clock change) counts as fresh.
+    pub fn is_stale(&self, ttl: Duration) -> bool {
+        SystemTime::now()
+            .duration_since(self.fetched_at)
+            .map(|age| age > ttl)
+            .unwrap_or(false)
+    }
+
+    /// Serialize to a JSON string
+    pub fn to_json(&self) -> HermesResult<String> {
+        Ok(serde_json::to_string(self)?)
+    }
+
+    /// Deserialize from a JSON string produced by [`to_json`](Self::to_json)
+    pub fn from_json(json: &str) -> HermesResult<Self> {
+        Ok(serde_json::from_str(json)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hermes_ebay_buy_browse::models::ItemSummary;
+
+    #[test]
+    fn round_trips_through_json() {
+        let results = SearchPagedCollection {
+            total: Some(1),
+            item_summaries: Some(vec![ItemSummary {
+                item_id: Some("v1|1234|0".to_string()),
+                title: Some("Vintage camera".to_string()),
+                ..Default::default()
+            }]),
+            ..Default::default()
+        };
+        let cached = CachedSearch::new("camera", Some("price:[..50]"), results);
+
+        let restored = CachedSearch::from_json(&cached.to_json().unwrap()).unwrap();
+        assert_eq!(restored.query, "camera");
+        assert_eq!(restored.filter.as_deref(), Some("price:[..50]"));
+        assert_eq!(restored.fetched_at, cached.fetched_at);
+        assert_eq!(restored.results, cached.results);
+    }
+
+    #[test]
+    fn is_stale_compares_age_with_ttl() {
+        let mut cached = CachedSearch::new("camera", None, SearchPagedCollection::default());
+        assert!(!cached.is_stale(Duration::from_secs(60)));
+
+        cached.fetched_at = SystemTime::now() - Duration::from_secs(120);
+        assert!(cached.is_stale(Duration::from_secs(60)));
+        assert!(!cached.is_stale(Duration::from_secs(600)));
+    }
+}
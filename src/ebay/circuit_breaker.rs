@@ -0,0 +1,287 @@
+//! Per-API circuit breaking
+//!
+//! After a configurable number of consecutive failures of one API family (e.g.
+//! "buy.browse"), calls to that family fail immediately with
+//! [`HermesError::CircuitOpen`] for a cooldown period. The first call after the
+//! cooldown is let through as a probe: success closes the circuit again, failure
+//! re-opens it for another cooldown. While the circuit is open only the probe's
+//! outcome counts; calls let through before it opened are ignored when they finish.
+//! A probe whose call is abandoned before it finishes (e.g. its future was dropped
+//! on a timeout) frees the slot for the next.
+//!
+//! Only outages count as failures: server errors (5xx) and requests that got no
+//! response at all. Client errors such as 404 never open the circuit. eBay's
+//! maintenance response opens it at once, for at least its `Retry-After` delay.
+
+use crate::error::{HermesError, HermesResult};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+/// Circuit breaker settings, applied to each API family separately
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CircuitBreakerConfig {
+    /// Consecutive failures that open the circuit
+    pub failure_threshold: u32,
+    /// How long an open circuit rejects calls before probing
+    pub cooldown: Duration,
+}
+
+/// Circuit state for every API family, shared by all clients built from one config
+#[derive(Debug, Default)]
+pub struct CircuitBreakers {
+    states: Mutex<HashMap<&'static str, BreakerState>>,
+}
+
+#[derive(Debug, Default)]
+struct BreakerState {
+    consecutive_failures: u32,
+    opened_at: Option<Instant>,
+    /// Cooldown replacing the configured one, set by a maintenance response
+    cooldown: Option<Duration>,
+    probe_in_flight: bool,
+    /// Counts probes, so an abandoned probe only releases its own slot
+    probe_generation: u64,
+}
+
+/// Held for the duration of a call that [`CircuitBreakers::check`] let through
+///
+/// Dropping the probe call before its outcome is recorded releases the probe
+/// slot, so the circuit does not stay half-open with no probe ever finishing.
+#[must_use = "the probe slot is released as soon as this is dropped"]
+pub(crate) struct CircuitProbe {
+    probe: Option<(Arc<CircuitBreakers>, &'static str, u64)>,
+}
+
+impl CircuitProbe {
+    /// A call that is not a probe, e.g. because circuit breaking is off
+    pub(crate) fn none() -> Self {
+        Self { probe: None }
+    }
+
+    fn generation(&self) -> Option<u64> {
+        self.probe.as_ref().map(|(_, _, generation)| *generation)
+    }
+}
+
+impl Drop for CircuitProbe {
+    fn drop(&mut self) {
+        if let Some((breakers, api, generation)) = self.probe.take() {
+            let mut states = breakers.states.lock().unwrap_or_else(|e| e.into_inner());
+            if let Some(state) = states.get_mut(api) {
+                if state.probe_in_flight && state.probe_generation == generation {
+                    state.probe_in_flight = false;
+                }
+            }
+        }
+    }
+}
+
+impl CircuitBreakers {
+    /// Fail fast if `api`'s circuit is open
+    pub(crate) fn check(self: &Arc<Self>, settings: &CircuitBreakerConfig, api: &'static str) -> HermesResult<CircuitProbe> {
+        let mut states = self.states.lock().unwrap_or_else(|e| e.into_inner());
+        let state = states.entry(api).or_default();
+        let Some(opened_at) = state.opened_at else {
+            return Ok(CircuitProbe::none());
+        };
+
+        // Half-open: let a single probe through once the cooldown has passed
+        if opened_at.elapsed() >= state.cooldown.unwrap_or(settings.cooldown) && !state.probe_in_flight {
+            state.probe_in_flight = true;
+            state.probe_generation += 1;
+            return Ok(CircuitProbe { probe: Some((Arc::clone(self), api, state.probe_generation)) });
+        }
+        Err(HermesError::CircuitOpen(api.to_string()))
+    }
+
+    /// Record the outcome of a call to `api`, made holding `probe`
+    pub(crate) fn record(&self, settings: &CircuitBreakerConfig, api: &'static str, probe: &CircuitProbe, failed: bool) {
+        let mut states = self.states.lock().unwrap_or_else(|e| e.into_inner());
+        let state = states.entry(api).or_default();
+        if state.opened_at.is_some() {
+            if !state.is_current_probe(probe) {
+                // Let through before the circuit opened; only the probe decides now
+                return;
+            }
+            state.probe_in_flight = false;
+        }
+
+        if !failed {
+            // Keep counting probes, so one still held from before cannot match a later one
+            *state = BreakerState { probe_generation: state.probe_generation, ..BreakerState::default() };
+            return;
+        }
+        state.consecutive_failures += 1;
+        if state.opened_at.is_some() || state.consecutive_failures >= settings.failure_threshold {
+            if state.opened_at.is_none() {
+                tracing::warn!("Circuit breaker opened for eBay {} after {} failures", api, state.consecutive_failures);
+            }
+            state.opened_at = Some(Instant::now());
+            state.cooldown = None;
+        }
+    }
+
+    /// Open `api`'s circuit immediately, e.g. because eBay is down for maintenance
+    ///
+    /// The circuit stays open for the configured cooldown or `retry_after`,
+    /// whichever is longer.
+    pub(crate) fn trip(
+        &self,
+        settings: &CircuitBreakerConfig,
+        api: &'static str,
+        probe: &CircuitProbe,
+        retry_after: Option<Duration>,
+    ) {
+        let mut states = self.states.lock().unwrap_or_else(|e| e.into_inner());
+        let state = states.entry(api).or_default();
+        tracing::warn!("Circuit breaker opened for eBay {}: service under maintenance", api);
+        if state.is_current_probe(probe) {
+            state.probe_in_flight = false;
+        }
+        state.consecutive_failures += 1;
+        state.opened_at = Some(Instant::now());
+        state.cooldown = retry_after.map(|delay| delay.max(settings.cooldown));
+    }
+}
+
+impl BreakerState {
+    /// Whether `probe` is the probe this circuit is waiting on
+    fn is_current_probe(&self, probe: &CircuitProbe) -> bool {
+        self.probe_in_flight && probe.generation() == Some(self.probe_generation)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ebay::test_support::mock_ebay;
+    use crate::ebay::EbayClient;
+    use wiremock::matchers::{method, path};
+    use wiremock::{Mock, ResponseTemplate};
+
+    #[tokio::test]
+    async fn consecutive_failures_open_circuit_and_fail_fast() {
+        let (server, config) = mock_ebay().await;
+        Mock::given(method("GET"))
+            .and(path("/buy/browse/v1/item/123"))
+            .respond_with(ResponseTemplate::new(503))
+            .expect(3)
+            .mount(&server)
+            .await;
+
+        let client = EbayClient::new(config.with_circuit_breaker(2, Duration::from_millis(200))).unwrap();
+        for _ in 0..2 {
+            assert!(matches!(client.get_item("123", None).await, Err(HermesError::Api { status: 503, .. })));
+        }
+
+        // Open: rejected without reaching eBay
+        let err = client.get_item("123", None).await.unwrap_err();
+        assert!(matches!(err, HermesError::CircuitOpen(ref api) if api == "buy.browse"));
+
+        // Half-open after the cooldown: one probe goes through and re-opens on failure
+        tokio::time::sleep(Duration::from_millis(250)).await;
+        assert!(matches!(client.get_item("123", None).await, Err(HermesError::Api { status: 503, .. })));
+        assert!(matches!(client.get_item("123", None).await, Err(HermesError::CircuitOpen(_))));
+    }
+
+    #[tokio::test]
+    async fn maintenance_opens_circuit_immediately() {
+        let (server, config) = mock_ebay().await;
+        Mock::given(method("GET"))
+            .and(path("/buy/browse/v1/item/123"))
+            .respond_with(
+                ResponseTemplate::new(503)
+                    .insert_header("Retry-After", "3600")
+                    .set_body_json(serde_json::json!({
+                        "errors": [{
+                            "errorId": 10001,
+                            "category": "APPLICATION",
+                            "message": "eBay is undergoing scheduled maintenance"
+                        }]
+                    })),
+            )
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = EbayClient::new(config.with_circuit_breaker(5, Duration::ZERO)).unwrap();
+        let err = client
+            .call_raw(crate::ebay::EbayService::BuyBrowse, reqwest::Method::GET, "/v1/item/123", &[], None)
+            .await
+            .unwrap_err();
+        assert!(matches!(err, HermesError::ServiceUnavailable { retry_after: Some(delay) } if delay.as_secs() == 3600));
+
+        // The Retry-After delay outlasts the zero cooldown
+        assert!(matches!(client.get_item("123", None).await, Err(HermesError::CircuitOpen(_))));
+    }
+
+    #[tokio::test]
+    async fn dropped_probe_releases_the_probe_slot() {
+        let (server, config) = mock_ebay().await;
+        Mock::given(method("GET"))
+            .and(path("/buy/browse/v1/item/123"))
+            .respond_with(ResponseTemplate::new(503).set_delay(Duration::from_millis(500)))
+            .mount(&server)
+            .await;
+
+        let client = EbayClient::new(config.with_circuit_breaker(1, Duration::ZERO)).unwrap();
+        assert!(matches!(client.get_item("123", None).await, Err(HermesError::Api { status: 503, .. })));
+
+        // The probe's caller gives up before eBay answers
+        let probe = tokio::time::timeout(Duration::from_millis(50), client.get_item("123", None)).await;
+        assert!(probe.is_err());
+
+        // The next call becomes the probe instead of failing fast forever
+        assert!(matches!(client.get_item("123", None).await, Err(HermesError::Api { status: 503, .. })));
+    }
+
+    #[test]
+    fn abandoned_probe_does_not_release_a_newer_one() {
+        let settings = CircuitBreakerConfig { failure_threshold: 1, cooldown: Duration::ZERO };
+        let breakers = Arc::new(CircuitBreakers::default());
+        breakers.record(&settings, "sell.inventory", &CircuitProbe::none(), true);
+
+        let stale = breakers.check(&settings, "sell.inventory").unwrap();
+        breakers.record(&settings, "sell.inventory", &stale, true);
+        let _current = breakers.check(&settings, "sell.inventory").unwrap();
+        drop(stale);
+        assert!(breakers.check(&settings, "sell.inventory").is_err());
+    }
+
+    #[test]
+    fn successful_probe_closes_circuit() {
+        let settings = CircuitBreakerConfig { failure_threshold: 1, cooldown: Duration::ZERO };
+        let breakers = Arc::new(CircuitBreakers::default());
+        breakers.record(&settings, "sell.inventory", &CircuitProbe::none(), true);
+
+        let probe = breakers.check(&settings, "sell.inventory").unwrap();
+        assert!(breakers.check(&settings, "sell.inventory").is_err());
+        breakers.record(&settings, "sell.inventory", &probe, false);
+        assert!(breakers.check(&settings, "sell.inventory").is_ok());
+        assert!(breakers.check(&settings, "sell.inventory").is_ok());
+        // Other API families are unaffected
+        assert!(breakers.check(&settings, "buy.browse").is_ok());
+    }
+
+    #[test]
+    fn overlapping_call_does_not_settle_the_probe() {
+        let settings = CircuitBreakerConfig { failure_threshold: 1, cooldown: Duration::ZERO };
+        let breakers = Arc::new(CircuitBreakers::default());
+
+        // Let through while the circuit was still closed, and still running
+        let earlier = breakers.check(&settings, "sell.inventory").unwrap();
+        breakers.record(&settings, "sell.inventory", &CircuitProbe::none(), true);
+        let probe = breakers.check(&settings, "sell.inventory").unwrap();
+
+        // Neither outcome of the earlier call frees the probe slot or closes the circuit
+        breakers.record(&settings, "sell.inventory", &earlier, false);
+        assert!(breakers.check(&settings, "sell.inventory").is_err());
+        breakers.record(&settings, "sell.inventory", &earlier, true);
+        assert!(breakers.check(&settings, "sell.inventory").is_err());
+
+        breakers.record(&settings, "sell.inventory", &probe, false);
+        assert!(breakers.check(&settings, "sell.inventory").is_ok());
+    }
+}
@@ -1,16 +1,443 @@
 use crate::config::EbayConfig;
 use crate::error::{HermesError, HermesResult};
+use crate::ebay::api_error::{parse_response, raw_error};
+use crate::ebay::telemetry::record_api_call;
 use crate::ebay::auth::EbayAuth;
+use crate::ebay::debug::request_url;
+use crate::ebay::item_id::ItemId;
+use crate::ebay::marketplace::Marketplace;
+use crate::ebay::paginate::paginate;
+use crate::ebay::rate_limit::{RateLimitStatus, RateLimitsResponse};
+use crate::ebay::response::EbayResponse;
+use crate::ebay::service::EbayService;
 use crate::ebay::buy::{FeedClient, MarketingClient, OfferClient, OrderClient};
-use crate::ebay::commerce::{CatalogClient, TaxonomyClient, IdentityClient, TranslationClient};
+use crate::ebay::commerce::{CatalogClient, TaxonomyClient, IdentityClient, TranslationClient, MediaClient};
 use crate::ebay::sell::{AnalyticsClient, AccountClient, InventoryClient, FulfillmentClient, ComplianceClient, FinancesClient, MetadataClient, NegotiationClient, RecommendationClient};
+use crate::money::{parse_decimal, Money};
+use base64::prelude::{Engine, BASE64_STANDARD};
+use futures::stream::{self, Stream, StreamExt, TryStreamExt};
+use rust_decimal::Decimal;
+use serde::de::DeserializeOwned;
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::fmt;
 use std::sync::Arc;
+use tracing::Instrument;
 
 // Import eBay SDK models and APIs
-use hermes_ebay_buy_browse::models::{SearchPagedCollection, Item, Items, CompatibilityPayload, CompatibilityResponse};
-use hermes_ebay_buy_browse::apis::configuration::Configuration as BrowseConfiguration;
-use hermes_ebay_commerce_taxonomy::models::CategoryTree;
-use hermes_ebay_commerce_taxonomy::apis::configuration::Configuration as TaxonomyConfiguration;
+use hermes_ebay_buy_browse::models::{SearchPagedCollection, SearchByImageRequest, Item, ItemGroup, ItemSummary, Items, CompatibilityPayload, CompatibilityResponse};
+use hermes_ebay_buy_browse::apis::urlencode;
+use hermes_ebay_commerce_taxonomy::models::{AspectMetadata, CategoryTree};
+
+/// Maximum number of requests issued concurrently by the bulk helpers
+const MAX_CONCURRENT_REQUESTS: usize = 5;
+
+/// Largest image eBay accepts for search_by_image (12 MB)
+const MAX_SEARCH_IMAGE_BYTES: usize = 12 * 1024 * 1024;
+
+/// search_by_image field groups when none are given: item summaries only, without refinements
+const DEFAULT_IMAGE_SEARCH_FIELDGROUPS: &str = "MATCHING_ITEMS";
+
+/// Largest Browse search page, used when listing a seller's items
+const SELLER_SEARCH_PAGE_SIZE: i32 = 200;
+
+/// Price and availability of a single item, for monitoring workloads
+#[derive(Debug, Clone, PartialEq)]
+pub struct ItemPriceSnapshot {
+    pub item_id: String,
+    pub price: Decimal,
+    pub currency: String,
+    /// Estimated quantity available, when eBay reports one
+    pub available_quantity: Option<u32>,
+    pub condition: Option<String>,
+}
+
+/// A live listing of the seller, joining its inventory offer with Browse data
+#[derive(Debug, Clone, PartialEq)]
+pub struct ActiveListing {
+    /// Legacy (numeric) listing ID
+    pub listing_id: String,
+    pub sku: Option<String>,
+    pub offer_id: Option<String>,
+    /// Title shown to buyers, or the inventory item's title if Browse has not indexed the listing
+    pub title: Option<String>,
+    /// Current price, or the offer's price if Browse has not indexed the listing
+    pub price: Option<Money>,
+    /// Buyers watching the listing, when Browse reports it
+    pub watch_count: Option<u32>,
+    pub item_web_url: Option<String>,
+}
+
+/// Convenience accessors for Browse search results
+pub trait SearchPagedCollectionExt {
+    /// The spelling-corrected query eBay searched for, when auto-correction applied
+    fn corrected_query(&self) -> Option<&str>;
+
+    /// Match counts per aspect value, e.g. "Brand" -> [("Apple", 12), ("Dell", 7)]
+    /// 
+    /// Values keep eBay's order. Empty unless the search requested aspect
+    /// refinements, see [`EbayClient::search_items_with_refinements`].
+    fn aspect_distributions(&self) -> BTreeMap<String, Vec<(String, u32)>>;
+}
+
+impl SearchPagedCollectionExt for SearchPagedCollection {
+    fn corrected_query(&self) -> Option<&str> {
+        self.auto_corrections.as_deref().and_then(|corrections| corrections.q.as_deref())
+    }
+
+    fn aspect_distributions(&self) -> BTreeMap<String, Vec<(String, u32)>> {
+        let aspects = self.refinement.as_deref().and_then(|refinement| refinement.aspect_distributions.as_ref());
+        aspects
+            .into_iter()
+            .flatten()
+            .filter_map(|aspect| {
+                let values = aspect
+                    .aspect_value_distributions
+                    .iter()
+                    .flatten()
+                    .filter_map(|value| {
+                        Some((value.localized_aspect_value.clone()?, value.match_count.unwrap_or(0).max(0) as u32))
+                    })
+                    .collect();
+                Some((aspect.localized_aspect_name.clone()?, values))
+            })
+            .collect()
+    }
+}
+
+/// A seller's identity and feedback, flattened from a Browse item
+#[derive(Debug, Clone, PartialEq)]
+pub struct SellerInfo {
+    pub username: String,
+    /// Positive feedback percentage, e.g. 99.8
+    pub feedback_percentage: Option<f64>,
+    /// Net feedback score
+    pub feedback_score: Option<i32>,
+}
+
+/// Seller details of a Browse search result
+pub trait ItemSummaryExt {
+    /// The listing's seller, if eBay returned one
+    fn seller_info(&self) -> Option<SellerInfo>;
+}
+
+impl ItemSummaryExt for ItemSummary {
+    fn seller_info(&self) -> Option<SellerInfo> {
+        let seller = self.seller.as_deref()?;
+        Some(seller_info(seller.username.as_deref(), seller.feedback_percentage.as_deref(), seller.feedback_score))
+    }
+}
+
+impl ItemSummaryExt for Item {
+    fn seller_info(&self) -> Option<SellerInfo> {
+        let seller = self.seller.as_deref()?;
+        Some(seller_info(seller.username.as_deref(), seller.feedback_percentage.as_deref(), seller.feedback_score))
+    }
+}
+
+/// Build a [`SellerInfo`] from the fields Browse's `Seller` and `SellerDetail` share
+fn seller_info(username: Option<&str>, feedback_percentage: Option<&str>, feedback_score: Option<i32>) -> SellerInfo {
+    SellerInfo {
+        username: username.unwrap_or_default().to_string(),
+        feedback_percentage: feedback_percentage.and_then(|p| p.parse().ok()),
+        feedback_score,
+    }
+}
+
+/// Estimated delivery window of a Browse item's fastest shipping option
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DeliveryEstimate {
+    /// Earliest estimated delivery (ISO 8601, UTC)
+    pub earliest: String,
+    /// Latest estimated delivery (ISO 8601, UTC), if eBay gave one
+    pub latest: Option<String>,
+    /// Whether delivery by `latest` is guaranteed
+    pub guaranteed: bool,
+}
+
+/// Delivery estimates of a Browse item or search result, for "arrives by" filters
+pub trait DeliveryEstimateExt {
+    /// Earliest estimated delivery date across all shipping options (ISO 8601, UTC)
+    fn earliest_delivery(&self) -> Option<String>;
+
+    /// Delivery window of the shipping option that arrives first
+    fn delivery_estimate(&self) -> Option<DeliveryEstimate>;
+}
+
+// Picks the option with the earliest minimum date; eBay formats every date as UTC
+// "YYYY-MM-DDTHH:MM:SS.sssZ", so the strings order chronologically
+fn fastest_delivery<'a>(
+    options: impl Iterator<Item = (Option<&'a str>, Option<&'a str>, Option<bool>)>,
+) -> Option<DeliveryEstimate> {
+    options
+        .filter_map(|(min, max, guaranteed)| Some((min?, max, guaranteed)))
+        .min_by(|a, b| a.0.cmp(b.0))
+        .map(|(min, max, guaranteed)| DeliveryEstimate {
+            earliest: min.to_string(),
+            latest: max.map(str::to_string),
+            guaranteed: guaranteed.unwrap_or(false),
+        })
+}
+
+impl DeliveryEstimateExt for ItemSummary {
+    fn earliest_delivery(&self) -> Option<String> {
+        self.delivery_estimate().map(|estimate| estimate.earliest)
+    }
+
+    fn delivery_estimate(&self) -> Option<DeliveryEstimate> {
+        fastest_delivery(self.shipping_options.iter().flatten().map(|option| {
+            (
+                option.min_estimated_delivery_date.as_deref(),
+                option.max_estimated_delivery_date.as_deref(),
+                option.guaranteed_delivery,
+            )
+        }))
+    }
+}
+
+impl DeliveryEstimateExt for Item {
+    fn earliest_delivery(&self) -> Option<String> {
+        self.delivery_estimate().map(|estimate| estimate.earliest)
+    }
+
+    fn delivery_estimate(&self) -> Option<DeliveryEstimate> {
+        fastest_delivery(self.shipping_options.iter().flatten().map(|option| {
+            (
+                option.min_estimated_delivery_date.as_deref(),
+                option.max_estimated_delivery_date.as_deref(),
+                option.guaranteed_delivery,
+            )
+        }))
+    }
+}
+
+/// Images of a full Browse item
+pub trait ItemImagesExt {
+    /// URLs of the primary image followed by every additional image, without duplicates
+    fn all_image_urls(&self) -> Vec<String>;
+}
+
+impl ItemImagesExt for Item {
+    fn all_image_urls(&self) -> Vec<String> {
+        let mut urls: Vec<String> = Vec::new();
+        let images = self.image.as_deref().into_iter().chain(self.additional_images.iter().flatten());
+        for url in images.filter_map(|image| image.image_url.as_deref()) {
+            if !urls.iter().any(|known| known == url) {
+                urls.push(url.to_string());
+            }
+        }
+        urls
+    }
+}
+
+/// A vehicle a part fits, from a Browse search with a compatibility filter
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CompatibleVehicle {
+    /// Compatibility properties in the order eBay lists them, e.g. ("Make", "Honda")
+    pub properties: Vec<(String, String)>,
+    /// Whether eBay reports an exact match rather than a possible one
+    pub exact: bool,
+}
+
+/// Properties that make up a vehicle's description, in display order
+const VEHICLE_DESCRIPTION_PROPERTIES: &[&str] = &["Year", "Make", "Model", "Trim", "Engine"];
+
+impl CompatibleVehicle {
+    /// Value of a compatibility property, e.g. `get("Model")`
+    pub fn get(&self, name: &str) -> Option<&str> {
+        self.properties
+            .iter()
+            .find(|(property, _)| property == name)
+            .map(|(_, value)| value.as_str())
+    }
+
+    /// Readable vehicle name, e.g. "2015 Honda Civic EX"
+    /// 
+    /// Uses year, make, model, trim and engine; for non-vehicle compatibility (no
+    /// such properties) all values are joined in eBay's order.
+    pub fn description(&self) -> String {
+        let parts: Vec<&str> = VEHICLE_DESCRIPTION_PROPERTIES.iter().filter_map(|name| self.get(name)).collect();
+        if parts.is_empty() {
+            let values: Vec<&str> = self.properties.iter().map(|(_, value)| value.as_str()).collect();
+            return values.join(" ");
+        }
+        parts.join(" ")
+    }
+}
+
+impl fmt::Display for CompatibleVehicle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.description())
+    }
+}
+
+/// Parts compatibility of a Browse search result
+/// 
+/// eBay only reports compatibility on search results requested with a
+/// `compatibility_filter`; full `Item` responses carry no fitment data.
+pub trait CompatibilityExt {
+    /// The vehicles the item was matched against, flattened for display
+    fn compatibility_summary(&self) -> Vec<CompatibleVehicle>;
+}
+
+impl CompatibilityExt for ItemSummary {
+    fn compatibility_summary(&self) -> Vec<CompatibleVehicle> {
+        let properties: Vec<(String, String)> = self
+            .compatibility_properties
+            .iter()
+            .flatten()
+            .filter_map(|property| {
+                let name = property.localized_name.as_ref().or(property.name.as_ref())?;
+                Some((name.clone(), property.value.clone()?))
+            })
+            .collect();
+        if properties.is_empty() {
+            return Vec::new();
+        }
+        vec![CompatibleVehicle { properties, exact: self.compatibility_match.as_deref() == Some("EXACT") }]
+    }
+}
+
+/// One purchasable variation of an item group
+#[derive(Debug, Clone, PartialEq)]
+pub struct Variation {
+    pub item_id: String,
+    /// Values of the group's varying aspects, e.g. {"Color": "Red", "Size": "M"}
+    pub aspects: BTreeMap<String, String>,
+    pub price: Option<Decimal>,
+    pub currency: Option<String>,
+    /// Whether eBay reports the variation as in stock (including limited stock)
+    pub available: bool,
+    pub available_quantity: Option<u32>,
+}
+
+/// The variations of an item group, keyed by aspect combination
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct VariationMatrix {
+    /// Aspects whose values differ between variations, in the order eBay lists them
+    pub aspects: Vec<String>,
+    pub variations: Vec<Variation>,
+}
+
+impl VariationMatrix {
+    /// Distinct values of a varying aspect, in first-seen order
+    pub fn values(&self, aspect: &str) -> Vec<&str> {
+        let mut values: Vec<&str> = Vec::new();
+        for value in self.variations.iter().filter_map(|v| v.aspects.get(aspect)) {
+            if !values.contains(&value.as_str()) {
+                values.push(value);
+            }
+        }
+        values
+    }
+
+    /// The variation matching every given `(aspect, value)` pair
+    pub fn find(&self, selection: &[(&str, &str)]) -> Option<&Variation> {
+        self.variations.iter().find(|variation| {
+            selection
+                .iter()
+                .all(|(aspect, value)| variation.aspects.get(*aspect).map(String::as_str) == Some(*value))
+        })
+    }
+}
+
+/// Variation picker data for a group returned by `get_items_by_item_group`
+pub trait ItemGroupExt {
+    /// Map each aspect combination of the group to its item, price and availability
+    fn variation_matrix(&self) -> VariationMatrix;
+
+    /// Total estimated quantity available across all variations
+    fn total_available(&self) -> i64;
+
+    /// Variations that can currently be bought, e.g. for "5 of 8 sizes available"
+    fn in_stock_variations(&self) -> Vec<&Item>;
+}
+
+impl ItemGroupExt for ItemGroup {
+    fn variation_matrix(&self) -> VariationMatrix {
+        let items = self.items.as_deref().unwrap_or_default();
+        let item_aspects: Vec<Vec<(&str, &str)>> = items
+            .iter()
+            .map(|item| {
+                item.localized_aspects
+                    .iter()
+                    .flatten()
+                    .filter_map(|aspect| Some((aspect.name.as_deref()?, aspect.value.as_deref()?)))
+                    .collect()
+            })
+            .collect();
+
+        // An aspect varies when its value is not the same on every item
+        let mut aspects: Vec<String> = Vec::new();
+        for (name, _) in item_aspects.iter().flatten() {
+            if aspects.iter().any(|aspect| aspect == name) {
+                continue;
+            }
+            let values: Vec<Option<&str>> = item_aspects
+                .iter()
+                .map(|pairs| pairs.iter().find(|(n, _)| n == name).map(|&(_, value)| value))
+                .collect();
+            if values.iter().any(|value| *value != values[0]) {
+                aspects.push(name.to_string());
+            }
+        }
+
+        let variations = items
+            .iter()
+            .zip(&item_aspects)
+            .map(|(item, pairs)| {
+                let availabilities = item.estimated_availabilities.iter().flatten();
+                Variation {
+                    item_id: item.item_id.clone().unwrap_or_default(),
+                    aspects: pairs
+                        .iter()
+                        .filter(|(name, _)| aspects.iter().any(|aspect| aspect == name))
+                        .map(|(name, value)| (name.to_string(), value.to_string()))
+                        .collect(),
+                    price: parse_decimal(item.price.as_deref().and_then(|p| p.value.as_deref())),
+                    currency: item.price.as_deref().and_then(|p| p.currency.clone()),
+                    available: is_in_stock(item),
+                    available_quantity: availabilities
+                        .filter_map(|a| a.estimated_available_quantity)
+                        .map(|quantity| quantity.max(0) as u32)
+                        .reduce(|total, quantity| total + quantity),
+                }
+            })
+            .collect();
+
+        VariationMatrix { aspects, variations }
+    }
+
+    fn total_available(&self) -> i64 {
+        self.items
+            .iter()
+            .flatten()
+            .flat_map(|item| item.estimated_availabilities.iter().flatten())
+            .filter_map(|a| a.estimated_available_quantity)
+            .map(|quantity| i64::from(quantity.max(0)))
+            .sum()
+    }
+
+    fn in_stock_variations(&self) -> Vec<&Item> {
+        self.items.iter().flatten().filter(|item| is_in_stock(item)).collect()
+    }
+}
+
+// eBay reports low but non-zero stock as LIMITED_STOCK
+fn is_in_stock(item: &Item) -> bool {
+    item.estimated_availabilities.iter().flatten().any(|a| {
+        matches!(a.estimated_availability_status.as_deref(), Some("IN_STOCK" | "LIMITED_STOCK"))
+    })
+}
+
+// Amount of a price in either API, skipping unparseable values
+fn money(value: Option<&str>, currency: Option<&str>) -> Option<Money> {
+    Some(Money::new(parse_decimal(value)?, currency?))
+}
+
+// Browse only supports keyword correction
+fn auto_correct_param(enabled: bool) -> Option<&'static str> {
+    enabled.then_some("KEYWORD")
+}
 
 /// Main eBay API client - provides unified access to all eBay APIs
 pub struct EbayClient {
@@ -25,6 +452,7 @@ pub struct EbayClient {
     taxonomy_client: Option<TaxonomyClient>,
     identity_client: Option<IdentityClient>,
     translation_client: Option<TranslationClient>,
+    media_client: Option<MediaClient>,
     // Sell API clients
     analytics_client: Option<AnalyticsClient>,
     account_client: Option<AccountClient>,
@@ -41,7 +469,12 @@ impl EbayClient {
     /// Create a new eBay client
     pub fn new(config: EbayConfig) -> HermesResult<Self> {
         let auth = Arc::new(EbayAuth::new(config.clone())?);
-        Ok(Self { 
+        Ok(Self::with_auth(config, auth))
+    }
+
+    // Client without any specialized clients initialized yet
+    fn with_auth(config: EbayConfig, auth: Arc<EbayAuth>) -> Self {
+        Self { 
             config, 
             auth,
             feed_client: None,
@@ -52,6 +485,7 @@ impl EbayClient {
             taxonomy_client: None,
             identity_client: None,
             translation_client: None,
+            media_client: None,
             analytics_client: None,
             account_client: None,
             inventory_client: None,
@@ -61,7 +495,7 @@ impl EbayClient {
             metadata_client: None,
             negotiation_client: None,
             recommendation_client: None,
-        })
+        }
     }
 
     /// Get the Feed API client (lazy initialization)
@@ -129,6 +563,14 @@ impl EbayClient {
         Ok(self.translation_client.as_ref().unwrap())
     }
 
+    /// Get the Media API client (lazy initialization)
+    pub fn media(&mut self) -> HermesResult<&MediaClient> {
+        if self.media_client.is_none() {
+            self.media_client = Some(MediaClient::new(self.config.clone())?);
+        }
+        Ok(self.media_client.as_ref().unwrap())
+    }
+
     /// Get the Analytics API client (lazy initialization)
     pub fn analytics(&mut self) -> HermesResult<&AnalyticsClient> {
         if self.analytics_client.is_none() {
@@ -201,11 +643,76 @@ impl EbayClient {
         Ok(self.recommendation_client.as_ref().unwrap())
     }
 
+    /// Scope calls to a specific marketplace
+    /// 
+    /// Returns a lightweight view whose search and item methods send `marketplace`
+    /// as `X-EBAY-C-MARKETPLACE-ID` instead of the config default, for that call only.
+    /// Useful for multi-tenant servers that can't afford a client per marketplace.
+    pub fn with_marketplace(&self, marketplace: Marketplace) -> MarketplaceScope<'_> {
+        MarketplaceScope { client: self, marketplace }
+    }
+
+    /// Derive a client whose default marketplace is `marketplace`
+    /// 
+    /// Unlike [`with_marketplace`](Self::with_marketplace), the result is an owned
+    /// client that can be stored per region. It shares this client's OAuth token
+    /// cache, circuit breakers and retry budget; specialized clients are created
+    /// lazily again on first use.
+    pub fn for_marketplace(&self, marketplace: Marketplace) -> EbayClient {
+        let config = self.config.clone().with_marketplace(marketplace);
+        Self::with_auth(config, Arc::clone(&self.auth))
+    }
+
+    /// Derive a client whose calls carry `correlation_id`
+    /// 
+    /// Intended to be created per incoming request; like
+    /// [`for_marketplace`](Self::for_marketplace) it shares this client's OAuth token
+    /// cache, circuit breakers and retry budget. See
+    /// [`EbayConfig::with_correlation_id`] for where the ID appears.
+    pub fn for_correlation_id(&self, correlation_id: &str) -> EbayClient {
+        let config = self.config.clone().with_correlation_id(correlation_id);
+        Self::with_auth(config, Arc::clone(&self.auth))
+    }
+
+    /// Swap the application keys without rebuilding the client
+    /// 
+    /// For key rotation in long-running services (e.g. a renewed cert ID). The swap
+    /// is atomic and applies to this client, its specialized clients and every client
+    /// derived from it. The next call fetches a token with the new keys; caches such
+    /// as category trees and aspects are kept.
+    /// 
+    /// # Arguments
+    /// * `app_id` - The new app ID (client ID)
+    /// * `cert_id` - The new cert ID (client secret)
+    pub fn update_credentials(&self, app_id: &str, cert_id: &str) {
+        self.config.rotate_credentials(app_id, cert_id);
+        tracing::info!("eBay credentials updated for app ID {}", app_id);
+    }
+
+    /// URL of a Browse API request
+    fn browse_url(&self, path: &str, query: &[(&str, Option<&str>)]) -> String {
+        request_url(&format!("{}/buy/browse/v1", self.config.base_url()), path, query)
+    }
+
     /// Search for items on eBay
+    /// 
+    /// With `auto_correct`, eBay corrects misspelled keywords and reports the query it
+    /// actually searched for, see [`SearchPagedCollectionExt::corrected_query`].
     pub async fn search_items(
         &self,
         query: &str,
         limit: Option<i32>,
+        auto_correct: bool,
+    ) -> HermesResult<SearchPagedCollection> {
+        self.search_items_in(self.config.marketplace, query, limit, auto_correct).await
+    }
+
+    async fn search_items_in(
+        &self,
+        marketplace: Marketplace,
+        query: &str,
+        limit: Option<i32>,
+        auto_correct: bool,
     ) -> HermesResult<SearchPagedCollection> {
         let start_time = std::time::Instant::now();
         
@@ -215,37 +722,27 @@ impl EbayClient {
         let token_duration = token_start.elapsed();
         tracing::info!("OAuth token request for search_items: {:?}", token_duration);
         
-        // Set up configuration
-        let mut config = BrowseConfiguration::new();
-        config.base_path = if self.config.sandbox {
-            "https://api.sandbox.ebay.com/buy/browse/v1".to_string()
-        } else {
-            "https://api.ebay.com/buy/browse/v1".to_string()
-        };
-        config.oauth_access_token = Some(token);
+        // Build the request URL
+        let url = self.browse_url("/item_summary/search", &[
+            ("auto_correct", auto_correct_param(auto_correct)),
+            ("limit", limit.map(|limit| limit.to_string()).as_deref()),
+            ("q", Some(query)),
+        ]);
+        let http = self.config.http_client();
         
-        // Call the eBay SDK
+        // Fail fast while this API's circuit breaker is open
+        let probe = self.config.check_circuit("buy.browse")?;
+        
+        // Call the eBay API
         let ebay_start = std::time::Instant::now();
-        let result = hermes_ebay_buy_browse::apis::item_summary_api::search(
-            &config,
-            Some(query),
-            None, // aspect_filter
-            None, // auto_correct
-            None, // category_ids
-            None, // charity_ids
-            None, // compatibility_filter
-            None, // condition_ids
-            None, // epid
-            None, // fieldgroups
-            None, // filter
-            None, // gtin
-            None, // offset
-            None, // sort
-            None, // x_ebay_c_enduserctx
-            Some("EBAY-US"), // x_ebay_c_marketplace_id
-            None, // accept_language
-        ).await;
+        let result = self.config.retrying("buy.browse", || {
+            self.config.send_json(
+                http.get(&url).bearer_auth(&token).header("X-EBAY-C-MARKETPLACE-ID", marketplace.as_str()),
+            )
+        }).instrument(self.config.call_span("buy.browse", "search_items")).await;
         let ebay_duration = ebay_start.elapsed();
+        record_api_call("buy.browse", "search_items", &result, ebay_duration);
+        self.config.record_circuit("buy.browse", &probe, &result);
         tracing::info!("eBay search API call: {:?}", ebay_duration);
         
         match result {
@@ -258,16 +755,100 @@ impl EbayClient {
             Err(e) => {
                 let total_duration = start_time.elapsed();
                 tracing::error!("eBay search error after {:?}: {:?}", total_duration, e);
-                Err(HermesError::ApiRequest(format!("eBay search failed: {:?}", e)))
+                Err(raw_error("search", &format!("query {}", query), e))
+            }
+        }
+    }
+
+    /// Search for items along with the refinement histograms for faceted search
+    /// 
+    /// Requests eBay's aspect, category, condition and buying-option refinements in
+    /// addition to the matching items; read aspect value counts with
+    /// [`SearchPagedCollectionExt::aspect_distributions`]. eBay only returns aspect
+    /// refinements when the search is restricted to a category.
+    /// 
+    /// # Arguments
+    /// * `query` - Search keywords
+    /// * `category_ids` - Category to search in, e.g. "9355"
+    /// * `limit` - Optional maximum number of items
+    pub async fn search_items_with_refinements(
+        &self,
+        query: &str,
+        category_ids: Option<&str>,
+        limit: Option<i32>,
+    ) -> HermesResult<SearchPagedCollection> {
+        let start_time = std::time::Instant::now();
+        
+        // Get access token
+        let token_start = std::time::Instant::now();
+        let token = self.auth.get_access_token().await?;
+        let token_duration = token_start.elapsed();
+        tracing::info!("OAuth token request for search_items_with_refinements: {:?}", token_duration);
+        
+        let marketplace = self.config.marketplace;
+        let fieldgroups = "MATCHING_ITEMS,FULL";
+        
+        // Build the request URL
+        let url = self.browse_url("/item_summary/search", &[
+            ("category_ids", category_ids),
+            ("fieldgroups", Some(fieldgroups)),
+            ("limit", limit.map(|limit| limit.to_string()).as_deref()),
+            ("q", Some(query)),
+        ]);
+        let http = self.config.http_client();
+        
+        // Fail fast while this API's circuit breaker is open
+        let probe = self.config.check_circuit("buy.browse")?;
+        
+        // Call the eBay API
+        let ebay_start = std::time::Instant::now();
+        let result = self.config.retrying("buy.browse", || {
+            self.config.send_json(
+                http.get(&url).bearer_auth(&token).header("X-EBAY-C-MARKETPLACE-ID", marketplace.as_str()),
+            )
+        }).instrument(self.config.call_span("buy.browse", "search_items_with_refinements")).await;
+        let ebay_duration = ebay_start.elapsed();
+        record_api_call("buy.browse", "search_items_with_refinements", &result, ebay_duration);
+        self.config.record_circuit("buy.browse", &probe, &result);
+        tracing::info!("eBay search_items_with_refinements API call: {:?}", ebay_duration);
+        
+        match result {
+            Ok(response) => {
+                let total_duration = start_time.elapsed();
+                let our_processing = total_duration - token_duration - ebay_duration;
+                tracing::info!("search_items_with_refinements total: {:?} | Our processing: {:?}", total_duration, our_processing);
+                Ok(response)
+            },
+            Err(e) => {
+                let total_duration = start_time.elapsed();
+                tracing::error!("eBay search_items_with_refinements error after {:?}: {:?}", total_duration, e);
+                Err(raw_error("search_items_with_refinements", &format!("query {}", query), e))
             }
         }
     }
 
     /// Get item details by ID
+    /// 
+    /// The full HTML `description` and all `additional_images` are returned unless
+    /// `fieldgroups` is "COMPACT"; "PRODUCT" adds the catalog product's details. Use
+    /// [`ItemImagesExt::all_image_urls`] to collect every image.
+    /// 
+    /// # Arguments
+    /// * `item_id` - RESTful item ID, e.g. "v1|123|0"
+    /// * `fieldgroups` - Optional field groups: "PRODUCT", "COMPACT", "ADDITIONAL_SELLER_DETAILS" or "CHARITY_DETAILS"
     pub async fn get_item(
         &self,
         item_id: &str,
         fieldgroups: Option<&str>,
+    ) -> HermesResult<Item> {
+        self.get_item_in(self.config.marketplace, item_id, fieldgroups).await
+    }
+
+    async fn get_item_in(
+        &self,
+        marketplace: Marketplace,
+        item_id: &str,
+        fieldgroups: Option<&str>,
     ) -> HermesResult<Item> {
         let start_time = std::time::Instant::now();
         
@@ -277,27 +858,23 @@ impl EbayClient {
         let token_duration = token_start.elapsed();
         tracing::info!("OAuth token request for get_item: {:?}", token_duration);
         
-        // Set up configuration
-        let mut config = BrowseConfiguration::new();
-        config.base_path = if self.config.sandbox {
-            "https://api.sandbox.ebay.com/buy/browse/v1".to_string()
-        } else {
-            "https://api.ebay.com/buy/browse/v1".to_string()
-        };
-        config.oauth_access_token = Some(token);
+        // Build the request URL
+        let url = self.browse_url(&format!("/item/{}", urlencode(item_id)), &[("fieldgroups", fieldgroups)]);
+        let http = self.config.http_client();
+        
+        // Fail fast while this API's circuit breaker is open
+        let probe = self.config.check_circuit("buy.browse")?;
         
-        // Call the eBay SDK
+        // Call the eBay API
         let ebay_start = std::time::Instant::now();
-        let result = hermes_ebay_buy_browse::apis::item_api::get_item(
-            &config,
-            item_id,
-            fieldgroups,
-            None, // x_ebay_c_enduserctx
-            Some("EBAY-US"), // x_ebay_c_marketplace_id
-            None, // accept_language
-            None, // quantity_for_shipping_estimate
-        ).await;
+        let result = self.config.retrying("buy.browse", || {
+            self.config.send_json(
+                http.get(&url).bearer_auth(&token).header("X-EBAY-C-MARKETPLACE-ID", marketplace.as_str()),
+            )
+        }).instrument(self.config.call_span("buy.browse", "get_item")).await;
         let ebay_duration = ebay_start.elapsed();
+        record_api_call("buy.browse", "get_item", &result, ebay_duration);
+        self.config.record_circuit("buy.browse", &probe, &result);
         tracing::info!("eBay get_item API call: {:?}", ebay_duration);
         
         match result {
@@ -310,16 +887,199 @@ impl EbayClient {
             Err(e) => {
                 let total_duration = start_time.elapsed();
                 tracing::error!("eBay get_item error after {:?}: {:?}", total_duration, e);
-                Err(HermesError::ApiRequest(format!("eBay get_item failed: {:?}", e)))
+                Err(raw_error("get_item", &format!("item_id {}", item_id), e))
             }
         }
     }
 
+    /// Get an item's current price and availability
+    /// 
+    /// Requests the `COMPACT` field group, which returns only the fields needed to
+    /// check price and availability, and flattens the response. Much cheaper than
+    /// [`get_item`](Self::get_item) for price-tracking jobs that poll many items.
+    pub async fn get_item_price(&self, item_id: &str) -> HermesResult<ItemPriceSnapshot> {
+        let item = self.get_item(item_id, Some("COMPACT")).await?;
+
+        let price = item.price.as_deref().ok_or_else(|| {
+            HermesError::ApiRequest(format!("eBay get_item returned no price for item {}", item_id))
+        })?;
+        let amount = parse_decimal(price.value.as_deref()).ok_or_else(|| {
+            HermesError::ApiRequest(format!("eBay get_item returned invalid price {:?}", price.value))
+        })?;
+
+        let available_quantity = item
+            .estimated_availabilities
+            .iter()
+            .flatten()
+            .filter_map(|availability| availability.estimated_available_quantity)
+            .map(|quantity| quantity.max(0) as u32)
+            .reduce(|total, quantity| total + quantity);
+
+        Ok(ItemPriceSnapshot {
+            item_id: item.item_id.unwrap_or_else(|| item_id.to_string()),
+            price: amount,
+            currency: price.currency.clone().unwrap_or_default(),
+            available_quantity,
+            condition: item.condition,
+        })
+    }
+
     /// Get item by legacy ID
     pub async fn get_item_by_legacy_id(
         &self,
         legacy_item_id: &str,
         fieldgroups: Option<&str>,
+    ) -> HermesResult<Item> {
+        self.get_item_by_legacy_id_in(self.config.marketplace, legacy_item_id, fieldgroups).await
+    }
+
+    /// Get item details by an ID in either format
+    /// 
+    /// RESTful IDs ("v1|123|0") go to [`get_item`](Self::get_item) and numeric legacy
+    /// IDs to [`get_item_by_legacy_id`](Self::get_item_by_legacy_id).
+    /// 
+    /// # Arguments
+    /// * `id` - RESTful or legacy item ID
+    pub async fn get_item_auto(&self, id: &str) -> HermesResult<Item> {
+        match id.parse()? {
+            ItemId::Rest(id) => self.get_item(&id, None).await,
+            ItemId::Legacy(id) => self.get_item_by_legacy_id(&id, None).await,
+        }
+    }
+
+    /// List the seller's live listings with their SKUs and current Browse data
+    /// 
+    /// Published offers on `marketplace` are read from the Inventory API (one request
+    /// per SKU) and joined by listing ID with a Browse search of the seller's items in
+    /// each of the offers' categories. Only listings created through the Inventory API
+    /// are included. Browse does not report view counts; `watch_count` is the closest
+    /// live engagement figure, and views are in the Analytics traffic report.
+    /// 
+    /// # Arguments
+    /// * `seller_username` - The seller's eBay username, as shown to buyers
+    /// * `marketplace` - Marketplace the listings are on
+    pub async fn active_listings(&mut self, seller_username: &str, marketplace: Marketplace) -> HermesResult<Vec<ActiveListing>> {
+        self.inventory()?;
+        let client = &*self;
+        let inventory = client.inventory_client.as_ref().unwrap();
+
+        // Each SKU's published offers, with the inventory title as a fallback
+        let items: Vec<_> = inventory.inventory_items_stream().try_collect().await?;
+        let skus: Vec<(String, Option<String>)> = items
+            .into_iter()
+            .filter_map(|item| Some((item.sku?, item.product.and_then(|product| product.title))))
+            .collect();
+        let offers: Vec<_> = stream::iter(&skus)
+            .map(|(sku, title)| async move {
+                let offers = inventory.get_offers_for_sku(sku).await?;
+                Ok::<_, HermesError>(offers.into_iter().map(|offer| (offer, title.clone())).collect::<Vec<_>>())
+            })
+            .buffer_unordered(MAX_CONCURRENT_REQUESTS)
+            .try_concat()
+            .await?;
+        let offers: Vec<_> = offers
+            .into_iter()
+            .filter(|(offer, _)| {
+                offer.status.as_deref() == Some("PUBLISHED")
+                    && offer.marketplace_id.as_deref() == Some(marketplace.as_str())
+                    && offer.listing.as_ref().is_some_and(|listing| {
+                        listing.listing_id.is_some() && listing.listing_status.as_deref().is_none_or(|status| status == "ACTIVE")
+                    })
+            })
+            .collect();
+
+        // Browse needs a keyword or category, so search the seller's items per category
+        let categories: BTreeSet<&str> = offers.iter().filter_map(|(offer, _)| offer.category_id.as_deref()).collect();
+        let filter = format!("sellers:{{{}}}", seller_username);
+        let filter = filter.as_str();
+        let summaries: Vec<ItemSummary> = stream::iter(categories)
+            .map(|category_id| {
+                paginate(SELLER_SEARCH_PAGE_SIZE as usize, move |offset| {
+                    client.search_items_advanced_in(
+                        marketplace,
+                        None,
+                        None,
+                        Some(category_id),
+                        Some(filter),
+                        Some(SELLER_SEARCH_PAGE_SIZE),
+                        Some(offset as i32),
+                        None,
+                        false,
+                    )
+                })
+                .try_collect::<Vec<_>>()
+            })
+            .buffer_unordered(MAX_CONCURRENT_REQUESTS)
+            .try_concat()
+            .await?;
+        let live: HashMap<&str, &ItemSummary> = summaries
+            .iter()
+            .filter_map(|summary| Some((summary.legacy_item_id.as_deref()?, summary)))
+            .collect();
+
+        let mut listings: Vec<ActiveListing> = offers
+            .into_iter()
+            .filter_map(|(offer, inventory_title)| {
+                let listing_id = offer.listing.as_ref()?.listing_id.clone()?;
+                let summary = live.get(listing_id.as_str()).copied();
+                let offer_price = offer.pricing_summary.as_ref().and_then(|pricing| pricing.price.as_deref());
+                let price = summary
+                    .and_then(|summary| summary.price.as_deref())
+                    .and_then(|price| money(price.value.as_deref(), price.currency.as_deref()))
+                    .or_else(|| offer_price.and_then(|price| money(price.value.as_deref(), price.currency.as_deref())));
+                Some(ActiveListing {
+                    sku: offer.sku,
+                    offer_id: offer.offer_id,
+                    title: summary.and_then(|summary| summary.title.clone()).or(inventory_title),
+                    price,
+                    watch_count: summary.and_then(|summary| summary.watch_count).map(|count| count.max(0) as u32),
+                    item_web_url: summary.and_then(|summary| summary.item_web_url.clone()),
+                    listing_id,
+                })
+            })
+            .collect();
+        listings.sort_by(|a, b| a.listing_id.cmp(&b.listing_id));
+        Ok(listings)
+    }
+
+    /// Get an item together with the aspects of its category
+    /// 
+    /// Looks up the default category tree of the configured marketplace while the
+    /// item is fetched, then gets the aspects of the item's category. Tree IDs and
+    /// aspects are cached by the Taxonomy client, so repeated calls for items in the
+    /// same category only fetch the item.
+    /// 
+    /// # Arguments
+    /// * `item_id` - RESTful or legacy item ID
+    pub async fn item_with_aspects(&mut self, item_id: &str) -> HermesResult<(Item, AspectMetadata)> {
+        self.taxonomy()?;
+        let taxonomy = self.taxonomy_client.as_ref().unwrap();
+        let marketplaces = [self.config.marketplace];
+
+        let (item, catalog) = tokio::try_join!(
+            self.get_item_auto(item_id),
+            taxonomy.marketplace_catalog(&marketplaces),
+        )?;
+        let category_id = item.category_id.as_deref().ok_or_else(|| {
+            HermesError::ApiRequest(format!("eBay get_item returned no category for item {}", item_id))
+        })?;
+        let tree_id = &catalog[0].tree_id;
+        if tree_id.is_empty() {
+            return Err(HermesError::ApiRequest(format!(
+                "eBay returned no default category tree for marketplace {}",
+                self.config.marketplace.as_str()
+            )));
+        }
+
+        let aspects = taxonomy.cached_item_aspects(tree_id, category_id).await?;
+        Ok((item, Arc::unwrap_or_clone(aspects)))
+    }
+
+    async fn get_item_by_legacy_id_in(
+        &self,
+        marketplace: Marketplace,
+        legacy_item_id: &str,
+        fieldgroups: Option<&str>,
     ) -> HermesResult<Item> {
         let start_time = std::time::Instant::now();
         
@@ -329,29 +1089,26 @@ impl EbayClient {
         let token_duration = token_start.elapsed();
         tracing::info!("OAuth token request for get_item_by_legacy_id: {:?}", token_duration);
         
-        // Set up configuration
-        let mut config = BrowseConfiguration::new();
-        config.base_path = if self.config.sandbox {
-            "https://api.sandbox.ebay.com/buy/browse/v1".to_string()
-        } else {
-            "https://api.ebay.com/buy/browse/v1".to_string()
-        };
-        config.oauth_access_token = Some(token);
+        // Build the request URL
+        let url = self.browse_url("/item/get_item_by_legacy_id", &[
+            ("fieldgroups", fieldgroups),
+            ("legacy_item_id", Some(legacy_item_id)),
+        ]);
+        let http = self.config.http_client();
+        
+        // Fail fast while this API's circuit breaker is open
+        let probe = self.config.check_circuit("buy.browse")?;
         
-        // Call the eBay SDK
+        // Call the eBay API
         let ebay_start = std::time::Instant::now();
-        let result = hermes_ebay_buy_browse::apis::item_api::get_item_by_legacy_id(
-            &config,
-            &legacy_item_id,
-            fieldgroups,
-            None, // legacy_variation_id
-            None, // legacy_variation_sku
-            None, // x_ebay_c_enduserctx
-            Some("EBAY-US"), // x_ebay_c_marketplace_id
-            None, // accept_language
-            None, // quantity_for_shipping_estimate
-        ).await;
+        let result = self.config.retrying("buy.browse", || {
+            self.config.send_json(
+                http.get(&url).bearer_auth(&token).header("X-EBAY-C-MARKETPLACE-ID", marketplace.as_str()),
+            )
+        }).instrument(self.config.call_span("buy.browse", "get_item_by_legacy_id")).await;
         let ebay_duration = ebay_start.elapsed();
+        record_api_call("buy.browse", "get_item_by_legacy_id", &result, ebay_duration);
+        self.config.record_circuit("buy.browse", &probe, &result);
         tracing::info!("eBay get_item_by_legacy_id API call: {:?}", ebay_duration);
         
         match result {
@@ -364,7 +1121,7 @@ impl EbayClient {
             Err(e) => {
                 let total_duration = start_time.elapsed();
                 tracing::error!("eBay get_item_by_legacy_id error after {:?}: {:?}", total_duration, e);
-                Err(HermesError::ApiRequest(format!("eBay get_item_by_legacy_id failed: {:?}", e)))
+                Err(raw_error("get_item_by_legacy_id", &format!("legacy_item_id {}", legacy_item_id), e))
             }
         }
     }
@@ -383,26 +1140,20 @@ impl EbayClient {
         let token_duration = token_start.elapsed();
         tracing::info!("OAuth token request for check_compatibility: {:?}", token_duration);
         
-        // Set up configuration
-        let mut config = BrowseConfiguration::new();
-        config.base_path = if self.config.sandbox {
-            "https://api.sandbox.ebay.com/buy/browse/v1".to_string()
-        } else {
-            "https://api.ebay.com/buy/browse/v1".to_string()
-        };
-        config.oauth_access_token = Some(token);
+        let url = format!("{}/buy/browse/v1/item/{}/check_compatibility", self.config.base_url(), urlencode(item_id));
+        let http = self.config.http_client();
         
-        // Call the eBay SDK
+        // Fail fast while this API's circuit breaker is open
+        let probe = self.config.check_circuit("buy.browse")?;
+        
+        // Call the eBay API
         let ebay_start = std::time::Instant::now();
-        let result = hermes_ebay_buy_browse::apis::item_api::check_compatibility(
-            &config,
-            item_id,
-            "application/json", // content_type
-            None, // x_ebay_c_marketplace_id
-            None, // accept_language
-            Some(compatibility_payload),
-        ).await;
+        let result = self.config.send_json(
+            http.post(&url).bearer_auth(&token).json(&compatibility_payload),
+        ).instrument(self.config.call_span("buy.browse", "check_compatibility")).await;
         let ebay_duration = ebay_start.elapsed();
+        record_api_call("buy.browse", "check_compatibility", &result, ebay_duration);
+        self.config.record_circuit("buy.browse", &probe, &result);
         tracing::info!("eBay check_compatibility API call: {:?}", ebay_duration);
         
         match result {
@@ -415,7 +1166,7 @@ impl EbayClient {
             Err(e) => {
                 let total_duration = start_time.elapsed();
                 tracing::error!("eBay check_compatibility error after {:?}: {:?}", total_duration, e);
-                Err(HermesError::ApiRequest(format!("eBay check_compatibility failed: {:?}", e)))
+                Err(raw_error("check_compatibility", &format!("item_id {}", item_id), e))
             }
         }
     }
@@ -433,23 +1184,25 @@ impl EbayClient {
         let token_duration = token_start.elapsed();
         tracing::info!("OAuth token request for get_categories: {:?}", token_duration);
         
-        // Set up configuration
-        let mut config = TaxonomyConfiguration::new();
-        config.base_path = if self.config.sandbox {
-            "https://api.sandbox.ebay.com/commerce/taxonomy/v1".to_string()
-        } else {
-            "https://api.ebay.com/commerce/taxonomy/v1".to_string()
-        };
-        config.oauth_access_token = Some(token);
+        let category_tree_id = marketplace_id.unwrap_or("EBAY-US");
+        let url = format!("{}/commerce/taxonomy/v1/category_tree/{}", self.config.base_url(), urlencode(category_tree_id));
+        let http = self.config.http_client();
+        
+        // Fail fast while this API's circuit breaker is open
+        let probe = self.config.check_circuit("commerce.taxonomy")?;
         
-        // Call the eBay SDK
+        // Call the eBay API
         let ebay_start = std::time::Instant::now();
-        let result = hermes_ebay_commerce_taxonomy::apis::category_tree_api::get_category_tree(
-            &config,
-            marketplace_id.unwrap_or("EBAY-US"),
-            None, // accept_encoding
-        ).await;
+        let result = self.config.retrying("commerce.taxonomy", || {
+            let mut request = http.get(&url).bearer_auth(&token);
+            if let Some(encoding) = self.config.taxonomy_accept_encoding(None) {
+                request = request.header(reqwest::header::ACCEPT_ENCODING, encoding);
+            }
+            self.config.send_json(request)
+        }).await;
         let ebay_duration = ebay_start.elapsed();
+        record_api_call("commerce.taxonomy", "get_categories", &result, ebay_duration);
+        self.config.record_circuit("commerce.taxonomy", &probe, &result);
         tracing::info!("eBay get_categories API call: {:?}", ebay_duration);
         
         match result {
@@ -462,7 +1215,7 @@ impl EbayClient {
             Err(e) => {
                 let total_duration = start_time.elapsed();
                 tracing::error!("eBay get_categories error after {:?}: {:?}", total_duration, e);
-                Err(HermesError::ApiRequest(format!("eBay get_categories failed: {:?}", e)))
+                Err(raw_error("get_categories", &format!("category_tree_id {}", category_tree_id), e))
             }
         }
     }
@@ -472,6 +1225,15 @@ impl EbayClient {
         &self,
         item_ids: Option<&str>,
         item_group_ids: Option<&str>,
+    ) -> HermesResult<Items> {
+        self.get_items_in(self.config.marketplace, item_ids, item_group_ids).await
+    }
+
+    async fn get_items_in(
+        &self,
+        marketplace: Marketplace,
+        item_ids: Option<&str>,
+        item_group_ids: Option<&str>,
     ) -> HermesResult<Items> {
         let start_time = std::time::Instant::now();
         
@@ -481,27 +1243,26 @@ impl EbayClient {
         let token_duration = token_start.elapsed();
         tracing::info!("OAuth token request for get_items: {:?}", token_duration);
         
-        // Set up configuration
-        let mut config = BrowseConfiguration::new();
-        config.base_path = if self.config.sandbox {
-            "https://api.sandbox.ebay.com/buy/browse/v1".to_string()
-        } else {
-            "https://api.ebay.com/buy/browse/v1".to_string()
-        };
-        config.oauth_access_token = Some(token);
+        // Build the request URL
+        let url = self.browse_url("/item/", &[
+            ("item_ids", item_ids),
+            ("item_group_ids", item_group_ids),
+        ]);
+        let http = self.config.http_client();
+        
+        // Fail fast while this API's circuit breaker is open
+        let probe = self.config.check_circuit("buy.browse")?;
         
-        // Call the eBay SDK
+        // Call the eBay API
         let ebay_start = std::time::Instant::now();
-        let result = hermes_ebay_buy_browse::apis::item_api::get_items(
-            &config,
-            item_ids,
-            item_group_ids,
-            None, // x_ebay_c_enduserctx
-            Some("EBAY-US"), // x_ebay_c_marketplace_id
-            None, // accept_language
-            None, // quantity_for_shipping_estimate
-        ).await;
+        let result = self.config.retrying("buy.browse", || {
+            self.config.send_json(
+                http.get(&url).bearer_auth(&token).header("X-EBAY-C-MARKETPLACE-ID", marketplace.as_str()),
+            )
+        }).instrument(self.config.call_span("buy.browse", "get_items")).await;
         let ebay_duration = ebay_start.elapsed();
+        record_api_call("buy.browse", "get_items", &result, ebay_duration);
+        self.config.record_circuit("buy.browse", &probe, &result);
         tracing::info!("eBay get_items API call: {:?}", ebay_duration);
         
         match result {
@@ -514,7 +1275,7 @@ impl EbayClient {
             Err(e) => {
                 let total_duration = start_time.elapsed();
                 tracing::error!("eBay get_items error after {:?}: {:?}", total_duration, e);
-                Err(HermesError::ApiRequest(format!("eBay get_items failed: {:?}", e)))
+                Err(raw_error("get_items", &format!("item_ids {}, item_group_ids {}", item_ids.unwrap_or("none"), item_group_ids.unwrap_or("none")), e))
             }
         }
     }
@@ -524,7 +1285,16 @@ impl EbayClient {
         &self,
         item_group_id: &str,
         fieldgroups: Option<&str>,
-    ) -> HermesResult<hermes_ebay_buy_browse::models::ItemGroup> {
+    ) -> HermesResult<ItemGroup> {
+        self.get_items_by_item_group_in(self.config.marketplace, item_group_id, fieldgroups).await
+    }
+
+    async fn get_items_by_item_group_in(
+        &self,
+        marketplace: Marketplace,
+        item_group_id: &str,
+        fieldgroups: Option<&str>,
+    ) -> HermesResult<ItemGroup> {
         let start_time = std::time::Instant::now();
         
         // Get access token
@@ -533,27 +1303,26 @@ impl EbayClient {
         let token_duration = token_start.elapsed();
         tracing::info!("OAuth token request for get_items_by_item_group: {:?}", token_duration);
         
-        // Set up configuration
-        let mut config = BrowseConfiguration::new();
-        config.base_path = if self.config.sandbox {
-            "https://api.sandbox.ebay.com/buy/browse/v1".to_string()
-        } else {
-            "https://api.ebay.com/buy/browse/v1".to_string()
-        };
-        config.oauth_access_token = Some(token);
+        // Build the request URL
+        let url = self.browse_url("/item/get_items_by_item_group", &[
+            ("fieldgroups", fieldgroups),
+            ("item_group_id", Some(item_group_id)),
+        ]);
+        let http = self.config.http_client();
+        
+        // Fail fast while this API's circuit breaker is open
+        let probe = self.config.check_circuit("buy.browse")?;
         
-        // Call the eBay SDK
+        // Call the eBay API
         let ebay_start = std::time::Instant::now();
-        let result = hermes_ebay_buy_browse::apis::item_api::get_items_by_item_group(
-            &config,
-            &item_group_id,
-            fieldgroups,
-            None, // x_ebay_c_enduserctx
-            Some("EBAY-US"), // x_ebay_c_marketplace_id
-            None, // accept_language
-            None, // quantity_for_shipping_estimate
-        ).await;
+        let result = self.config.retrying("buy.browse", || {
+            self.config.send_json(
+                http.get(&url).bearer_auth(&token).header("X-EBAY-C-MARKETPLACE-ID", marketplace.as_str()),
+            )
+        }).instrument(self.config.call_span("buy.browse", "get_items_by_item_group")).await;
         let ebay_duration = ebay_start.elapsed();
+        record_api_call("buy.browse", "get_items_by_item_group", &result, ebay_duration);
+        self.config.record_circuit("buy.browse", &probe, &result);
         tracing::info!("eBay get_items_by_item_group API call: {:?}", ebay_duration);
         
         match result {
@@ -566,12 +1335,52 @@ impl EbayClient {
             Err(e) => {
                 let total_duration = start_time.elapsed();
                 tracing::error!("eBay get_items_by_item_group error after {:?}: {:?}", total_duration, e);
-                Err(HermesError::ApiRequest(format!("eBay get_items_by_item_group failed: {:?}", e)))
+                Err(raw_error("get_items_by_item_group", &format!("item_group_id {}", item_group_id), e))
             }
         }
     }
 
+    /// Get several item groups concurrently
+    /// 
+    /// Fetches each group with [`get_items_by_item_group`](Self::get_items_by_item_group),
+    /// running at most a handful of requests at a time. Every group ID gets its own
+    /// result, so one missing group does not fail the whole batch.
+    pub async fn get_item_groups(
+        &self,
+        group_ids: &[&str],
+    ) -> HashMap<String, HermesResult<ItemGroup>> {
+        stream::iter(group_ids.iter().copied())
+            .map(|group_id| async move {
+                (group_id.to_string(), self.get_items_by_item_group(group_id, None).await)
+            })
+            .buffer_unordered(MAX_CONCURRENT_REQUESTS)
+            .collect()
+            .await
+    }
+
+    /// Build a Browse search with named parameters
+    /// 
+    /// ```no_run
+    /// # async fn example(client: &hermes_sdk::ebay::EbayClient) -> hermes_sdk::error::HermesResult<()> {
+    /// let laptops = client
+    ///     .search()
+    ///     .query("laptop")
+    ///     .category("58058")
+    ///     .filter("price:[100..1000],priceCurrency:USD")
+    ///     .sort("price")
+    ///     .limit(20)
+    ///     .send()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn search(&self) -> SearchRequestBuilder<'_> {
+        SearchRequestBuilder::new(self, self.config.marketplace)
+    }
+
     /// Search items with advanced parameters
+    #[deprecated(note = "use `search()`, whose named methods cannot swap `filter` and `aspect_filter`")]
+    #[allow(clippy::too_many_arguments)]
     pub async fn search_items_advanced(
         &self,
         query: Option<&str>,
@@ -581,6 +1390,23 @@ impl EbayClient {
         limit: Option<i32>,
         offset: Option<i32>,
         sort: Option<&str>,
+        auto_correct: bool,
+    ) -> HermesResult<SearchPagedCollection> {
+        self.search_items_advanced_in(self.config.marketplace, query, aspect_filter, category_ids, filter, limit, offset, sort, auto_correct).await
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    async fn search_items_advanced_in(
+        &self,
+        marketplace: Marketplace,
+        query: Option<&str>,
+        aspect_filter: Option<&str>,
+        category_ids: Option<&str>,
+        filter: Option<&str>,
+        limit: Option<i32>,
+        offset: Option<i32>,
+        sort: Option<&str>,
+        auto_correct: bool,
     ) -> HermesResult<SearchPagedCollection> {
         let start_time = std::time::Instant::now();
         
@@ -590,37 +1416,32 @@ impl EbayClient {
         let token_duration = token_start.elapsed();
         tracing::info!("OAuth token request for search_items_advanced: {:?}", token_duration);
         
-        // Set up configuration
-        let mut config = BrowseConfiguration::new();
-        config.base_path = if self.config.sandbox {
-            "https://api.sandbox.ebay.com/buy/browse/v1".to_string()
-        } else {
-            "https://api.ebay.com/buy/browse/v1".to_string()
-        };
-        config.oauth_access_token = Some(token);
+        // Build the request URL
+        let url = self.browse_url("/item_summary/search", &[
+            ("aspect_filter", aspect_filter),
+            ("auto_correct", auto_correct_param(auto_correct)),
+            ("category_ids", category_ids),
+            ("filter", filter),
+            ("limit", limit.map(|limit| limit.to_string()).as_deref()),
+            ("offset", offset.map(|offset| offset.to_string()).as_deref()),
+            ("q", query),
+            ("sort", sort),
+        ]);
+        let http = self.config.http_client();
         
-        // Call the eBay SDK
+        // Fail fast while this API's circuit breaker is open
+        let probe = self.config.check_circuit("buy.browse")?;
+        
+        // Call the eBay API
         let ebay_start = std::time::Instant::now();
-        let result = hermes_ebay_buy_browse::apis::item_summary_api::search(
-            &config,
-            query,
-            aspect_filter,
-            None, // auto_correct
-            category_ids,
-            None, // charity_ids
-            None, // compatibility_filter
-            None, // condition_ids
-            None, // epid
-            None, // fieldgroups
-            filter,
-            None, // gtin
-            None, // offset
-            sort,
-            None, // x_ebay_c_enduserctx
-            Some("EBAY-US"), // x_ebay_c_marketplace_id
-            None, // accept_language
-        ).await;
+        let result = self.config.retrying("buy.browse", || {
+            self.config.send_json(
+                http.get(&url).bearer_auth(&token).header("X-EBAY-C-MARKETPLACE-ID", marketplace.as_str()),
+            )
+        }).instrument(self.config.call_span("buy.browse", "search_items_advanced")).await;
         let ebay_duration = ebay_start.elapsed();
+        record_api_call("buy.browse", "search_items_advanced", &result, ebay_duration);
+        self.config.record_circuit("buy.browse", &probe, &result);
         tracing::info!("eBay search_items_advanced API call: {:?}", ebay_duration);
         
         match result {
@@ -633,18 +1454,58 @@ impl EbayClient {
             Err(e) => {
                 let total_duration = start_time.elapsed();
                 tracing::error!("eBay search_items_advanced error after {:?}: {:?}", total_duration, e);
-                Err(HermesError::ApiRequest(format!("eBay search_items_advanced failed: {:?}", e)))
+                Err(raw_error("search_items_advanced", &format!("query {}, category_ids {}", query.unwrap_or("none"), category_ids.unwrap_or("none")), e))
             }
         }
     }
 
+    /// Stream search results, fetching pages lazily
+    /// 
+    /// A page of `page_size` items is requested only once the previous page has been
+    /// consumed, so `.take(n)` never fetches past what is needed. The stream ends at the
+    /// search's reported `total`, on a short or empty page, or after yielding the first error.
+    pub fn search_items_stream<'a>(
+        &'a self,
+        query: &'a str,
+        page_size: i32,
+    ) -> impl Stream<Item = HermesResult<ItemSummary>> + 'a {
+        paginate(page_size.max(0) as usize, move |offset| {
+            self.search().query(query).limit(page_size).offset(offset as i32).send()
+        })
+    }
+
     /// Search items by image
+    /// 
+    /// The image must be a JPEG or PNG of at most 12 MB; anything else fails with
+    /// `HermesError::Configuration` before eBay is called.
+    /// 
+    /// # Arguments
+    /// * `image_data` - JPEG or PNG image to search with
+    /// * `category_ids` - Optional categories to limit the search to
+    /// * `limit` - Optional maximum number of items to return
+    /// * `fieldgroups` - Optional field groups, e.g. "FULL" or "ASPECT_REFINEMENTS";
+    ///   defaults to "MATCHING_ITEMS", which is enough for a results grid
     pub async fn search_by_image(
         &self,
         image_data: &[u8],
         category_ids: Option<&str>,
         limit: Option<i32>,
+        fieldgroups: Option<&str>,
+    ) -> HermesResult<SearchPagedCollection> {
+        self.search_by_image_in(self.config.marketplace, image_data, category_ids, limit, fieldgroups).await
+    }
+
+    async fn search_by_image_in(
+        &self,
+        marketplace: Marketplace,
+        image_data: &[u8],
+        category_ids: Option<&str>,
+        limit: Option<i32>,
+        fieldgroups: Option<&str>,
     ) -> HermesResult<SearchPagedCollection> {
+        validate_search_image(image_data)?;
+        let fieldgroups = fieldgroups.unwrap_or(DEFAULT_IMAGE_SEARCH_FIELDGROUPS);
+        let limit = limit.map(|l| l.to_string());
         let start_time = std::time::Instant::now();
         
         // Get access token
@@ -653,34 +1514,29 @@ impl EbayClient {
         let token_duration = token_start.elapsed();
         tracing::info!("OAuth token request for search_by_image: {:?}", token_duration);
         
-        // Set up configuration
-        let mut config = BrowseConfiguration::new();
-        config.base_path = if self.config.sandbox {
-            "https://api.sandbox.ebay.com/buy/browse/v1".to_string()
-        } else {
-            "https://api.ebay.com/buy/browse/v1".to_string()
-        };
-        config.oauth_access_token = Some(token);
+        // Build the request URL
+        let url = self.browse_url("/item_summary/search_by_image", &[
+            ("category_ids", category_ids),
+            ("fieldgroups", Some(fieldgroups)),
+            ("limit", limit.as_deref()),
+        ]);
+        let http = self.config.http_client();
+        
+        // Fail fast while this API's circuit breaker is open
+        let probe = self.config.check_circuit("buy.browse")?;
         
-        // Call the eBay SDK
+        // Call the eBay API
         let ebay_start = std::time::Instant::now();
-        let result = hermes_ebay_buy_browse::apis::item_summary_api::search_by_image(
-            &config,
-            "application/octet-stream",
-            None, // aspect_filter
-            category_ids,
-            None, // charity_ids
-            None, // fieldgroups
-            None, // filter
-            None, // limit (expects &str)
-            None, // offset
-            None, // sort
-            None, // x_ebay_c_enduserctx
-            Some("EBAY-US"), // x_ebay_c_marketplace_id
-            None, // accept_language
-            None, // search_by_image_request (expects SearchByImageRequest)
-        ).await;
+        let request = SearchByImageRequest { image: Some(BASE64_STANDARD.encode(image_data)) };
+        let result = self.config.send_json(
+            http.post(&url)
+                .bearer_auth(&token)
+                .header("X-EBAY-C-MARKETPLACE-ID", marketplace.as_str())
+                .json(&request),
+        ).instrument(self.config.call_span("buy.browse", "search_by_image")).await;
         let ebay_duration = ebay_start.elapsed();
+        record_api_call("buy.browse", "search_by_image", &result, ebay_duration);
+        self.config.record_circuit("buy.browse", &probe, &result);
         tracing::info!("eBay search_by_image API call: {:?}", ebay_duration);
         
         match result {
@@ -693,11 +1549,177 @@ impl EbayClient {
             Err(e) => {
                 let total_duration = start_time.elapsed();
                 tracing::error!("eBay search_by_image error after {:?}: {:?}", total_duration, e);
-                Err(HermesError::ApiRequest(format!("eBay search_by_image failed: {:?}", e)))
+                Err(raw_error("search_by_image", &format!("image search on marketplace_id {}", marketplace), e))
             }
         }
     }
 
+    /// Call any eBay REST endpoint, for APIs the SDK does not wrap yet
+    /// 
+    /// Handles the OAuth token, base URL, marketplace and correlation headers, circuit
+    /// breaking, retries of GETs and error mapping like the typed methods; the request
+    /// and response bodies are plain JSON. An empty response body yields `Value::Null`,
+    /// as does any request other than GET, HEAD or OPTIONS in dry-run mode.
+    /// 
+    /// # Arguments
+    /// * `service` - API family the endpoint belongs to
+    /// * `method` - HTTP method
+    /// * `path` - Path below the service root, starting with the version (e.g. "/v1/item/v1|123|0")
+    /// * `query` - Query parameters
+    /// * `body` - JSON request body, if any
+    pub async fn call_raw(
+        &self,
+        service: EbayService,
+        method: reqwest::Method,
+        path: &str,
+        query: &[(&str, &str)],
+        body: Option<serde_json::Value>,
+    ) -> HermesResult<serde_json::Value> {
+        // Dry-run mode logs mutating requests instead of sending them
+        if !method.is_safe() {
+            let request = (method.as_str(), service.root_path(), path, query, &body);
+            if let Some(response) = self.config.skip_in_dry_run("call_raw", &request) {
+                return Ok(response);
+            }
+        }
+
+        let start_time = std::time::Instant::now();
+        let api = service.api_name();
+        
+        // Get access token
+        let token = self.auth.get_access_token().await?;
+        
+        let url = format!("{}{}{}", self.config.base_url(), service.root_path(), path);
+        let marketplace = self.config.marketplace.as_str();
+        
+        // Fail fast while this API's circuit breaker is open
+        let probe = self.config.check_circuit(api)?;
+        
+        let http = self.config.http_client();
+        let send = || {
+            let mut request = http
+                .request(method.clone(), &url)
+                .bearer_auth(&token)
+                .header("X-EBAY-C-MARKETPLACE-ID", marketplace)
+                .query(query);
+            if let Some(body) = &body {
+                request = request.json(body);
+            }
+            self.config.send(request)
+        };
+        
+        // Only GETs are retried; other methods may not be idempotent
+        let ebay_start = std::time::Instant::now();
+        let result = if method == reqwest::Method::GET {
+            self.config.retrying(api, send).await
+        } else {
+            send().await
+        };
+        let ebay_duration = ebay_start.elapsed();
+        record_api_call(api, "call_raw", &result, ebay_duration);
+        self.config.record_circuit(api, &probe, &result);
+        tracing::info!("eBay call_raw {} {}{} API call: {:?}", method, service.root_path(), path, ebay_duration);
+        
+        match result {
+            Ok(text) if text.trim().is_empty() => Ok(serde_json::Value::Null),
+            Ok(text) => parse_response(&text),
+            Err(e) => {
+                tracing::error!("eBay call_raw error after {:?}: {:?}", start_time.elapsed(), e);
+                Err(raw_error("call_raw", &format!("{} {}{}", method, service.root_path(), path), e))
+            }
+        }
+    }
+
+    /// Call any eBay REST endpoint and parse the response into a model
+    /// 
+    /// Like [`call_raw`](Self::call_raw), but deserializes the body into `T`. With
+    /// `EbayConfig::with_raw_capture(true)` the returned [`EbayResponse`] also keeps
+    /// the JSON, so fields `T` does not model yet stay readable.
+    /// 
+    /// A mutating request skipped in dry-run mode is parsed from an empty JSON
+    /// object, so models whose fields are all optional come back empty.
+    /// 
+    /// # Arguments
+    /// * `service` - API family the endpoint belongs to
+    /// * `method` - HTTP method
+    /// * `path` - Path below the service root, starting with the version (e.g. "/v1/item/v1|123|0")
+    /// * `query` - Query parameters
+    /// * `body` - JSON request body, if any
+    pub async fn call_typed<T: DeserializeOwned>(
+        &self,
+        service: EbayService,
+        method: reqwest::Method,
+        path: &str,
+        query: &[(&str, &str)],
+        body: Option<serde_json::Value>,
+    ) -> HermesResult<EbayResponse<T>> {
+        let skipped = self.config.dry_run && !method.is_safe();
+        let raw = self.call_raw(service, method, path, query, body).await?;
+        let value = match T::deserialize(&raw) {
+            Err(_) if skipped => T::deserialize(serde_json::json!({})),
+            parsed => parsed,
+        }
+        .map_err(|e| HermesError::Deserialize { reason: e.to_string(), body: raw.to_string() })?;
+        Ok(EbayResponse::new(value, self.config.raw_capture.then_some(raw)))
+    }
+
+    /// Get the application's current call-limit usage
+    /// 
+    /// Queries the Developer Analytics API and returns one entry per rate-limited
+    /// resource, so batch jobs can slow down before eBay starts rejecting calls.
+    /// 
+    /// # Arguments
+    /// * `api_context` - Optional API context filter (e.g. "buy", "sell", "commerce")
+    /// * `api_name` - Optional API name filter (e.g. "browse")
+    pub async fn rate_limit_status(
+        &self,
+        api_context: Option<&str>,
+        api_name: Option<&str>,
+    ) -> HermesResult<Vec<RateLimitStatus>> {
+        let start_time = std::time::Instant::now();
+        
+        // Get access token
+        let token = self.auth.get_access_token().await?;
+        
+        let url = format!("{}/developer/analytics/v1_beta/rate_limit/", self.config.base_url());
+        let mut query = Vec::new();
+        if let Some(api_context) = api_context {
+            query.push(("api_context", api_context));
+        }
+        if let Some(api_name) = api_name {
+            query.push(("api_name", api_name));
+        }
+        
+        let http = self.config.http_client();
+        
+        // Fail fast while this API's circuit breaker is open
+        let probe = self.config.check_circuit("developer.analytics")?;
+        
+        let ebay_start = std::time::Instant::now();
+        let result = self
+            .config
+            .retrying("developer.analytics", || {
+                self.config.send(http.get(&url).bearer_auth(&token).query(&query))
+            })
+            .await;
+        let ebay_duration = ebay_start.elapsed();
+        record_api_call("developer.analytics", "get_rate_limits", &result, ebay_duration);
+        self.config.record_circuit("developer.analytics", &probe, &result);
+        tracing::info!("eBay get_rate_limits API call: {:?}", ebay_duration);
+        
+        let body = match result {
+            Ok(body) => body,
+            Err(e) => {
+                tracing::error!("eBay get_rate_limits error after {:?}: {:?}", start_time.elapsed(), e);
+                return Err(raw_error("get_rate_limits", "rate_limit", e));
+            }
+        };
+        
+        let rate_limits: RateLimitsResponse = parse_response(&body)?;
+        tracing::info!("rate_limit_status total: {:?}", start_time.elapsed());
+        Ok(rate_limits.into_statuses())
+    }
+
     /// Mock data for development (when no credentials provided)
     pub fn get_mock_items() -> SearchPagedCollection {
         use hermes_ebay_buy_browse::models::{ItemSummary, ConvertedAmount, Image};
@@ -749,4 +1771,1278 @@ impl EbayClient {
             ..Default::default()
         }
     }
-}
\ No newline at end of file
+}
+
+/// Marketplace-scoped view of an [`EbayClient`]
+/// 
+/// Created by [`EbayClient::with_marketplace`]. Shares the client's auth and
+/// configuration; only the marketplace sent with each request differs.
+pub struct MarketplaceScope<'a> {
+    client: &'a EbayClient,
+    marketplace: Marketplace,
+}
+
+impl MarketplaceScope<'_> {
+    /// The marketplace these calls are sent to
+    pub fn marketplace(&self) -> Marketplace {
+        self.marketplace
+    }
+
+    /// Search for items on eBay
+    pub async fn search_items(
+        &self,
+        query: &str,
+        limit: Option<i32>,
+        auto_correct: bool,
+    ) -> HermesResult<SearchPagedCollection> {
+        self.client.search_items_in(self.marketplace, query, limit, auto_correct).await
+    }
+
+    /// Build a Browse search with named parameters (see [`EbayClient::search`])
+    pub fn search(&self) -> SearchRequestBuilder<'_> {
+        SearchRequestBuilder::new(self.client, self.marketplace)
+    }
+
+    /// Search items with advanced parameters
+    #[deprecated(note = "use `search()`, whose named methods cannot swap `filter` and `aspect_filter`")]
+    #[allow(clippy::too_many_arguments)]
+    pub async fn search_items_advanced(
+        &self,
+        query: Option<&str>,
+        aspect_filter: Option<&str>,
+        category_ids: Option<&str>,
+        filter: Option<&str>,
+        limit: Option<i32>,
+        offset: Option<i32>,
+        sort: Option<&str>,
+        auto_correct: bool,
+    ) -> HermesResult<SearchPagedCollection> {
+        self.client.search_items_advanced_in(self.marketplace, query, aspect_filter, category_ids, filter, limit, offset, sort, auto_correct).await
+    }
+
+    /// Search items by image
+    pub async fn search_by_image(
+        &self,
+        image_data: &[u8],
+        category_ids: Option<&str>,
+        limit: Option<i32>,
+        fieldgroups: Option<&str>,
+    ) -> HermesResult<SearchPagedCollection> {
+        self.client.search_by_image_in(self.marketplace, image_data, category_ids, limit, fieldgroups).await
+    }
+
+    /// Get item details by ID
+    pub async fn get_item(
+        &self,
+        item_id: &str,
+        fieldgroups: Option<&str>,
+    ) -> HermesResult<Item> {
+        self.client.get_item_in(self.marketplace, item_id, fieldgroups).await
+    }
+
+    /// Get item by legacy ID
+    pub async fn get_item_by_legacy_id(
+        &self,
+        legacy_item_id: &str,
+        fieldgroups: Option<&str>,
+    ) -> HermesResult<Item> {
+        self.client.get_item_by_legacy_id_in(self.marketplace, legacy_item_id, fieldgroups).await
+    }
+
+    /// Get multiple items by IDs
+    pub async fn get_items(
+        &self,
+        item_ids: Option<&str>,
+        item_group_ids: Option<&str>,
+    ) -> HermesResult<Items> {
+        self.client.get_items_in(self.marketplace, item_ids, item_group_ids).await
+    }
+
+    /// Get items by item group ID
+    pub async fn get_items_by_item_group(
+        &self,
+        item_group_id: &str,
+        fieldgroups: Option<&str>,
+    ) -> HermesResult<ItemGroup> {
+        self.client.get_items_by_item_group_in(self.marketplace, item_group_id, fieldgroups).await
+    }
+}
+
+/// A Browse search under construction, sent with [`send`](Self::send)
+/// 
+/// Created by [`EbayClient::search`] or [`MarketplaceScope::search`]. Every
+/// parameter is optional, but eBay requires at least a query or a category.
+#[must_use = "a search does nothing until `send` is awaited"]
+pub struct SearchRequestBuilder<'a> {
+    client: &'a EbayClient,
+    marketplace: Marketplace,
+    query: Option<&'a str>,
+    aspect_filter: Option<&'a str>,
+    category_ids: Option<&'a str>,
+    filter: Option<&'a str>,
+    limit: Option<i32>,
+    offset: Option<i32>,
+    sort: Option<&'a str>,
+    auto_correct: bool,
+}
+
+impl<'a> SearchRequestBuilder<'a> {
+    fn new(client: &'a EbayClient, marketplace: Marketplace) -> Self {
+        Self {
+            client,
+            marketplace,
+            query: None,
+            aspect_filter: None,
+            category_ids: None,
+            filter: None,
+            limit: None,
+            offset: None,
+            sort: None,
+            auto_correct: false,
+        }
+    }
+
+    /// Keywords to search for, e.g. "iphone 15 case"
+    pub fn query(mut self, query: &'a str) -> Self {
+        self.query = Some(query);
+        self
+    }
+
+    /// Category to search in, e.g. "9355" (Browse takes one category per search)
+    pub fn category(mut self, category_id: &'a str) -> Self {
+        self.category_ids = Some(category_id);
+        self
+    }
+
+    /// Item filters, e.g. "price:[10..50],priceCurrency:USD,conditions:{NEW}"
+    pub fn filter(mut self, filter: &'a str) -> Self {
+        self.filter = Some(filter);
+        self
+    }
+
+    /// Aspect filters, e.g. "categoryId:9355,Color:{Black}" (needs a category)
+    pub fn aspect_filter(mut self, aspect_filter: &'a str) -> Self {
+        self.aspect_filter = Some(aspect_filter);
+        self
+    }
+
+    /// Sort order, e.g. "price", "-price" or "newlyListed" (best match when unset)
+    pub fn sort(mut self, sort: &'a str) -> Self {
+        self.sort = Some(sort);
+        self
+    }
+
+    /// Maximum number of items in the page (eBay allows up to 200)
+    pub fn limit(mut self, limit: i32) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Number of items to skip, for paging
+    pub fn offset(mut self, offset: i32) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+
+    /// Let eBay correct misspelled keywords
+    pub fn auto_correct(mut self, enabled: bool) -> Self {
+        self.auto_correct = enabled;
+        self
+    }
+
+    /// Run the search
+    pub async fn send(self) -> HermesResult<SearchPagedCollection> {
+        self.client
+            .search_items_advanced_in(
+                self.marketplace,
+                self.query,
+                self.aspect_filter,
+                self.category_ids,
+                self.filter,
+                self.limit,
+                self.offset,
+                self.sort,
+                self.auto_correct,
+            )
+            .await
+    }
+}
+
+/// Reject images eBay's search_by_image would refuse, with a clearer message than eBay's
+fn validate_search_image(image_data: &[u8]) -> HermesResult<()> {
+    if image_data.len() > MAX_SEARCH_IMAGE_BYTES {
+        return Err(HermesError::Configuration(format!(
+            "search_by_image image is {} bytes; eBay accepts at most {} bytes",
+            image_data.len(),
+            MAX_SEARCH_IMAGE_BYTES
+        )));
+    }
+    let is_jpeg = image_data.starts_with(&[0xFF, 0xD8, 0xFF]);
+    let is_png = image_data.starts_with(b"\x89PNG\r\n\x1a\n");
+    if !is_jpeg && !is_png {
+        return Err(HermesError::Configuration(
+            "search_by_image image must be a JPEG or PNG file".to_string(),
+        ));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ebay::test_support::mock_ebay;
+    use wiremock::matchers::{body_partial_json, header, method, path, query_param};
+    use wiremock::{Mock, ResponseTemplate};
+
+    #[test]
+    fn variation_matrix_maps_aspect_combinations() {
+        let variant = |id: &str, color: &str, size: &str, price: &str, status: &str| {
+            serde_json::json!({
+                "itemId": id,
+                "price": { "value": price, "currency": "USD" },
+                "localizedAspects": [
+                    { "type": "STRING", "name": "Brand", "value": "Acme" },
+                    { "type": "STRING", "name": "Color", "value": color },
+                    { "type": "STRING", "name": "Size", "value": size }
+                ],
+                "estimatedAvailabilities": [{ "estimatedAvailabilityStatus": status, "estimatedAvailableQuantity": 3 }]
+            })
+        };
+        let group: ItemGroup = serde_json::from_value(serde_json::json!({
+            "items": [
+                variant("v1|1|10", "Red", "M", "19.99", "IN_STOCK"),
+                variant("v1|1|11", "Red", "L", "21.99", "IN_STOCK"),
+                variant("v1|1|12", "Blue", "M", "19.99", "OUT_OF_STOCK")
+            ]
+        }))
+        .unwrap();
+
+        let matrix = group.variation_matrix();
+        assert_eq!(matrix.aspects, ["Color", "Size"]);
+        assert_eq!(matrix.values("Color"), ["Red", "Blue"]);
+        assert_eq!(matrix.values("Size"), ["M", "L"]);
+
+        let red_large = matrix.find(&[("Color", "Red"), ("Size", "L")]).unwrap();
+        assert_eq!(red_large.item_id, "v1|1|11");
+        assert_eq!(red_large.price, Some(Decimal::new(2199, 2)));
+        assert!(red_large.available);
+
+        let blue_medium = matrix.find(&[("Size", "M"), ("Color", "Blue")]).unwrap();
+        assert!(!blue_medium.available);
+        assert!(!blue_medium.aspects.contains_key("Brand"));
+        assert!(matrix.find(&[("Color", "Blue"), ("Size", "L")]).is_none());
+    }
+
+    #[tokio::test]
+    async fn get_item_auto_dispatches_on_id_format() {
+        let (server, config) = mock_ebay().await;
+        Mock::given(method("GET"))
+            .and(path("/buy/browse/v1/item/v1%7C123%7C0"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({ "itemId": "v1|123|0", "title": "RESTful" })))
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/buy/browse/v1/item/get_item_by_legacy_id"))
+            .and(query_param("legacy_item_id", "123"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({ "itemId": "v1|123|0", "title": "Legacy" })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = EbayClient::new(config).unwrap();
+        assert_eq!(client.get_item_auto("v1|123|0").await.unwrap().title.as_deref(), Some("RESTful"));
+        assert_eq!(client.get_item_auto("123").await.unwrap().title.as_deref(), Some("Legacy"));
+        assert!(matches!(client.get_item_auto("not-an-id").await, Err(HermesError::Configuration(_))));
+    }
+
+    #[tokio::test]
+    async fn active_listings_joins_offers_with_browse_results() {
+        let (server, config) = mock_ebay().await;
+        Mock::given(method("GET"))
+            .and(path("/sell/inventory/v1/inventory_item"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "total": 3,
+                "inventoryItems": [
+                    { "sku": "MUG-1", "product": { "title": "Coffee mug" } },
+                    { "sku": "MUG-2", "product": { "title": "Tea mug" } },
+                    { "sku": "MUG-3", "product": { "title": "Draft mug" } }
+                ]
+            })))
+            .mount(&server)
+            .await;
+        let offer = |sku: &str, status: &str, listing_id: &str| {
+            serde_json::json!({ "offers": [{
+                "offerId": format!("offer-{}", sku),
+                "sku": sku,
+                "marketplaceId": "EBAY_US",
+                "categoryId": "9355",
+                "status": status,
+                "listing": { "listingId": listing_id, "listingStatus": "ACTIVE" },
+                "pricingSummary": { "price": { "value": "12.00", "currency": "USD" } }
+            }]})
+        };
+        for (sku, status, listing_id) in [("MUG-1", "PUBLISHED", "1101"), ("MUG-2", "PUBLISHED", "1102"), ("MUG-3", "UNPUBLISHED", "")] {
+            Mock::given(method("GET"))
+                .and(path("/sell/inventory/v1/offer"))
+                .and(query_param("sku", sku))
+                .respond_with(ResponseTemplate::new(200).set_body_json(offer(sku, status, listing_id)))
+                .mount(&server)
+                .await;
+        }
+        Mock::given(method("GET"))
+            .and(path("/buy/browse/v1/item_summary/search"))
+            .and(query_param("category_ids", "9355"))
+            .and(query_param("filter", "sellers:{mug_shop}"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "total": 1,
+                "itemSummaries": [{
+                    "itemId": "v1|1101|0",
+                    "legacyItemId": "1101",
+                    "title": "Coffee mug, 12oz",
+                    "price": { "value": "9.99", "currency": "USD" },
+                    "watchCount": 4,
+                    "itemWebUrl": "https://www.ebay.com/itm/1101"
+                }]
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let mut client = EbayClient::new(config).unwrap();
+        let listings = client.active_listings("mug_shop", Marketplace::EbayUs).await.unwrap();
+
+        assert_eq!(listings.len(), 2);
+        assert_eq!(
+            listings[0],
+            ActiveListing {
+                listing_id: "1101".to_string(),
+                sku: Some("MUG-1".to_string()),
+                offer_id: Some("offer-MUG-1".to_string()),
+                title: Some("Coffee mug, 12oz".to_string()),
+                price: Some(Money::new(Decimal::new(999, 2), "USD")),
+                watch_count: Some(4),
+                item_web_url: Some("https://www.ebay.com/itm/1101".to_string()),
+            }
+        );
+        // Not in Browse yet: inventory title and offer price
+        assert_eq!(listings[1].title.as_deref(), Some("Tea mug"));
+        assert_eq!(listings[1].price, Some(Money::new(Decimal::new(1200, 2), "USD")));
+        assert_eq!(listings[1].watch_count, None);
+    }
+
+    #[tokio::test]
+    async fn item_with_aspects_fetches_aspects_of_item_category() {
+        let (server, config) = mock_ebay().await;
+        Mock::given(method("GET"))
+            .and(path("/buy/browse/v1/item/v1%7C123%7C0"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "itemId": "v1|123|0",
+                "categoryId": "9355"
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/commerce/taxonomy/v1/get_default_category_tree_id"))
+            .and(query_param("marketplace_id", "EBAY_US"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "categoryTreeId": "0",
+                "categoryTreeVersion": "129"
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/commerce/taxonomy/v1/category_tree/0/get_item_aspects_for_category"))
+            .and(query_param("category_id", "9355"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "aspects": [{ "localizedAspectName": "Brand" }]
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let mut client = EbayClient::new(config).unwrap();
+        let (item, aspects) = client.item_with_aspects("v1|123|0").await.unwrap();
+        assert_eq!(item.category_id.as_deref(), Some("9355"));
+        let names: Vec<_> = aspects.aspects.iter().flatten().filter_map(|a| a.localized_aspect_name.as_deref()).collect();
+        assert_eq!(names, ["Brand"]);
+    }
+
+    #[tokio::test]
+    async fn item_with_aspects_rejects_missing_tree_id() {
+        let (server, config) = mock_ebay().await;
+        Mock::given(method("GET"))
+            .and(path("/buy/browse/v1/item/v1%7C123%7C0"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "itemId": "v1|123|0",
+                "categoryId": "9355"
+            })))
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/commerce/taxonomy/v1/get_default_category_tree_id"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({})))
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/commerce/taxonomy/v1/category_tree//get_item_aspects_for_category"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({ "aspects": [] })))
+            .expect(0)
+            .mount(&server)
+            .await;
+
+        let mut client = EbayClient::new(config).unwrap();
+        let err = client.item_with_aspects("v1|123|0").await.unwrap_err();
+        assert!(matches!(err, HermesError::ApiRequest(ref message) if message.contains("EBAY_US")));
+    }
+
+    #[test]
+    fn item_group_counts_available_variations() {
+        let variant = |id: &str, size: &str, status: &str, quantity: i32| {
+            serde_json::json!({
+                "itemId": id,
+                "localizedAspects": [{ "type": "STRING", "name": "Size", "value": size }],
+                "estimatedAvailabilities": [{ "estimatedAvailabilityStatus": status, "estimatedAvailableQuantity": quantity }]
+            })
+        };
+        let group: ItemGroup = serde_json::from_value(serde_json::json!({
+            "items": [
+                variant("v1|2|20", "S", "IN_STOCK", 4),
+                variant("v1|2|21", "M", "OUT_OF_STOCK", 0),
+                variant("v1|2|22", "L", "LIMITED_STOCK", 1),
+                variant("v1|2|23", "XL", "OUT_OF_STOCK", 0)
+            ]
+        }))
+        .unwrap();
+
+        assert_eq!(group.total_available(), 5);
+        let in_stock: Vec<_> = group.in_stock_variations().iter().filter_map(|item| item.item_id.as_deref()).collect();
+        assert_eq!(in_stock, ["v1|2|20", "v1|2|22"]);
+    }
+
+    #[test]
+    fn seller_info_flattens_nested_seller() {
+        let summary: ItemSummary = serde_json::from_value(serde_json::json!({
+            "itemId": "v1|123|0",
+            "seller": { "username": "acme_outlet", "feedbackPercentage": "99.7", "feedbackScore": 4821 }
+        }))
+        .unwrap();
+        assert_eq!(
+            summary.seller_info(),
+            Some(SellerInfo {
+                username: "acme_outlet".to_string(),
+                feedback_percentage: Some(99.7),
+                feedback_score: Some(4821),
+            })
+        );
+
+        let without_seller: ItemSummary = serde_json::from_value(serde_json::json!({ "itemId": "v1|456|0" })).unwrap();
+        assert_eq!(without_seller.seller_info(), None);
+    }
+
+    #[tokio::test]
+    async fn search_by_image_rejects_invalid_images_before_calling_ebay() {
+        let (server, config) = mock_ebay().await;
+        let client = EbayClient::new(config).unwrap();
+
+        let mut oversized = vec![0xFF, 0xD8, 0xFF];
+        oversized.resize(MAX_SEARCH_IMAGE_BYTES + 1, 0);
+        let err = client.search_by_image(&oversized, None, None, None).await.unwrap_err();
+        assert!(matches!(err, HermesError::Configuration(ref message) if message.contains("at most")));
+
+        let err = client.search_by_image(b"not an image", None, None, None).await.unwrap_err();
+        assert!(matches!(err, HermesError::Configuration(ref message) if message.contains("JPEG or PNG")));
+
+        assert!(server.received_requests().await.unwrap().is_empty());
+        assert!(validate_search_image(b"\x89PNG\r\n\x1a\nrest").is_ok());
+    }
+
+    #[tokio::test]
+    async fn malformed_response_keeps_its_body() {
+        let (server, config) = mock_ebay().await;
+        let malformed = r#"{"itemId":"v1|123|0","price":"not an object"}"#;
+        Mock::given(method("GET"))
+            .and(path("/buy/browse/v1/item/v1%7C123%7C0"))
+            .respond_with(ResponseTemplate::new(200).set_body_raw(malformed, "application/json"))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = EbayClient::new(config).unwrap();
+        let err = client.get_item("v1|123|0", None).await.unwrap_err();
+        assert!(matches!(err, HermesError::Deserialize { ref body, .. } if body == malformed));
+    }
+
+    #[tokio::test]
+    async fn search_by_image_sends_image_and_limit() {
+        let (server, config) = mock_ebay().await;
+        let jpeg = b"\xFF\xD8\xFFrest";
+        Mock::given(method("POST"))
+            .and(path("/buy/browse/v1/item_summary/search_by_image"))
+            .and(query_param("limit", "5"))
+            .and(header("Content-Type", "application/json"))
+            .and(body_partial_json(serde_json::json!({ "image": BASE64_STANDARD.encode(jpeg) })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({ "total": 2 })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = EbayClient::new(config).unwrap();
+        assert_eq!(client.search_by_image(jpeg, None, Some(5), None).await.unwrap().total, Some(2));
+    }
+
+    #[tokio::test]
+    async fn search_by_image_sends_fieldgroups() {
+        let (server, config) = mock_ebay().await;
+        let jpeg = b"\xFF\xD8\xFFrest";
+        for (fieldgroups, total) in [("MATCHING_ITEMS", 3), ("FULL", 4)] {
+            Mock::given(method("POST"))
+                .and(path("/buy/browse/v1/item_summary/search_by_image"))
+                .and(query_param("fieldgroups", fieldgroups))
+                .and(body_partial_json(serde_json::json!({ "image": BASE64_STANDARD.encode(jpeg) })))
+                .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({ "total": total })))
+                .expect(1)
+                .mount(&server)
+                .await;
+        }
+
+        let client = EbayClient::new(config).unwrap();
+        assert_eq!(client.search_by_image(jpeg, None, None, None).await.unwrap().total, Some(3));
+        assert_eq!(client.search_by_image(jpeg, None, None, Some("FULL")).await.unwrap().total, Some(4));
+    }
+
+    #[test]
+    fn compatibility_summary_describes_matched_vehicle() {
+        let summary: ItemSummary = serde_json::from_value(serde_json::json!({
+            "itemId": "v1|789|0",
+            "compatibilityMatch": "EXACT",
+            "compatibilityProperties": [
+                { "name": "Make", "localizedName": "Make", "value": "Honda" },
+                { "name": "Model", "localizedName": "Model", "value": "Civic" },
+                { "name": "Year", "localizedName": "Year", "value": "2015" },
+                { "name": "Trim", "localizedName": "Trim", "value": "EX" }
+            ]
+        }))
+        .unwrap();
+
+        let vehicles = summary.compatibility_summary();
+        assert_eq!(vehicles.len(), 1);
+        assert!(vehicles[0].exact);
+        assert_eq!(vehicles[0].get("Model"), Some("Civic"));
+        assert_eq!(vehicles[0].to_string(), "2015 Honda Civic EX");
+
+        let without: ItemSummary = serde_json::from_value(serde_json::json!({ "itemId": "v1|456|0" })).unwrap();
+        assert!(without.compatibility_summary().is_empty());
+    }
+
+    #[tokio::test]
+    async fn get_item_groups_returns_result_per_group() {
+        let (server, config) = mock_ebay().await;
+        Mock::given(method("GET"))
+            .and(path("/buy/browse/v1/item/get_items_by_item_group"))
+            .and(query_param("item_group_id", "111"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "items": [{ "itemId": "v1|111|1" }, { "itemId": "v1|111|2" }]
+            })))
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/buy/browse/v1/item/get_items_by_item_group"))
+            .and(query_param("item_group_id", "222"))
+            .respond_with(ResponseTemplate::new(404).set_body_json(serde_json::json!({
+                "errors": [{ "errorId": 11006, "message": "The item group was not found." }]
+            })))
+            .mount(&server)
+            .await;
+
+        let client = EbayClient::new(config).unwrap();
+        let groups = client.get_item_groups(&["111", "222"]).await;
+
+        assert_eq!(groups.len(), 2);
+        let found = groups["111"].as_ref().unwrap();
+        assert_eq!(found.items.as_ref().unwrap().len(), 2);
+        assert!(groups["222"].is_err());
+    }
+
+    #[tokio::test]
+    async fn marketplace_override_wins_over_config_default() {
+        let (server, config) = mock_ebay().await;
+        Mock::given(method("GET"))
+            .and(path("/buy/browse/v1/item_summary/search"))
+            .and(query_param("q", "laptop"))
+            .and(header("X-EBAY-C-MARKETPLACE-ID", "EBAY_DE"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({ "total": 3 })))
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/buy/browse/v1/item_summary/search"))
+            .and(header("X-EBAY-C-MARKETPLACE-ID", "EBAY_GB"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({ "total": 7 })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = EbayClient::new(config.with_marketplace(Marketplace::EbayGb)).unwrap();
+        let scoped = client
+            .with_marketplace(Marketplace::EbayDe)
+            .search_items("laptop", Some(10), false)
+            .await
+            .unwrap();
+        let default = client.search_items("laptop", Some(10), false).await.unwrap();
+
+        assert_eq!(scoped.total, Some(3));
+        assert_eq!(default.total, Some(7));
+    }
+
+    #[tokio::test]
+    async fn derived_marketplace_client_shares_token_cache() {
+        let (server, config) = mock_ebay().await;
+        for (marketplace, total) in [("EBAY_US", 5), ("EBAY_AU", 2)] {
+            Mock::given(method("GET"))
+                .and(path("/buy/browse/v1/item_summary/search"))
+                .and(header("X-EBAY-C-MARKETPLACE-ID", marketplace))
+                .and(header("Authorization", "Bearer test-token"))
+                .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({ "total": total })))
+                .expect(1)
+                .mount(&server)
+                .await;
+        }
+
+        let client = EbayClient::new(config).unwrap();
+        let australia = client.for_marketplace(Marketplace::EbayAu);
+        assert_eq!(client.search_items("mug", None, false).await.unwrap().total, Some(5));
+        assert_eq!(australia.search_items("mug", None, false).await.unwrap().total, Some(2));
+
+        let token_requests = server
+            .received_requests()
+            .await
+            .unwrap()
+            .into_iter()
+            .filter(|request| request.url.path() == "/identity/v1/oauth2/token")
+            .count();
+        assert_eq!(token_requests, 1);
+    }
+
+    /// Collects formatted log output so tests can assert on it
+    #[derive(Clone, Default)]
+    struct CapturedLogs(Arc<std::sync::Mutex<Vec<u8>>>);
+
+    impl std::io::Write for CapturedLogs {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            self.0.lock().unwrap().extend_from_slice(buf);
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn curl_debug_logs_every_request_as_curl_command() {
+        use tracing::instrument::WithSubscriber;
+
+        let (server, config) = mock_ebay().await;
+        Mock::given(method("GET"))
+            .and(path("/buy/browse/v1/item_summary/search"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({ "total": 0 })))
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/sell/inventory/v1/offer/5001/publish"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({ "listingId": "1" })))
+            .mount(&server)
+            .await;
+
+        let logs = CapturedLogs::default();
+        let writer = logs.clone();
+        let subscriber = tracing_subscriber::fmt()
+            .with_max_level(tracing::Level::DEBUG)
+            .with_ansi(false)
+            .with_writer(move || writer.clone())
+            .finish();
+
+        let client = EbayClient::new(config.with_curl_debug(true)).unwrap();
+        async {
+            client.search_items("red shoes", Some(5), false).await.unwrap();
+            client
+                .call_raw(
+                    EbayService::SellInventory,
+                    reqwest::Method::POST,
+                    "/v1/offer/5001/publish",
+                    &[],
+                    Some(serde_json::json!({ "note": "it's" })),
+                )
+                .await
+                .unwrap();
+        }
+        .with_subscriber(subscriber)
+        .await;
+
+        let output = String::from_utf8(logs.0.lock().unwrap().clone()).unwrap();
+        let search = format!(
+            "curl -X GET '{}/buy/browse/v1/item_summary/search?limit=5&q=red+shoes' \
+             -H 'authorization: Bearer ***' -H 'x-ebay-c-marketplace-id: EBAY_US'",
+            server.uri()
+        );
+        assert!(output.contains(&search), "no curl command for the search in logs:\n{}", output);
+        let publish = format!(
+            "curl -X POST '{}/sell/inventory/v1/offer/5001/publish' \
+             -H 'authorization: Bearer ***' -H 'x-ebay-c-marketplace-id: EBAY_US' \
+             -H 'content-type: application/json' --data '{{\"note\":\"it'\\''s\"}}'",
+            server.uri()
+        );
+        assert!(output.contains(&publish), "no curl command for the publish in logs:\n{}", output);
+        assert!(!output.contains("test-token"));
+    }
+
+    #[tokio::test]
+    async fn correlation_id_is_recorded_on_span_and_sent_as_header() {
+        use tracing::instrument::WithSubscriber;
+        use tracing_subscriber::fmt::format::FmtSpan;
+
+        let (server, config) = mock_ebay().await;
+        Mock::given(method("GET"))
+            .and(path("/buy/browse/v1/item_summary/search"))
+            .and(header("X-Request-Id", "req-42"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({ "total": 0 })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let logs = CapturedLogs::default();
+        let writer = logs.clone();
+        let subscriber = tracing_subscriber::fmt()
+            .with_span_events(FmtSpan::NEW)
+            .with_ansi(false)
+            .with_writer(move || writer.clone())
+            .finish();
+
+        let client = EbayClient::new(config.with_correlation_header("X-Request-Id")).unwrap();
+        client
+            .for_correlation_id("req-42")
+            .search_items("mug", None, false)
+            .with_subscriber(subscriber)
+            .await
+            .unwrap();
+
+        let output = String::from_utf8(logs.0.lock().unwrap().clone()).unwrap();
+        assert!(output.contains("ebay_call"), "no call span in logs:\n{}", output);
+        assert!(output.contains(r#"correlation_id="req-42""#), "no correlation ID in logs:\n{}", output);
+    }
+
+    #[tokio::test]
+    async fn search_builder_sends_named_parameters() {
+        let (server, config) = mock_ebay().await;
+        Mock::given(method("GET"))
+            .and(path("/buy/browse/v1/item_summary/search"))
+            .and(query_param("q", "laptop"))
+            .and(query_param("category_ids", "58058"))
+            .and(query_param("filter", "price:[100..1000]"))
+            .and(query_param("aspect_filter", "categoryId:58058,Brand:{Dell}"))
+            .and(query_param("sort", "-price"))
+            .and(query_param("limit", "5"))
+            .and(query_param("offset", "10"))
+            .and(query_param("auto_correct", "KEYWORD"))
+            .and(header("X-EBAY-C-MARKETPLACE-ID", "EBAY_US"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({ "total": 11 })))
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/buy/browse/v1/item_summary/search"))
+            .and(query_param("q", "mug"))
+            .and(header("X-EBAY-C-MARKETPLACE-ID", "EBAY_DE"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({ "total": 3 })))
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/buy/browse/v1/item_summary/search"))
+            .and(query_param("category_ids", "9355"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({ "total": 7 })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = EbayClient::new(config).unwrap();
+        let laptops = client
+            .search()
+            .query("laptop")
+            .category("58058")
+            .filter("price:[100..1000]")
+            .aspect_filter("categoryId:58058,Brand:{Dell}")
+            .sort("-price")
+            .limit(5)
+            .offset(10)
+            .auto_correct(true)
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(laptops.total, Some(11));
+
+        let mugs = client.with_marketplace(Marketplace::EbayDe).search().query("mug").send().await.unwrap();
+        assert_eq!(mugs.total, Some(3));
+
+        let phones = client.search().category("9355").send().await.unwrap();
+        assert_eq!(phones.total, Some(7));
+        let requests = server.received_requests().await.unwrap();
+        let category_only = requests.iter().find(|r| r.url.query().is_some_and(|q| q.contains("category_ids=9355"))).unwrap();
+        assert!(!category_only.url.query().unwrap().contains("q="));
+    }
+
+    #[tokio::test]
+    async fn update_credentials_fetches_new_token_and_keeps_caches() {
+        let server = wiremock::MockServer::start().await;
+        // Basic auth of "test-app-id:test-cert-id" and "test-app-id:renewed-cert"; the
+        // old keys are used once by the Browse and once by the Taxonomy client
+        for (credentials, access_token, requests) in [
+            ("Basic dGVzdC1hcHAtaWQ6dGVzdC1jZXJ0LWlk", "old-token", 2),
+            ("Basic dGVzdC1hcHAtaWQ6cmVuZXdlZC1jZXJ0", "new-token", 1),
+        ] {
+            Mock::given(method("POST"))
+                .and(path("/identity/v1/oauth2/token"))
+                .and(header("Authorization", credentials))
+                .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                    "access_token": access_token,
+                    "token_type": "Application Access Token",
+                    "expires_in": 7200
+                })))
+                .expect(requests)
+                .mount(&server)
+                .await;
+        }
+        Mock::given(method("GET"))
+            .and(path("/commerce/taxonomy/v1/category_tree/0"))
+            .and(header("Authorization", "Bearer old-token"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "categoryTreeId": "0",
+                "categoryTreeVersion": "129"
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+        for token in ["Bearer old-token", "Bearer new-token"] {
+            Mock::given(method("GET"))
+                .and(path("/buy/browse/v1/item/v1%7C123%7C0"))
+                .and(header("Authorization", token))
+                .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({ "itemId": "v1|123|0" })))
+                .expect(1)
+                .mount(&server)
+                .await;
+        }
+
+        let config = EbayConfig::new()
+            .with_app_id("test-app-id")
+            .with_cert_id("test-cert-id")
+            .with_api_base_url(&server.uri());
+        let mut client = EbayClient::new(config).unwrap();
+        client.get_item("v1|123|0", None).await.unwrap();
+        client.taxonomy().unwrap().cached_category_tree("0").await.unwrap();
+
+        client.update_credentials("test-app-id", "renewed-cert");
+        client.get_item("v1|123|0", None).await.unwrap();
+        let tree = client.taxonomy().unwrap().cached_category_tree("0").await.unwrap();
+        assert_eq!(tree.category_tree_version.as_deref(), Some("129"));
+    }
+
+    #[tokio::test]
+    async fn rate_limit_status_flattens_developer_analytics_response() {
+        let (server, config) = mock_ebay().await;
+        Mock::given(method("GET"))
+            .and(path("/developer/analytics/v1_beta/rate_limit/"))
+            .and(query_param("api_name", "browse"))
+            .and(header("authorization", "Bearer test-token"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "rateLimits": [{
+                    "apiContext": "buy",
+                    "apiName": "Browse",
+                    "apiVersion": "v1",
+                    "resources": [{
+                        "name": "buy.browse.item.summary",
+                        "rates": [{
+                            "count": 1200,
+                            "limit": 5000,
+                            "remaining": 3800,
+                            "reset": "2024-05-01T07:00:00.000Z",
+                            "timeWindow": 86400
+                        }]
+                    }]
+                }]
+            })))
+            .mount(&server)
+            .await;
+
+        let client = EbayClient::new(config).unwrap();
+        let statuses = client.rate_limit_status(None, Some("browse")).await.unwrap();
+        assert_eq!(statuses.len(), 1);
+        let status = &statuses[0];
+        assert_eq!(status.resource, "buy.browse.item.summary");
+        assert_eq!((status.limit, status.remaining), (5000, 3800));
+        assert_eq!(status.time_window_seconds, Some(86400));
+        assert!((status.usage_ratio() - 0.24).abs() < 1e-9);
+    }
+
+    #[tokio::test]
+    async fn rate_limit_status_maps_errors_and_opens_circuit() {
+        let (server, config) = mock_ebay().await;
+        Mock::given(method("GET"))
+            .and(path("/developer/analytics/v1_beta/rate_limit/"))
+            .respond_with(
+                ResponseTemplate::new(503)
+                    .insert_header("Retry-After", "120")
+                    .set_body_string("Service down for maintenance"),
+            )
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = EbayClient::new(config.with_circuit_breaker(5, std::time::Duration::ZERO)).unwrap();
+        let err = client.rate_limit_status(None, None).await.unwrap_err();
+        assert!(matches!(err, HermesError::ServiceUnavailable { retry_after: Some(delay) } if delay.as_secs() == 120));
+
+        // The maintenance response opened the circuit for its Retry-After delay
+        let err = client.rate_limit_status(None, None).await.unwrap_err();
+        assert!(matches!(err, HermesError::CircuitOpen(ref api) if api == "developer.analytics"));
+    }
+
+    #[tokio::test]
+    async fn get_item_price_requests_compact_fields_and_parses_decimal() {
+        let (server, config) = mock_ebay().await;
+        Mock::given(method("GET"))
+            .and(path("/buy/browse/v1/item/v1%7C1234%7C0"))
+            .and(query_param("fieldgroups", "COMPACT"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "itemId": "v1|1234|0",
+                "price": { "value": "1299.99", "currency": "USD" },
+                "condition": "New",
+                "estimatedAvailabilities": [{
+                    "estimatedAvailabilityStatus": "IN_STOCK",
+                    "estimatedAvailableQuantity": 7
+                }]
+            })))
+            .mount(&server)
+            .await;
+
+        let client = EbayClient::new(config).unwrap();
+        let snapshot = client.get_item_price("v1|1234|0").await.unwrap();
+        assert_eq!(snapshot.item_id, "v1|1234|0");
+        assert_eq!(snapshot.price, Decimal::new(129999, 2));
+        assert_eq!(snapshot.currency, "USD");
+        assert_eq!(snapshot.available_quantity, Some(7));
+        assert_eq!(snapshot.condition.as_deref(), Some("New"));
+    }
+
+    #[tokio::test]
+    async fn search_items_stream_fetches_only_the_pages_taken() {
+        let (server, config) = mock_ebay().await;
+        let summaries: Vec<_> = (0..50)
+            .map(|i| serde_json::json!({ "itemId": format!("v1|{}|0", i) }))
+            .collect();
+        Mock::given(method("GET"))
+            .and(path("/buy/browse/v1/item_summary/search"))
+            .and(query_param("limit", "50"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "total": 50000,
+                "itemSummaries": summaries
+            })))
+            .expect(3)
+            .mount(&server)
+            .await;
+
+        let client = EbayClient::new(config).unwrap();
+        let items: Vec<_> = client.search_items_stream("camera", 50).take(120).collect().await;
+        assert_eq!(items.len(), 120);
+        assert!(items.iter().all(Result::is_ok));
+    }
+
+    #[tokio::test]
+    async fn search_items_stream_stops_at_total() {
+        let (server, config) = mock_ebay().await;
+        Mock::given(method("GET"))
+            .and(path("/buy/browse/v1/item_summary/search"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "total": 2,
+                "itemSummaries": [{ "itemId": "v1|1|0" }, { "itemId": "v1|2|0" }]
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = EbayClient::new(config).unwrap();
+        let items: Vec<_> = client.search_items_stream("camera", 2).collect().await;
+        assert_eq!(items.len(), 2);
+    }
+
+    #[tokio::test]
+    async fn call_raw_issues_authenticated_request_and_maps_errors() {
+        let (server, config) = mock_ebay().await;
+        Mock::given(method("GET"))
+            .and(path("/sell/analytics/v1/seller_standards_profile"))
+            .and(query_param("limit", "2"))
+            .and(header("Authorization", "Bearer test-token"))
+            .and(header("X-EBAY-C-MARKETPLACE-ID", "EBAY_US"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "standardsProfiles": [{ "program": "PROGRAM_US", "standardsLevel": "TOP_RATED" }]
+            })))
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/buy/browse/v1/item/broken"))
+            .respond_with(ResponseTemplate::new(400).set_body_json(serde_json::json!({
+                "errors": [{ "errorId": 12001, "message": "Bad item" }]
+            })))
+            .mount(&server)
+            .await;
+
+        let client = EbayClient::new(config).unwrap();
+        let profiles = client
+            .call_raw(
+                EbayService::SellAnalytics,
+                reqwest::Method::GET,
+                "/v1/seller_standards_profile",
+                &[("limit", "2")],
+                None,
+            )
+            .await
+            .unwrap();
+        assert_eq!(profiles["standardsProfiles"][0]["standardsLevel"], "TOP_RATED");
+
+        let err = client
+            .call_raw(EbayService::BuyBrowse, reqwest::Method::GET, "/v1/item/broken", &[], None)
+            .await
+            .unwrap_err();
+        assert!(matches!(err, HermesError::Api { status: 400, .. }));
+        assert_eq!(err.api_messages(), ["Bad item"]);
+    }
+
+    #[tokio::test]
+    async fn call_raw_retries_gets_and_sends_correlation_header() {
+        let (server, config) = mock_ebay().await;
+        Mock::given(method("GET"))
+            .and(path("/sell/analytics/v1/seller_standards_profile"))
+            .respond_with(ResponseTemplate::new(503))
+            .up_to_n_times(1)
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/sell/analytics/v1/seller_standards_profile"))
+            .and(header("X-Request-Id", "req-7"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({ "standardsProfiles": [] })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let config = config.with_retries(1).with_correlation_header("X-Request-Id").with_correlation_id("req-7");
+        let client = EbayClient::new(config).unwrap();
+        let profiles = client
+            .call_raw(EbayService::SellAnalytics, reqwest::Method::GET, "/v1/seller_standards_profile", &[], None)
+            .await
+            .unwrap();
+        assert_eq!(profiles["standardsProfiles"], serde_json::json!([]));
+    }
+
+    #[tokio::test]
+    async fn call_raw_skips_mutating_requests_in_dry_run() {
+        let (server, config) = mock_ebay().await;
+        Mock::given(method("GET"))
+            .and(path("/sell/inventory/v1/offer/5001"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({ "offerId": "5001" })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = EbayClient::new(config.with_dry_run(true)).unwrap();
+        let deleted = client
+            .call_raw(EbayService::SellInventory, reqwest::Method::DELETE, "/v1/offer/5001", &[], None)
+            .await
+            .unwrap();
+        assert_eq!(deleted, serde_json::Value::Null);
+
+        let body = serde_json::json!({ "offerIds": ["5001"] });
+        let created: EbayResponse<hermes_ebay_sell_inventory::models::OfferResponse> = client
+            .call_typed(EbayService::SellInventory, reqwest::Method::POST, "/v1/offer", &[], Some(body))
+            .await
+            .unwrap();
+        assert_eq!(created.offer_id, None);
+
+        // Reads still reach eBay
+        let offer = client
+            .call_raw(EbayService::SellInventory, reqwest::Method::GET, "/v1/offer/5001", &[], None)
+            .await
+            .unwrap();
+        assert_eq!(offer["offerId"], "5001");
+    }
+
+    #[tokio::test]
+    async fn search_with_refinements_exposes_aspect_histogram() {
+        let (server, config) = mock_ebay().await;
+        Mock::given(method("GET"))
+            .and(path("/buy/browse/v1/item_summary/search"))
+            .and(query_param("fieldgroups", "MATCHING_ITEMS,FULL"))
+            .and(query_param("category_ids", "177"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "total": 19,
+                "itemSummaries": [{ "itemId": "v1|1|0" }],
+                "refinement": {
+                    "dominantCategoryId": "177",
+                    "aspectDistributions": [
+                        {
+                            "localizedAspectName": "Brand",
+                            "aspectValueDistributions": [
+                                { "localizedAspectValue": "Apple", "matchCount": 12 },
+                                { "localizedAspectValue": "Dell", "matchCount": 7 }
+                            ]
+                        },
+                        {
+                            "localizedAspectName": "Screen Size",
+                            "aspectValueDistributions": [{ "localizedAspectValue": "13 in", "matchCount": 4 }]
+                        }
+                    ]
+                }
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = EbayClient::new(config).unwrap();
+        let results = client.search_items_with_refinements("laptop", Some("177"), None).await.unwrap();
+        let histogram = results.aspect_distributions();
+
+        assert_eq!(histogram.len(), 2);
+        assert_eq!(histogram["Brand"], [("Apple".to_string(), 12), ("Dell".to_string(), 7)]);
+        assert_eq!(histogram["Screen Size"], [("13 in".to_string(), 4)]);
+        assert!(SearchPagedCollection::default().aspect_distributions().is_empty());
+    }
+
+    #[test]
+    fn earliest_delivery_picks_fastest_shipping_option() {
+        let summary: ItemSummary = serde_json::from_value(serde_json::json!({
+            "itemId": "v1|123|0",
+            "shippingOptions": [
+                {
+                    "shippingCostType": "FIXED",
+                    "minEstimatedDeliveryDate": "2024-06-12T07:00:00.000Z",
+                    "maxEstimatedDeliveryDate": "2024-06-17T07:00:00.000Z"
+                },
+                {
+                    "shippingCostType": "FIXED",
+                    "guaranteedDelivery": true,
+                    "minEstimatedDeliveryDate": "2024-06-08T07:00:00.000Z",
+                    "maxEstimatedDeliveryDate": "2024-06-10T07:00:00.000Z"
+                },
+                { "shippingCostType": "CALCULATED" }
+            ]
+        }))
+        .unwrap();
+
+        assert_eq!(summary.earliest_delivery().as_deref(), Some("2024-06-08T07:00:00.000Z"));
+        assert_eq!(
+            summary.delivery_estimate(),
+            Some(DeliveryEstimate {
+                earliest: "2024-06-08T07:00:00.000Z".to_string(),
+                latest: Some("2024-06-10T07:00:00.000Z".to_string()),
+                guaranteed: true,
+            })
+        );
+        assert_eq!(ItemSummary::default().earliest_delivery(), None);
+    }
+
+    #[tokio::test]
+    async fn get_item_exposes_description_and_every_image() {
+        let (server, config) = mock_ebay().await;
+        Mock::given(method("GET"))
+            .and(path("/buy/browse/v1/item/v1%7C123%7C0"))
+            .and(query_param("fieldgroups", "PRODUCT"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "itemId": "v1|123|0",
+                "description": "<p>Hand-thrown <b>stoneware</b> mug</p>",
+                "image": { "imageUrl": "https://i.ebayimg.com/images/g/a/s-l1600.jpg" },
+                "additionalImages": [
+                    { "imageUrl": "https://i.ebayimg.com/images/g/b/s-l1600.jpg" },
+                    { "imageUrl": "https://i.ebayimg.com/images/g/a/s-l1600.jpg" },
+                    { "imageUrl": "https://i.ebayimg.com/images/g/c/s-l1600.jpg" }
+                ]
+            })))
+            .mount(&server)
+            .await;
+
+        let client = EbayClient::new(config).unwrap();
+        let item = client.get_item("v1|123|0", Some("PRODUCT")).await.unwrap();
+        assert_eq!(item.description.as_deref(), Some("<p>Hand-thrown <b>stoneware</b> mug</p>"));
+        assert_eq!(
+            item.all_image_urls(),
+            [
+                "https://i.ebayimg.com/images/g/a/s-l1600.jpg",
+                "https://i.ebayimg.com/images/g/b/s-l1600.jpg",
+                "https://i.ebayimg.com/images/g/c/s-l1600.jpg",
+            ]
+        );
+        assert!(Item::default().all_image_urls().is_empty());
+    }
+
+    #[tokio::test]
+    async fn raw_capture_keeps_unmodeled_fields() {
+        let (server, config) = mock_ebay().await;
+        Mock::given(method("GET"))
+            .and(path("/buy/browse/v1/item/v1|123|0"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "itemId": "v1|123|0",
+                "title": "Stoneware mug",
+                "sustainabilityScore": { "grade": "A" }
+            })))
+            .mount(&server)
+            .await;
+
+        let client = EbayClient::new(config.with_raw_capture(true)).unwrap();
+        let item: EbayResponse<Item> = client
+            .call_typed(EbayService::BuyBrowse, reqwest::Method::GET, "/v1/item/v1|123|0", &[], None)
+            .await
+            .unwrap();
+        assert_eq!(item.title.as_deref(), Some("Stoneware mug"));
+        assert_eq!(item.raw()["sustainabilityScore"]["grade"], "A");
+    }
+
+    #[tokio::test]
+    async fn oversized_response_is_rejected() {
+        let (server, config) = mock_ebay().await;
+        Mock::given(method("GET"))
+            .and(path("/buy/browse/v1/item/v1|123|0"))
+            .respond_with(ResponseTemplate::new(200).set_body_string(format!("\"{}\"", "x".repeat(4096))))
+            .mount(&server)
+            .await;
+
+        let client = EbayClient::new(config.with_max_response_bytes(1024)).unwrap();
+        let err = client
+            .call_raw(EbayService::BuyBrowse, reqwest::Method::GET, "/v1/item/v1|123|0", &[], None)
+            .await
+            .unwrap_err();
+        assert!(matches!(err, HermesError::ResponseTooLarge { limit: 1024 }));
+    }
+
+    #[tokio::test]
+    async fn auto_correct_is_sent_and_correction_extracted() {
+        let (server, config) = mock_ebay().await;
+        Mock::given(method("GET"))
+            .and(path("/buy/browse/v1/item_summary/search"))
+            .and(query_param("q", "iphnoe"))
+            .and(query_param("auto_correct", "KEYWORD"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "autoCorrections": { "q": "iphone" },
+                "total": 1,
+                "itemSummaries": [{ "itemId": "v1|1|0", "title": "iPhone 13" }]
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = EbayClient::new(config).unwrap();
+        let results = client.search_items("iphnoe", Some(10), true).await.unwrap();
+        assert_eq!(results.corrected_query(), Some("iphone"));
+        assert_eq!(SearchPagedCollection::default().corrected_query(), None);
+    }
+}
@@ -1,11 +1,14 @@
 use crate::config::EbayConfig;
-use crate::error::{HermesError, HermesResult};
+use crate::error::HermesResult;
+use crate::ebay::api_error::raw_error;
+use crate::ebay::telemetry::record_api_call;
 use crate::ebay::auth::EbayAuth;
+use crate::ebay::marketplace::{normalize_marketplace, MarketplaceStyle};
 use std::sync::Arc;
 
 // Import eBay Commerce Catalog SDK models and APIs
-use hermes_ebay_commerce_catalog::models::{Product, ProductSearchResponse};
-use hermes_ebay_commerce_catalog::apis::configuration::Configuration as CatalogConfiguration;
+use hermes_ebay_commerce_catalog::models::{Product, ProductSearchResponse, ProductSummary};
+use hermes_ebay_commerce_catalog::apis::urlencode;
 
 /// eBay Commerce Catalog API client for product catalog operations
 pub struct CatalogClient {
@@ -34,23 +37,26 @@ impl CatalogClient {
         let token_duration = token_start.elapsed();
         tracing::info!("OAuth token request for get_product: {:?}", token_duration);
         
-        // Set up configuration
-        let mut config = CatalogConfiguration::new();
-        config.base_path = if self.config.sandbox {
-            "https://api.sandbox.ebay.com/commerce/catalog/v1".to_string()
-        } else {
-            "https://api.ebay.com/commerce/catalog/v1".to_string()
-        };
-        config.oauth_access_token = Some(token);
+        let url = format!("{}/commerce/catalog/v1/product/{}", self.config.base_url(), urlencode(epid));
+        let http = self.config.http_client();
         
-        // Call the eBay SDK
+        // Fail fast while this API's circuit breaker is open
+        let probe = self.config.check_circuit("commerce.catalog")?;
+        
+        let marketplace_id = marketplace_id.map(|id| normalize_marketplace(id, MarketplaceStyle::Underscore));
+        
+        // Call the eBay API
         let ebay_start = std::time::Instant::now();
-        let result = hermes_ebay_commerce_catalog::apis::product_api::get_product(
-            &config,
-            epid,
-            marketplace_id,
-        ).await;
+        let result = self.config.retrying("commerce.catalog", || {
+            let mut request = http.get(&url).bearer_auth(&token);
+            if let Some(marketplace_id) = &marketplace_id {
+                request = request.header("X-EBAY-C-MARKETPLACE-ID", marketplace_id);
+            }
+            self.config.send_json(request)
+        }).await;
         let ebay_duration = ebay_start.elapsed();
+        record_api_call("commerce.catalog", "get_product", &result, ebay_duration);
+        self.config.record_circuit("commerce.catalog", &probe, &result);
         tracing::info!("eBay get_product API call: {:?}", ebay_duration);
         
         match result {
@@ -63,7 +69,7 @@ impl CatalogClient {
             Err(e) => {
                 let total_duration = start_time.elapsed();
                 tracing::error!("eBay get_product error after {:?}: {:?}", total_duration, e);
-                Err(HermesError::ApiRequest(format!("eBay get_product failed: {:?}", e)))
+                Err(raw_error("get_product", &format!("epid {}", epid), e))
             }
         }
     }
@@ -89,30 +95,35 @@ impl CatalogClient {
         let token_duration = token_start.elapsed();
         tracing::info!("OAuth token request for search_catalog: {:?}", token_duration);
         
-        // Set up configuration
-        let mut config = CatalogConfiguration::new();
-        config.base_path = if self.config.sandbox {
-            "https://api.sandbox.ebay.com/commerce/catalog/v1".to_string()
-        } else {
-            "https://api.ebay.com/commerce/catalog/v1".to_string()
-        };
-        config.oauth_access_token = Some(token);
+        let url = format!("{}/commerce/catalog/v1/product_summary/search", self.config.base_url());
+        let http = self.config.http_client();
         
-        // Call the eBay SDK
+        // Fail fast while this API's circuit breaker is open
+        let probe = self.config.check_circuit("commerce.catalog")?;
+        
+        let marketplace_id = marketplace_id.map(|id| normalize_marketplace(id, MarketplaceStyle::Underscore));
+        
+        // Call the eBay API
         let ebay_start = std::time::Instant::now();
-        let result = hermes_ebay_commerce_catalog::apis::product_summary_api::search(
-            &config,
-            marketplace_id,
-            aspect_filter,
-            category_ids,
-            fieldgroups,
-            gtin,
-            limit,
-            mpn,
-            offset,
-            query,
-        ).await;
+        let result = self.config.retrying("commerce.catalog", || {
+            let mut request = http.get(&url).bearer_auth(&token).query(&[
+                ("aspect_filter", aspect_filter),
+                ("category_ids", category_ids),
+                ("fieldgroups", fieldgroups),
+                ("gtin", gtin),
+                ("limit", limit),
+                ("mpn", mpn),
+                ("offset", offset),
+                ("q", query),
+            ]);
+            if let Some(marketplace_id) = &marketplace_id {
+                request = request.header("X-EBAY-C-MARKETPLACE-ID", marketplace_id);
+            }
+            self.config.send_json(request)
+        }).await;
         let ebay_duration = ebay_start.elapsed();
+        record_api_call("commerce.catalog", "search_catalog", &result, ebay_duration);
+        self.config.record_circuit("commerce.catalog", &probe, &result);
         tracing::info!("eBay search_catalog API call: {:?}", ebay_duration);
         
         match result {
@@ -125,8 +136,87 @@ impl CatalogClient {
             Err(e) => {
                 let total_duration = start_time.elapsed();
                 tracing::error!("eBay search_catalog error after {:?}: {:?}", total_duration, e);
-                Err(HermesError::ApiRequest(format!("eBay search_catalog failed: {:?}", e)))
+                Err(raw_error("search_catalog", &format!("query {}, gtin {}, mpn {}", query.unwrap_or("none"), gtin.unwrap_or("none"), mpn.unwrap_or("none")), e))
             }
         }
     }
-}
\ No newline at end of file
+    /// Find related products in the same product family
+    /// 
+    /// The Catalog API has no "related products" endpoint, so this looks up the product
+    /// and searches the catalog by its brand and MPN, returning the matches other than
+    /// the product itself (e.g. other sizes or colors of the same model).
+    /// Returns an empty list when the product has neither a brand nor an MPN.
+    pub async fn related_products(
+        &self,
+        epid: &str,
+        marketplace_id: Option<&str>,
+    ) -> HermesResult<Vec<ProductSummary>> {
+        let product = self.get_product(epid, marketplace_id).await?;
+        let brand = product.brand.as_deref();
+        let mpn = product.mpn.as_ref().and_then(|mpns| mpns.first()).map(String::as_str);
+        if brand.is_none() && mpn.is_none() {
+            return Ok(Vec::new());
+        }
+
+        let response = self.search_catalog(
+            marketplace_id,
+            None, // aspect_filter
+            None, // category_ids
+            None, // fieldgroups
+            None, // gtin
+            None, // limit
+            mpn,
+            None, // offset
+            brand,
+        ).await?;
+
+        Ok(response
+            .product_summaries
+            .unwrap_or_default()
+            .into_iter()
+            .filter(|summary| summary.epid.as_deref() != Some(epid))
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ebay::test_support::mock_ebay;
+    use wiremock::matchers::{method, path, query_param};
+    use wiremock::{Mock, ResponseTemplate};
+
+    #[tokio::test]
+    async fn related_products_searches_by_brand_and_mpn() {
+        let (server, config) = mock_ebay().await;
+        Mock::given(method("GET"))
+            .and(path("/commerce/catalog/v1/product/1001"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "epid": "1001",
+                "brand": "Apple",
+                "mpn": ["MK2K3LL/A"],
+                "title": "Apple iPad 9th Gen 64GB"
+            })))
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/commerce/catalog/v1/product_summary/search"))
+            .and(query_param("q", "Apple"))
+            .and(query_param("mpn", "MK2K3LL/A"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "productSummaries": [
+                    { "epid": "1001", "title": "Apple iPad 9th Gen 64GB" },
+                    { "epid": "1002", "title": "Apple iPad 9th Gen 256GB" }
+                ]
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = CatalogClient::new(config).unwrap();
+        let related = client.related_products("1001", Some("EBAY_US")).await.unwrap();
+
+        assert_eq!(related.len(), 1);
+        assert_eq!(related[0].epid.as_deref(), Some("1002"));
+    }
+}
@@ -1,11 +1,12 @@
 use crate::config::EbayConfig;
-use crate::error::{HermesError, HermesResult};
+use crate::error::HermesResult;
+use crate::ebay::api_error::raw_error;
+use crate::ebay::telemetry::record_api_call;
 use crate::ebay::auth::EbayAuth;
 use std::sync::Arc;
 
-// Import eBay Commerce Identity SDK models and APIs
+// Import eBay Commerce Identity SDK models
 use hermes_ebay_commerce_identity::models::UserResponse;
-use hermes_ebay_commerce_identity::apis::configuration::Configuration as IdentityConfiguration;
 
 /// eBay Commerce Identity API client for user identity operations
 pub struct IdentityClient {
@@ -30,19 +31,20 @@ impl IdentityClient {
         let token_duration = token_start.elapsed();
         tracing::info!("OAuth token request for get_user: {:?}", token_duration);
         
-        // Set up configuration
-        let mut config = IdentityConfiguration::new();
-        config.base_path = if self.config.sandbox {
-            "https://api.sandbox.ebay.com/commerce/identity/v1".to_string()
-        } else {
-            "https://api.ebay.com/commerce/identity/v1".to_string()
-        };
-        config.oauth_access_token = Some(token);
+        let url = format!("{}/commerce/identity/v1/user/", self.config.base_url());
+        let http = self.config.http_client();
         
-        // Call the eBay SDK
+        // Fail fast while this API's circuit breaker is open
+        let probe = self.config.check_circuit("commerce.identity")?;
+        
+        // Call the eBay API
         let ebay_start = std::time::Instant::now();
-        let result = hermes_ebay_commerce_identity::apis::user_api::get_user(&config).await;
+        let result = self.config.retrying("commerce.identity", || {
+            self.config.send_json(http.get(&url).bearer_auth(&token))
+        }).await;
         let ebay_duration = ebay_start.elapsed();
+        record_api_call("commerce.identity", "get_user", &result, ebay_duration);
+        self.config.record_circuit("commerce.identity", &probe, &result);
         tracing::info!("eBay get_user API call: {:?}", ebay_duration);
         
         match result {
@@ -55,7 +57,7 @@ impl IdentityClient {
             Err(e) => {
                 let total_duration = start_time.elapsed();
                 tracing::error!("eBay get_user error after {:?}: {:?}", total_duration, e);
-                Err(HermesError::ApiRequest(format!("eBay get_user failed: {:?}", e)))
+                Err(raw_error("get_user", "the authenticated user", e))
             }
         }
     }
@@ -0,0 +1,207 @@
+//! eBay Commerce Media API client
+//!
+//! Listings reference their pictures by URL. Sellers whose images are only local
+//! bytes can upload them to eBay Picture Services (EPS) here and use the returned
+//! hosted URL in `Product::image_urls`. Images that are already publicly hosted
+//! over HTTPS can be referenced directly, without uploading.
+//!
+//! No generated crate exists for the Media API, so requests are made with reqwest.
+//! The API is served from `apim.ebay.com` rather than `api.ebay.com`.
+
+use crate::config::{EbayConfig, DRY_RUN_ID};
+use crate::error::{HermesError, HermesResult};
+use crate::ebay::api_error::{parse_response, raw_error, read_body, retry_after, RawRequestError};
+use crate::ebay::telemetry::record_api_call;
+use crate::ebay::auth::EbayAuth;
+use crate::ebay::debug::send_logged;
+use reqwest::multipart::{Form, Part};
+use serde::Deserialize;
+use std::sync::Arc;
+
+/// Image details returned by createImageFromFile and getImage
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ImageResponse {
+    image_url: Option<String>,
+}
+
+/// eBay Commerce Media API client for hosting listing images
+pub struct MediaClient {
+    config: EbayConfig,
+    auth: Arc<EbayAuth>,
+}
+
+impl MediaClient {
+    /// Create a new Media API client
+    pub fn new(config: EbayConfig) -> HermesResult<Self> {
+        let auth = Arc::new(EbayAuth::new(config.clone())?);
+        Ok(Self { config, auth })
+    }
+
+    /// Upload an image to eBay Picture Services
+    ///
+    /// Returns the hosted (EPS) URL to use in a listing's image URLs. If eBay does
+    /// not include the URL in the upload response, the image is fetched by the ID
+    /// in the `Location` header. In dry-run mode nothing is uploaded and the URL is
+    /// [`DRY_RUN_ID`].
+    ///
+    /// # Arguments
+    /// * `bytes` - The image file contents (JPG, GIF, PNG, BMP, TIFF, AVIF, HEIC or WEBP)
+    /// * `name` - File name sent with the upload, e.g. "front.jpg"
+    pub async fn upload_image(&self, bytes: Vec<u8>, name: &str) -> HermesResult<String> {
+        // Dry-run mode logs the request instead of sending it
+        if self.config.skip_in_dry_run::<()>("upload_image", &(name, bytes.len())).is_some() {
+            return Ok(DRY_RUN_ID.to_string());
+        }
+
+        let start_time = std::time::Instant::now();
+
+        // Get access token
+        let token_start = std::time::Instant::now();
+        let token = self.auth.get_access_token().await?;
+        let token_duration = token_start.elapsed();
+        tracing::info!("OAuth token request for upload_image: {:?}", token_duration);
+
+        let base = format!("{}/commerce/media/v1_beta", self.config.media_base_url());
+        let http = self.config.http_client();
+
+        // Fail fast while this API's circuit breaker is open
+        let probe = self.config.check_circuit("commerce.media")?;
+
+        let form = Form::new().part("image", Part::bytes(bytes).file_name(name.to_string()));
+        let ebay_start = std::time::Instant::now();
+        let result = send(
+            http.post(format!("{}/image/create_image_from_file", base))
+                .bearer_auth(&token)
+                .multipart(form),
+            &self.config,
+        )
+        .await;
+        let ebay_duration = ebay_start.elapsed();
+        record_api_call("commerce.media", "upload_image", &result, ebay_duration);
+        self.config.record_circuit("commerce.media", &probe, &result);
+        tracing::info!("eBay upload_image API call: {:?}", ebay_duration);
+
+        let (location, body) = match result {
+            Ok(response) => response,
+            Err(e) => {
+                tracing::error!("eBay upload_image error after {:?}: {:?}", start_time.elapsed(), e);
+                return Err(raw_error("upload_image", name, e));
+            }
+        };
+        if let Some(url) = parse_image_url(&body)? {
+            tracing::info!("upload_image total: {:?}", start_time.elapsed());
+            return Ok(url);
+        }
+
+        // Older responses only carry the image ID, in the Location header
+        let location = location.ok_or_else(|| {
+            HermesError::ApiRequest("eBay upload_image returned neither an image URL nor a Location header".to_string())
+        })?;
+        let image_id = location.rsplit('/').next().unwrap_or_default();
+
+        let ebay_start = std::time::Instant::now();
+        let result = self
+            .config
+            .retrying("commerce.media", || {
+                send(http.get(format!("{}/image/{}", base, image_id)).bearer_auth(&token), &self.config)
+            })
+            .await;
+        let ebay_duration = ebay_start.elapsed();
+        record_api_call("commerce.media", "get_image", &result, ebay_duration);
+        self.config.record_circuit("commerce.media", &probe, &result);
+        tracing::info!("eBay get_image API call: {:?}", ebay_duration);
+
+        match result {
+            Ok((_, body)) => {
+                tracing::info!("upload_image total: {:?}", start_time.elapsed());
+                parse_image_url(&body)?.ok_or_else(|| {
+                    HermesError::ApiRequest(format!("eBay get_image returned no URL for image {}", image_id))
+                })
+            }
+            Err(e) => {
+                tracing::error!("eBay get_image error after {:?}: {:?}", start_time.elapsed(), e);
+                Err(raw_error("get_image", image_id, e))
+            }
+        }
+    }
+}
+
+/// Send a request, returning its `Location` header and body on success
+async fn send(
+    request: reqwest::RequestBuilder,
+    config: &EbayConfig,
+) -> Result<(Option<String>, String), RawRequestError> {
+    let response = send_logged(config, request).await.map_err(RawRequestError::Transport)?;
+    let status = response.status();
+    let location = response
+        .headers()
+        .get(reqwest::header::LOCATION)
+        .and_then(|value| value.to_str().ok())
+        .map(str::to_string);
+    let retry_after = retry_after(&response);
+    let body = read_body(response, config.max_response_bytes).await?;
+    if status.is_success() {
+        Ok((location, body))
+    } else {
+        Err(RawRequestError::Response { status: status.as_u16(), body, retry_after })
+    }
+}
+
+fn parse_image_url(body: &str) -> HermesResult<Option<String>> {
+    if body.trim().is_empty() {
+        return Ok(None);
+    }
+    let image: ImageResponse = parse_response(body)?;
+    Ok(image.image_url)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ebay::test_support::mock_ebay;
+    use wiremock::matchers::{body_string_contains, header, header_regex, method, path};
+    use wiremock::{Mock, ResponseTemplate};
+
+    #[tokio::test]
+    async fn upload_image_sends_multipart_file_and_follows_location() {
+        let (server, config) = mock_ebay().await;
+        Mock::given(method("POST"))
+            .and(path("/commerce/media/v1_beta/image/create_image_from_file"))
+            .and(header("Authorization", "Bearer test-token"))
+            .and(header("X-Request-Id", "req-9"))
+            .and(header_regex("Content-Type", "^multipart/form-data; boundary="))
+            .and(body_string_contains(r#"name="image"; filename="front.jpg""#))
+            .and(body_string_contains("JPEGDATA"))
+            .respond_with(
+                ResponseTemplate::new(201)
+                    .insert_header("Location", format!("{}/commerce/media/v1_beta/image/img-1", server.uri())),
+            )
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/commerce/media/v1_beta/image/img-1"))
+            .and(header("X-Request-Id", "req-9"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "imageUrl": "https://i.ebayimg.com/images/g/abc/s-l1600.jpg",
+                "expirationDate": "2027-01-01T00:00:00.000Z"
+            })))
+            .mount(&server)
+            .await;
+
+        let config = config.with_correlation_header("X-Request-Id").with_correlation_id("req-9");
+        let client = MediaClient::new(config).unwrap();
+        let url = client.upload_image(b"JPEGDATA".to_vec(), "front.jpg").await.unwrap();
+        assert_eq!(url, "https://i.ebayimg.com/images/g/abc/s-l1600.jpg");
+    }
+
+    #[tokio::test]
+    async fn dry_run_skips_upload() {
+        let (server, config) = mock_ebay().await;
+        let client = MediaClient::new(config.with_dry_run(true)).unwrap();
+
+        assert_eq!(client.upload_image(b"JPEGDATA".to_vec(), "front.jpg").await.unwrap(), DRY_RUN_ID);
+        assert!(server.received_requests().await.unwrap().is_empty());
+    }
+}
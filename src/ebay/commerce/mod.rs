@@ -1,14 +1,19 @@
 //! eBay Commerce APIs
 //! 
-//! This module provides access to eBay's Commerce APIs for catalog, taxonomy, identity, and translation.
+//! This module provides access to eBay's Commerce APIs for catalog, taxonomy, identity, translation, and media.
 
 pub mod catalog;
 pub mod taxonomy;
 pub mod identity;
 pub mod translation;
+pub mod media;
 
 // Re-export commonly used types
 pub use catalog::CatalogClient;
-pub use taxonomy::TaxonomyClient;
+pub use taxonomy::{
+    AspectFormField, AspectInfo, AspectMetadataExt, AspectValidation, CategoryBreadcrumb, CategorySuggestionResponseExt, InvalidAspectValue,
+    MarketplaceInfo, RankedCategorySuggestion, SuggestionSource, TaxonomyClient, TreeVersionListener, TreeVersionStatus,
+};
 pub use identity::IdentityClient;
-pub use translation::TranslationClient;
\ No newline at end of file
+pub use translation::{TranslationClient, TranslationContext};
+pub use media::MediaClient;
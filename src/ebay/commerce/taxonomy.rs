@@ -1,15 +1,21 @@
 use crate::config::EbayConfig;
-use crate::error::{HermesError, HermesResult};
+use crate::error::{ApiErrorMessage, HermesError, HermesResult};
+use crate::ebay::api_error::{parse_response, raw_error};
+use crate::ebay::telemetry::record_api_call;
 use crate::ebay::auth::EbayAuth;
+use crate::ebay::marketplace::{normalize_marketplace, Marketplace, MarketplaceStyle};
+use futures::stream::{self, StreamExt, TryStreamExt};
+use std::collections::HashMap;
 use std::sync::Arc;
+use tokio::sync::RwLock;
 
 // Import eBay Commerce Taxonomy SDK models and APIs
 use hermes_ebay_commerce_taxonomy::models::{
     GetCategoriesAspectResponse, CategorySubtree, CategorySuggestionResponse, CategoryTree,
     GetCompatibilityMetadataResponse, GetCompatibilityPropertyValuesResponse, BaseCategoryTree,
-    ExpiredCategories, AspectMetadata,
+    ExpiredCategories, AspectMetadata, CategoryTreeNode, CategorySuggestion, Aspect,
 };
-use hermes_ebay_commerce_taxonomy::apis::configuration::Configuration as TaxonomyConfiguration;
+use hermes_ebay_commerce_taxonomy::apis::urlencode;
 
 /// eBay Commerce Taxonomy API client for category and taxonomy operations
 /// 
@@ -20,13 +26,299 @@ use hermes_ebay_commerce_taxonomy::apis::configuration::Configuration as Taxonom
 pub struct TaxonomyClient {
     config: EbayConfig,
     auth: Arc<EbayAuth>,
+    // Full category trees keyed by category tree ID
+    tree_cache: Arc<RwLock<HashMap<String, Arc<CategoryTree>>>>,
+    // Item aspects keyed by (category tree ID, category ID)
+    aspect_cache: Arc<RwLock<AspectCache>>,
+    // Default category tree of each marketplace
+    default_tree_cache: Arc<RwLock<HashMap<Marketplace, Arc<BaseCategoryTree>>>>,
+    // Called by `check_tree_version` when a marketplace's tree version changes
+    tree_version_listener: Option<TreeVersionListener>,
+}
+
+type AspectCache = HashMap<(String, String), Arc<AspectMetadata>>;
+
+/// Callback receiving the marketplace and the old and new category tree versions
+pub type TreeVersionListener = Arc<dyn Fn(Marketplace, &str, &str) + Send + Sync>;
+
+/// Maximum aspect requests in flight while prefetching
+const MAX_CONCURRENT_ASPECT_REQUESTS: usize = 5;
+
+/// Taxonomy error ID for a marketplace ID that is not supported
+const MARKETPLACE_NOT_SUPPORTED_ERROR_ID: i64 = 62004;
+
+/// A marketplace with its default category tree
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MarketplaceInfo {
+    pub marketplace: Marketplace,
+    pub tree_id: String,
+    pub tree_version: Option<String>,
+}
+
+/// Outcome of [`TaxonomyClient::check_tree_version`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TreeVersionStatus {
+    /// The remote version matches the cached one (or nothing was cached yet)
+    Unchanged,
+    /// eBay published a new category tree version
+    Updated { from: String, to: String },
+}
+
+/// One level of a category breadcrumb (e.g. "Computers" in "Electronics > Computers > Laptops")
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CategoryBreadcrumb {
+    pub category_id: String,
+    pub category_name: String,
+}
+
+/// Result of checking a listing's aspects against a category's aspect metadata
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct AspectValidation {
+    /// Required aspects with no value
+    pub missing_required: Vec<String>,
+    /// Recommended aspects with no value; these do not make the aspects invalid
+    pub missing_recommended: Vec<String>,
+    /// Provided aspects the category does not define
+    pub unknown: Vec<String>,
+    /// Values of selection-only aspects that are not among the allowed values
+    pub invalid_values: Vec<InvalidAspectValue>,
+}
+
+/// A value not allowed for a selection-only aspect
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InvalidAspectValue {
+    pub aspect: String,
+    pub value: String,
+}
+
+impl AspectValidation {
+    /// Whether the aspects passed every check
+    pub fn is_valid(&self) -> bool {
+        self.missing_required.is_empty() && self.unknown.is_empty() && self.invalid_values.is_empty()
+    }
+}
+
+/// An item aspect with its constraints flattened
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AspectInfo {
+    /// Localized aspect name, e.g. "Brand"
+    pub name: String,
+    pub required: bool,
+    /// "RECOMMENDED" or "OPTIONAL"
+    pub usage: Option<String>,
+    /// "STRING", "NUMBER", "DATE" or "STRING_ARRAY"
+    pub data_type: Option<String>,
+    /// Whether values must be picked from `allowed_values` ("SELECTION_ONLY")
+    pub selection_only: bool,
+    /// Whether more than one value may be given
+    pub multi_valued: bool,
+    pub max_length: Option<u32>,
+    /// Values eBay lists for the aspect (suggestions unless `selection_only`)
+    pub allowed_values: Vec<String>,
+}
+
+impl From<&Aspect> for AspectInfo {
+    fn from(aspect: &Aspect) -> Self {
+        let constraint = aspect.aspect_constraint.as_deref();
+        Self {
+            name: aspect.localized_aspect_name.clone().unwrap_or_default(),
+            required: constraint.and_then(|c| c.aspect_required).unwrap_or(false),
+            usage: constraint.and_then(|c| c.aspect_usage.clone()),
+            data_type: constraint.and_then(|c| c.aspect_data_type.clone()),
+            selection_only: constraint.and_then(|c| c.aspect_mode.as_deref()) == Some("SELECTION_ONLY"),
+            multi_valued: constraint.and_then(|c| c.item_to_aspect_cardinality.as_deref()) == Some("MULTI"),
+            max_length: constraint.and_then(|c| c.aspect_max_length).map(|length| length.max(0) as u32),
+            allowed_values: aspect
+                .aspect_values
+                .iter()
+                .flatten()
+                .filter_map(|value| value.localized_value.clone())
+                .collect(),
+        }
+    }
+}
+
+/// One input of a listing form generated from a category's aspects
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AspectFormField {
+    /// Localized aspect name, used as the field label and the aspect key
+    pub name: String,
+    pub required: bool,
+    /// Whether the field accepts several values (a multi-select or tag input)
+    pub multi_value: bool,
+    /// Whether any text is accepted; otherwise a value must be picked from `allowed_values`
+    pub free_text: bool,
+    /// Values to offer, as suggestions for free-text fields or as the options of a select
+    pub allowed_values: Vec<String>,
+    /// "STRING", "NUMBER", "DATE" or "STRING_ARRAY"
+    pub data_type: Option<String>,
+    /// Expected value format, e.g. "int32", "double" or "YYYY"
+    pub format: Option<String>,
+    pub max_length: Option<u32>,
+}
+
+impl From<&Aspect> for AspectFormField {
+    fn from(aspect: &Aspect) -> Self {
+        let info = AspectInfo::from(aspect);
+        Self {
+            name: info.name,
+            required: info.required,
+            multi_value: info.multi_valued,
+            free_text: !info.selection_only,
+            allowed_values: info.allowed_values,
+            data_type: info.data_type,
+            format: aspect.aspect_constraint.as_deref().and_then(|c| c.aspect_format.clone()),
+            max_length: info.max_length,
+        }
+    }
+}
+
+/// Typed access to the aspects in an [`AspectMetadata`]
+pub trait AspectMetadataExt {
+    /// Aspects a listing in the category must have
+    fn required_aspects(&self) -> Vec<AspectInfo>;
+
+    /// Aspects eBay recommends but does not require
+    ///
+    /// eBay also marks required aspects as "RECOMMENDED"; those are excluded here.
+    fn recommended_aspects(&self) -> Vec<AspectInfo>;
+
+    /// Values listed for an aspect, or `None` if the category has no such aspect
+    fn allowed_values(&self, aspect_name: &str) -> Option<Vec<String>>;
+
+    /// Every aspect as a listing form field, in eBay's order
+    fn form_fields(&self) -> Vec<AspectFormField>;
+}
+
+impl AspectMetadataExt for AspectMetadata {
+    fn required_aspects(&self) -> Vec<AspectInfo> {
+        self.aspects.iter().flatten().map(AspectInfo::from).filter(|aspect| aspect.required).collect()
+    }
+
+    fn recommended_aspects(&self) -> Vec<AspectInfo> {
+        self.aspects
+            .iter()
+            .flatten()
+            .map(AspectInfo::from)
+            .filter(|aspect| !aspect.required && aspect.usage.as_deref() == Some("RECOMMENDED"))
+            .collect()
+    }
+
+    fn allowed_values(&self, aspect_name: &str) -> Option<Vec<String>> {
+        self.aspects
+            .iter()
+            .flatten()
+            .find(|aspect| aspect.localized_aspect_name.as_deref() == Some(aspect_name))
+            .map(|aspect| AspectInfo::from(aspect).allowed_values)
+    }
+
+    fn form_fields(&self) -> Vec<AspectFormField> {
+        self.aspects.iter().flatten().map(AspectFormField::from).collect()
+    }
+}
+
+/// Where a category suggestion came from
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SuggestionSource {
+    /// eBay's get_category_suggestions
+    Remote,
+    /// Keyword match against the cached category tree, used when eBay could not be reached
+    Fallback,
+}
+
+/// A category suggestion with its rank and full breadcrumb
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RankedCategorySuggestion {
+    /// Position in eBay's ranking, starting at 1 for the most relevant suggestion
+    pub rank: usize,
+    pub category_id: String,
+    pub category_name: String,
+    /// Breadcrumb from the top-level category down to the suggested category
+    pub breadcrumb: Vec<CategoryBreadcrumb>,
+    /// eBay's relevancy indicator, when returned
+    pub relevancy: Option<String>,
+    pub source: SuggestionSource,
+}
+
+/// Ranked access to the suggestions in a [`CategorySuggestionResponse`]
+///
+/// eBay returns suggestions most relevant first, so rank follows response order.
+pub trait CategorySuggestionResponseExt {
+    /// The most relevant suggestion
+    fn best(&self) -> Option<RankedCategorySuggestion>;
+
+    /// Up to `n` suggestions, most relevant first
+    fn top_n(&self, n: usize) -> Vec<RankedCategorySuggestion>;
+}
+
+impl CategorySuggestionResponseExt for CategorySuggestionResponse {
+    fn best(&self) -> Option<RankedCategorySuggestion> {
+        self.top_n(1).into_iter().next()
+    }
+
+    fn top_n(&self, n: usize) -> Vec<RankedCategorySuggestion> {
+        self.category_suggestions
+            .iter()
+            .flatten()
+            .take(n)
+            .enumerate()
+            .map(|(index, suggestion)| ranked_suggestion(index + 1, suggestion))
+            .collect()
+    }
+}
+
+fn ranked_suggestion(rank: usize, suggestion: &CategorySuggestion) -> RankedCategorySuggestion {
+    let category = suggestion.category.as_deref();
+    let category_id = category.and_then(|c| c.category_id.clone()).unwrap_or_default();
+    let category_name = category.and_then(|c| c.category_name.clone()).unwrap_or_default();
+
+    // Ancestors are listed from the immediate parent up to the root
+    let mut breadcrumb: Vec<CategoryBreadcrumb> = suggestion
+        .category_tree_node_ancestors
+        .iter()
+        .flatten()
+        .rev()
+        .filter(|ancestor| ancestor.category_tree_node_level != Some(0))
+        .map(|ancestor| CategoryBreadcrumb {
+            category_id: ancestor.category_id.clone().unwrap_or_default(),
+            category_name: ancestor.category_name.clone().unwrap_or_default(),
+        })
+        .collect();
+    breadcrumb.push(CategoryBreadcrumb {
+        category_id: category_id.clone(),
+        category_name: category_name.clone(),
+    });
+
+    RankedCategorySuggestion {
+        rank,
+        category_id,
+        category_name,
+        breadcrumb,
+        relevancy: suggestion.relevancy.clone(),
+        source: SuggestionSource::Remote,
+    }
 }
 
 impl TaxonomyClient {
     /// Create a new Taxonomy API client
     pub fn new(config: EbayConfig) -> HermesResult<Self> {
         let auth = Arc::new(EbayAuth::new(config.clone())?);
-        Ok(Self { config, auth })
+        Ok(Self {
+            config,
+            auth,
+            tree_cache: Arc::new(RwLock::new(HashMap::new())),
+            aspect_cache: Arc::new(RwLock::new(HashMap::new())),
+            default_tree_cache: Arc::new(RwLock::new(HashMap::new())),
+            tree_version_listener: None,
+        })
+    }
+
+    /// Call `listener` whenever [`check_tree_version`](Self::check_tree_version) finds a new tree version
+    /// 
+    /// The listener receives the marketplace and the previous and new versions.
+    pub fn on_tree_version_change(mut self, listener: impl Fn(Marketplace, &str, &str) + Send + Sync + 'static) -> Self {
+        self.tree_version_listener = Some(Arc::new(listener));
+        self
     }
 
     /// Fetch item aspects for a category tree
@@ -43,22 +335,20 @@ impl TaxonomyClient {
         let token_duration = token_start.elapsed();
         tracing::info!("OAuth token request for fetch_item_aspects: {:?}", token_duration);
         
-        // Set up configuration
-        let mut config = TaxonomyConfiguration::new();
-        config.base_path = if self.config.sandbox {
-            "https://api.sandbox.ebay.com/commerce/taxonomy/v1".to_string()
-        } else {
-            "https://api.ebay.com/commerce/taxonomy/v1".to_string()
-        };
-        config.oauth_access_token = Some(token);
+        let url = format!("{}/commerce/taxonomy/v1/category_tree/{}/fetch_item_aspects", self.config.base_url(), urlencode(category_tree_id));
+        let http = self.config.http_client();
+        
+        // Fail fast while this API's circuit breaker is open
+        let probe = self.config.check_circuit("commerce.taxonomy")?;
         
-        // Call the eBay SDK
+        // Call the eBay API
         let ebay_start = std::time::Instant::now();
-        let result = hermes_ebay_commerce_taxonomy::apis::category_tree_api::fetch_item_aspects(
-            &config,
-            &category_tree_id,
-        ).await;
+        let result = self.config.retrying("commerce.taxonomy", || {
+            self.config.send_json(http.get(&url).bearer_auth(&token))
+        }).await;
         let ebay_duration = ebay_start.elapsed();
+        record_api_call("commerce.taxonomy", "fetch_item_aspects", &result, ebay_duration);
+        self.config.record_circuit("commerce.taxonomy", &probe, &result);
         tracing::info!("eBay fetch_item_aspects API call: {:?}", ebay_duration);
         
         match result {
@@ -71,7 +361,7 @@ impl TaxonomyClient {
             Err(e) => {
                 let total_duration = start_time.elapsed();
                 tracing::error!("eBay fetch_item_aspects error after {:?}: {:?}", total_duration, e);
-                Err(HermesError::ApiRequest(format!("eBay fetch_item_aspects failed: {:?}", e)))
+                Err(raw_error("fetch_item_aspects", &format!("category_tree_id {}", category_tree_id), e))
             }
         }
     }
@@ -91,24 +381,24 @@ impl TaxonomyClient {
         let token_duration = token_start.elapsed();
         tracing::info!("OAuth token request for get_category_subtree: {:?}", token_duration);
         
-        // Set up configuration
-        let mut config = TaxonomyConfiguration::new();
-        config.base_path = if self.config.sandbox {
-            "https://api.sandbox.ebay.com/commerce/taxonomy/v1".to_string()
-        } else {
-            "https://api.ebay.com/commerce/taxonomy/v1".to_string()
-        };
-        config.oauth_access_token = Some(token);
+        let url = format!("{}/commerce/taxonomy/v1/category_tree/{}/get_category_subtree", self.config.base_url(), urlencode(category_tree_id));
+        let http = self.config.http_client();
+        
+        // Fail fast while this API's circuit breaker is open
+        let probe = self.config.check_circuit("commerce.taxonomy")?;
         
-        // Call the eBay SDK
+        // Call the eBay API
         let ebay_start = std::time::Instant::now();
-        let result = hermes_ebay_commerce_taxonomy::apis::category_tree_api::get_category_subtree(
-            &config,
-            category_id,
-            &category_tree_id,
-            accept_encoding,
-        ).await;
+        let result = self.config.retrying("commerce.taxonomy", || {
+            let mut request = http.get(&url).bearer_auth(&token).query(&[("category_id", category_id)]);
+            if let Some(encoding) = self.config.taxonomy_accept_encoding(accept_encoding) {
+                request = request.header(reqwest::header::ACCEPT_ENCODING, encoding);
+            }
+            self.config.send_json(request)
+        }).await;
         let ebay_duration = ebay_start.elapsed();
+        record_api_call("commerce.taxonomy", "get_category_subtree", &result, ebay_duration);
+        self.config.record_circuit("commerce.taxonomy", &probe, &result);
         tracing::info!("eBay get_category_subtree API call: {:?}", ebay_duration);
         
         match result {
@@ -121,7 +411,7 @@ impl TaxonomyClient {
             Err(e) => {
                 let total_duration = start_time.elapsed();
                 tracing::error!("eBay get_category_subtree error after {:?}: {:?}", total_duration, e);
-                Err(HermesError::ApiRequest(format!("eBay get_category_subtree failed: {:?}", e)))
+                Err(raw_error("get_category_subtree", &format!("category_id {}", category_id), e))
             }
         }
     }
@@ -141,23 +431,20 @@ impl TaxonomyClient {
         let token_duration = token_start.elapsed();
         tracing::info!("OAuth token request for get_category_suggestions: {:?}", token_duration);
         
-        // Set up configuration
-        let mut config = TaxonomyConfiguration::new();
-        config.base_path = if self.config.sandbox {
-            "https://api.sandbox.ebay.com/commerce/taxonomy/v1".to_string()
-        } else {
-            "https://api.ebay.com/commerce/taxonomy/v1".to_string()
-        };
-        config.oauth_access_token = Some(token);
+        let url = format!("{}/commerce/taxonomy/v1/category_tree/{}/get_category_suggestions", self.config.base_url(), urlencode(category_tree_id));
+        let http = self.config.http_client();
         
-        // Call the eBay SDK
+        // Fail fast while this API's circuit breaker is open
+        let probe = self.config.check_circuit("commerce.taxonomy")?;
+        
+        // Call the eBay API
         let ebay_start = std::time::Instant::now();
-        let result = hermes_ebay_commerce_taxonomy::apis::category_tree_api::get_category_suggestions(
-            &config,
-            &category_tree_id,
-            query,
-        ).await;
+        let result = self.config.retrying("commerce.taxonomy", || {
+            self.config.send_json(http.get(&url).bearer_auth(&token).query(&[("q", query)]))
+        }).await;
         let ebay_duration = ebay_start.elapsed();
+        record_api_call("commerce.taxonomy", "get_category_suggestions", &result, ebay_duration);
+        self.config.record_circuit("commerce.taxonomy", &probe, &result);
         tracing::info!("eBay get_category_suggestions API call: {:?}", ebay_duration);
         
         match result {
@@ -170,11 +457,67 @@ impl TaxonomyClient {
             Err(e) => {
                 let total_duration = start_time.elapsed();
                 tracing::error!("eBay get_category_suggestions error after {:?}: {:?}", total_duration, e);
-                Err(HermesError::ApiRequest(format!("eBay get_category_suggestions failed: {:?}", e)))
+                Err(raw_error("get_category_suggestions", &format!("category_tree_id {}", category_tree_id), e))
             }
         }
     }
 
+    /// Get up to `n` ranked category suggestions, degrading to a local search
+    /// 
+    /// When eBay's suggestions cannot be fetched (e.g. rate limited or unavailable),
+    /// leaf categories of the cached category tree are matched against the words of
+    /// `query` instead and marked [`SuggestionSource::Fallback`]. The original error
+    /// is returned if the category tree is not available either.
+    /// 
+    /// # Arguments
+    /// * `category_tree_id` - The category tree ID (e.g., "0" for EBAY_US)
+    /// * `query` - Keywords describing the item
+    /// * `n` - Maximum number of suggestions
+    pub async fn suggest_categories_with_fallback(
+        &self,
+        category_tree_id: &str,
+        query: &str,
+        n: usize,
+    ) -> HermesResult<Vec<RankedCategorySuggestion>> {
+        let error = match self.get_category_suggestions(category_tree_id, query).await {
+            Ok(response) => return Ok(response.top_n(n)),
+            Err(e) => e,
+        };
+        tracing::warn!("Category suggestions unavailable ({}); searching the cached category tree", error);
+
+        let Ok(tree) = self.cached_category_tree(category_tree_id).await else {
+            return Err(error);
+        };
+        let words: Vec<String> = query.split_whitespace().map(str::to_lowercase).collect();
+        let mut matches = Vec::new();
+        if let Some(root) = tree.root_category_node.as_deref() {
+            collect_keyword_matches(root, &words, &mut Vec::new(), &mut matches);
+        }
+        // Best score first; ties keep tree order
+        matches.sort_by_key(|(score, _)| std::cmp::Reverse(*score));
+
+        Ok(matches
+            .into_iter()
+            .take(n)
+            .enumerate()
+            .map(|(index, (_, breadcrumb))| {
+                // Matches always end at their leaf category
+                let (category_id, category_name) = breadcrumb
+                    .last()
+                    .map(|leaf| (leaf.category_id.clone(), leaf.category_name.clone()))
+                    .unwrap_or_default();
+                RankedCategorySuggestion {
+                    rank: index + 1,
+                    category_id,
+                    category_name,
+                    breadcrumb,
+                    relevancy: None,
+                    source: SuggestionSource::Fallback,
+                }
+            })
+            .collect())
+    }
+
     /// Get complete category tree
     /// Already implemented in main EbayClient, but included here for completeness
     pub async fn get_category_tree(
@@ -190,36 +533,38 @@ impl TaxonomyClient {
         let token_duration = token_start.elapsed();
         tracing::info!("OAuth token request for get_category_tree: {:?}", token_duration);
         
-        // Set up configuration
-        let mut config = TaxonomyConfiguration::new();
-        config.base_path = if self.config.sandbox {
-            "https://api.sandbox.ebay.com/commerce/taxonomy/v1".to_string()
-        } else {
-            "https://api.ebay.com/commerce/taxonomy/v1".to_string()
-        };
-        config.oauth_access_token = Some(token);
+        // Trees run to tens of megabytes, so the body is read here, within `max_response_bytes`
+        let url = format!("{}/commerce/taxonomy/v1/category_tree/{}", self.config.base_url(), urlencode(category_tree_id));
+        let http = self.config.http_client();
+        
+        // Fail fast while this API's circuit breaker is open
+        let probe = self.config.check_circuit("commerce.taxonomy")?;
         
-        // Call the eBay SDK
         let ebay_start = std::time::Instant::now();
-        let result = hermes_ebay_commerce_taxonomy::apis::category_tree_api::get_category_tree(
-            &config,
-            &category_tree_id,
-            accept_encoding,
-        ).await;
+        let result = self.config.retrying("commerce.taxonomy", || {
+            let mut request = http.get(&url).bearer_auth(&token);
+            if let Some(encoding) = self.config.taxonomy_accept_encoding(accept_encoding) {
+                request = request.header(reqwest::header::ACCEPT_ENCODING, encoding);
+            }
+            self.config.send(request)
+        }).await;
         let ebay_duration = ebay_start.elapsed();
+        record_api_call("commerce.taxonomy", "get_category_tree", &result, ebay_duration);
+        self.config.record_circuit("commerce.taxonomy", &probe, &result);
         tracing::info!("eBay get_category_tree API call: {:?}", ebay_duration);
         
         match result {
-            Ok(response) => {
+            Ok(body) => {
+                let tree = parse_response(&body)?;
                 let total_duration = start_time.elapsed();
                 let our_processing = total_duration - token_duration - ebay_duration;
                 tracing::info!("get_category_tree total: {:?} | Our processing: {:?}", total_duration, our_processing);
-                Ok(response)
+                Ok(tree)
             },
             Err(e) => {
                 let total_duration = start_time.elapsed();
                 tracing::error!("eBay get_category_tree error after {:?}: {:?}", total_duration, e);
-                Err(HermesError::ApiRequest(format!("eBay get_category_tree failed: {:?}", e)))
+                Err(raw_error("get_category_tree", &format!("category_tree_id {}", category_tree_id), e))
             }
         }
     }
@@ -238,23 +583,20 @@ impl TaxonomyClient {
         let token_duration = token_start.elapsed();
         tracing::info!("OAuth token request for get_compatibility_properties: {:?}", token_duration);
         
-        // Set up configuration
-        let mut config = TaxonomyConfiguration::new();
-        config.base_path = if self.config.sandbox {
-            "https://api.sandbox.ebay.com/commerce/taxonomy/v1".to_string()
-        } else {
-            "https://api.ebay.com/commerce/taxonomy/v1".to_string()
-        };
-        config.oauth_access_token = Some(token);
+        let url = format!("{}/commerce/taxonomy/v1/category_tree/{}/get_compatibility_properties", self.config.base_url(), urlencode(category_tree_id));
+        let http = self.config.http_client();
+        
+        // Fail fast while this API's circuit breaker is open
+        let probe = self.config.check_circuit("commerce.taxonomy")?;
         
-        // Call the eBay SDK
+        // Call the eBay API
         let ebay_start = std::time::Instant::now();
-        let result = hermes_ebay_commerce_taxonomy::apis::category_tree_api::get_compatibility_properties(
-            &config,
-            &category_tree_id,
-            category_id,
-        ).await;
+        let result = self.config.retrying("commerce.taxonomy", || {
+            self.config.send_json(http.get(&url).bearer_auth(&token).query(&[("category_id", category_id)]))
+        }).await;
         let ebay_duration = ebay_start.elapsed();
+        record_api_call("commerce.taxonomy", "get_compatibility_properties", &result, ebay_duration);
+        self.config.record_circuit("commerce.taxonomy", &probe, &result);
         tracing::info!("eBay get_compatibility_properties API call: {:?}", ebay_duration);
         
         match result {
@@ -267,7 +609,7 @@ impl TaxonomyClient {
             Err(e) => {
                 let total_duration = start_time.elapsed();
                 tracing::error!("eBay get_compatibility_properties error after {:?}: {:?}", total_duration, e);
-                Err(HermesError::ApiRequest(format!("eBay get_compatibility_properties failed: {:?}", e)))
+                Err(raw_error("get_compatibility_properties", &format!("category_tree_id {}", category_tree_id), e))
             }
         }
     }
@@ -288,25 +630,26 @@ impl TaxonomyClient {
         let token_duration = token_start.elapsed();
         tracing::info!("OAuth token request for get_compatibility_property_values: {:?}", token_duration);
         
-        // Set up configuration
-        let mut config = TaxonomyConfiguration::new();
-        config.base_path = if self.config.sandbox {
-            "https://api.sandbox.ebay.com/commerce/taxonomy/v1".to_string()
-        } else {
-            "https://api.ebay.com/commerce/taxonomy/v1".to_string()
-        };
-        config.oauth_access_token = Some(token);
+        let url = format!("{}/commerce/taxonomy/v1/category_tree/{}/get_compatibility_property_values", self.config.base_url(), urlencode(category_tree_id));
+        let http = self.config.http_client();
         
-        // Call the eBay SDK
+        // Fail fast while this API's circuit breaker is open
+        let probe = self.config.check_circuit("commerce.taxonomy")?;
+        
+        // Call the eBay API
         let ebay_start = std::time::Instant::now();
-        let result = hermes_ebay_commerce_taxonomy::apis::category_tree_api::get_compatibility_property_values(
-            &config,
-            &category_tree_id,
-            &compatibility_property,
-            category_id,
-            filter,
-        ).await;
+        let result = self.config.retrying("commerce.taxonomy", || {
+            self.config.send_json(
+                http.get(&url).bearer_auth(&token).query(&[
+                    ("compatibility_property", Some(compatibility_property)),
+                    ("category_id", Some(category_id)),
+                    ("filter", filter),
+                ]),
+            )
+        }).await;
         let ebay_duration = ebay_start.elapsed();
+        record_api_call("commerce.taxonomy", "get_compatibility_property_values", &result, ebay_duration);
+        self.config.record_circuit("commerce.taxonomy", &probe, &result);
         tracing::info!("eBay get_compatibility_property_values API call: {:?}", ebay_duration);
         
         match result {
@@ -319,7 +662,7 @@ impl TaxonomyClient {
             Err(e) => {
                 let total_duration = start_time.elapsed();
                 tracing::error!("eBay get_compatibility_property_values error after {:?}: {:?}", total_duration, e);
-                Err(HermesError::ApiRequest(format!("eBay get_compatibility_property_values failed: {:?}", e)))
+                Err(raw_error("get_compatibility_property_values", &format!("category_tree_id {}", category_tree_id), e))
             }
         }
     }
@@ -337,22 +680,22 @@ impl TaxonomyClient {
         let token_duration = token_start.elapsed();
         tracing::info!("OAuth token request for get_default_category_tree_id: {:?}", token_duration);
         
-        // Set up configuration
-        let mut config = TaxonomyConfiguration::new();
-        config.base_path = if self.config.sandbox {
-            "https://api.sandbox.ebay.com/commerce/taxonomy/v1".to_string()
-        } else {
-            "https://api.ebay.com/commerce/taxonomy/v1".to_string()
-        };
-        config.oauth_access_token = Some(token);
+        let url = format!("{}/commerce/taxonomy/v1/get_default_category_tree_id", self.config.base_url());
+        let http = self.config.http_client();
+        
+        // Fail fast while this API's circuit breaker is open
+        let probe = self.config.check_circuit("commerce.taxonomy")?;
+        
+        let marketplace_id = normalize_marketplace(marketplace_id, MarketplaceStyle::Underscore);
         
-        // Call the eBay SDK
+        // Call the eBay API
         let ebay_start = std::time::Instant::now();
-        let result = hermes_ebay_commerce_taxonomy::apis::category_tree_api::get_default_category_tree_id(
-            &config,
-            marketplace_id,
-        ).await;
+        let result = self.config.retrying("commerce.taxonomy", || {
+            self.config.send_json(http.get(&url).bearer_auth(&token).query(&[("marketplace_id", &marketplace_id)]))
+        }).await;
         let ebay_duration = ebay_start.elapsed();
+        record_api_call("commerce.taxonomy", "get_default_category_tree_id", &result, ebay_duration);
+        self.config.record_circuit("commerce.taxonomy", &probe, &result);
         tracing::info!("eBay get_default_category_tree_id API call: {:?}", ebay_duration);
         
         match result {
@@ -365,11 +708,75 @@ impl TaxonomyClient {
             Err(e) => {
                 let total_duration = start_time.elapsed();
                 tracing::error!("eBay get_default_category_tree_id error after {:?}: {:?}", total_duration, e);
-                Err(HermesError::ApiRequest(format!("eBay get_default_category_tree_id failed: {:?}", e)))
+                let error = raw_error("get_default_category_tree_id", &format!("marketplace_id {}", marketplace_id), e);
+                Err(explain_unsupported_marketplace(error, &marketplace_id))
             }
         }
     }
 
+    /// Get the default category tree of each marketplace
+    /// 
+    /// Tree IDs are looked up once per marketplace and cached for the lifetime of
+    /// this client. Fails if the application is not enabled for one of the
+    /// marketplaces.
+    /// 
+    /// # Arguments
+    /// * `marketplaces` - Marketplaces to describe, e.g. those offered in a picker
+    pub async fn marketplace_catalog(&self, marketplaces: &[Marketplace]) -> HermesResult<Vec<MarketplaceInfo>> {
+        let mut catalog = Vec::with_capacity(marketplaces.len());
+        for &marketplace in marketplaces {
+            let cached = self.default_tree_cache.read().await.get(&marketplace).cloned();
+            let tree = match cached {
+                Some(tree) => tree,
+                None => {
+                    let tree = Arc::new(self.get_default_category_tree_id(marketplace.as_str()).await?);
+                    self.default_tree_cache.write().await.insert(marketplace, Arc::clone(&tree));
+                    tree
+                }
+            };
+            catalog.push(MarketplaceInfo {
+                marketplace,
+                tree_id: tree.category_tree_id.clone().unwrap_or_default(),
+                tree_version: tree.category_tree_version.clone(),
+            });
+        }
+        Ok(catalog)
+    }
+
+    /// Check whether eBay has published a new category tree for a marketplace
+    /// 
+    /// Compares the remote `category_tree_version` with the one cached by this
+    /// client (see [`marketplace_catalog`](Self::marketplace_catalog)). The first check
+    /// for a marketplace only records the version. On a change, the cached tree and
+    /// aspects of the old version are dropped and the change listener, if any, is called.
+    /// 
+    /// # Arguments
+    /// * `marketplace_id` - Marketplace whose default tree to check, e.g. "EBAY_US"
+    pub async fn check_tree_version(&self, marketplace_id: &str) -> HermesResult<TreeVersionStatus> {
+        let marketplace: Marketplace = marketplace_id.parse()?;
+        let remote = Arc::new(self.get_default_category_tree_id(marketplace.as_str()).await?);
+        let previous = self.default_tree_cache.write().await.insert(marketplace, Arc::clone(&remote));
+
+        let Some(previous) = previous else {
+            return Ok(TreeVersionStatus::Unchanged);
+        };
+        let from = previous.category_tree_version.clone().unwrap_or_default();
+        let to = remote.category_tree_version.clone().unwrap_or_default();
+        if from == to {
+            return Ok(TreeVersionStatus::Unchanged);
+        }
+
+        tracing::info!("Category tree for {} updated from version {} to {}", marketplace, from, to);
+        if let Some(tree_id) = previous.category_tree_id.as_deref() {
+            self.tree_cache.write().await.remove(tree_id);
+            self.aspect_cache.write().await.retain(|(cached_tree_id, _), _| cached_tree_id != tree_id);
+        }
+        if let Some(listener) = &self.tree_version_listener {
+            listener(marketplace, &from, &to);
+        }
+        Ok(TreeVersionStatus::Updated { from, to })
+    }
+
     /// Get expired categories
     pub async fn get_expired_categories(
         &self,
@@ -383,22 +790,20 @@ impl TaxonomyClient {
         let token_duration = token_start.elapsed();
         tracing::info!("OAuth token request for get_expired_categories: {:?}", token_duration);
         
-        // Set up configuration
-        let mut config = TaxonomyConfiguration::new();
-        config.base_path = if self.config.sandbox {
-            "https://api.sandbox.ebay.com/commerce/taxonomy/v1".to_string()
-        } else {
-            "https://api.ebay.com/commerce/taxonomy/v1".to_string()
-        };
-        config.oauth_access_token = Some(token);
+        let url = format!("{}/commerce/taxonomy/v1/category_tree/{}/get_expired_categories", self.config.base_url(), urlencode(category_tree_id));
+        let http = self.config.http_client();
+        
+        // Fail fast while this API's circuit breaker is open
+        let probe = self.config.check_circuit("commerce.taxonomy")?;
         
-        // Call the eBay SDK
+        // Call the eBay API
         let ebay_start = std::time::Instant::now();
-        let result = hermes_ebay_commerce_taxonomy::apis::category_tree_api::get_expired_categories(
-            &config,
-            &category_tree_id,
-        ).await;
+        let result = self.config.retrying("commerce.taxonomy", || {
+            self.config.send_json(http.get(&url).bearer_auth(&token))
+        }).await;
         let ebay_duration = ebay_start.elapsed();
+        record_api_call("commerce.taxonomy", "get_expired_categories", &result, ebay_duration);
+        self.config.record_circuit("commerce.taxonomy", &probe, &result);
         tracing::info!("eBay get_expired_categories API call: {:?}", ebay_duration);
         
         match result {
@@ -411,7 +816,7 @@ impl TaxonomyClient {
             Err(e) => {
                 let total_duration = start_time.elapsed();
                 tracing::error!("eBay get_expired_categories error after {:?}: {:?}", total_duration, e);
-                Err(HermesError::ApiRequest(format!("eBay get_expired_categories failed: {:?}", e)))
+                Err(raw_error("get_expired_categories", &format!("category_tree_id {}", category_tree_id), e))
             }
         }
     }
@@ -431,23 +836,20 @@ impl TaxonomyClient {
         let token_duration = token_start.elapsed();
         tracing::info!("OAuth token request for get_item_aspects_for_category: {:?}", token_duration);
         
-        // Set up configuration
-        let mut config = TaxonomyConfiguration::new();
-        config.base_path = if self.config.sandbox {
-            "https://api.sandbox.ebay.com/commerce/taxonomy/v1".to_string()
-        } else {
-            "https://api.ebay.com/commerce/taxonomy/v1".to_string()
-        };
-        config.oauth_access_token = Some(token);
+        let url = format!("{}/commerce/taxonomy/v1/category_tree/{}/get_item_aspects_for_category", self.config.base_url(), urlencode(category_tree_id));
+        let http = self.config.http_client();
+        
+        // Fail fast while this API's circuit breaker is open
+        let probe = self.config.check_circuit("commerce.taxonomy")?;
         
-        // Call the eBay SDK
+        // Call the eBay API
         let ebay_start = std::time::Instant::now();
-        let result = hermes_ebay_commerce_taxonomy::apis::category_tree_api::get_item_aspects_for_category(
-            &config,
-            category_id,
-            &category_tree_id,
-        ).await;
+        let result = self.config.retrying("commerce.taxonomy", || {
+            self.config.send_json(http.get(&url).bearer_auth(&token).query(&[("category_id", category_id)]))
+        }).await;
         let ebay_duration = ebay_start.elapsed();
+        record_api_call("commerce.taxonomy", "get_item_aspects_for_category", &result, ebay_duration);
+        self.config.record_circuit("commerce.taxonomy", &probe, &result);
         tracing::info!("eBay get_item_aspects_for_category API call: {:?}", ebay_duration);
         
         match result {
@@ -460,8 +862,709 @@ impl TaxonomyClient {
             Err(e) => {
                 let total_duration = start_time.elapsed();
                 tracing::error!("eBay get_item_aspects_for_category error after {:?}: {:?}", total_duration, e);
-                Err(HermesError::ApiRequest(format!("eBay get_item_aspects_for_category failed: {:?}", e)))
+                Err(raw_error("get_item_aspects_for_category", &format!("category_id {}", category_id), e))
+            }
+        }
+    }
+
+    /// Validate listing aspects against a category's aspect requirements
+    ///
+    /// Reports required and recommended aspects without a value, aspects the
+    /// category does not define, and values outside the allowed list of
+    /// selection-only aspects, so problems can be fixed before eBay rejects the
+    /// listing. Aspect names and values are compared exactly, as eBay localizes them
+    /// for the tree's marketplace.
+    ///
+    /// # Arguments
+    /// * `category_tree_id` - Category tree ID (e.g., "0" for US)
+    /// * `category_id` - Leaf category the item will be listed in
+    /// * `aspects` - Aspect names mapped to their values
+    pub async fn validate_aspects(
+        &self,
+        category_tree_id: &str,
+        category_id: &str,
+        aspects: &HashMap<String, Vec<String>>,
+    ) -> HermesResult<AspectValidation> {
+        let metadata = self.cached_item_aspects(category_tree_id, category_id).await?;
+        Ok(check_aspects(&metadata, aspects))
+    }
+
+    /// Get a category's item aspects, fetching them only on first use
+    ///
+    /// Aspect requirements change rarely, so each category's aspects are kept in
+    /// memory for the lifetime of this client.
+    pub async fn cached_item_aspects(
+        &self,
+        category_tree_id: &str,
+        category_id: &str,
+    ) -> HermesResult<Arc<AspectMetadata>> {
+        let key = (category_tree_id.to_string(), category_id.to_string());
+        if let Some(metadata) = self.aspect_cache.read().await.get(&key) {
+            return Ok(Arc::clone(metadata));
+        }
+
+        let metadata = Arc::new(self.get_item_aspects_for_category(category_id, category_tree_id).await?);
+        self.aspect_cache.write().await.insert(key, Arc::clone(&metadata));
+        Ok(metadata)
+    }
+
+    /// Fetch and cache the item aspects of several categories up front
+    ///
+    /// Categories are fetched concurrently, at most five at a time; categories
+    /// already cached are not fetched again. Afterwards [`Self::cached_item_aspects`]
+    /// and [`Self::validate_aspects`] answer from memory for these categories.
+    ///
+    /// # Arguments
+    /// * `category_tree_id` - Category tree ID (e.g., "0" for US)
+    /// * `category_ids` - Categories whose aspects to fetch
+    pub async fn prefetch_aspects(&self, category_tree_id: &str, category_ids: &[&str]) -> HermesResult<()> {
+        stream::iter(category_ids)
+            .map(|category_id| self.cached_item_aspects(category_tree_id, category_id))
+            .buffer_unordered(MAX_CONCURRENT_ASPECT_REQUESTS)
+            .try_for_each(|_| async { Ok(()) })
+            .await
+    }
+
+    /// Get a category tree, downloading it only on first use
+    /// 
+    /// Category trees are large and change rarely, so the first download is kept
+    /// in memory for the lifetime of this client.
+    pub async fn cached_category_tree(&self, category_tree_id: &str) -> HermesResult<Arc<CategoryTree>> {
+        if let Some(tree) = self.tree_cache.read().await.get(category_tree_id) {
+            return Ok(Arc::clone(tree));
+        }
+
+        let tree = Arc::new(self.get_category_tree(category_tree_id, None).await?);
+        self.tree_cache
+            .write()
+            .await
+            .insert(category_tree_id.to_string(), Arc::clone(&tree));
+        Ok(tree)
+    }
+
+    /// Get the breadcrumb path for a category
+    /// 
+    /// Returns the ancestor chain from the top-level category down to (and including)
+    /// `category_id`, e.g. Electronics > Computers > Laptops. The tree's root node is
+    /// not included. Uses the cached category tree.
+    pub async fn category_path(
+        &self,
+        category_tree_id: &str,
+        category_id: &str,
+    ) -> HermesResult<Vec<CategoryBreadcrumb>> {
+        let tree = self.cached_category_tree(category_tree_id).await?;
+        let mut path = Vec::new();
+        let found = tree
+            .root_category_node
+            .as_deref()
+            .is_some_and(|root| find_category_path(root, category_id, &mut path));
+
+        if !found {
+            return Err(HermesError::ApiRequest(format!(
+                "Category {} not found in category tree {}",
+                category_id, category_tree_id
+            )));
+        }
+        Ok(path)
+    }
+}
+
+/// Explain eBay's terse error for a marketplace the application cannot use
+///
+/// eBay does not report which marketplaces an application is enabled for, so the
+/// message points at the developer account settings instead.
+fn explain_unsupported_marketplace(error: HermesError, marketplace_id: &str) -> HermesError {
+    match error {
+        HermesError::Api { operation, status, mut errors, body }
+            if errors.iter().any(|e| e.error_id == Some(MARKETPLACE_NOT_SUPPORTED_ERROR_ID)) =>
+        {
+            errors.insert(0, ApiErrorMessage {
+                message: Some(format!(
+                    "Marketplace {} is not enabled for this application; check the marketplace ID and the \
+                     marketplaces enabled for the keyset in the eBay developer account",
+                    marketplace_id
+                )),
+                ..Default::default()
+            });
+            HermesError::Api { operation, status, errors, body }
+        }
+        error => error,
+    }
+}
+
+/// Check `aspects` (name to values, as on an inventory item's product) against `metadata`
+fn check_aspects(metadata: &AspectMetadata, aspects: &HashMap<String, Vec<String>>) -> AspectValidation {
+    let is_filled = |name: &str| aspects.get(name).is_some_and(|values| values.iter().any(|value| !value.trim().is_empty()));
+    let missing = |infos: Vec<AspectInfo>| -> Vec<String> {
+        infos.into_iter().map(|info| info.name).filter(|name| !name.is_empty() && !is_filled(name)).collect()
+    };
+    let mut validation = AspectValidation {
+        missing_required: missing(metadata.required_aspects()),
+        missing_recommended: missing(metadata.recommended_aspects()),
+        ..Default::default()
+    };
+
+    let infos: Vec<AspectInfo> = metadata.aspects.iter().flatten().map(AspectInfo::from).collect();
+    for info in infos.iter().filter(|info| info.selection_only && !info.allowed_values.is_empty()) {
+        let values = aspects.get(&info.name).map(Vec::as_slice).unwrap_or_default();
+        for value in values.iter().filter(|value| !info.allowed_values.contains(value)) {
+            validation.invalid_values.push(InvalidAspectValue {
+                aspect: info.name.clone(),
+                value: value.clone(),
+            });
+        }
+    }
+
+    validation.unknown = aspects
+        .keys()
+        .filter(|name| !infos.iter().any(|info| &info.name == *name))
+        .cloned()
+        .collect();
+    validation.unknown.sort();
+
+    validation
+}
+
+/// Collect the leaf categories under `node` that match any of `words`, with their score
+///
+/// A word found in the leaf's own name scores 2, one found only in an ancestor's
+/// name scores 1.
+fn collect_keyword_matches(
+    node: &CategoryTreeNode,
+    words: &[String],
+    path: &mut Vec<CategoryBreadcrumb>,
+    matches: &mut Vec<(usize, Vec<CategoryBreadcrumb>)>,
+) {
+    let category = node.category.as_deref();
+    let is_root = node.category_tree_node_level == Some(0);
+    if !is_root {
+        path.push(CategoryBreadcrumb {
+            category_id: category.and_then(|c| c.category_id.clone()).unwrap_or_default(),
+            category_name: category.and_then(|c| c.category_name.clone()).unwrap_or_default(),
+        });
+    }
+
+    let children = node.child_category_tree_nodes.as_deref().unwrap_or_default();
+    if children.is_empty() || node.leaf_category_tree_node == Some(true) {
+        if let Some((leaf, ancestors)) = path.split_last() {
+            let leaf_name = leaf.category_name.to_lowercase();
+            let score: usize = words
+                .iter()
+                .map(|word| {
+                    if leaf_name.contains(word.as_str()) {
+                        2
+                    } else if ancestors.iter().any(|a| a.category_name.to_lowercase().contains(word.as_str())) {
+                        1
+                    } else {
+                        0
+                    }
+                })
+                .sum();
+            if score > 0 {
+                matches.push((score, path.clone()));
+            }
+        }
+    } else {
+        for child in children {
+            collect_keyword_matches(child, words, path, matches);
+        }
+    }
+
+    if !is_root {
+        path.pop();
+    }
+}
+
+/// Depth-first search for `category_id`, collecting breadcrumbs on the way down
+fn find_category_path(
+    node: &CategoryTreeNode,
+    category_id: &str,
+    path: &mut Vec<CategoryBreadcrumb>,
+) -> bool {
+    let category = node.category.as_deref();
+    // The level-0 node is the tree's "Root" category, which never appears in breadcrumbs
+    let is_root = node.category_tree_node_level == Some(0);
+    if !is_root {
+        path.push(CategoryBreadcrumb {
+            category_id: category.and_then(|c| c.category_id.clone()).unwrap_or_default(),
+            category_name: category.and_then(|c| c.category_name.clone()).unwrap_or_default(),
+        });
+    }
+
+    if category.and_then(|c| c.category_id.as_deref()) == Some(category_id) && !is_root {
+        return true;
+    }
+    for child in node.child_category_tree_nodes.iter().flatten() {
+        if find_category_path(child, category_id, path) {
+            return true;
+        }
+    }
+
+    if !is_root {
+        path.pop();
+    }
+    false
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ebay::test_support::mock_ebay;
+    use wiremock::matchers::{header, method, path, query_param};
+    use wiremock::{Mock, ResponseTemplate};
+
+    fn node(id: &str, name: &str, level: i32, children: Vec<serde_json::Value>) -> serde_json::Value {
+        serde_json::json!({
+            "category": { "categoryId": id, "categoryName": name },
+            "categoryTreeNodeLevel": level,
+            "childCategoryTreeNodes": children,
+            "leafCategoryTreeNode": false
+        })
+    }
+
+    fn fixture_tree() -> serde_json::Value {
+        serde_json::json!({
+            "categoryTreeId": "0",
+            "categoryTreeVersion": "130",
+            "rootCategoryNode": node("0", "Root", 0, vec![
+                node("550", "Art", 1, vec![]),
+                node("293", "Consumer Electronics", 1, vec![
+                    node("58058", "Computers/Tablets & Networking", 2, vec![
+                        node("177", "PC Laptops & Netbooks", 3, vec![]),
+                    ]),
+                ]),
+            ])
+        })
+    }
+
+    #[tokio::test]
+    async fn get_category_tree_respects_max_response_bytes() {
+        let (server, config) = mock_ebay().await;
+        Mock::given(method("GET"))
+            .and(path("/commerce/taxonomy/v1/category_tree/0"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(fixture_tree()))
+            .expect(2)
+            .mount(&server)
+            .await;
+
+        let client = TaxonomyClient::new(config.clone().with_max_response_bytes(64)).unwrap();
+        let err = client.get_category_tree("0", None).await.unwrap_err();
+        assert!(matches!(err, HermesError::ResponseTooLarge { limit: 64 }));
+
+        let client = TaxonomyClient::new(config.with_max_response_bytes(1 << 20)).unwrap();
+        let tree = client.get_category_tree("0", None).await.unwrap();
+        assert_eq!(tree.category_tree_id.as_deref(), Some("0"));
+    }
+
+    #[tokio::test]
+    async fn category_path_returns_ordered_breadcrumbs_from_cached_tree() {
+        let (server, config) = mock_ebay().await;
+        Mock::given(method("GET"))
+            .and(path("/commerce/taxonomy/v1/category_tree/0"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(fixture_tree()))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = TaxonomyClient::new(config).unwrap();
+        let path = client.category_path("0", "177").await.unwrap();
+        let names: Vec<&str> = path.iter().map(|crumb| crumb.category_name.as_str()).collect();
+        assert_eq!(
+            names,
+            ["Consumer Electronics", "Computers/Tablets & Networking", "PC Laptops & Netbooks"]
+        );
+        assert_eq!(path[0].category_id, "293");
+
+        // Served from the cache; the mock only allows one tree download
+        let top_level = client.category_path("0", "550").await.unwrap();
+        assert_eq!(top_level.len(), 1);
+        assert!(client.category_path("0", "999").await.is_err());
+    }
+
+    #[test]
+    fn suggestions_are_ranked_in_response_order_with_breadcrumbs() {
+        let response: CategorySuggestionResponse = serde_json::from_value(serde_json::json!({
+            "categoryTreeId": "0",
+            "categorySuggestions": [
+                {
+                    "category": { "categoryId": "177", "categoryName": "PC Laptops & Netbooks" },
+                    "categoryTreeNodeAncestors": [
+                        { "categoryId": "58058", "categoryName": "Computers/Tablets & Networking", "categoryTreeNodeLevel": 2 },
+                        { "categoryId": "293", "categoryName": "Consumer Electronics", "categoryTreeNodeLevel": 1 }
+                    ],
+                    "categoryTreeNodeLevel": 3,
+                    "relevancy": "HIGH"
+                },
+                {
+                    "category": { "categoryId": "111422", "categoryName": "Apple Laptops" },
+                    "categoryTreeNodeAncestors": [
+                        { "categoryId": "58058", "categoryName": "Computers/Tablets & Networking", "categoryTreeNodeLevel": 1 }
+                    ],
+                    "categoryTreeNodeLevel": 2
+                },
+                {
+                    "category": { "categoryId": "31530", "categoryName": "Laptop Cases & Bags" },
+                    "categoryTreeNodeLevel": 1
+                }
+            ]
+        }))
+        .unwrap();
+
+        let top = response.top_n(2);
+        assert_eq!(
+            top.iter().map(|s| (s.rank, s.category_id.as_str())).collect::<Vec<_>>(),
+            [(1, "177"), (2, "111422")]
+        );
+        let names: Vec<_> = top[0].breadcrumb.iter().map(|b| b.category_name.as_str()).collect();
+        assert_eq!(names, ["Consumer Electronics", "Computers/Tablets & Networking", "PC Laptops & Netbooks"]);
+        assert_eq!(top[0].relevancy.as_deref(), Some("HIGH"));
+        assert_eq!(top[1].relevancy, None);
+
+        assert_eq!(response.top_n(10).len(), 3);
+        assert_eq!(response.best(), top.into_iter().next());
+        assert_eq!(CategorySuggestionResponse::default().best(), None);
+    }
+
+    #[tokio::test]
+    async fn suggestions_fall_back_to_cached_tree_search() {
+        let (server, config) = mock_ebay().await;
+        Mock::given(method("GET"))
+            .and(path("/commerce/taxonomy/v1/category_tree/0/get_category_suggestions"))
+            .respond_with(ResponseTemplate::new(429))
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/commerce/taxonomy/v1/category_tree/0"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(fixture_tree()))
+            .mount(&server)
+            .await;
+
+        let client = TaxonomyClient::new(config).unwrap();
+        let suggestions = client.suggest_categories_with_fallback("0", "gaming laptop", 3).await.unwrap();
+        assert_eq!(suggestions.len(), 1);
+        assert_eq!(suggestions[0].category_id, "177");
+        assert_eq!(suggestions[0].rank, 1);
+        assert_eq!(suggestions[0].source, SuggestionSource::Fallback);
+        assert_eq!(suggestions[0].breadcrumb.len(), 3);
+    }
+
+    #[tokio::test]
+    async fn unsupported_marketplace_is_explained() {
+        let (server, config) = mock_ebay().await;
+        Mock::given(method("GET"))
+            .and(path("/commerce/taxonomy/v1/get_default_category_tree_id"))
+            .respond_with(ResponseTemplate::new(400).set_body_json(serde_json::json!({
+                "errors": [{
+                    "errorId": 62004,
+                    "domain": "API_TAXONOMY",
+                    "category": "REQUEST",
+                    "message": "The specified marketplace ID was not found."
+                }]
+            })))
+            .mount(&server)
+            .await;
+
+        let client = TaxonomyClient::new(config).unwrap();
+        let err = client.get_default_category_tree_id("EBAY_PL").await.unwrap_err();
+        assert!(matches!(err, HermesError::Api { status: 400, ref errors, .. } if errors.len() == 2));
+        assert!(err.to_string().contains("Marketplace EBAY_PL is not enabled for this application"));
+    }
+
+    #[tokio::test]
+    async fn marketplace_catalog_resolves_and_caches_tree_ids() {
+        let (server, config) = mock_ebay().await;
+        for (marketplace_id, tree_id, version) in [("EBAY_US", "0", "130"), ("EBAY_DE", "77", "128")] {
+            Mock::given(method("GET"))
+                .and(path("/commerce/taxonomy/v1/get_default_category_tree_id"))
+                .and(query_param("marketplace_id", marketplace_id))
+                .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                    "categoryTreeId": tree_id,
+                    "categoryTreeVersion": version
+                })))
+                .expect(1)
+                .mount(&server)
+                .await;
+        }
+
+        let client = TaxonomyClient::new(config).unwrap();
+        let marketplaces = [Marketplace::EbayUs, Marketplace::EbayDe];
+        let catalog = client.marketplace_catalog(&marketplaces).await.unwrap();
+        assert_eq!(
+            catalog[1],
+            MarketplaceInfo {
+                marketplace: Marketplace::EbayDe,
+                tree_id: "77".to_string(),
+                tree_version: Some("128".to_string()),
             }
+        );
+        assert_eq!(catalog[0].tree_id, "0");
+
+        // Served from the cache; each mock only allows one lookup
+        assert_eq!(client.marketplace_catalog(&marketplaces).await.unwrap(), catalog);
+    }
+
+    #[tokio::test]
+    async fn check_tree_version_reports_version_bump() {
+        let (server, config) = mock_ebay().await;
+        for version in ["130", "131"] {
+            Mock::given(method("GET"))
+                .and(path("/commerce/taxonomy/v1/get_default_category_tree_id"))
+                .and(query_param("marketplace_id", "EBAY_US"))
+                .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                    "categoryTreeId": "0",
+                    "categoryTreeVersion": version
+                })))
+                .up_to_n_times(if version == "130" { 2 } else { 1 })
+                .mount(&server)
+                .await;
+        }
+
+        let changes = Arc::new(std::sync::Mutex::new(Vec::new()));
+        let recorded = Arc::clone(&changes);
+        let client = TaxonomyClient::new(config).unwrap().on_tree_version_change(move |marketplace, from, to| {
+            recorded.lock().unwrap().push(format!("{} {}->{}", marketplace, from, to));
+        });
+
+        assert_eq!(client.check_tree_version("EBAY_US").await.unwrap(), TreeVersionStatus::Unchanged);
+        assert_eq!(client.check_tree_version("EBAY-US").await.unwrap(), TreeVersionStatus::Unchanged);
+        assert_eq!(
+            client.check_tree_version("EBAY_US").await.unwrap(),
+            TreeVersionStatus::Updated { from: "130".to_string(), to: "131".to_string() }
+        );
+        assert_eq!(*changes.lock().unwrap(), ["EBAY_US 130->131"]);
+    }
+
+    #[tokio::test]
+    async fn validate_aspects_reports_missing_unknown_and_invalid_values() {
+        let (server, config) = mock_ebay().await;
+        Mock::given(method("GET"))
+            .and(path("/commerce/taxonomy/v1/category_tree/0/get_item_aspects_for_category"))
+            .and(query_param("category_id", "177"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "aspects": [
+                    {
+                        "localizedAspectName": "Brand",
+                        "aspectConstraint": { "aspectRequired": true, "aspectMode": "FREE_TEXT" }
+                    },
+                    {
+                        "localizedAspectName": "Processor",
+                        "aspectConstraint": { "aspectRequired": true, "aspectMode": "FREE_TEXT" }
+                    },
+                    {
+                        "localizedAspectName": "Screen Size",
+                        "aspectConstraint": { "aspectRequired": false, "aspectMode": "SELECTION_ONLY" },
+                        "aspectValues": [{ "localizedValue": "13 in" }, { "localizedValue": "15.6 in" }]
+                    },
+                    {
+                        "localizedAspectName": "Model",
+                        "aspectConstraint": { "aspectRequired": false, "aspectUsage": "RECOMMENDED" }
+                    }
+                ]
+            })))
+            .mount(&server)
+            .await;
+
+        let aspects = HashMap::from([
+            ("Brand".to_string(), vec!["Lenovo".to_string()]),
+            ("Screen Size".to_string(), vec!["14 in".to_string()]),
+            ("Colour".to_string(), vec!["Black".to_string()]),
+        ]);
+        let client = TaxonomyClient::new(config).unwrap();
+        let validation = client.validate_aspects("0", "177", &aspects).await.unwrap();
+
+        assert!(!validation.is_valid());
+        assert_eq!(validation.missing_required, ["Processor"]);
+        assert_eq!(validation.missing_recommended, ["Model"]);
+        assert_eq!(validation.unknown, ["Colour"]);
+        assert_eq!(
+            validation.invalid_values,
+            [InvalidAspectValue { aspect: "Screen Size".to_string(), value: "14 in".to_string() }]
+        );
+    }
+
+    #[tokio::test]
+    async fn prefetch_aspects_warms_the_cache() {
+        let (server, config) = mock_ebay().await;
+        for category_id in ["177", "9355"] {
+            Mock::given(method("GET"))
+                .and(path("/commerce/taxonomy/v1/category_tree/0/get_item_aspects_for_category"))
+                .and(query_param("category_id", category_id))
+                .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                    "aspects": [{ "localizedAspectName": "Brand" }]
+                })))
+                .expect(1)
+                .mount(&server)
+                .await;
         }
+
+        let aspect_requests = || async {
+            server
+                .received_requests()
+                .await
+                .unwrap()
+                .iter()
+                .filter(|request| request.url.path().ends_with("/get_item_aspects_for_category"))
+                .count()
+        };
+
+        let client = TaxonomyClient::new(config).unwrap();
+        client.prefetch_aspects("0", &["177", "9355"]).await.unwrap();
+        assert_eq!(aspect_requests().await, 2);
+
+        let metadata = client.cached_item_aspects("0", "9355").await.unwrap();
+        assert_eq!(metadata.aspects.as_ref().map(Vec::len), Some(1));
+        let validation = client.validate_aspects("0", "177", &HashMap::new()).await.unwrap();
+        assert!(validation.is_valid());
+        client.prefetch_aspects("0", &["177"]).await.unwrap();
+        assert_eq!(aspect_requests().await, 2);
+    }
+
+    #[tokio::test]
+    async fn gzip_flag_requests_compressed_category_trees() {
+        let (server, config) = mock_ebay().await;
+        Mock::given(method("GET"))
+            .and(path("/commerce/taxonomy/v1/category_tree/0"))
+            .and(header("Accept-Encoding", "application/gzip"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .insert_header("Content-Encoding", "gzip")
+                    .set_body_raw(gzipped(&fixture_tree()), "application/json"),
+            )
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = TaxonomyClient::new(config.with_gzip_taxonomy(true)).unwrap();
+        let tree = client.get_category_tree("0", None).await.unwrap();
+        assert_eq!(tree.category_tree_version.as_deref(), Some("130"));
+    }
+
+    #[tokio::test]
+    async fn gzip_file_without_content_encoding_is_inflated() {
+        let (server, config) = mock_ebay().await;
+        let download = gzipped(&fixture_tree());
+        let limit = download.len();
+        assert!(fixture_tree().to_string().len() > limit);
+        Mock::given(method("GET"))
+            .and(path("/commerce/taxonomy/v1/category_tree/0"))
+            .respond_with(ResponseTemplate::new(200).set_body_raw(download, "application/gzip"))
+            .expect(2)
+            .mount(&server)
+            .await;
+
+        let client = TaxonomyClient::new(config.clone().with_gzip_taxonomy(true)).unwrap();
+        let tree = client.get_category_tree("0", None).await.unwrap();
+        assert_eq!(tree.category_tree_version.as_deref(), Some("130"));
+
+        // The limit applies to the inflated tree, not just the compressed download
+        let client = TaxonomyClient::new(config.with_gzip_taxonomy(true).with_max_response_bytes(limit)).unwrap();
+        let err = client.get_category_tree("0", None).await.unwrap_err();
+        assert!(matches!(err, HermesError::ResponseTooLarge { limit: l } if l == limit));
+    }
+
+    fn gzipped(value: &serde_json::Value) -> Vec<u8> {
+        use std::io::Write;
+        let mut encoder = flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::default());
+        encoder.write_all(value.to_string().as_bytes()).unwrap();
+        encoder.finish().unwrap()
+    }
+
+    fn mixed_aspects() -> AspectMetadata {
+        serde_json::from_value(serde_json::json!({
+            "aspects": [
+                {
+                    "localizedAspectName": "Brand",
+                    "aspectConstraint": {
+                        "aspectRequired": true,
+                        "aspectUsage": "RECOMMENDED",
+                        "aspectDataType": "STRING",
+                        "aspectMode": "FREE_TEXT",
+                        "itemToAspectCardinality": "SINGLE",
+                        "aspectMaxLength": 65
+                    },
+                    "aspectValues": [{ "localizedValue": "Apple" }, { "localizedValue": "Dell" }]
+                },
+                {
+                    "localizedAspectName": "Screen Size",
+                    "aspectConstraint": {
+                        "aspectRequired": false,
+                        "aspectUsage": "RECOMMENDED",
+                        "aspectMode": "SELECTION_ONLY",
+                        "itemToAspectCardinality": "SINGLE"
+                    },
+                    "aspectValues": [{ "localizedValue": "13 in" }, { "localizedValue": "15.6 in" }]
+                },
+                {
+                    "localizedAspectName": "Features",
+                    "aspectConstraint": {
+                        "aspectRequired": false,
+                        "aspectUsage": "OPTIONAL",
+                        "aspectMode": "FREE_TEXT",
+                        "itemToAspectCardinality": "MULTI"
+                    }
+                }
+            ]
+        }))
+        .unwrap()
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn aspects_are_split_by_requirement() {
+        let metadata = mixed_aspects();
+
+        let required = metadata.required_aspects();
+        assert_eq!(required.len(), 1);
+        assert_eq!(required[0].name, "Brand");
+        assert_eq!(required[0].max_length, Some(65));
+        assert!(!required[0].selection_only);
+
+        let recommended = metadata.recommended_aspects();
+        assert_eq!(recommended.iter().map(|a| a.name.as_str()).collect::<Vec<_>>(), ["Screen Size"]);
+        assert!(recommended[0].selection_only);
+    }
+
+    #[test]
+    fn form_fields_describe_free_text_and_enum_aspects() {
+        let mut metadata = mixed_aspects();
+        metadata.aspects.as_mut().unwrap().push(
+            serde_json::from_value(serde_json::json!({
+                "localizedAspectName": "Release Year",
+                "aspectConstraint": {
+                    "aspectRequired": false,
+                    "aspectDataType": "NUMBER",
+                    "aspectFormat": "YYYY",
+                    "aspectMode": "FREE_TEXT",
+                    "itemToAspectCardinality": "SINGLE"
+                }
+            }))
+            .unwrap(),
+        );
+
+        let fields = metadata.form_fields();
+        let names: Vec<&str> = fields.iter().map(|field| field.name.as_str()).collect();
+        assert_eq!(names, ["Brand", "Screen Size", "Features", "Release Year"]);
+
+        let brand = &fields[0];
+        assert!(brand.required && brand.free_text && !brand.multi_value);
+        assert_eq!(brand.allowed_values, ["Apple", "Dell"]);
+        assert_eq!(brand.max_length, Some(65));
+
+        let screen_size = &fields[1];
+        assert!(!screen_size.free_text);
+        assert_eq!(screen_size.allowed_values, ["13 in", "15.6 in"]);
+
+        assert!(fields[2].multi_value);
+
+        let year = &fields[3];
+        assert_eq!(year.data_type.as_deref(), Some("NUMBER"));
+        assert_eq!(year.format.as_deref(), Some("YYYY"));
+    }
+
+    #[test]
+    fn allowed_values_looks_up_aspect_by_name() {
+        let metadata = mixed_aspects();
+        assert_eq!(metadata.allowed_values("Screen Size"), Some(vec!["13 in".to_string(), "15.6 in".to_string()]));
+        assert_eq!(metadata.allowed_values("Features"), Some(Vec::new()));
+        assert_eq!(metadata.allowed_values("Colour"), None);
+    }
+}
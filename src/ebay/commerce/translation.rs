@@ -1,11 +1,39 @@
 use crate::config::EbayConfig;
 use crate::error::{HermesError, HermesResult};
+use crate::ebay::api_error::raw_error;
+use crate::ebay::telemetry::record_api_call;
 use crate::ebay::auth::EbayAuth;
 use std::sync::Arc;
 
-// Import eBay Commerce Translation SDK models and APIs
+// Import eBay Commerce Translation SDK models
 use hermes_ebay_commerce_translationbeta::models::{TranslateRequest, TranslateResponse};
-use hermes_ebay_commerce_translationbeta::apis::configuration::Configuration as TranslationConfiguration;
+
+/// What a text is, which lets eBay tune its translation
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TranslationContext {
+    /// A listing title, or a short value such as an aspect value
+    ItemTitle,
+    /// A listing description
+    ItemDescription,
+}
+
+impl TranslationContext {
+    /// eBay's `translationContext` value, e.g. "ITEM_TITLE"
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TranslationContext::ItemTitle => "ITEM_TITLE",
+            TranslationContext::ItemDescription => "ITEM_DESCRIPTION",
+        }
+    }
+
+    // eBay translates a single description per request
+    fn max_texts_per_request(&self) -> usize {
+        match self {
+            TranslationContext::ItemTitle => usize::MAX,
+            TranslationContext::ItemDescription => 1,
+        }
+    }
+}
 
 /// eBay Commerce Translation API client for multi-language support
 /// 
@@ -44,23 +72,18 @@ impl TranslationClient {
         let token_duration = token_start.elapsed();
         tracing::info!("OAuth token request for translate: {:?}", token_duration);
         
-        // Set up configuration
-        let mut config = TranslationConfiguration::new();
-        config.base_path = if self.config.sandbox {
-            "https://api.sandbox.ebay.com/commerce/translation/v1".to_string()
-        } else {
-            "https://api.ebay.com/commerce/translation/v1".to_string()
-        };
-        config.oauth_access_token = Some(token);
+        let url = format!("{}/commerce/translation/v1/translate", self.config.base_url());
+        let http = self.config.http_client();
         
-        // Call the eBay SDK
+        // Fail fast while this API's circuit breaker is open
+        let probe = self.config.check_circuit("commerce.translation")?;
+        
+        // Call the eBay API
         let ebay_start = std::time::Instant::now();
-        let result = hermes_ebay_commerce_translationbeta::apis::language_api::translate(
-            &config,
-            "application/json",
-            translate_request.clone(),
-        ).await;
+        let result = self.config.send_json(http.post(&url).bearer_auth(&token).json(translate_request)).await;
         let ebay_duration = ebay_start.elapsed();
+        record_api_call("commerce.translation", "translate", &result, ebay_duration);
+        self.config.record_circuit("commerce.translation", &probe, &result);
         tracing::info!("eBay translate API call: {:?}", ebay_duration);
         
         match result {
@@ -73,7 +96,7 @@ impl TranslationClient {
             Err(e) => {
                 let total_duration = start_time.elapsed();
                 tracing::error!("eBay translate error after {:?}: {:?}", total_duration, e);
-                Err(HermesError::ApiRequest(format!("eBay translate failed: {:?}", e)))
+                Err(raw_error("translate", &format!("translation from {} to {}", translate_request.from.as_deref().unwrap_or("none"), translate_request.to.as_deref().unwrap_or("none")), e))
             }
         }
     }
@@ -106,7 +129,92 @@ impl TranslationClient {
         Err(HermesError::ApiRequest("No translation found in response".to_string()))
     }
 
+    /// Translate the messages of an eBay error response
+    ///
+    /// eBay reports errors in English; the `longMessage` (or `message`) of each
+    /// entry in a [`HermesError::Api`] is translated and the results joined with
+    /// newlines. Any other error is returned as its untranslated display string.
+    pub async fn translate_error(&self, err: &HermesError, to_language: &str) -> HermesResult<String> {
+        let messages = err.api_messages();
+        if messages.is_empty() {
+            return Ok(err.to_string());
+        }
+
+        let translate_request = TranslateRequest {
+            from: Some("en".to_string()),
+            to: Some(to_language.to_string()),
+            text: Some(messages.iter().map(|message| message.to_string()).collect()),
+            translation_context: None,
+        };
+        let response = self.translate(&translate_request).await?;
+
+        let translated: Vec<String> = response
+            .translations
+            .into_iter()
+            .flatten()
+            .filter_map(|translation| translation.translated_text)
+            .collect();
+        if translated.is_empty() {
+            return Err(HermesError::ApiRequest("No translation found in response".to_string()));
+        }
+        Ok(translated.join("\n"))
+    }
+
+    /// Translate several texts, each with its own context
+    /// 
+    /// Texts sharing a context are sent together, so titles and aspect values are
+    /// translated as titles while descriptions are translated as descriptions.
+    /// Returns the translations in the order of `texts`.
+    /// 
+    /// # Arguments
+    /// * `texts` - Texts to translate with their context
+    /// * `from_language` - Language of the texts, e.g. "en"
+    /// * `to_language` - Language to translate into, e.g. "de"
+    pub async fn translate_batch(
+        &self,
+        texts: &[(&str, TranslationContext)],
+        from_language: &str,
+        to_language: &str,
+    ) -> HermesResult<Vec<String>> {
+        // Group text positions by context, keeping the order contexts first appear in
+        let mut groups: Vec<(TranslationContext, Vec<usize>)> = Vec::new();
+        for (index, (_, context)) in texts.iter().enumerate() {
+            match groups.iter_mut().find(|(group_context, _)| group_context == context) {
+                Some((_, indices)) => indices.push(index),
+                None => groups.push((*context, vec![index])),
+            }
+        }
+
+        let mut translated = vec![String::new(); texts.len()];
+        for (context, indices) in groups {
+            for chunk in indices.chunks(context.max_texts_per_request()) {
+                let translate_request = TranslateRequest {
+                    from: Some(from_language.to_string()),
+                    to: Some(to_language.to_string()),
+                    text: Some(chunk.iter().map(|&index| texts[index].0.to_string()).collect()),
+                    translation_context: Some(context.as_str().to_string()),
+                };
+                let response = self.translate(&translate_request).await?;
+
+                let translations = response.translations.unwrap_or_default();
+                if translations.len() != chunk.len() {
+                    return Err(HermesError::ApiRequest(format!(
+                        "eBay returned {} translations for {} texts",
+                        translations.len(),
+                        chunk.len()
+                    )));
+                }
+                for (&index, translation) in chunk.iter().zip(translations) {
+                    translated[index] = translation.translated_text.unwrap_or_default();
+                }
+            }
+        }
+        Ok(translated)
+    }
+
     /// Translate listing title and description
+    /// 
+    /// Uses the `ITEM_TITLE` and `ITEM_DESCRIPTION` translation contexts.
     pub async fn translate_listing(
         &self,
         title: &str,
@@ -114,12 +222,106 @@ impl TranslationClient {
         from_language: &str,
         to_language: &str,
     ) -> HermesResult<(String, String)> {
-        // Translate title
-        let translated_title = self.translate_text(title, from_language, to_language).await?;
-        
-        // Translate description
-        let translated_description = self.translate_text(description, from_language, to_language).await?;
+        let texts = [(title, TranslationContext::ItemTitle), (description, TranslationContext::ItemDescription)];
+        let mut translated = self.translate_batch(&texts, from_language, to_language).await?.into_iter();
+        let translated_title = translated.next().unwrap_or_default();
+        let translated_description = translated.next().unwrap_or_default();
         
         Ok((translated_title, translated_description))
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ebay::test_support::mock_ebay;
+    use crate::error::ApiErrorMessage;
+    use wiremock::matchers::{body_partial_json, method, path};
+    use wiremock::{Mock, ResponseTemplate};
+
+    #[tokio::test]
+    async fn translate_error_translates_long_message() {
+        let (server, config) = mock_ebay().await;
+        Mock::given(method("POST"))
+            .and(path("/commerce/translation/v1/translate"))
+            .and(body_partial_json(serde_json::json!({
+                "from": "en",
+                "to": "de",
+                "text": ["The item is no longer available."]
+            })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "from": "en",
+                "to": "de",
+                "translations": [{
+                    "originalText": "The item is no longer available.",
+                    "translatedText": "Der Artikel ist nicht mehr verfügbar."
+                }]
+            })))
+            .mount(&server)
+            .await;
+
+        let err = HermesError::Api {
+            operation: "get_item".to_string(),
+            status: 400,
+            errors: vec![ApiErrorMessage {
+                error_id: Some(11002),
+                message: Some("Item unavailable".to_string()),
+                long_message: Some("The item is no longer available.".to_string()),
+                ..Default::default()
+            }],
+            body: String::new(),
+        };
+        let client = TranslationClient::new(config).unwrap();
+        assert_eq!(
+            client.translate_error(&err, "de").await.unwrap(),
+            "Der Artikel ist nicht mehr verfügbar."
+        );
+
+        // Non-API errors are not sent for translation
+        let config_err = HermesError::Configuration("missing app id".to_string());
+        assert_eq!(
+            client.translate_error(&config_err, "de").await.unwrap(),
+            "Invalid configuration: missing app id"
+        );
+    }
+
+    #[tokio::test]
+    async fn translate_batch_sends_context_per_group() {
+        let (server, config) = mock_ebay().await;
+        Mock::given(method("POST"))
+            .and(path("/commerce/translation/v1/translate"))
+            .and(body_partial_json(serde_json::json!({
+                "translationContext": "ITEM_TITLE",
+                "text": ["Red mug", "Stoneware"]
+            })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "translations": [{ "translatedText": "Rote Tasse" }, { "translatedText": "Steinzeug" }]
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/commerce/translation/v1/translate"))
+            .and(body_partial_json(serde_json::json!({
+                "translationContext": "ITEM_DESCRIPTION",
+                "text": ["Holds 12oz of coffee."]
+            })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "translations": [{ "translatedText": "Fasst 350 ml Kaffee." }]
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = TranslationClient::new(config).unwrap();
+        let texts = [
+            ("Red mug", TranslationContext::ItemTitle),
+            ("Holds 12oz of coffee.", TranslationContext::ItemDescription),
+            ("Stoneware", TranslationContext::ItemTitle),
+        ];
+        assert_eq!(
+            client.translate_batch(&texts, "en", "de").await.unwrap(),
+            ["Rote Tasse", "Fasst 350 ml Kaffee.", "Steinzeug"]
+        );
+    }
+}
@@ -0,0 +1,108 @@
+//! Request debugging helpers
+//!
+//! With [`EbayConfig::with_curl_debug`] enabled, outgoing requests are logged at debug
+//! level as curl commands that can be pasted into a shell to reproduce the call.
+
+use crate::config::EbayConfig;
+
+/// Tracing target for curl dumps, so they can be enabled on their own
+pub(crate) const CURL_TARGET: &str = "hermes_sdk::curl";
+
+/// Build a request URL, skipping query parameters that are not set
+pub(crate) fn request_url(base: &str, path: &str, query: &[(&str, Option<&str>)]) -> String {
+    let url = format!("{}{}", base, path);
+    let params = query
+        .iter()
+        .filter_map(|(name, value)| value.map(|value| (*name, value)));
+    match reqwest::Url::parse_with_params(&url, params) {
+        Ok(url) => url.to_string(),
+        Err(_) => url,
+    }
+}
+
+/// Render a request as a copy-pasteable curl command
+///
+/// The `Authorization` header value is always replaced with `Bearer ***`, and the
+/// Trading API's `X-EBAY-API-IAF-TOKEN` with `***`.
+pub(crate) fn curl_command(
+    method: &str,
+    url: &str,
+    headers: &[(&str, &str)],
+    body: Option<&str>,
+) -> String {
+    let mut command = format!("curl -X {} {}", method, shell_quote(url));
+    for (name, value) in headers {
+        let value = if name.eq_ignore_ascii_case("authorization") {
+            "Bearer ***"
+        } else if name.eq_ignore_ascii_case("x-ebay-api-iaf-token") {
+            "***"
+        } else {
+            value
+        };
+        command.push_str(&format!(" -H {}", shell_quote(&format!("{}: {}", name, value))));
+    }
+    if let Some(body) = body {
+        command.push_str(&format!(" --data {}", shell_quote(body)));
+    }
+    command
+}
+
+/// Send a request, first logging it as a curl command if curl debugging is enabled
+///
+/// Bodies that are not held in memory, such as multipart uploads, are left out of
+/// the command.
+pub(crate) async fn send_logged(
+    config: &EbayConfig,
+    request: reqwest::RequestBuilder,
+) -> reqwest::Result<reqwest::Response> {
+    if !config.curl_debug {
+        return request.send().await;
+    }
+    let (client, request) = request.build_split();
+    let request = request?;
+    let headers: Vec<(&str, &str)> = request
+        .headers()
+        .iter()
+        .filter_map(|(name, value)| Some((name.as_str(), value.to_str().ok()?)))
+        .collect();
+    let body = request.body().and_then(|body| body.as_bytes()).map(String::from_utf8_lossy);
+    let command = curl_command(request.method().as_str(), request.url().as_str(), &headers, body.as_deref());
+    tracing::debug!(target: CURL_TARGET, "{}", command);
+    client.execute(request).await
+}
+
+fn shell_quote(value: &str) -> String {
+    format!("'{}'", value.replace('\'', r"'\''"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn curl_command_redacts_authorization_and_quotes_values() {
+        let command = curl_command(
+            "POST",
+            "https://api.ebay.com/buy/browse/v1/item_summary/search?q=it%27s",
+            &[("Authorization", "Bearer v^1.1#secret"), ("Content-Type", "application/json")],
+            Some(r#"{"note":"it's"}"#),
+        );
+        assert_eq!(
+            command,
+            r#"curl -X POST 'https://api.ebay.com/buy/browse/v1/item_summary/search?q=it%27s' -H 'Authorization: Bearer ***' -H 'Content-Type: application/json' --data '{"note":"it'\''s"}'"#
+        );
+
+        let trading = curl_command("POST", "https://api.ebay.com/ws/api.dll", &[("X-EBAY-API-IAF-TOKEN", "v^1.1#secret")], None);
+        assert_eq!(trading, "curl -X POST 'https://api.ebay.com/ws/api.dll' -H 'X-EBAY-API-IAF-TOKEN: ***'");
+    }
+
+    #[test]
+    fn request_url_skips_unset_parameters() {
+        let url = request_url(
+            "https://api.ebay.com",
+            "/buy/browse/v1/item_summary/search",
+            &[("q", Some("red shoes")), ("limit", None)],
+        );
+        assert_eq!(url, "https://api.ebay.com/buy/browse/v1/item_summary/search?q=red+shoes");
+    }
+}
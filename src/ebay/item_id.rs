@@ -0,0 +1,67 @@
+//! eBay item ID formats
+//!
+//! The RESTful APIs identify items as "v1|<legacy id>|<variation id>" (e.g.
+//! "v1|123456789|0"), while listings, URLs and the legacy APIs use the bare numeric
+//! ID. Browse has a separate endpoint for each, so [`ItemId`] detects which one a
+//! caller has.
+
+use crate::error::HermesError;
+use std::fmt;
+use std::str::FromStr;
+
+/// An eBay item ID in either format
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ItemId {
+    /// RESTful ID, e.g. "v1|123456789|0"
+    Rest(String),
+    /// Legacy numeric ID, e.g. "123456789"
+    Legacy(String),
+}
+
+impl ItemId {
+    /// The ID as eBay expects it
+    pub fn as_str(&self) -> &str {
+        match self {
+            ItemId::Rest(id) | ItemId::Legacy(id) => id,
+        }
+    }
+}
+
+impl fmt::Display for ItemId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for ItemId {
+    type Err = HermesError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let id = s.trim();
+        let is_number = |part: &str| !part.is_empty() && part.bytes().all(|b| b.is_ascii_digit());
+
+        let parts: Vec<&str> = id.split('|').collect();
+        match parts.as_slice() {
+            [version, legacy_id, variation_id]
+                if version.starts_with('v') && is_number(legacy_id) && is_number(variation_id) =>
+            {
+                Ok(ItemId::Rest(id.to_string()))
+            }
+            [legacy_id] if is_number(legacy_id) => Ok(ItemId::Legacy(id.to_string())),
+            _ => Err(HermesError::Configuration(format!("Invalid eBay item ID: {:?}", s))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn item_id_detects_format() {
+        assert_eq!("v1|123456789|0".parse::<ItemId>().unwrap(), ItemId::Rest("v1|123456789|0".to_string()));
+        assert_eq!(" 123456789 ".parse::<ItemId>().unwrap(), ItemId::Legacy("123456789".to_string()));
+        assert!(matches!("v1|abc|0".parse::<ItemId>(), Err(HermesError::Configuration(_))));
+        assert!("".parse::<ItemId>().is_err());
+    }
+}
@@ -0,0 +1,177 @@
+//! Localhost redirect listener for the user consent flow (`cli-auth` feature)
+//!
+//! Desktop and command-line tools have no web server to receive eBay's consent
+//! redirect. [`EbayAuth::run_local_oauth_flow`] opens the consent page in a browser
+//! and listens on localhost for the redirect carrying the authorization code. The
+//! accept URL of the configured RuName must be `http://localhost:<port>/`.
+
+use crate::ebay::auth::{EbayAuth, EbayToken};
+use crate::error::{HermesError, HermesResult};
+use std::process::Command;
+use std::time::Duration;
+use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+use tokio::net::TcpListener;
+
+/// Page shown in the browser once the redirect has been received
+const REDIRECT_RESPONSE: &str = "HTTP/1.1 200 OK\r\n\
+    Content-Type: text/html; charset=utf-8\r\n\
+    Connection: close\r\n\r\n\
+    <html><body>eBay authorization received. You can close this window.</body></html>";
+
+/// How long to wait for the seller to finish on the consent page
+const CONSENT_TIMEOUT: Duration = Duration::from_secs(300);
+
+impl EbayAuth {
+    /// Get user tokens by walking the seller through eBay's consent page
+    ///
+    /// Opens the consent URL for `scopes` in the default browser (the URL is also
+    /// logged, in case no browser can be launched), waits up to five minutes for
+    /// eBay to redirect to `http://localhost:<port>/`, and exchanges the
+    /// authorization code for tokens. Redirects whose `state` does not match the
+    /// one sent with the consent URL are rejected.
+    ///
+    /// # Arguments
+    /// * `scopes` - OAuth scopes to request
+    /// * `port` - Local port the RuName's accept URL points at
+    pub async fn run_local_oauth_flow(&self, scopes: &[&str], port: u16) -> HermesResult<EbayToken> {
+        let listener = TcpListener::bind(("127.0.0.1", port)).await?;
+        let state = random_state()?;
+        let consent_url = self.consent_url_with_state(scopes, &state)?;
+        tracing::info!("Open this URL to authorize the application: {}", consent_url);
+        if let Err(e) = open_browser(&consent_url) {
+            tracing::warn!("Could not open a browser ({}); open the consent URL manually", e);
+        }
+
+        let code = receive_code(&listener, &state, CONSENT_TIMEOUT).await?;
+        self.exchange_authorization_code(&code).await
+    }
+}
+
+/// Wait on `listener` for eBay's redirect and return its authorization code
+///
+/// Fails with [`HermesError::Authentication`] if no redirect arrives within `timeout`.
+async fn receive_code(listener: &TcpListener, state: &str, timeout: Duration) -> HermesResult<String> {
+    let accept = async {
+        loop {
+            let (stream, _) = listener.accept().await?;
+            let mut reader = BufReader::new(stream);
+            let mut request_line = String::new();
+            reader.read_line(&mut request_line).await?;
+
+            // Browsers also request e.g. /favicon.ico; only the redirect carries a code or error
+            let Some(code) = parse_redirect(&request_line, state) else {
+                continue;
+            };
+            reader.get_mut().write_all(REDIRECT_RESPONSE.as_bytes()).await?;
+            return code;
+        }
+    };
+    tokio::time::timeout(timeout, accept).await.map_err(|_| {
+        HermesError::Authentication(format!("no eBay consent redirect within {} seconds", timeout.as_secs()))
+    })?
+}
+
+/// Extract the authorization code from the request line of eBay's redirect
+///
+/// Returns `None` for requests that are not the redirect, and an error when the
+/// seller declined consent or the redirect's `state` is not `expected_state`.
+fn parse_redirect(request_line: &str, expected_state: &str) -> Option<HermesResult<String>> {
+    let target = request_line.split_whitespace().nth(1)?;
+    let url = reqwest::Url::parse("http://localhost").ok()?.join(target).ok()?;
+
+    let (mut code, mut error, mut state) = (None, None, None);
+    for (name, value) in url.query_pairs() {
+        match name.as_ref() {
+            "code" => code = Some(value.into_owned()),
+            "error" => error = Some(value.into_owned()),
+            "state" => state = Some(value.into_owned()),
+            _ => {}
+        }
+    }
+    if code.is_none() && error.is_none() {
+        return None;
+    }
+    if state.as_deref() != Some(expected_state) {
+        return Some(Err(HermesError::Authentication(
+            "eBay consent redirect has a missing or mismatched state".to_string(),
+        )));
+    }
+    match (code, error) {
+        (Some(code), _) => Some(Ok(code)),
+        (None, error) => Some(Err(HermesError::Authentication(format!(
+            "eBay consent was not granted: {}",
+            error.unwrap_or_default()
+        )))),
+    }
+}
+
+/// Random hex value for the OAuth `state` parameter
+fn random_state() -> HermesResult<String> {
+    let mut bytes = [0u8; 16];
+    getrandom::fill(&mut bytes)
+        .map_err(|e| HermesError::Authentication(format!("could not generate OAuth state: {}", e)))?;
+    Ok(bytes.iter().map(|b| format!("{:02x}", b)).collect())
+}
+
+/// Open `url` in the platform's default browser
+fn open_browser(url: &str) -> std::io::Result<()> {
+    let mut command = if cfg!(target_os = "macos") {
+        Command::new("open")
+    } else if cfg!(target_os = "windows") {
+        let mut command = Command::new("rundll32");
+        command.arg("url.dll,FileProtocolHandler");
+        command
+    } else {
+        Command::new("xdg-open")
+    };
+    command.arg(url).spawn().map(|_| ())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_redirect_extracts_code() {
+        let line = "GET /?code=v%5E1.1%23i%5E1%23f%5E0&state=abc&expires_in=299 HTTP/1.1\r\n";
+        assert_eq!(parse_redirect(line, "abc").unwrap().unwrap(), "v^1.1#i^1#f^0");
+
+        let declined = "GET /?error=access_denied&state=abc HTTP/1.1\r\n";
+        assert!(matches!(parse_redirect(declined, "abc"), Some(Err(HermesError::Authentication(_)))));
+
+        assert!(parse_redirect("GET /favicon.ico HTTP/1.1\r\n", "abc").is_none());
+        assert!(parse_redirect("", "abc").is_none());
+    }
+
+    #[test]
+    fn parse_redirect_rejects_mismatched_state() {
+        let forged = "GET /?code=attacker-code&state=other HTTP/1.1\r\n";
+        assert!(matches!(parse_redirect(forged, "abc"), Some(Err(HermesError::Authentication(_)))));
+
+        let stateless = "GET /?code=attacker-code HTTP/1.1\r\n";
+        assert!(matches!(parse_redirect(stateless, "abc"), Some(Err(HermesError::Authentication(_)))));
+    }
+
+    #[test]
+    fn consent_url_carries_state() {
+        let config = crate::config::EbayConfig::new()
+            .with_app_id("test-app-id")
+            .with_cert_id("test-cert-id")
+            .with_ru_name("Test-RuName");
+        let auth = EbayAuth::new(config).unwrap();
+
+        let state = random_state().unwrap();
+        assert_eq!(state.len(), 32);
+        assert_ne!(state, random_state().unwrap());
+
+        let url = reqwest::Url::parse(&auth.consent_url_with_state(&["scope-a"], &state).unwrap()).unwrap();
+        assert!(url.query_pairs().any(|(name, value)| name == "state" && value == state));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn receive_code_times_out_without_redirect() {
+        let listener = TcpListener::bind(("127.0.0.1", 0)).await.unwrap();
+        let result = receive_code(&listener, "abc", CONSENT_TIMEOUT).await;
+        assert!(matches!(result, Err(HermesError::Authentication(_))));
+    }
+}
@@ -0,0 +1,290 @@
+//! eBay marketplace ID helpers
+//!
+//! eBay marketplace IDs show up in two spellings: the dash form used by the legacy
+//! APIs (e.g. "EBAY-US") and the underscore form the RESTful APIs expect in the
+//! `X-EBAY-C-MARKETPLACE-ID` header (e.g. "EBAY_US"). Sending the wrong one is the
+//! most common cause of an otherwise silent 400, so every client coerces the ID
+//! at the boundary.
+
+use crate::error::HermesError;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+
+/// Separator style expected by an endpoint for marketplace IDs
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MarketplaceStyle {
+    /// Underscore form, e.g. "EBAY_US" (all RESTful Buy, Sell, and Commerce APIs)
+    Underscore,
+    /// Dash form, e.g. "EBAY-US" (legacy APIs)
+    Dash,
+}
+
+/// An eBay marketplace (site)
+///
+/// Serializes to and parses from the canonical underscore ID (e.g. "EBAY_US"); parsing
+/// also accepts the dash form.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub enum Marketplace {
+    #[default]
+    EbayUs,
+    EbayAt,
+    EbayAu,
+    EbayBe,
+    EbayCa,
+    EbayCh,
+    EbayCz,
+    EbayDe,
+    EbayDk,
+    EbayEs,
+    EbayFi,
+    EbayFr,
+    EbayGb,
+    EbayGr,
+    EbayHk,
+    EbayHu,
+    EbayIe,
+    EbayIl,
+    EbayIn,
+    EbayIt,
+    EbayJp,
+    EbayMy,
+    EbayNl,
+    EbayNo,
+    EbayNz,
+    EbayPe,
+    EbayPh,
+    EbayPl,
+    EbayPr,
+    EbayPt,
+    EbayQa,
+    EbayRu,
+    EbaySe,
+    EbaySg,
+    EbaySk,
+    EbayTh,
+    EbayTw,
+    EbayVn,
+    EbayZa,
+    EbayMotorsUs,
+}
+
+impl Marketplace {
+    /// Every marketplace known to the SDK
+    pub const ALL: &'static [Marketplace] = &[
+        Marketplace::EbayUs, Marketplace::EbayAt, Marketplace::EbayAu, Marketplace::EbayBe,
+        Marketplace::EbayCa, Marketplace::EbayCh, Marketplace::EbayCz, Marketplace::EbayDe,
+        Marketplace::EbayDk, Marketplace::EbayEs, Marketplace::EbayFi, Marketplace::EbayFr,
+        Marketplace::EbayGb, Marketplace::EbayGr, Marketplace::EbayHk, Marketplace::EbayHu,
+        Marketplace::EbayIe, Marketplace::EbayIl, Marketplace::EbayIn, Marketplace::EbayIt,
+        Marketplace::EbayJp, Marketplace::EbayMy, Marketplace::EbayNl, Marketplace::EbayNo,
+        Marketplace::EbayNz, Marketplace::EbayPe, Marketplace::EbayPh, Marketplace::EbayPl,
+        Marketplace::EbayPr, Marketplace::EbayPt, Marketplace::EbayQa, Marketplace::EbayRu,
+        Marketplace::EbaySe, Marketplace::EbaySg, Marketplace::EbaySk, Marketplace::EbayTh,
+        Marketplace::EbayTw, Marketplace::EbayVn, Marketplace::EbayZa, Marketplace::EbayMotorsUs,
+    ];
+
+    /// The canonical marketplace ID, e.g. "EBAY_US"
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Marketplace::EbayUs => "EBAY_US",
+            Marketplace::EbayAt => "EBAY_AT",
+            Marketplace::EbayAu => "EBAY_AU",
+            Marketplace::EbayBe => "EBAY_BE",
+            Marketplace::EbayCa => "EBAY_CA",
+            Marketplace::EbayCh => "EBAY_CH",
+            Marketplace::EbayCz => "EBAY_CZ",
+            Marketplace::EbayDe => "EBAY_DE",
+            Marketplace::EbayDk => "EBAY_DK",
+            Marketplace::EbayEs => "EBAY_ES",
+            Marketplace::EbayFi => "EBAY_FI",
+            Marketplace::EbayFr => "EBAY_FR",
+            Marketplace::EbayGb => "EBAY_GB",
+            Marketplace::EbayGr => "EBAY_GR",
+            Marketplace::EbayHk => "EBAY_HK",
+            Marketplace::EbayHu => "EBAY_HU",
+            Marketplace::EbayIe => "EBAY_IE",
+            Marketplace::EbayIl => "EBAY_IL",
+            Marketplace::EbayIn => "EBAY_IN",
+            Marketplace::EbayIt => "EBAY_IT",
+            Marketplace::EbayJp => "EBAY_JP",
+            Marketplace::EbayMy => "EBAY_MY",
+            Marketplace::EbayNl => "EBAY_NL",
+            Marketplace::EbayNo => "EBAY_NO",
+            Marketplace::EbayNz => "EBAY_NZ",
+            Marketplace::EbayPe => "EBAY_PE",
+            Marketplace::EbayPh => "EBAY_PH",
+            Marketplace::EbayPl => "EBAY_PL",
+            Marketplace::EbayPr => "EBAY_PR",
+            Marketplace::EbayPt => "EBAY_PT",
+            Marketplace::EbayQa => "EBAY_QA",
+            Marketplace::EbayRu => "EBAY_RU",
+            Marketplace::EbaySe => "EBAY_SE",
+            Marketplace::EbaySg => "EBAY_SG",
+            Marketplace::EbaySk => "EBAY_SK",
+            Marketplace::EbayTh => "EBAY_TH",
+            Marketplace::EbayTw => "EBAY_TW",
+            Marketplace::EbayVn => "EBAY_VN",
+            Marketplace::EbayZa => "EBAY_ZA",
+            Marketplace::EbayMotorsUs => "EBAY_MOTORS_US",
+        }
+    }
+
+    /// Host of the marketplace's buyer-facing website, e.g. "www.ebay.co.uk"
+    ///
+    /// Marketplace IDs without a site of their own fall back to "www.ebay.com".
+    pub fn website_host(&self) -> &'static str {
+        match self {
+            Marketplace::EbayAt => "www.ebay.at",
+            Marketplace::EbayAu => "www.ebay.com.au",
+            Marketplace::EbayBe => "www.befr.ebay.be",
+            Marketplace::EbayCa => "www.ebay.ca",
+            Marketplace::EbayCh => "www.ebay.ch",
+            Marketplace::EbayDe => "www.ebay.de",
+            Marketplace::EbayEs => "www.ebay.es",
+            Marketplace::EbayFr => "www.ebay.fr",
+            Marketplace::EbayGb => "www.ebay.co.uk",
+            Marketplace::EbayHk => "www.ebay.com.hk",
+            Marketplace::EbayIe => "www.ebay.ie",
+            Marketplace::EbayIn => "www.ebay.in",
+            Marketplace::EbayIt => "www.ebay.it",
+            Marketplace::EbayMy => "www.ebay.com.my",
+            Marketplace::EbayNl => "www.ebay.nl",
+            Marketplace::EbayPh => "www.ebay.ph",
+            Marketplace::EbayPl => "www.ebay.pl",
+            Marketplace::EbaySg => "www.ebay.com.sg",
+            Marketplace::EbayTw => "www.ebay.com.tw",
+            Marketplace::EbayVn => "www.ebay.vn",
+            _ => "www.ebay.com",
+        }
+    }
+
+    /// Buyer-facing URL of a listing on this marketplace
+    pub fn listing_url(&self, listing_id: &str) -> String {
+        format!("https://{}/itm/{}", self.website_host(), listing_id)
+    }
+
+    /// Site ID the Trading API expects in `X-EBAY-API-SITEID`, e.g. 3 for EBAY_GB
+    ///
+    /// Marketplace IDs without a Trading site of their own fall back to 0 (eBay US).
+    pub fn trading_site_id(&self) -> u16 {
+        match self {
+            Marketplace::EbayCa => 2,
+            Marketplace::EbayGb => 3,
+            Marketplace::EbayAu => 15,
+            Marketplace::EbayAt => 16,
+            Marketplace::EbayBe => 23,
+            Marketplace::EbayFr => 71,
+            Marketplace::EbayDe => 77,
+            Marketplace::EbayMotorsUs => 100,
+            Marketplace::EbayIt => 101,
+            Marketplace::EbayNl => 146,
+            Marketplace::EbayEs => 186,
+            Marketplace::EbayCh => 193,
+            Marketplace::EbayHk => 201,
+            Marketplace::EbayIn => 203,
+            Marketplace::EbayIe => 205,
+            Marketplace::EbayMy => 207,
+            Marketplace::EbayPh => 211,
+            Marketplace::EbayPl => 212,
+            Marketplace::EbaySg => 216,
+            _ => 0,
+        }
+    }
+}
+
+impl fmt::Display for Marketplace {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for Marketplace {
+    type Err = HermesError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let canonical = s.trim().replace('-', "_").to_ascii_uppercase();
+        Marketplace::ALL
+            .iter()
+            .copied()
+            .find(|marketplace| marketplace.as_str() == canonical)
+            .ok_or_else(|| HermesError::Configuration(format!("Unknown eBay marketplace: {}", s)))
+    }
+}
+
+impl TryFrom<String> for Marketplace {
+    type Error = HermesError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+impl From<Marketplace> for String {
+    fn from(marketplace: Marketplace) -> Self {
+        marketplace.as_str().to_string()
+    }
+}
+
+/// Convert a marketplace ID to the separator style an endpoint expects
+///
+/// Accepts either "EBAY-US" or "EBAY_US" (case-insensitive) and returns the ID in
+/// the requested style. Inputs that are not a known marketplace ID are returned
+/// unchanged so that new marketplaces keep working without an SDK release.
+///
+/// # Arguments
+/// * `input` - The marketplace ID as supplied by the caller
+/// * `style` - The separator style expected by the endpoint
+pub fn normalize_marketplace(input: &str, style: MarketplaceStyle) -> String {
+    let Ok(marketplace) = input.parse::<Marketplace>() else {
+        return input.to_string();
+    };
+
+    match style {
+        MarketplaceStyle::Underscore => marketplace.as_str().to_string(),
+        MarketplaceStyle::Dash => marketplace.as_str().replace('_', "-"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn converts_dash_to_underscore() {
+        assert_eq!(normalize_marketplace("EBAY-US", MarketplaceStyle::Underscore), "EBAY_US");
+        assert_eq!(normalize_marketplace("ebay-de", MarketplaceStyle::Underscore), "EBAY_DE");
+        assert_eq!(normalize_marketplace("EBAY_GB", MarketplaceStyle::Underscore), "EBAY_GB");
+        assert_eq!(
+            normalize_marketplace("EBAY-MOTORS-US", MarketplaceStyle::Underscore),
+            "EBAY_MOTORS_US"
+        );
+    }
+
+    #[test]
+    fn converts_underscore_to_dash() {
+        assert_eq!(normalize_marketplace("EBAY_US", MarketplaceStyle::Dash), "EBAY-US");
+        assert_eq!(normalize_marketplace("EBAY-AU", MarketplaceStyle::Dash), "EBAY-AU");
+        assert_eq!(
+            normalize_marketplace("EBAY_MOTORS_US", MarketplaceStyle::Dash),
+            "EBAY-MOTORS-US"
+        );
+    }
+
+    #[test]
+    fn unknown_inputs_pass_through_unchanged() {
+        assert_eq!(normalize_marketplace("EBAY-XX", MarketplaceStyle::Underscore), "EBAY-XX");
+        assert_eq!(normalize_marketplace("ETSY_US", MarketplaceStyle::Dash), "ETSY_US");
+        assert_eq!(normalize_marketplace("", MarketplaceStyle::Underscore), "");
+    }
+
+    #[test]
+    fn marketplace_parses_both_forms_and_serializes_canonically() {
+        assert_eq!("EBAY-DE".parse::<Marketplace>().unwrap(), Marketplace::EbayDe);
+        assert_eq!("ebay_motors_us".parse::<Marketplace>().unwrap(), Marketplace::EbayMotorsUs);
+        assert!("EBAY_XX".parse::<Marketplace>().is_err());
+        assert_eq!(serde_json::to_string(&Marketplace::EbayGb).unwrap(), "\"EBAY_GB\"");
+        assert_eq!(serde_json::from_str::<Marketplace>("\"EBAY-AU\"").unwrap(), Marketplace::EbayAu);
+    }
+}
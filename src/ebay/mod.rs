@@ -2,17 +2,46 @@
 //! 
 //! This module provides access to eBay's Buy, Sell, and Commerce APIs.
 
+pub(crate) mod api_error;
 pub mod auth;
+pub mod cached_search;
+pub mod circuit_breaker;
 pub mod client;
+pub mod item_id;
+mod debug;
+#[cfg(feature = "cli-auth")]
+mod local_oauth;
+pub mod marketplace;
+mod paginate;
+pub mod rate_limit;
+pub mod response;
+pub mod retry;
+pub mod service;
 pub mod buy;
 pub mod commerce;
 pub mod sell;
+mod telemetry;
+
+#[cfg(test)]
+pub(crate) mod test_support;
 
 // Re-export commonly used types
-pub use auth::EbayAuth;
-pub use client::EbayClient;
-pub use buy::{FeedClient, MarketingClient, OfferClient, OrderClient};
-pub use commerce::{CatalogClient, TaxonomyClient, IdentityClient, TranslationClient};
+pub use auth::{Clock, EbayAuth, EbayToken, InMemoryTokenStore, ManualClock, SharedClock, SharedTokenStore, SystemClock, TokenStore};
+pub use cached_search::CachedSearch;
+pub use circuit_breaker::CircuitBreakerConfig;
+pub use client::{
+    ActiveListing, CompatibilityExt, CompatibleVehicle, DeliveryEstimate, DeliveryEstimateExt, EbayClient, ItemGroupExt, ItemImagesExt, ItemPriceSnapshot, ItemSummaryExt, MarketplaceScope, SearchPagedCollectionExt, SearchRequestBuilder, SellerInfo,
+    Variation, VariationMatrix,
+};
+pub use item_id::ItemId;
+pub use marketplace::{normalize_marketplace, Marketplace, MarketplaceStyle};
+pub use paginate::Paged;
+pub use rate_limit::RateLimitStatus;
+pub use response::EbayResponse;
+pub use retry::{RetryBudgetConfig, RetryPolicy, ShutdownSignal};
+pub use service::EbayService;
+pub use buy::{FeedClient, FeedSession, MarketingClient, OfferClient, OrderClient};
+pub use commerce::{CatalogClient, TaxonomyClient, IdentityClient, TranslationClient, MediaClient};
 pub use sell::{AnalyticsClient, AccountClient, InventoryClient, FulfillmentClient, ComplianceClient, FinancesClient, MetadataClient, NegotiationClient, RecommendationClient};
 pub use crate::config::EbayConfig;
 
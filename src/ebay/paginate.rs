@@ -0,0 +1,250 @@
+//! Lazy streaming over offset-paginated eBay collections
+//!
+//! eBay's list endpoints page with `limit`/`offset` and report a `total`. [`paginate`]
+//! turns such an endpoint into a stream of items, so every stream helper stops and
+//! reports errors the same way. [`Paged`] also gives lookups that only want one
+//! result a single way to reach it.
+
+use crate::error::HermesResult;
+use futures::stream::{self, Stream};
+use std::collections::VecDeque;
+use std::future::Future;
+
+use hermes_ebay_buy_browse::models::{ItemSummary, SearchPagedCollection};
+use hermes_ebay_sell_finances::models::{Transaction, Transactions};
+use hermes_ebay_sell_fulfillment::models::{Order, OrderSearchPagedCollection};
+use hermes_ebay_sell_inventory::models::{EbayOfferDetailsWithAll, InventoryItemWithSkuLocaleGroupid, InventoryItems, Offers};
+
+/// One page of an offset-paginated collection
+///
+/// Implemented for the paged responses of Browse search, inventory items, offers,
+/// orders and transactions, whose items sit in differently named fields.
+pub trait Paged {
+    type Item;
+
+    /// Size of the whole collection, if eBay reported it
+    fn total(&self) -> Option<i32>;
+
+    /// The page's items
+    fn into_items(self) -> Vec<Self::Item>;
+
+    /// The page's items, borrowed
+    fn items(&self) -> &[Self::Item];
+
+    /// The page's first item, e.g. for lookups expecting a single match
+    fn first_item(&self) -> Option<&Self::Item> {
+        self.items().first()
+    }
+}
+
+impl Paged for SearchPagedCollection {
+    type Item = ItemSummary;
+
+    fn total(&self) -> Option<i32> {
+        self.total
+    }
+
+    fn into_items(self) -> Vec<ItemSummary> {
+        self.item_summaries.unwrap_or_default()
+    }
+
+    fn items(&self) -> &[ItemSummary] {
+        self.item_summaries.as_deref().unwrap_or_default()
+    }
+}
+
+impl Paged for InventoryItems {
+    type Item = InventoryItemWithSkuLocaleGroupid;
+
+    fn total(&self) -> Option<i32> {
+        self.total
+    }
+
+    fn into_items(self) -> Vec<InventoryItemWithSkuLocaleGroupid> {
+        self.inventory_items.unwrap_or_default()
+    }
+
+    fn items(&self) -> &[InventoryItemWithSkuLocaleGroupid] {
+        self.inventory_items.as_deref().unwrap_or_default()
+    }
+}
+
+impl Paged for OrderSearchPagedCollection {
+    type Item = Order;
+
+    fn total(&self) -> Option<i32> {
+        self.total
+    }
+
+    fn into_items(self) -> Vec<Order> {
+        self.orders.unwrap_or_default()
+    }
+
+    fn items(&self) -> &[Order] {
+        self.orders.as_deref().unwrap_or_default()
+    }
+}
+
+impl Paged for Transactions {
+    type Item = Transaction;
+
+    fn total(&self) -> Option<i32> {
+        self.total
+    }
+
+    fn into_items(self) -> Vec<Transaction> {
+        self.transactions.unwrap_or_default()
+    }
+
+    fn items(&self) -> &[Transaction] {
+        self.transactions.as_deref().unwrap_or_default()
+    }
+}
+
+impl Paged for Offers {
+    type Item = EbayOfferDetailsWithAll;
+
+    fn total(&self) -> Option<i32> {
+        self.total
+    }
+
+    fn into_items(self) -> Vec<EbayOfferDetailsWithAll> {
+        self.offers.unwrap_or_default()
+    }
+
+    fn items(&self) -> &[EbayOfferDetailsWithAll] {
+        self.offers.as_deref().unwrap_or_default()
+    }
+}
+
+struct PageState<T, F> {
+    fetch_page: F,
+    offset: usize,
+    buffer: VecDeque<T>,
+    done: bool,
+}
+
+/// Stream every item of a collection, fetching pages lazily
+///
+/// `fetch_page` is called with the offset of the page to fetch, which should hold
+/// `page_size` items. A page is only requested once the previous one has been
+/// consumed, so `.take(n)` never fetches past what is needed. The stream ends at the
+/// reported total, on a short or empty page, or after yielding the first error.
+pub(crate) fn paginate<P, F, Fut>(page_size: usize, fetch_page: F) -> impl Stream<Item = HermesResult<P::Item>>
+where
+    P: Paged,
+    F: FnMut(usize) -> Fut,
+    Fut: Future<Output = HermesResult<P>>,
+{
+    let state = PageState { fetch_page, offset: 0, buffer: VecDeque::new(), done: false };
+    stream::unfold(state, move |mut state| async move {
+        loop {
+            if let Some(item) = state.buffer.pop_front() {
+                return Some((Ok(item), state));
+            }
+            if state.done {
+                return None;
+            }
+
+            match (state.fetch_page)(state.offset).await {
+                Ok(page) => {
+                    let total = page.total();
+                    let items = page.into_items();
+                    state.offset += items.len();
+                    state.done = items.len() < page_size
+                        || total.is_some_and(|total| state.offset >= total.max(0) as usize);
+                    state.buffer.extend(items);
+                }
+                Err(e) => {
+                    state.done = true;
+                    return Some((Err(e), state));
+                }
+            }
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::HermesError;
+    use futures::StreamExt;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    struct NumberPage {
+        total: Option<i32>,
+        numbers: Vec<u32>,
+    }
+
+    impl Paged for NumberPage {
+        type Item = u32;
+
+        fn total(&self) -> Option<i32> {
+            self.total
+        }
+
+        fn into_items(self) -> Vec<u32> {
+            self.numbers
+        }
+
+        fn items(&self) -> &[u32] {
+            &self.numbers
+        }
+    }
+
+    // Seven numbers served three per page
+    async fn fetch(offset: usize) -> HermesResult<NumberPage> {
+        let numbers = (offset as u32..7).take(3).collect();
+        Ok(NumberPage { total: Some(7), numbers })
+    }
+
+    #[tokio::test]
+    async fn paginate_walks_all_pages_lazily() {
+        let requests = AtomicUsize::new(0);
+        let counted_fetch = |offset| {
+            requests.fetch_add(1, Ordering::SeqCst);
+            fetch(offset)
+        };
+
+        let first_four: Vec<u32> = paginate(3, counted_fetch).take(4).map(Result::unwrap).collect().await;
+        assert_eq!(first_four, [0, 1, 2, 3]);
+        assert_eq!(requests.load(Ordering::SeqCst), 2);
+
+        let all: Vec<u32> = paginate(3, fetch).map(Result::unwrap).collect().await;
+        assert_eq!(all, [0, 1, 2, 3, 4, 5, 6]);
+    }
+
+    #[test]
+    fn first_item_reads_differently_named_fields() {
+        let search: SearchPagedCollection = serde_json::from_value(serde_json::json!({
+            "total": 2,
+            "itemSummaries": [{ "itemId": "v1|1|0" }, { "itemId": "v1|2|0" }]
+        }))
+        .unwrap();
+        assert_eq!(search.first_item().and_then(|item| item.item_id.as_deref()), Some("v1|1|0"));
+
+        let offers: Offers = serde_json::from_value(serde_json::json!({
+            "total": 1,
+            "offers": [{ "offerId": "5005", "sku": "MUG-1" }]
+        }))
+        .unwrap();
+        assert_eq!(offers.first_item().and_then(|offer| offer.offer_id.as_deref()), Some("5005"));
+
+        assert!(Offers::default().first_item().is_none());
+    }
+
+    #[tokio::test]
+    async fn paginate_ends_after_first_error() {
+        let failing = |offset: usize| async move {
+            if offset == 0 {
+                fetch(offset).await
+            } else {
+                Err(HermesError::ApiRequest("page failed".to_string()))
+            }
+        };
+
+        let results: Vec<_> = paginate(3, failing).collect().await;
+        assert_eq!(results.len(), 4);
+        assert!(matches!(results[3], Err(HermesError::ApiRequest(_))));
+    }
+}
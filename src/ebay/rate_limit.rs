@@ -0,0 +1,99 @@
+//! eBay call-limit usage from the Developer Analytics API
+//!
+//! eBay does not report quota usage on ordinary API responses; the Developer
+//! Analytics `rate_limit` resource is the supported way to see how many calls an
+//! application has left in the current window.
+
+use serde::{Deserialize, Serialize};
+
+/// Current call-limit usage for one API resource (e.g. `buy.browse` / `buy.browse.item.summary`)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RateLimitStatus {
+    /// API context, e.g. "buy"
+    pub api_context: String,
+    /// API name, e.g. "Browse"
+    pub api_name: String,
+    /// Resource the quota applies to, e.g. "buy.browse.item.summary"
+    pub resource: String,
+    /// Calls allowed per time window
+    pub limit: u64,
+    /// Calls left in the current time window
+    pub remaining: u64,
+    /// When the current window resets (ISO 8601)
+    pub reset: Option<String>,
+    /// Length of the quota window in seconds
+    pub time_window_seconds: Option<u64>,
+}
+
+impl RateLimitStatus {
+    /// Fraction of the quota already used, from 0.0 to 1.0
+    pub fn usage_ratio(&self) -> f64 {
+        if self.limit == 0 {
+            return 1.0;
+        }
+        1.0 - (self.remaining as f64 / self.limit as f64)
+    }
+}
+
+// Wire format of GET /developer/analytics/v1_beta/rate_limit/
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct RateLimitsResponse {
+    #[serde(default)]
+    pub rate_limits: Vec<RateLimit>,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct RateLimit {
+    #[serde(default)]
+    pub api_context: String,
+    #[serde(default)]
+    pub api_name: String,
+    #[serde(default)]
+    pub resources: Vec<RateLimitResource>,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct RateLimitResource {
+    #[serde(default)]
+    pub name: String,
+    #[serde(default)]
+    pub rates: Vec<Rate>,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct Rate {
+    #[serde(default)]
+    pub limit: u64,
+    #[serde(default)]
+    pub remaining: u64,
+    pub reset: Option<String>,
+    pub time_window: Option<u64>,
+}
+
+impl RateLimitsResponse {
+    /// Flatten the nested API / resource / rate structure into one entry per rate
+    pub(crate) fn into_statuses(self) -> Vec<RateLimitStatus> {
+        let mut statuses = Vec::new();
+        for api in self.rate_limits {
+            for resource in api.resources {
+                for rate in resource.rates {
+                    statuses.push(RateLimitStatus {
+                        api_context: api.api_context.clone(),
+                        api_name: api.api_name.clone(),
+                        resource: resource.name.clone(),
+                        limit: rate.limit,
+                        remaining: rate.remaining,
+                        reset: rate.reset,
+                        time_window_seconds: rate.time_window,
+                    });
+                }
+            }
+        }
+        statuses
+    }
+}
@@ -0,0 +1,41 @@
+//! Typed responses that can keep eBay's raw JSON
+//!
+//! The generated models drop fields they do not know, so data eBay adds to a
+//! response is lost until the models are regenerated. With
+//! [`EbayConfig::with_raw_capture`](crate::config::EbayConfig::with_raw_capture),
+//! an [`EbayResponse`] also keeps the JSON it was parsed from, so new fields can be
+//! read straight away. Capturing doubles the memory held per response.
+
+use serde_json::Value;
+use std::ops::Deref;
+
+/// A parsed eBay response, optionally with the raw JSON it came from
+#[derive(Debug, Clone)]
+pub struct EbayResponse<T> {
+    value: T,
+    raw: Value,
+}
+
+impl<T> EbayResponse<T> {
+    pub(crate) fn new(value: T, raw: Option<Value>) -> Self {
+        Self { value, raw: raw.unwrap_or(Value::Null) }
+    }
+
+    /// The response body as eBay sent it; `Value::Null` unless raw capture is enabled
+    pub fn raw(&self) -> &Value {
+        &self.raw
+    }
+
+    /// The parsed response, dropping the raw JSON
+    pub fn into_inner(self) -> T {
+        self.value
+    }
+}
+
+impl<T> Deref for EbayResponse<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.value
+    }
+}
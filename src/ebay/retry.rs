@@ -0,0 +1,364 @@
+//! Retries of transient eBay failures
+//!
+//! With [`EbayConfig::with_retries`](crate::config::EbayConfig::with_retries),
+//! idempotent (GET) calls that fail with a server error, a 429, or no response at
+//! all are retried with exponential backoff.
+//!
+//! A retry budget caps how often that happens across every call sharing a config:
+//! each retry spends a token from a token bucket, and once the bucket is empty
+//! failures are returned straight away. During a broad outage this keeps retries
+//! from multiplying the load on eBay (and on the application's call quota).
+//!
+//! Which failures count as transient can be replaced with a [`RetryPolicy`].
+//!
+//! Waiting between attempts never outlives the caller's patience: no wait is longer
+//! than 30 seconds, a `Retry-After` longer than the backoff is honored only while it
+//! fits within the configured retry timeout, and a triggered [`ShutdownSignal`] ends
+//! any pending wait at once. Either way the call returns its last failure instead of
+//! retrying.
+
+use crate::ebay::api_error::{api_error_ref, is_maintenance, ApiResponseError};
+use crate::error::HermesError;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::future::Future;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use tokio::sync::watch;
+
+/// Delay before the first retry; doubled for every further attempt
+const BASE_BACKOFF: Duration = Duration::from_millis(100);
+
+/// Longest wait before a retry, however many attempts came before or how long
+/// eBay's `Retry-After` asks for
+const MAX_BACKOFF: Duration = Duration::from_secs(30);
+
+/// Token bucket settings for the retry budget
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct RetryBudgetConfig {
+    /// Retries that can be spent in a burst
+    pub capacity: u32,
+    /// Tokens added back per second
+    pub refill_per_second: f64,
+}
+
+/// Decides whether a failed call is retried, replacing the default 429/5xx rule
+///
+/// The failure is passed as the error the call would return: `HermesError::Api`
+/// (or `NotFound`) for eBay error responses, and `HermesError::ApiRequest` when no
+/// response was received.
+#[derive(Clone)]
+pub struct RetryPolicy(pub Arc<dyn Fn(&HermesError) -> bool + Send + Sync>);
+
+impl fmt::Debug for RetryPolicy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("RetryPolicy")
+    }
+}
+
+/// Cancels pending retry waits, e.g. when the application shuts down
+///
+/// Clones share one signal. Once triggered, calls waiting to retry return their
+/// last failure straight away and later failures are not retried.
+#[derive(Debug, Clone)]
+pub struct ShutdownSignal(Arc<watch::Sender<bool>>);
+
+impl ShutdownSignal {
+    pub fn new() -> Self {
+        Self(Arc::new(watch::Sender::new(false)))
+    }
+
+    /// Cancel pending and future retries
+    pub fn trigger(&self) {
+        self.0.send_replace(true);
+    }
+
+    pub fn is_triggered(&self) -> bool {
+        *self.0.borrow()
+    }
+
+    /// Resolves once the signal has been triggered
+    async fn triggered(&self) {
+        let mut receiver = self.0.subscribe();
+        // The sender lives in `self`, so the channel cannot close while waiting
+        let _ = receiver.wait_for(|triggered| *triggered).await;
+    }
+}
+
+impl Default for ShutdownSignal {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Retry tokens, shared by all clients built from one config
+#[derive(Debug, Default)]
+pub struct RetryBudget {
+    bucket: Mutex<Option<Bucket>>,
+}
+
+#[derive(Debug)]
+struct Bucket {
+    tokens: f64,
+    refilled_at: Instant,
+}
+
+impl RetryBudget {
+    /// Take one token, returning false when the budget is exhausted
+    pub(crate) fn try_spend(&self, settings: &RetryBudgetConfig) -> bool {
+        let mut bucket = self.bucket.lock().unwrap_or_else(|e| e.into_inner());
+        let now = Instant::now();
+        let bucket = bucket.get_or_insert(Bucket {
+            tokens: settings.capacity as f64,
+            refilled_at: now,
+        });
+
+        let elapsed = now.duration_since(bucket.refilled_at).as_secs_f64();
+        bucket.tokens = (bucket.tokens + elapsed * settings.refill_per_second).min(settings.capacity as f64);
+        bucket.refilled_at = now;
+
+        if bucket.tokens < 1.0 {
+            return false;
+        }
+        bucket.tokens -= 1.0;
+        true
+    }
+}
+
+/// Whether a failed call is worth retrying
+///
+/// Maintenance windows outlast any backoff, so eBay's maintenance 503 is not retried.
+fn is_transient<E: ApiResponseError>(error: &E) -> bool {
+    match error.response() {
+        Some((status, body)) => (status >= 500 && !is_maintenance(status, body)) || status == 429,
+        None => error.is_transport_error(),
+    }
+}
+
+/// Exponential backoff before the retry following `retries` earlier ones
+fn backoff(retries: u32) -> Duration {
+    2u32.checked_pow(retries)
+        .and_then(|factor| BASE_BACKOFF.checked_mul(factor))
+        .map_or(MAX_BACKOFF, |delay| delay.min(MAX_BACKOFF))
+}
+
+/// Sleep for `delay`, returning false if `shutdown` is triggered first
+async fn sleep_unless_shutdown(delay: Duration, shutdown: Option<&ShutdownSignal>) -> bool {
+    let Some(shutdown) = shutdown else {
+        tokio::time::sleep(delay).await;
+        return true;
+    };
+    tokio::select! {
+        _ = tokio::time::sleep(delay) => true,
+        _ = shutdown.triggered() => false,
+    }
+}
+
+/// Run `call`, retrying transient failures up to `max_retries` times
+///
+/// # Arguments
+/// * `api` - API family, e.g. "buy.browse", used in logs
+/// * `max_retries` - Retries after the first attempt
+/// * `budget` - Retry budget settings and the shared bucket, if a budget is configured
+/// * `policy` - Custom retry decision, if one is configured
+/// * `timeout` - Time after the first attempt past which no retry may start, if configured
+/// * `shutdown` - Signal cancelling retry waits, if configured
+/// * `call` - Issues the request; called once per attempt
+pub(crate) async fn with_retries<T, E, F, Fut>(
+    api: &'static str,
+    max_retries: u32,
+    budget: Option<(&RetryBudgetConfig, &RetryBudget)>,
+    policy: Option<&RetryPolicy>,
+    timeout: Option<Duration>,
+    shutdown: Option<&ShutdownSignal>,
+    mut call: F,
+) -> Result<T, E>
+where
+    E: ApiResponseError + fmt::Debug,
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<T, E>>,
+{
+    let retryable = |error: &E| match policy {
+        Some(RetryPolicy(decide)) => decide(&api_error_ref(api, api, error)),
+        None => is_transient(error),
+    };
+
+    let started = Instant::now();
+    let shutting_down = || shutdown.is_some_and(ShutdownSignal::is_triggered);
+
+    let mut retries = 0;
+    loop {
+        let result = call().await;
+        let delay = match &result {
+            // eBay's Retry-After wins when it asks for a longer wait than the backoff
+            Err(e) if retries < max_retries && retryable(e) && !shutting_down() => {
+                let backoff = backoff(retries);
+                e.retry_after().map_or(backoff, |wait| wait.max(backoff).min(MAX_BACKOFF))
+            }
+            _ => return result,
+        };
+        if timeout.is_some_and(|timeout| started.elapsed() + delay > timeout) {
+            tracing::warn!("Not retrying eBay {}: waiting {:?} would pass the retry timeout", api, delay);
+            return result;
+        }
+
+        if let Some((settings, bucket)) = budget {
+            if !bucket.try_spend(settings) {
+                tracing::warn!("Retry budget exhausted, not retrying eBay {}", api);
+                return result;
+            }
+        }
+        retries += 1;
+        tracing::warn!("Retrying eBay {} in {:?} (retry {} of {})", api, delay, retries, max_retries);
+        if !sleep_unless_shutdown(delay, shutdown).await {
+            tracing::warn!("Shutting down, not retrying eBay {}", api);
+            return result;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ebay::api_error::RawRequestError;
+    use crate::ebay::test_support::mock_ebay;
+    use crate::ebay::EbayClient;
+    use crate::error::HermesError;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use wiremock::matchers::{method, path};
+    use wiremock::{Mock, ResponseTemplate};
+
+    #[tokio::test]
+    async fn retries_stop_once_budget_is_drained() {
+        let (server, config) = mock_ebay().await;
+        // Call 1: first attempt plus two budgeted retries; call 2: no tokens left
+        Mock::given(method("GET"))
+            .and(path("/buy/browse/v1/item/123"))
+            .respond_with(ResponseTemplate::new(503))
+            .expect(4)
+            .mount(&server)
+            .await;
+
+        let client = EbayClient::new(config.with_retries(5).with_retry_budget(2, 0.0)).unwrap();
+        for _ in 0..2 {
+            let err = client.get_item("123", None).await.unwrap_err();
+            assert!(matches!(err, HermesError::Api { status: 503, .. }));
+        }
+    }
+
+    #[tokio::test]
+    async fn retry_policy_overrides_default_classification() {
+        let (server, config) = mock_ebay().await;
+        Mock::given(method("GET"))
+            .and(path("/buy/browse/v1/item/123"))
+            .respond_with(ResponseTemplate::new(500))
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/buy/browse/v1/item/456"))
+            .respond_with(ResponseTemplate::new(400))
+            .expect(3)
+            .mount(&server)
+            .await;
+
+        // Only 400s are retried, so the 500 fails on the first attempt
+        let policy = Arc::new(|e: &HermesError| matches!(e, HermesError::Api { status: 400, .. }));
+        let client = EbayClient::new(config.with_retries(2).with_retry_policy(policy)).unwrap();
+
+        let err = client.get_item("123", None).await.unwrap_err();
+        assert!(matches!(err, HermesError::Api { status: 500, .. }));
+        let err = client.get_item("456", None).await.unwrap_err();
+        assert!(matches!(err, HermesError::Api { status: 400, .. }));
+    }
+
+    // A 503 asking to retry after 30 seconds
+    async fn unavailable(attempts: &AtomicUsize) -> Result<(), RawRequestError> {
+        attempts.fetch_add(1, Ordering::SeqCst);
+        Err(RawRequestError::Response { status: 503, body: String::new(), retry_after: Some(Duration::from_secs(30)) })
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn backoff_is_capped_however_many_retries() {
+        let attempts = AtomicUsize::new(0);
+        let started = tokio::time::Instant::now();
+        let result = with_retries("buy.browse", 40, None, None, None, None, || async {
+            attempts.fetch_add(1, Ordering::SeqCst);
+            Err::<(), _>(RawRequestError::Response { status: 503, body: String::new(), retry_after: None })
+        })
+        .await;
+
+        assert!(result.is_err());
+        assert_eq!(attempts.load(Ordering::SeqCst), 41);
+        assert!(started.elapsed() <= MAX_BACKOFF * 40);
+        assert_eq!(backoff(0), BASE_BACKOFF);
+        assert_eq!(backoff(32), MAX_BACKOFF);
+        assert_eq!(backoff(u32::MAX), MAX_BACKOFF);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn retry_after_is_capped() {
+        let started = tokio::time::Instant::now();
+        let result = with_retries("buy.browse", 1, None, None, None, None, || async {
+            Err::<(), _>(RawRequestError::Response {
+                status: 503,
+                body: String::new(),
+                retry_after: Some(Duration::from_secs(3600)),
+            })
+        })
+        .await;
+
+        assert!(result.is_err());
+        assert_eq!(started.elapsed(), MAX_BACKOFF);
+    }
+
+    #[tokio::test]
+    async fn shutdown_cancels_retry_sleep() {
+        let shutdown = ShutdownSignal::new();
+        let attempts = Arc::new(AtomicUsize::new(0));
+        let task = tokio::spawn({
+            let shutdown = shutdown.clone();
+            let attempts = Arc::clone(&attempts);
+            async move { with_retries("buy.browse", 3, None, None, None, Some(&shutdown), || unavailable(&attempts)).await }
+        });
+
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        let cancelled_at = Instant::now();
+        shutdown.trigger();
+        let result = tokio::time::timeout(Duration::from_secs(1), task).await.expect("retry sleep was not cancelled");
+
+        assert!(cancelled_at.elapsed() < Duration::from_millis(500));
+        assert!(matches!(result.unwrap(), Err(RawRequestError::Response { status: 503, .. })));
+        assert_eq!(attempts.load(Ordering::SeqCst), 1);
+
+        // Once triggered, failures are not retried at all
+        let result = with_retries("buy.browse", 3, None, None, None, Some(&shutdown), || unavailable(&attempts)).await;
+        assert!(result.is_err());
+        assert_eq!(attempts.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn retry_after_past_timeout_returns_immediately() {
+        let attempts = AtomicUsize::new(0);
+        let started = Instant::now();
+        let timeout = Some(Duration::from_secs(5));
+        let result = with_retries("buy.browse", 3, None, None, timeout, None, || unavailable(&attempts)).await;
+
+        assert!(result.is_err());
+        assert_eq!(attempts.load(Ordering::SeqCst), 1);
+        assert!(started.elapsed() < Duration::from_secs(1));
+    }
+
+    #[test]
+    fn budget_refills_over_time() {
+        let settings = RetryBudgetConfig { capacity: 1, refill_per_second: 1000.0 };
+        let budget = RetryBudget::default();
+        assert!(budget.try_spend(&settings));
+
+        std::thread::sleep(Duration::from_millis(5));
+        assert!(budget.try_spend(&settings));
+
+        let empty = RetryBudgetConfig { capacity: 0, refill_per_second: 0.0 };
+        assert!(!RetryBudget::default().try_spend(&empty));
+    }
+}
@@ -1,15 +1,50 @@
 use crate::config::EbayConfig;
 use crate::error::{HermesError, HermesResult};
+use crate::ebay::api_error::raw_error;
+use crate::ebay::telemetry::record_api_call;
 use crate::ebay::auth::EbayAuth;
+use crate::ebay::marketplace::{normalize_marketplace, Marketplace, MarketplaceStyle};
+use crate::ebay::sell::InventoryClient;
 use std::sync::Arc;
 
 // Import eBay Sell Account SDK models and APIs
 use hermes_ebay_sell_account::models::{
     ReturnPolicyRequest, SetReturnPolicyResponse, PaymentPolicyRequest, SetPaymentPolicyResponse,
     FulfillmentPolicyRequest, SetFulfillmentPolicyResponse, 
-    CustomPolicyCreateRequest, SalesTaxBase, SalesTax, KycResponse, CompactCustomPolicyResponse, SellerEligibilityMultiProgramResponse,
+    CustomPolicyCreateRequest, SalesTaxBase, SalesTax, KycCheck, CompactCustomPolicyResponse, SellerEligibilityResponse,
+    FulfillmentPolicy, PaymentPolicy, ReturnPolicy,
+    CustomPolicyResponse, SalesTaxes, KycResponse, FulfillmentPolicyResponse, PaymentPolicyResponse,
+    ReturnPolicyResponse, SellerEligibilityMultiProgramResponse,
 };
-use hermes_ebay_sell_account::apis::configuration::Configuration as AccountConfiguration;
+use hermes_ebay_sell_account::apis::urlencode;
+
+/// Business policy type referenced by an offer
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PolicyKind {
+    Fulfillment,
+    Payment,
+    Return,
+}
+
+/// A policy ID that does not match any of the seller's policies
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MissingPolicy {
+    pub kind: PolicyKind,
+    pub policy_id: String,
+}
+
+/// Result of checking an offer's policy IDs against the seller's policies
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PolicyValidation {
+    pub missing: Vec<MissingPolicy>,
+}
+
+impl PolicyValidation {
+    /// Whether every referenced policy exists
+    pub fn is_valid(&self) -> bool {
+        self.missing.is_empty()
+    }
+}
 
 /// eBay Sell Account API client for seller account management
 /// 
@@ -43,6 +78,11 @@ impl AccountClient {
         policy_id: &str,
         policy_request: &ReturnPolicyRequest,
     ) -> HermesResult<SetReturnPolicyResponse> {
+        // Dry-run mode logs the request instead of sending it
+        if let Some(response) = self.config.skip_in_dry_run("update_return_policy", &(policy_id, policy_request)) {
+            return Ok(response);
+        }
+
         let start_time = std::time::Instant::now();
         
         // Get access token
@@ -51,24 +91,18 @@ impl AccountClient {
         let token_duration = token_start.elapsed();
         tracing::info!("OAuth token request for update_return_policy: {:?}", token_duration);
         
-        // Set up configuration
-        let mut config = AccountConfiguration::new();
-        config.base_path = if self.config.sandbox {
-            "https://api.sandbox.ebay.com/sell/account/v1".to_string()
-        } else {
-            "https://api.ebay.com/sell/account/v1".to_string()
-        };
-        config.oauth_access_token = Some(token);
+        let url = format!("{}/sell/account/v1/return_policy/{}", self.config.base_url(), urlencode(policy_id));
+        let http = self.config.http_client();
+        
+        // Fail fast while this API's circuit breaker is open
+        let probe = self.config.check_circuit("sell.account")?;
         
-        // Call the eBay SDK
+        // Call the eBay API
         let ebay_start = std::time::Instant::now();
-        let result = hermes_ebay_sell_account::apis::return_policy_api::update_return_policy(
-            &config,
-            policy_id,
-            "application/json",
-            policy_request.clone(),
-        ).await;
+        let result = self.config.send_json(http.put(&url).bearer_auth(&token).json(policy_request)).await;
         let ebay_duration = ebay_start.elapsed();
+        record_api_call("sell.account", "update_return_policy", &result, ebay_duration);
+        self.config.record_circuit("sell.account", &probe, &result);
         tracing::info!("eBay update_return_policy API call: {:?}", ebay_duration);
         
         match result {
@@ -81,7 +115,7 @@ impl AccountClient {
             Err(e) => {
                 let total_duration = start_time.elapsed();
                 tracing::error!("eBay update_return_policy error after {:?}: {:?}", total_duration, e);
-                Err(HermesError::ApiRequest(format!("eBay update_return_policy failed: {:?}", e)))
+                Err(raw_error("update_return_policy", &format!("policy_id {}", policy_id), e))
             }
         }
     }
@@ -93,6 +127,11 @@ impl AccountClient {
     /// # Arguments
     /// * `policy_id` - The ID of the return policy to delete
     pub async fn delete_return_policy(&self, policy_id: &str) -> HermesResult<()> {
+        // Dry-run mode logs the request instead of sending it
+        if let Some(response) = self.config.skip_in_dry_run("delete_return_policy", &policy_id) {
+            return Ok(response);
+        }
+
         let start_time = std::time::Instant::now();
         
         // Get access token
@@ -101,19 +140,18 @@ impl AccountClient {
         let token_duration = token_start.elapsed();
         tracing::info!("OAuth token request for delete_return_policy: {:?}", token_duration);
         
-        // Set up configuration
-        let mut config = AccountConfiguration::new();
-        config.base_path = if self.config.sandbox {
-            "https://api.sandbox.ebay.com/sell/account/v1".to_string()
-        } else {
-            "https://api.ebay.com/sell/account/v1".to_string()
-        };
-        config.oauth_access_token = Some(token);
+        let url = format!("{}/sell/account/v1/return_policy/{}", self.config.base_url(), urlencode(policy_id));
+        let http = self.config.http_client();
+        
+        // Fail fast while this API's circuit breaker is open
+        let probe = self.config.check_circuit("sell.account")?;
         
-        // Call the eBay SDK
+        // Call the eBay API
         let ebay_start = std::time::Instant::now();
-        let result = hermes_ebay_sell_account::apis::return_policy_api::delete_return_policy(&config, policy_id).await;
+        let result = self.config.send(http.delete(&url).bearer_auth(&token)).await;
         let ebay_duration = ebay_start.elapsed();
+        record_api_call("sell.account", "delete_return_policy", &result, ebay_duration);
+        self.config.record_circuit("sell.account", &probe, &result);
         tracing::info!("eBay delete_return_policy API call: {:?}", ebay_duration);
         
         match result {
@@ -126,11 +164,48 @@ impl AccountClient {
             Err(e) => {
                 let total_duration = start_time.elapsed();
                 tracing::error!("eBay delete_return_policy error after {:?}: {:?}", total_duration, e);
-                Err(HermesError::ApiRequest(format!("eBay delete_return_policy failed: {:?}", e)))
+                Err(raw_error("delete_return_policy", &format!("policy_id {}", policy_id), e))
             }
         }
     }
 
+    /// Whether any offer references a business policy
+    /// 
+    /// Checks every offer of every inventory item, see
+    /// [`InventoryClient::offers_using_policy`].
+    /// 
+    /// # Arguments
+    /// * `policy_id` - The return, payment, fulfillment or take-back policy ID
+    pub async fn is_policy_in_use(&self, policy_id: &str) -> HermesResult<bool> {
+        Ok(!self.offers_using_policy(policy_id).await?.is_empty())
+    }
+
+    /// Delete a return policy only if no offer uses it
+    /// 
+    /// Returns [`HermesError::PolicyInUse`] listing the offers that reference the
+    /// policy instead of attempting a delete eBay would reject. With `dry_run`, the
+    /// check runs but nothing is deleted.
+    /// 
+    /// # Arguments
+    /// * `policy_id` - The ID of the return policy to delete
+    /// * `dry_run` - Only check whether the policy could be deleted
+    pub async fn delete_return_policy_safe(&self, policy_id: &str, dry_run: bool) -> HermesResult<()> {
+        let offer_ids = self.offers_using_policy(policy_id).await?;
+        if !offer_ids.is_empty() {
+            return Err(HermesError::PolicyInUse { policy_id: policy_id.to_string(), offer_ids });
+        }
+        if dry_run {
+            return Ok(());
+        }
+        self.delete_return_policy(policy_id).await
+    }
+
+    async fn offers_using_policy(&self, policy_id: &str) -> HermesResult<Vec<String>> {
+        InventoryClient::with_auth(self.config.clone(), self.auth.clone())
+            .offers_using_policy(policy_id)
+            .await
+    }
+
     /// Create payment policy
     /// 
     /// Creates a new payment policy that defines acceptable payment methods and terms.
@@ -141,6 +216,11 @@ impl AccountClient {
         &self,
         policy_request: &PaymentPolicyRequest,
     ) -> HermesResult<SetPaymentPolicyResponse> {
+        // Dry-run mode logs the request instead of sending it
+        if let Some(response) = self.config.skip_in_dry_run("create_payment_policy", &policy_request) {
+            return Ok(response);
+        }
+
         let start_time = std::time::Instant::now();
         
         // Get access token
@@ -149,23 +229,18 @@ impl AccountClient {
         let token_duration = token_start.elapsed();
         tracing::info!("OAuth token request for create_payment_policy: {:?}", token_duration);
         
-        // Set up configuration
-        let mut config = AccountConfiguration::new();
-        config.base_path = if self.config.sandbox {
-            "https://api.sandbox.ebay.com/sell/account/v1".to_string()
-        } else {
-            "https://api.ebay.com/sell/account/v1".to_string()
-        };
-        config.oauth_access_token = Some(token);
+        let url = format!("{}/sell/account/v1/payment_policy", self.config.base_url());
+        let http = self.config.http_client();
+        
+        // Fail fast while this API's circuit breaker is open
+        let probe = self.config.check_circuit("sell.account")?;
         
-        // Call the eBay SDK
+        // Call the eBay API
         let ebay_start = std::time::Instant::now();
-        let result = hermes_ebay_sell_account::apis::payment_policy_api::create_payment_policy(
-            &config,
-            "application/json",
-            policy_request.clone(),
-        ).await;
+        let result = self.config.send_json(http.post(&url).bearer_auth(&token).json(policy_request)).await;
         let ebay_duration = ebay_start.elapsed();
+        record_api_call("sell.account", "create_payment_policy", &result, ebay_duration);
+        self.config.record_circuit("sell.account", &probe, &result);
         tracing::info!("eBay create_payment_policy API call: {:?}", ebay_duration);
         
         match result {
@@ -178,7 +253,7 @@ impl AccountClient {
             Err(e) => {
                 let total_duration = start_time.elapsed();
                 tracing::error!("eBay create_payment_policy error after {:?}: {:?}", total_duration, e);
-                Err(HermesError::ApiRequest(format!("eBay create_payment_policy failed: {:?}", e)))
+                Err(raw_error("create_payment_policy", &format!("payment policy {}", policy_request.name.as_deref().unwrap_or_default()), e))
             }
         }
     }
@@ -193,6 +268,11 @@ impl AccountClient {
         &self,
         policy_request: &FulfillmentPolicyRequest,
     ) -> HermesResult<SetFulfillmentPolicyResponse> {
+        // Dry-run mode logs the request instead of sending it
+        if let Some(response) = self.config.skip_in_dry_run("create_fulfillment_policy", &policy_request) {
+            return Ok(response);
+        }
+
         let start_time = std::time::Instant::now();
         
         // Get access token
@@ -201,23 +281,18 @@ impl AccountClient {
         let token_duration = token_start.elapsed();
         tracing::info!("OAuth token request for create_fulfillment_policy: {:?}", token_duration);
         
-        // Set up configuration
-        let mut config = AccountConfiguration::new();
-        config.base_path = if self.config.sandbox {
-            "https://api.sandbox.ebay.com/sell/account/v1".to_string()
-        } else {
-            "https://api.ebay.com/sell/account/v1".to_string()
-        };
-        config.oauth_access_token = Some(token);
+        let url = format!("{}/sell/account/v1/fulfillment_policy/", self.config.base_url());
+        let http = self.config.http_client();
         
-        // Call the eBay SDK
+        // Fail fast while this API's circuit breaker is open
+        let probe = self.config.check_circuit("sell.account")?;
+        
+        // Call the eBay API
         let ebay_start = std::time::Instant::now();
-        let result = hermes_ebay_sell_account::apis::fulfillment_policy_api::create_fulfillment_policy(
-            &config,
-            "application/json",
-            policy_request.clone(),
-        ).await;
+        let result = self.config.send_json(http.post(&url).bearer_auth(&token).json(policy_request)).await;
         let ebay_duration = ebay_start.elapsed();
+        record_api_call("sell.account", "create_fulfillment_policy", &result, ebay_duration);
+        self.config.record_circuit("sell.account", &probe, &result);
         tracing::info!("eBay create_fulfillment_policy API call: {:?}", ebay_duration);
         
         match result {
@@ -230,7 +305,7 @@ impl AccountClient {
             Err(e) => {
                 let total_duration = start_time.elapsed();
                 tracing::error!("eBay create_fulfillment_policy error after {:?}: {:?}", total_duration, e);
-                Err(HermesError::ApiRequest(format!("eBay create_fulfillment_policy failed: {:?}", e)))
+                Err(raw_error("create_fulfillment_policy", &format!("fulfillment policy {}", policy_request.name.as_deref().unwrap_or_default()), e))
             }
         }
     }
@@ -253,19 +328,20 @@ impl AccountClient {
         let token_duration = token_start.elapsed();
         tracing::info!("OAuth token request for get_custom_policies: {:?}", token_duration);
         
-        // Set up configuration
-        let mut config = AccountConfiguration::new();
-        config.base_path = if self.config.sandbox {
-            "https://api.sandbox.ebay.com/sell/account/v1".to_string()
-        } else {
-            "https://api.ebay.com/sell/account/v1".to_string()
-        };
-        config.oauth_access_token = Some(token);
+        let url = format!("{}/sell/account/v1/custom_policy/", self.config.base_url());
+        let http = self.config.http_client();
+        
+        // Fail fast while this API's circuit breaker is open
+        let probe = self.config.check_circuit("sell.account")?;
         
-        // Call the eBay SDK
+        // Call the eBay API
         let ebay_start = std::time::Instant::now();
-        let result = hermes_ebay_sell_account::apis::custom_policy_api::get_custom_policies(&config, policy_types).await;
+        let result = self.config.retrying("sell.account", || {
+            self.config.send_json::<CustomPolicyResponse>(http.get(&url).bearer_auth(&token).query(&[("policy_types", policy_types)]))
+        }).await;
         let ebay_duration = ebay_start.elapsed();
+        record_api_call("sell.account", "get_custom_policies", &result, ebay_duration);
+        self.config.record_circuit("sell.account", &probe, &result);
         tracing::info!("eBay get_custom_policies API call: {:?}", ebay_duration);
         
         match result {
@@ -278,7 +354,7 @@ impl AccountClient {
             Err(e) => {
                 let total_duration = start_time.elapsed();
                 tracing::error!("eBay get_custom_policies error after {:?}: {:?}", total_duration, e);
-                Err(HermesError::ApiRequest(format!("eBay get_custom_policies failed: {:?}", e)))
+                Err(raw_error("get_custom_policies", &format!("policy_types {}", policy_types.unwrap_or("all")), e))
             }
         }
     }
@@ -293,6 +369,11 @@ impl AccountClient {
         &self,
         policy_request: &CustomPolicyCreateRequest,
     ) -> HermesResult<serde_json::Value> {
+        // Dry-run mode logs the request instead of sending it
+        if let Some(response) = self.config.skip_in_dry_run("create_custom_policy", &policy_request) {
+            return Ok(response);
+        }
+
         let start_time = std::time::Instant::now();
         
         // Get access token
@@ -301,23 +382,18 @@ impl AccountClient {
         let token_duration = token_start.elapsed();
         tracing::info!("OAuth token request for create_custom_policy: {:?}", token_duration);
         
-        // Set up configuration
-        let mut config = AccountConfiguration::new();
-        config.base_path = if self.config.sandbox {
-            "https://api.sandbox.ebay.com/sell/account/v1".to_string()
-        } else {
-            "https://api.ebay.com/sell/account/v1".to_string()
-        };
-        config.oauth_access_token = Some(token);
+        let url = format!("{}/sell/account/v1/custom_policy/", self.config.base_url());
+        let http = self.config.http_client();
+        
+        // Fail fast while this API's circuit breaker is open
+        let probe = self.config.check_circuit("sell.account")?;
         
-        // Call the eBay SDK
+        // Call the eBay API
         let ebay_start = std::time::Instant::now();
-        let result = hermes_ebay_sell_account::apis::custom_policy_api::create_custom_policy(
-            &config,
-            "application/json",
-            policy_request.clone(),
-        ).await;
+        let result = self.config.send_json(http.post(&url).bearer_auth(&token).json(policy_request)).await;
         let ebay_duration = ebay_start.elapsed();
+        record_api_call("sell.account", "create_custom_policy", &result, ebay_duration);
+        self.config.record_circuit("sell.account", &probe, &result);
         tracing::info!("eBay create_custom_policy API call: {:?}", ebay_duration);
         
         match result {
@@ -330,7 +406,7 @@ impl AccountClient {
             Err(e) => {
                 let total_duration = start_time.elapsed();
                 tracing::error!("eBay create_custom_policy error after {:?}: {:?}", total_duration, e);
-                Err(HermesError::ApiRequest(format!("eBay create_custom_policy failed: {:?}", e)))
+                Err(raw_error("create_custom_policy", &format!("custom policy {}", policy_request.name.as_deref().unwrap_or_default()), e))
             }
         }
     }
@@ -350,19 +426,20 @@ impl AccountClient {
         let token_duration = token_start.elapsed();
         tracing::info!("OAuth token request for get_sales_taxes: {:?}", token_duration);
         
-        // Set up configuration
-        let mut config = AccountConfiguration::new();
-        config.base_path = if self.config.sandbox {
-            "https://api.sandbox.ebay.com/sell/account/v1".to_string()
-        } else {
-            "https://api.ebay.com/sell/account/v1".to_string()
-        };
-        config.oauth_access_token = Some(token);
+        let url = format!("{}/sell/account/v1/sales_tax", self.config.base_url());
+        let http = self.config.http_client();
         
-        // Call the eBay SDK
+        // Fail fast while this API's circuit breaker is open
+        let probe = self.config.check_circuit("sell.account")?;
+        
+        // Call the eBay API
         let ebay_start = std::time::Instant::now();
-        let result = hermes_ebay_sell_account::apis::sales_tax_api::get_sales_taxes(&config, country_code).await;
+        let result = self.config.retrying("sell.account", || {
+            self.config.send_json::<SalesTaxes>(http.get(&url).bearer_auth(&token).query(&[("country_code", country_code)]))
+        }).await;
         let ebay_duration = ebay_start.elapsed();
+        record_api_call("sell.account", "get_sales_taxes", &result, ebay_duration);
+        self.config.record_circuit("sell.account", &probe, &result);
         tracing::info!("eBay get_sales_taxes API call: {:?}", ebay_duration);
         
         match result {
@@ -375,7 +452,7 @@ impl AccountClient {
             Err(e) => {
                 let total_duration = start_time.elapsed();
                 tracing::error!("eBay get_sales_taxes error after {:?}: {:?}", total_duration, e);
-                Err(HermesError::ApiRequest(format!("eBay get_sales_taxes failed: {:?}", e)))
+                Err(raw_error("get_sales_taxes", &format!("country_code {}", country_code), e))
             }
         }
     }
@@ -394,6 +471,11 @@ impl AccountClient {
         jurisdiction_id: &str,
         sales_tax_base: &SalesTaxBase,
     ) -> HermesResult<()> {
+        // Dry-run mode logs the request instead of sending it
+        if let Some(response) = self.config.skip_in_dry_run("create_or_replace_sales_tax", &(country_code, jurisdiction_id, sales_tax_base)) {
+            return Ok(response);
+        }
+
         let start_time = std::time::Instant::now();
         
         // Get access token
@@ -402,25 +484,18 @@ impl AccountClient {
         let token_duration = token_start.elapsed();
         tracing::info!("OAuth token request for create_or_replace_sales_tax: {:?}", token_duration);
         
-        // Set up configuration
-        let mut config = AccountConfiguration::new();
-        config.base_path = if self.config.sandbox {
-            "https://api.sandbox.ebay.com/sell/account/v1".to_string()
-        } else {
-            "https://api.ebay.com/sell/account/v1".to_string()
-        };
-        config.oauth_access_token = Some(token);
+        let url = format!("{}/sell/account/v1/sales_tax/{}/{}", self.config.base_url(), urlencode(country_code), urlencode(jurisdiction_id));
+        let http = self.config.http_client();
         
-        // Call the eBay SDK
+        // Fail fast while this API's circuit breaker is open
+        let probe = self.config.check_circuit("sell.account")?;
+        
+        // Call the eBay API
         let ebay_start = std::time::Instant::now();
-        let result = hermes_ebay_sell_account::apis::sales_tax_api::create_or_replace_sales_tax(
-            &config,
-            country_code,
-            jurisdiction_id,
-            "application/json",
-            sales_tax_base.clone(),
-        ).await;
+        let result = self.config.send(http.put(&url).bearer_auth(&token).json(sales_tax_base)).await;
         let ebay_duration = ebay_start.elapsed();
+        record_api_call("sell.account", "create_or_replace_sales_tax", &result, ebay_duration);
+        self.config.record_circuit("sell.account", &probe, &result);
         tracing::info!("eBay create_or_replace_sales_tax API call: {:?}", ebay_duration);
         
         match result {
@@ -433,15 +508,16 @@ impl AccountClient {
             Err(e) => {
                 let total_duration = start_time.elapsed();
                 tracing::error!("eBay create_or_replace_sales_tax error after {:?}: {:?}", total_duration, e);
-                Err(HermesError::ApiRequest(format!("eBay create_or_replace_sales_tax failed: {:?}", e)))
+                Err(raw_error("create_or_replace_sales_tax", &format!("jurisdiction_id {}", jurisdiction_id), e))
             }
         }
     }
 
     /// Get KYC information
     /// 
-    /// Retrieves Know Your Customer (KYC) information and verification status for the seller.
-    pub async fn get_kyc(&self) -> HermesResult<KycResponse> {
+    /// Retrieves the Know Your Customer (KYC) checks the seller still has to complete.
+    /// Empty when no checks are outstanding.
+    pub async fn get_kyc(&self) -> HermesResult<Vec<KycCheck>> {
         let start_time = std::time::Instant::now();
         
         // Get access token
@@ -450,19 +526,20 @@ impl AccountClient {
         let token_duration = token_start.elapsed();
         tracing::info!("OAuth token request for get_kyc: {:?}", token_duration);
         
-        // Set up configuration
-        let mut config = AccountConfiguration::new();
-        config.base_path = if self.config.sandbox {
-            "https://api.sandbox.ebay.com/sell/account/v1".to_string()
-        } else {
-            "https://api.ebay.com/sell/account/v1".to_string()
-        };
-        config.oauth_access_token = Some(token);
+        let url = format!("{}/sell/account/v1/kyc", self.config.base_url());
+        let http = self.config.http_client();
+        
+        // Fail fast while this API's circuit breaker is open
+        let probe = self.config.check_circuit("sell.account")?;
         
-        // Call the eBay SDK
+        // Call the eBay API
         let ebay_start = std::time::Instant::now();
-        let result = hermes_ebay_sell_account::apis::kyc_api::get_kyc(&config).await;
+        let result = self.config.retrying("sell.account", || {
+            self.config.send_json::<KycResponse>(http.get(&url).bearer_auth(&token))
+        }).await;
         let ebay_duration = ebay_start.elapsed();
+        record_api_call("sell.account", "get_kyc", &result, ebay_duration);
+        self.config.record_circuit("sell.account", &probe, &result);
         tracing::info!("eBay get_kyc API call: {:?}", ebay_duration);
         
         match result {
@@ -470,19 +547,201 @@ impl AccountClient {
                 let total_duration = start_time.elapsed();
                 let our_processing = total_duration - token_duration - ebay_duration;
                 tracing::info!("get_kyc total: {:?} | Our processing: {:?}", total_duration, our_processing);
-                Ok(response)
+                Ok(response.kyc_checks.unwrap_or_default())
             },
             Err(e) => {
                 let total_duration = start_time.elapsed();
                 tracing::error!("eBay get_kyc error after {:?}: {:?}", total_duration, e);
-                Err(HermesError::ApiRequest(format!("eBay get_kyc failed: {:?}", e)))
+                Err(raw_error("get_kyc", "KYC checks", e))
+            }
+        }
+    }
+
+    /// Get fulfillment policies
+    /// 
+    /// Retrieves every fulfillment policy the seller has defined for a marketplace.
+    /// 
+    /// # Arguments
+    /// * `marketplace_id` - The marketplace ID (e.g., "EBAY_US")
+    pub async fn get_fulfillment_policies(&self, marketplace_id: &str) -> HermesResult<Vec<FulfillmentPolicy>> {
+        let start_time = std::time::Instant::now();
+        
+        // Get access token
+        let token_start = std::time::Instant::now();
+        let token = self.auth.get_access_token().await?;
+        let token_duration = token_start.elapsed();
+        tracing::info!("OAuth token request for get_fulfillment_policies: {:?}", token_duration);
+        
+        let url = format!("{}/sell/account/v1/fulfillment_policy", self.config.base_url());
+        let http = self.config.http_client();
+        
+        // Fail fast while this API's circuit breaker is open
+        let probe = self.config.check_circuit("sell.account")?;
+        
+        let marketplace_id = normalize_marketplace(marketplace_id, MarketplaceStyle::Underscore);
+        
+        // Call the eBay API
+        let ebay_start = std::time::Instant::now();
+        let result = self.config.retrying("sell.account", || {
+            self.config.send_json::<FulfillmentPolicyResponse>(http.get(&url).bearer_auth(&token).query(&[("marketplace_id", &marketplace_id)]))
+        }).await;
+        let ebay_duration = ebay_start.elapsed();
+        record_api_call("sell.account", "get_fulfillment_policies", &result, ebay_duration);
+        self.config.record_circuit("sell.account", &probe, &result);
+        tracing::info!("eBay get_fulfillment_policies API call: {:?}", ebay_duration);
+        
+        match result {
+            Ok(response) => {
+                let total_duration = start_time.elapsed();
+                let our_processing = total_duration - token_duration - ebay_duration;
+                tracing::info!("get_fulfillment_policies total: {:?} | Our processing: {:?}", total_duration, our_processing);
+                Ok(response.fulfillment_policies.unwrap_or_default())
+            },
+            Err(e) => {
+                let total_duration = start_time.elapsed();
+                tracing::error!("eBay get_fulfillment_policies error after {:?}: {:?}", total_duration, e);
+                Err(raw_error("get_fulfillment_policies", &format!("marketplace_id {}", marketplace_id), e))
+            }
+        }
+    }
+
+    /// Get payment policies
+    /// 
+    /// Retrieves every payment policy the seller has defined for a marketplace.
+    /// 
+    /// # Arguments
+    /// * `marketplace_id` - The marketplace ID (e.g., "EBAY_US")
+    pub async fn get_payment_policies(&self, marketplace_id: &str) -> HermesResult<Vec<PaymentPolicy>> {
+        let start_time = std::time::Instant::now();
+        
+        // Get access token
+        let token_start = std::time::Instant::now();
+        let token = self.auth.get_access_token().await?;
+        let token_duration = token_start.elapsed();
+        tracing::info!("OAuth token request for get_payment_policies: {:?}", token_duration);
+        
+        let url = format!("{}/sell/account/v1/payment_policy", self.config.base_url());
+        let http = self.config.http_client();
+        
+        // Fail fast while this API's circuit breaker is open
+        let probe = self.config.check_circuit("sell.account")?;
+        
+        let marketplace_id = normalize_marketplace(marketplace_id, MarketplaceStyle::Underscore);
+        
+        // Call the eBay API
+        let ebay_start = std::time::Instant::now();
+        let result = self.config.retrying("sell.account", || {
+            self.config.send_json::<PaymentPolicyResponse>(http.get(&url).bearer_auth(&token).query(&[("marketplace_id", &marketplace_id)]))
+        }).await;
+        let ebay_duration = ebay_start.elapsed();
+        record_api_call("sell.account", "get_payment_policies", &result, ebay_duration);
+        self.config.record_circuit("sell.account", &probe, &result);
+        tracing::info!("eBay get_payment_policies API call: {:?}", ebay_duration);
+        
+        match result {
+            Ok(response) => {
+                let total_duration = start_time.elapsed();
+                let our_processing = total_duration - token_duration - ebay_duration;
+                tracing::info!("get_payment_policies total: {:?} | Our processing: {:?}", total_duration, our_processing);
+                Ok(response.payment_policies.unwrap_or_default())
+            },
+            Err(e) => {
+                let total_duration = start_time.elapsed();
+                tracing::error!("eBay get_payment_policies error after {:?}: {:?}", total_duration, e);
+                Err(raw_error("get_payment_policies", &format!("marketplace_id {}", marketplace_id), e))
+            }
+        }
+    }
+
+    /// Get return policies
+    /// 
+    /// Retrieves every return policy the seller has defined for a marketplace.
+    /// 
+    /// # Arguments
+    /// * `marketplace_id` - The marketplace ID (e.g., "EBAY_US")
+    pub async fn get_return_policies(&self, marketplace_id: &str) -> HermesResult<Vec<ReturnPolicy>> {
+        let start_time = std::time::Instant::now();
+        
+        // Get access token
+        let token_start = std::time::Instant::now();
+        let token = self.auth.get_access_token().await?;
+        let token_duration = token_start.elapsed();
+        tracing::info!("OAuth token request for get_return_policies: {:?}", token_duration);
+        
+        let url = format!("{}/sell/account/v1/return_policy", self.config.base_url());
+        let http = self.config.http_client();
+        
+        // Fail fast while this API's circuit breaker is open
+        let probe = self.config.check_circuit("sell.account")?;
+        
+        let marketplace_id = normalize_marketplace(marketplace_id, MarketplaceStyle::Underscore);
+        
+        // Call the eBay API
+        let ebay_start = std::time::Instant::now();
+        let result = self.config.retrying("sell.account", || {
+            self.config.send_json::<ReturnPolicyResponse>(http.get(&url).bearer_auth(&token).query(&[("marketplace_id", &marketplace_id)]))
+        }).await;
+        let ebay_duration = ebay_start.elapsed();
+        record_api_call("sell.account", "get_return_policies", &result, ebay_duration);
+        self.config.record_circuit("sell.account", &probe, &result);
+        tracing::info!("eBay get_return_policies API call: {:?}", ebay_duration);
+        
+        match result {
+            Ok(response) => {
+                let total_duration = start_time.elapsed();
+                let our_processing = total_duration - token_duration - ebay_duration;
+                tracing::info!("get_return_policies total: {:?} | Our processing: {:?}", total_duration, our_processing);
+                Ok(response.return_policies.unwrap_or_default())
+            },
+            Err(e) => {
+                let total_duration = start_time.elapsed();
+                tracing::error!("eBay get_return_policies error after {:?}: {:?}", total_duration, e);
+                Err(raw_error("get_return_policies", &format!("marketplace_id {}", marketplace_id), e))
             }
         }
     }
 
+    /// Check that the policies an offer references exist
+    /// 
+    /// Fetches the seller's fulfillment, payment and return policies for
+    /// `marketplace` concurrently and reports the IDs that are not among them, so a
+    /// bad reference is caught before `publish_offer` fails on it.
+    /// 
+    /// # Arguments
+    /// * `fulfillment_id` - Fulfillment policy ID the offer uses
+    /// * `payment_id` - Payment policy ID the offer uses
+    /// * `return_id` - Return policy ID the offer uses
+    /// * `marketplace` - Marketplace the offer is listed on
+    pub async fn validate_policy_references(
+        &self,
+        fulfillment_id: &str,
+        payment_id: &str,
+        return_id: &str,
+        marketplace: Marketplace,
+    ) -> HermesResult<PolicyValidation> {
+        let (fulfillment, payment, returns) = tokio::try_join!(
+            self.get_fulfillment_policies(marketplace.as_str()),
+            self.get_payment_policies(marketplace.as_str()),
+            self.get_return_policies(marketplace.as_str()),
+        )?;
+
+        let references = [
+            (PolicyKind::Fulfillment, fulfillment_id, fulfillment.iter().any(|p| p.fulfillment_policy_id.as_deref() == Some(fulfillment_id))),
+            (PolicyKind::Payment, payment_id, payment.iter().any(|p| p.payment_policy_id.as_deref() == Some(payment_id))),
+            (PolicyKind::Return, return_id, returns.iter().any(|p| p.return_policy_id.as_deref() == Some(return_id))),
+        ];
+        let missing = references
+            .into_iter()
+            .filter(|(_, _, exists)| !exists)
+            .map(|(kind, policy_id, _)| MissingPolicy { kind, policy_id: policy_id.to_string() })
+            .collect();
+        Ok(PolicyValidation { missing })
+    }
+
     /// Get advertising eligibility
     /// 
-    /// Retrieves the seller's eligibility status for eBay advertising programs.
+    /// Retrieves the seller's eligibility status for eBay advertising programs, one
+    /// entry per program.
     /// 
     /// # Arguments
     /// * `marketplace_id` - The marketplace ID (e.g., "EBAY_US")
@@ -491,7 +750,7 @@ impl AccountClient {
         &self,
         marketplace_id: &str,
         program_types: Option<&str>,
-    ) -> HermesResult<SellerEligibilityMultiProgramResponse> {
+    ) -> HermesResult<Vec<SellerEligibilityResponse>> {
         let start_time = std::time::Instant::now();
         
         // Get access token
@@ -500,23 +759,28 @@ impl AccountClient {
         let token_duration = token_start.elapsed();
         tracing::info!("OAuth token request for get_advertising_eligibility: {:?}", token_duration);
         
-        // Set up configuration
-        let mut config = AccountConfiguration::new();
-        config.base_path = if self.config.sandbox {
-            "https://api.sandbox.ebay.com/sell/account/v1".to_string()
-        } else {
-            "https://api.ebay.com/sell/account/v1".to_string()
-        };
-        config.oauth_access_token = Some(token);
+        let url = format!("{}/sell/account/v1/advertising_eligibility", self.config.base_url());
+        let http = self.config.http_client();
+        
+        // Fail fast while this API's circuit breaker is open
+        let probe = self.config.check_circuit("sell.account")?;
         
-        // Call the eBay SDK
+        let marketplace_id = normalize_marketplace(marketplace_id, MarketplaceStyle::Underscore);
+        
+        // Call the eBay API
         let ebay_start = std::time::Instant::now();
-        let result = hermes_ebay_sell_account::apis::advertising_eligibility_api::get_advertising_eligibility(
-            &config,
-            marketplace_id,
-            program_types,
-        ).await;
+        let result = self.config.retrying("sell.account", || {
+            self.config.send_json::<SellerEligibilityMultiProgramResponse>(
+                http
+                    .get(&url)
+                    .bearer_auth(&token)
+                    .header("X-EBAY-C-MARKETPLACE-ID", marketplace_id.as_str())
+                    .query(&[("program_types", program_types)]),
+            )
+        }).await;
         let ebay_duration = ebay_start.elapsed();
+        record_api_call("sell.account", "get_advertising_eligibility", &result, ebay_duration);
+        self.config.record_circuit("sell.account", &probe, &result);
         tracing::info!("eBay get_advertising_eligibility API call: {:?}", ebay_duration);
         
         match result {
@@ -524,13 +788,123 @@ impl AccountClient {
                 let total_duration = start_time.elapsed();
                 let our_processing = total_duration - token_duration - ebay_duration;
                 tracing::info!("get_advertising_eligibility total: {:?} | Our processing: {:?}", total_duration, our_processing);
-                Ok(response)
+                Ok(response.advertising_eligibility.unwrap_or_default())
             },
             Err(e) => {
                 let total_duration = start_time.elapsed();
                 tracing::error!("eBay get_advertising_eligibility error after {:?}: {:?}", total_duration, e);
-                Err(HermesError::ApiRequest(format!("eBay get_advertising_eligibility failed: {:?}", e)))
+                Err(raw_error("get_advertising_eligibility", &format!("marketplace_id {}", marketplace_id), e))
             }
         }
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ebay::test_support::mock_ebay;
+    use wiremock::matchers::{method, path, query_param};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    #[tokio::test]
+    async fn list_getters_return_empty_vec_for_missing_lists() {
+        let (server, config) = mock_ebay().await;
+        for endpoint in ["/sell/account/v1/kyc", "/sell/account/v1/advertising_eligibility", "/sell/account/v1/sales_tax"] {
+            Mock::given(method("GET"))
+                .and(path(endpoint))
+                .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({})))
+                .mount(&server)
+                .await;
+        }
+
+        let client = AccountClient::new(config).unwrap();
+        assert!(client.get_kyc().await.unwrap().is_empty());
+        assert!(client.get_advertising_eligibility("EBAY_US", None).await.unwrap().is_empty());
+        assert!(client.get_sales_taxes("US").await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn validate_policy_references_reports_missing_ids() {
+        let (server, config) = mock_ebay().await;
+        for (kind, body) in [
+            ("fulfillment", serde_json::json!({ "fulfillmentPolicies": [{ "fulfillmentPolicyId": "FP-1" }] })),
+            ("payment", serde_json::json!({ "paymentPolicies": [{ "paymentPolicyId": "PP-1" }, { "paymentPolicyId": "PP-2" }] })),
+            ("return", serde_json::json!({ "returnPolicies": [{ "returnPolicyId": "RP-1" }] })),
+        ] {
+            Mock::given(method("GET"))
+                .and(path(format!("/sell/account/v1/{}_policy", kind)))
+                .and(query_param("marketplace_id", "EBAY_GB"))
+                .respond_with(ResponseTemplate::new(200).set_body_json(body))
+                .mount(&server)
+                .await;
+        }
+
+        let client = AccountClient::new(config).unwrap();
+        let validation = client.validate_policy_references("FP-1", "PP-2", "RP-9", Marketplace::EbayGb).await.unwrap();
+        assert!(!validation.is_valid());
+        assert_eq!(validation.missing, [MissingPolicy { kind: PolicyKind::Return, policy_id: "RP-9".to_string() }]);
+
+        let validation = client.validate_policy_references("FP-1", "PP-1", "RP-1", Marketplace::EbayGb).await.unwrap();
+        assert!(validation.is_valid());
+    }
+
+    async fn mount_inventory(server: &MockServer) {
+        Mock::given(method("GET"))
+            .and(path("/sell/inventory/v1/inventory_item"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "total": 2,
+                "inventoryItems": [{ "sku": "MUG-001" }, { "sku": "MUG-002" }]
+            })))
+            .mount(server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/sell/inventory/v1/offer"))
+            .and(query_param("sku", "MUG-001"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "offers": [{ "offerId": "5001", "listingPolicies": { "returnPolicyId": "RP-1" } }]
+            })))
+            .mount(server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/sell/inventory/v1/offer"))
+            .and(query_param("sku", "MUG-002"))
+            .respond_with(ResponseTemplate::new(404))
+            .mount(server)
+            .await;
+    }
+
+    #[tokio::test]
+    async fn policy_in_use_is_not_deleted() {
+        let (server, config) = mock_ebay().await;
+        mount_inventory(&server).await;
+        Mock::given(method("DELETE"))
+            .and(path("/sell/account/v1/return_policy/RP-1"))
+            .respond_with(ResponseTemplate::new(204))
+            .expect(0)
+            .mount(&server)
+            .await;
+
+        let client = AccountClient::new(config).unwrap();
+        assert!(client.is_policy_in_use("RP-1").await.unwrap());
+        let err = client.delete_return_policy_safe("RP-1", false).await.unwrap_err();
+        assert!(matches!(err, HermesError::PolicyInUse { ref offer_ids, .. } if offer_ids == &["5001"]));
+        assert_eq!(err.to_string(), "Policy RP-1 is in use by offers: 5001");
+    }
+
+    #[tokio::test]
+    async fn unused_policy_is_deleted_unless_dry_run() {
+        let (server, config) = mock_ebay().await;
+        mount_inventory(&server).await;
+        Mock::given(method("DELETE"))
+            .and(path("/sell/account/v1/return_policy/RP-2"))
+            .respond_with(ResponseTemplate::new(204))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = AccountClient::new(config).unwrap();
+        assert!(!client.is_policy_in_use("RP-2").await.unwrap());
+        client.delete_return_policy_safe("RP-2", true).await.unwrap();
+        client.delete_return_policy_safe("RP-2", false).await.unwrap();
+    }
+}
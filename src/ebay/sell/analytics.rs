@@ -1,14 +1,50 @@
 use crate::config::EbayConfig;
-use crate::error::{HermesError, HermesResult};
+use crate::error::HermesResult;
+use crate::ebay::api_error::raw_error;
+use crate::ebay::telemetry::record_api_call;
 use crate::ebay::auth::EbayAuth;
+use crate::ebay::marketplace::{normalize_marketplace, MarketplaceStyle};
+use futures::try_join;
 use std::sync::Arc;
 
 // Import eBay Sell Analytics SDK models and APIs
 use hermes_ebay_sell_analytics::models::{
-    GetCustomerServiceMetricResponse, FindSellerStandardsProfilesResponse, 
-    StandardsProfile, Report,
+    FindSellerStandardsProfilesResponse, GetCustomerServiceMetricResponse, StandardsProfile, Report,
 };
-use hermes_ebay_sell_analytics::apis::configuration::Configuration as AnalyticsConfiguration;
+use hermes_ebay_sell_analytics::apis::urlencode;
+
+/// Direction a seller metric is heading, from the seller's point of view
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TrendDirection {
+    Improving,
+    Stable,
+    Worsening,
+    /// One of the evaluations had no value
+    Unknown,
+}
+
+/// Current and projected values of a seller metric
+#[derive(Debug, Clone, PartialEq)]
+pub struct MetricTrend {
+    pub current: Option<f64>,
+    pub projected: Option<f64>,
+    pub direction: TrendDirection,
+}
+
+/// The highest `RATE` metric across all dimensions of a response
+///
+/// Rates are reported per dimension (e.g. per shipping region); the highest one is
+/// the closest to breaching seller standards.
+fn highest_rate(response: &GetCustomerServiceMetricResponse) -> Option<f64> {
+    response
+        .dimension_metrics
+        .iter()
+        .flatten()
+        .flat_map(|dimension| dimension.metrics.iter().flatten())
+        .filter(|metric| metric.metric_key.as_deref() == Some("RATE"))
+        .filter_map(|metric| metric.value.as_deref()?.parse::<f64>().ok())
+        .reduce(f64::max)
+}
 
 /// eBay Sell Analytics API client for seller performance metrics and reports
 /// 
@@ -52,24 +88,22 @@ impl AnalyticsClient {
         let token_duration = token_start.elapsed();
         tracing::info!("OAuth token request for get_customer_service_metric: {:?}", token_duration);
         
-        // Set up configuration
-        let mut config = AnalyticsConfiguration::new();
-        config.base_path = if self.config.sandbox {
-            "https://api.sandbox.ebay.com/sell/analytics/v1".to_string()
-        } else {
-            "https://api.ebay.com/sell/analytics/v1".to_string()
-        };
-        config.oauth_access_token = Some(token);
+        let url = format!("{}/sell/analytics/v1/customer_service_metric/{}/{}", self.config.base_url(), urlencode(metric_type), urlencode(evaluation_type));
+        let http = self.config.http_client();
+        
+        // Fail fast while this API's circuit breaker is open
+        let probe = self.config.check_circuit("sell.analytics")?;
+        
+        let marketplace_id = normalize_marketplace(marketplace_id, MarketplaceStyle::Underscore);
         
-        // Call the eBay SDK
+        // Call the eBay API
         let ebay_start = std::time::Instant::now();
-        let result = hermes_ebay_sell_analytics::apis::customer_service_metric_api::get_customer_service_metric(
-            &config,
-            &metric_type,
-            marketplace_id,
-            &evaluation_type,
-        ).await;
+        let result = self.config.retrying("sell.analytics", || {
+            self.config.send_json(http.get(&url).bearer_auth(&token).query(&[("evaluation_marketplace_id", &marketplace_id)]))
+        }).await;
         let ebay_duration = ebay_start.elapsed();
+        record_api_call("sell.analytics", "get_customer_service_metric", &result, ebay_duration);
+        self.config.record_circuit("sell.analytics", &probe, &result);
         tracing::info!("eBay get_customer_service_metric API call: {:?}", ebay_duration);
         
         match result {
@@ -82,7 +116,7 @@ impl AnalyticsClient {
             Err(e) => {
                 let total_duration = start_time.elapsed();
                 tracing::error!("eBay get_customer_service_metric error after {:?}: {:?}", total_duration, e);
-                Err(HermesError::ApiRequest(format!("eBay get_customer_service_metric failed: {:?}", e)))
+                Err(raw_error("get_customer_service_metric", &format!("metric_type {}, marketplace_id {}", metric_type, marketplace_id), e))
             }
         }
     }
@@ -91,7 +125,8 @@ impl AnalyticsClient {
     /// 
     /// Retrieves all available seller standards profiles for the authenticated seller,
     /// showing compliance status across different programs and evaluation cycles.
-    pub async fn find_seller_standards_profiles(&self) -> HermesResult<FindSellerStandardsProfilesResponse> {
+    /// Empty when eBay returns no profiles.
+    pub async fn find_seller_standards_profiles(&self) -> HermesResult<Vec<StandardsProfile>> {
         let start_time = std::time::Instant::now();
         
         // Get access token
@@ -100,19 +135,20 @@ impl AnalyticsClient {
         let token_duration = token_start.elapsed();
         tracing::info!("OAuth token request for find_seller_standards_profiles: {:?}", token_duration);
         
-        // Set up configuration
-        let mut config = AnalyticsConfiguration::new();
-        config.base_path = if self.config.sandbox {
-            "https://api.sandbox.ebay.com/sell/analytics/v1".to_string()
-        } else {
-            "https://api.ebay.com/sell/analytics/v1".to_string()
-        };
-        config.oauth_access_token = Some(token);
+        let url = format!("{}/sell/analytics/v1/seller_standards_profile", self.config.base_url());
+        let http = self.config.http_client();
+        
+        // Fail fast while this API's circuit breaker is open
+        let probe = self.config.check_circuit("sell.analytics")?;
         
-        // Call the eBay SDK
+        // Call the eBay API
         let ebay_start = std::time::Instant::now();
-        let result = hermes_ebay_sell_analytics::apis::seller_standards_profile_api::find_seller_standards_profiles(&config).await;
+        let result = self.config.retrying("sell.analytics", || {
+            self.config.send_json::<FindSellerStandardsProfilesResponse>(http.get(&url).bearer_auth(&token))
+        }).await;
         let ebay_duration = ebay_start.elapsed();
+        record_api_call("sell.analytics", "find_seller_standards_profiles", &result, ebay_duration);
+        self.config.record_circuit("sell.analytics", &probe, &result);
         tracing::info!("eBay find_seller_standards_profiles API call: {:?}", ebay_duration);
         
         match result {
@@ -120,12 +156,12 @@ impl AnalyticsClient {
                 let total_duration = start_time.elapsed();
                 let our_processing = total_duration - token_duration - ebay_duration;
                 tracing::info!("find_seller_standards_profiles total: {:?} | Our processing: {:?}", total_duration, our_processing);
-                Ok(response)
+                Ok(response.standards_profiles.unwrap_or_default())
             },
             Err(e) => {
                 let total_duration = start_time.elapsed();
                 tracing::error!("eBay find_seller_standards_profiles error after {:?}: {:?}", total_duration, e);
-                Err(HermesError::ApiRequest(format!("eBay find_seller_standards_profiles failed: {:?}", e)))
+                Err(raw_error("find_seller_standards_profiles", "seller standards profiles", e))
             }
         }
     }
@@ -151,23 +187,20 @@ impl AnalyticsClient {
         let token_duration = token_start.elapsed();
         tracing::info!("OAuth token request for get_seller_standards_profile: {:?}", token_duration);
         
-        // Set up configuration
-        let mut config = AnalyticsConfiguration::new();
-        config.base_path = if self.config.sandbox {
-            "https://api.sandbox.ebay.com/sell/analytics/v1".to_string()
-        } else {
-            "https://api.ebay.com/sell/analytics/v1".to_string()
-        };
-        config.oauth_access_token = Some(token);
+        let url = format!("{}/sell/analytics/v1/seller_standards_profile/{}/{}", self.config.base_url(), urlencode(program), urlencode(cycle));
+        let http = self.config.http_client();
+        
+        // Fail fast while this API's circuit breaker is open
+        let probe = self.config.check_circuit("sell.analytics")?;
         
-        // Call the eBay SDK
+        // Call the eBay API
         let ebay_start = std::time::Instant::now();
-        let result = hermes_ebay_sell_analytics::apis::seller_standards_profile_api::get_seller_standards_profile(
-            &config,
-            cycle,
-            program,
-        ).await;
+        let result = self.config.retrying("sell.analytics", || {
+            self.config.send_json(http.get(&url).bearer_auth(&token))
+        }).await;
         let ebay_duration = ebay_start.elapsed();
+        record_api_call("sell.analytics", "get_seller_standards_profile", &result, ebay_duration);
+        self.config.record_circuit("sell.analytics", &probe, &result);
         tracing::info!("eBay get_seller_standards_profile API call: {:?}", ebay_duration);
         
         match result {
@@ -180,7 +213,7 @@ impl AnalyticsClient {
             Err(e) => {
                 let total_duration = start_time.elapsed();
                 tracing::error!("eBay get_seller_standards_profile error after {:?}: {:?}", total_duration, e);
-                Err(HermesError::ApiRequest(format!("eBay get_seller_standards_profile failed: {:?}", e)))
+                Err(raw_error("get_seller_standards_profile", &format!("program {}, cycle {}", program, cycle), e))
             }
         }
     }
@@ -210,25 +243,27 @@ impl AnalyticsClient {
         let token_duration = token_start.elapsed();
         tracing::info!("OAuth token request for get_traffic_report: {:?}", token_duration);
         
-        // Set up configuration
-        let mut config = AnalyticsConfiguration::new();
-        config.base_path = if self.config.sandbox {
-            "https://api.sandbox.ebay.com/sell/analytics/v1".to_string()
-        } else {
-            "https://api.ebay.com/sell/analytics/v1".to_string()
-        };
-        config.oauth_access_token = Some(token);
+        let url = format!("{}/sell/analytics/v1/traffic_report", self.config.base_url());
+        let http = self.config.http_client();
+        
+        // Fail fast while this API's circuit breaker is open
+        let probe = self.config.check_circuit("sell.analytics")?;
         
-        // Call the eBay SDK
+        // Call the eBay API
         let ebay_start = std::time::Instant::now();
-        let result = hermes_ebay_sell_analytics::apis::traffic_report_api::get_traffic_report(
-            &config,
-            dimension,
-            filter,
-            metric,
-            sort,
-        ).await;
+        let result = self.config.retrying("sell.analytics", || {
+            self.config.send_json(
+                http.get(&url).bearer_auth(&token).query(&[
+                    ("dimension", dimension),
+                    ("filter", filter),
+                    ("metric", metric),
+                    ("sort", sort),
+                ]),
+            )
+        }).await;
         let ebay_duration = ebay_start.elapsed();
+        record_api_call("sell.analytics", "get_traffic_report", &result, ebay_duration);
+        self.config.record_circuit("sell.analytics", &probe, &result);
         tracing::info!("eBay get_traffic_report API call: {:?}", ebay_duration);
         
         match result {
@@ -241,7 +276,7 @@ impl AnalyticsClient {
             Err(e) => {
                 let total_duration = start_time.elapsed();
                 tracing::error!("eBay get_traffic_report error after {:?}: {:?}", total_duration, e);
-                Err(HermesError::ApiRequest(format!("eBay get_traffic_report failed: {:?}", e)))
+                Err(raw_error("get_traffic_report", &format!("dimension {}, metric {}", dimension.unwrap_or("none"), metric.unwrap_or("none")), e))
             }
         }
     }
@@ -257,4 +292,90 @@ impl AnalyticsClient {
     pub async fn get_case_resolution_metrics(&self, marketplace_id: &str) -> HermesResult<GetCustomerServiceMetricResponse> {
         self.get_customer_service_metric("CASE_RESOLUTION", marketplace_id, "CURRENT").await
     }
-}
\ No newline at end of file
+
+    /// Compare the current defect rate with eBay's projection
+    /// 
+    /// Fetches the `CURRENT` and `PROJECTED` evaluations concurrently. A projected
+    /// rate above the current one means the seller is heading toward a standards
+    /// breach.
+    /// 
+    /// # Arguments
+    /// * `marketplace_id` - The marketplace ID (e.g., "EBAY_US")
+    pub async fn defect_rate_trend(&self, marketplace_id: &str) -> HermesResult<MetricTrend> {
+        let (current, projected) = try_join!(
+            self.get_customer_service_metric("DEFECT_RATE", marketplace_id, "CURRENT"),
+            self.get_customer_service_metric("DEFECT_RATE", marketplace_id, "PROJECTED"),
+        )?;
+        let current = highest_rate(&current);
+        let projected = highest_rate(&projected);
+
+        let direction = match (current, projected) {
+            (Some(current), Some(projected)) if projected > current => TrendDirection::Worsening,
+            (Some(current), Some(projected)) if projected < current => TrendDirection::Improving,
+            (Some(_), Some(_)) => TrendDirection::Stable,
+            _ => TrendDirection::Unknown,
+        };
+        Ok(MetricTrend { current, projected, direction })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ebay::test_support::mock_ebay;
+    use wiremock::matchers::{method, path, query_param};
+    use wiremock::{Mock, ResponseTemplate};
+
+    #[tokio::test]
+    async fn find_seller_standards_profiles_is_empty_without_profiles() {
+        let (server, config) = mock_ebay().await;
+        Mock::given(method("GET"))
+            .and(path("/sell/analytics/v1/seller_standards_profile"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({})))
+            .mount(&server)
+            .await;
+
+        let client = AnalyticsClient::new(config).unwrap();
+        assert!(client.find_seller_standards_profiles().await.unwrap().is_empty());
+    }
+
+    fn metric_response(evaluation_type: &str, rates: &[&str]) -> serde_json::Value {
+        let dimensions: Vec<_> = rates
+            .iter()
+            .map(|rate| serde_json::json!({
+                "metrics": [
+                    { "metricKey": "COUNT", "value": "3" },
+                    { "metricKey": "RATE", "value": rate }
+                ]
+            }))
+            .collect();
+        serde_json::json!({
+            "evaluationCycle": { "evaluationType": evaluation_type },
+            "marketplaceId": "EBAY_US",
+            "dimensionMetrics": dimensions
+        })
+    }
+
+    #[tokio::test]
+    async fn defect_rate_trend_combines_current_and_projected() {
+        let (server, config) = mock_ebay().await;
+        Mock::given(method("GET"))
+            .and(path("/sell/analytics/v1/customer_service_metric/DEFECT_RATE/CURRENT"))
+            .and(query_param("evaluation_marketplace_id", "EBAY_US"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(metric_response("CURRENT", &["0.4", "0.9"])))
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/sell/analytics/v1/customer_service_metric/DEFECT_RATE/PROJECTED"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(metric_response("PROJECTED", &["1.7"])))
+            .mount(&server)
+            .await;
+
+        let client = AnalyticsClient::new(config).unwrap();
+        let trend = client.defect_rate_trend("EBAY-US").await.unwrap();
+        assert_eq!(
+            trend,
+            MetricTrend { current: Some(0.9), projected: Some(1.7), direction: TrendDirection::Worsening }
+        );
+    }
+}
@@ -1,13 +1,21 @@
 use crate::config::EbayConfig;
-use crate::error::{HermesError, HermesResult};
+use crate::error::HermesResult;
+use crate::ebay::api_error::raw_error;
+use crate::ebay::telemetry::record_api_call;
 use crate::ebay::auth::EbayAuth;
+use crate::ebay::marketplace::{normalize_marketplace, MarketplaceStyle};
+use futures::stream::{self, StreamExt};
+use std::collections::HashMap;
 use std::sync::Arc;
 
-// Import eBay Sell Compliance SDK models and APIs
+// Import eBay Sell Compliance SDK models
 use hermes_ebay_sell_compliance::models::{
     PagedComplianceViolationCollection, SuppressViolationRequest, ComplianceSummary,
+    ComplianceViolation, ComplianceDetail,
 };
-use hermes_ebay_sell_compliance::apis::configuration::Configuration as ComplianceConfiguration;
+
+/// Maximum number of suppressions issued concurrently by `suppress_violations`
+const MAX_CONCURRENT_SUPPRESSIONS: usize = 5;
 
 /// eBay Sell Compliance API client for listing compliance and violation management
 /// 
@@ -57,27 +65,33 @@ impl ComplianceClient {
         let token_duration = token_start.elapsed();
         tracing::info!("OAuth token request for get_listing_violations: {:?}", token_duration);
         
-        // Set up configuration
-        let mut config = ComplianceConfiguration::new();
-        config.base_path = if self.config.sandbox {
-            "https://api.sandbox.ebay.com/sell/compliance/v1".to_string()
-        } else {
-            "https://api.ebay.com/sell/compliance/v1".to_string()
-        };
-        config.oauth_access_token = Some(token);
+        let url = format!("{}/sell/compliance/v1/listing_violation", self.config.base_url());
+        let http = self.config.http_client();
+        
+        // Fail fast while this API's circuit breaker is open
+        let probe = self.config.check_circuit("sell.compliance")?;
         
-        // Call the eBay SDK
+        let marketplace_id = normalize_marketplace(marketplace_id, MarketplaceStyle::Underscore);
+        
+        // Call the eBay API
         let ebay_start = std::time::Instant::now();
-        let result = hermes_ebay_sell_compliance::apis::listing_violation_api::get_listing_violations(
-            &config,
-            marketplace_id,
-            &compliance_type,
-            offset,
-            listing_id,
-            limit,
-            filter,
-        ).await;
+        let result = self.config.retrying("sell.compliance", || {
+            self.config.send_json(
+                http.get(&url)
+                    .bearer_auth(&token)
+                    .header("X-EBAY-C-MARKETPLACE-ID", &marketplace_id)
+                    .query(&[
+                        ("compliance_type", Some(compliance_type)),
+                        ("offset", offset),
+                        ("listing_id", listing_id),
+                        ("limit", limit),
+                        ("filter", filter),
+                    ]),
+            )
+        }).await;
         let ebay_duration = ebay_start.elapsed();
+        record_api_call("sell.compliance", "get_listing_violations", &result, ebay_duration);
+        self.config.record_circuit("sell.compliance", &probe, &result);
         tracing::info!("eBay get_listing_violations API call: {:?}", ebay_duration);
         
         match result {
@@ -90,7 +104,7 @@ impl ComplianceClient {
             Err(e) => {
                 let total_duration = start_time.elapsed();
                 tracing::error!("eBay get_listing_violations error after {:?}: {:?}", total_duration, e);
-                Err(HermesError::ApiRequest(format!("eBay get_listing_violations failed: {:?}", e)))
+                Err(raw_error("get_listing_violations", &format!("marketplace_id {}", marketplace_id), e))
             }
         }
     }
@@ -108,6 +122,11 @@ impl ComplianceClient {
         marketplace_id: &str,
         suppress_request: &SuppressViolationRequest,
     ) -> HermesResult<()> {
+        // Dry-run mode logs the request instead of sending it
+        if let Some(response) = self.config.skip_in_dry_run("suppress_violation", &(marketplace_id, suppress_request)) {
+            return Ok(response);
+        }
+
         let start_time = std::time::Instant::now();
         
         // Get access token
@@ -116,24 +135,23 @@ impl ComplianceClient {
         let token_duration = token_start.elapsed();
         tracing::info!("OAuth token request for suppress_violation: {:?}", token_duration);
         
-        // Set up configuration
-        let mut config = ComplianceConfiguration::new();
-        config.base_path = if self.config.sandbox {
-            "https://api.sandbox.ebay.com/sell/compliance/v1".to_string()
-        } else {
-            "https://api.ebay.com/sell/compliance/v1".to_string()
-        };
-        config.oauth_access_token = Some(token);
+        let url = format!("{}/sell/compliance/v1/suppress_listing_violation", self.config.base_url());
+        let http = self.config.http_client();
+        
+        // Fail fast while this API's circuit breaker is open
+        let probe = self.config.check_circuit("sell.compliance")?;
         
-        // Call the eBay SDK
+        // Call the eBay API
         let ebay_start = std::time::Instant::now();
-        let result = hermes_ebay_sell_compliance::apis::listing_violation_api::suppress_violation(
-            &config,
-            "application/json",
-            marketplace_id,
-            suppress_request.clone(),
+        let result = self.config.send(
+            http.post(&url)
+                .bearer_auth(&token)
+                .header("X-EBAY-C-MARKETPLACE-ID", normalize_marketplace(marketplace_id, MarketplaceStyle::Underscore))
+                .json(suppress_request),
         ).await;
         let ebay_duration = ebay_start.elapsed();
+        record_api_call("sell.compliance", "suppress_violation", &result, ebay_duration);
+        self.config.record_circuit("sell.compliance", &probe, &result);
         tracing::info!("eBay suppress_violation API call: {:?}", ebay_duration);
         
         match result {
@@ -146,11 +164,38 @@ impl ComplianceClient {
             Err(e) => {
                 let total_duration = start_time.elapsed();
                 tracing::error!("eBay suppress_violation error after {:?}: {:?}", total_duration, e);
-                Err(HermesError::ApiRequest(format!("eBay suppress_violation failed: {:?}", e)))
+                Err(raw_error("suppress_violation", &format!("marketplace_id {}", marketplace_id), e))
             }
         }
     }
 
+    /// Suppress several listing violations at once
+    /// 
+    /// Issues the suppressions concurrently and returns one result per violation,
+    /// in input order, so a failed suppression does not stop the others.
+    /// 
+    /// # Arguments
+    /// * `marketplace_id` - The marketplace ID (e.g., "EBAY_US")
+    /// * `violations` - (listing ID, compliance type) pairs, e.g. ("110039009999", "ASPECTS_ADOPTION")
+    pub async fn suppress_violations(
+        &self,
+        marketplace_id: &str,
+        violations: &[(&str, &str)],
+    ) -> HermesResult<Vec<HermesResult<()>>> {
+        let results = stream::iter(violations)
+            .map(|&(listing_id, compliance_type)| async move {
+                let request = SuppressViolationRequest {
+                    listing_id: Some(listing_id.to_string()),
+                    compliance_type: Some(compliance_type.to_string()),
+                };
+                self.suppress_violation(marketplace_id, &request).await
+            })
+            .buffered(MAX_CONCURRENT_SUPPRESSIONS)
+            .collect()
+            .await;
+        Ok(results)
+    }
+
     /// Get listing violations summary
     /// 
     /// Retrieves a summary of listing violations for the authenticated seller,
@@ -172,23 +217,27 @@ impl ComplianceClient {
         let token_duration = token_start.elapsed();
         tracing::info!("OAuth token request for get_listing_violations_summary: {:?}", token_duration);
         
-        // Set up configuration
-        let mut config = ComplianceConfiguration::new();
-        config.base_path = if self.config.sandbox {
-            "https://api.sandbox.ebay.com/sell/compliance/v1".to_string()
-        } else {
-            "https://api.ebay.com/sell/compliance/v1".to_string()
-        };
-        config.oauth_access_token = Some(token);
+        let url = format!("{}/sell/compliance/v1/listing_violation_summary", self.config.base_url());
+        let http = self.config.http_client();
         
-        // Call the eBay SDK
+        // Fail fast while this API's circuit breaker is open
+        let probe = self.config.check_circuit("sell.compliance")?;
+        
+        let marketplace_id = normalize_marketplace(marketplace_id, MarketplaceStyle::Underscore);
+        
+        // Call the eBay API
         let ebay_start = std::time::Instant::now();
-        let result = hermes_ebay_sell_compliance::apis::listing_violation_summary_api::get_listing_violations_summary(
-            &config,
-            marketplace_id,
-            compliance_type,
-        ).await;
+        let result = self.config.retrying("sell.compliance", || {
+            self.config.send_json(
+                http.get(&url)
+                    .bearer_auth(&token)
+                    .header("X-EBAY-C-MARKETPLACE-ID", &marketplace_id)
+                    .query(&[("compliance_type", compliance_type)]),
+            )
+        }).await;
         let ebay_duration = ebay_start.elapsed();
+        record_api_call("sell.compliance", "get_listing_violations_summary", &result, ebay_duration);
+        self.config.record_circuit("sell.compliance", &probe, &result);
         tracing::info!("eBay get_listing_violations_summary API call: {:?}", ebay_duration);
         
         match result {
@@ -201,7 +250,7 @@ impl ComplianceClient {
             Err(e) => {
                 let total_duration = start_time.elapsed();
                 tracing::error!("eBay get_listing_violations_summary error after {:?}: {:?}", total_duration, e);
-                Err(HermesError::ApiRequest(format!("eBay get_listing_violations_summary failed: {:?}", e)))
+                Err(raw_error("get_listing_violations_summary", &format!("marketplace_id {}", marketplace_id), e))
             }
         }
     }
@@ -241,4 +290,194 @@ impl ComplianceClient {
             None,
         ).await
     }
-}
\ No newline at end of file
+}
+
+/// Convenience accessors for [`ComplianceSummary`]
+pub trait ComplianceSummaryExt {
+    /// Violation counts keyed by compliance type (e.g. "PRODUCT_ADOPTION")
+    ///
+    /// Counts for the same compliance type are summed across marketplaces, giving the
+    /// at-a-glance account health number most seller dashboards display.
+    fn counts_by_type(&self) -> HashMap<String, u64>;
+}
+
+impl ComplianceSummaryExt for ComplianceSummary {
+    fn counts_by_type(&self) -> HashMap<String, u64> {
+        let mut counts = HashMap::new();
+        for summary in self.violation_summaries.iter().flatten() {
+            let Some(compliance_type) = summary.compliance_type.as_ref() else {
+                continue;
+            };
+            let listing_count = summary.listing_count.unwrap_or(0).max(0) as u64;
+            *counts.entry(compliance_type.clone()).or_insert(0) += listing_count;
+        }
+        counts
+    }
+}
+
+/// A listing's compliance violation, flattened for programmatic fixing
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ViolationDetail {
+    pub listing_id: Option<String>,
+    pub sku: Option<String>,
+    pub offer_id: Option<String>,
+    /// Compliance type, e.g. "PRODUCT_ADOPTION"
+    pub compliance_type: Option<String>,
+    /// Individual problems found on the listing
+    pub issues: Vec<ViolationIssue>,
+}
+
+/// One problem within a [`ViolationDetail`]
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ViolationIssue {
+    pub reason_code: Option<String>,
+    pub message: Option<String>,
+    /// SKU of the affected variation, for multi-variation listings
+    pub variation_sku: Option<String>,
+    /// Aspect names with the values eBay suggests for them
+    pub suggested_aspects: Vec<(String, Vec<String>)>,
+    /// eBay catalog product (ePID) the listing should be matched to
+    pub suggested_epid: Option<String>,
+}
+
+/// Convenience accessors for [`ComplianceViolation`]
+pub trait ComplianceViolationExt {
+    /// Listing identifiers, compliance type and each issue with its correction guidance
+    fn details(&self) -> ViolationDetail;
+}
+
+impl ComplianceViolationExt for ComplianceViolation {
+    fn details(&self) -> ViolationDetail {
+        ViolationDetail {
+            listing_id: self.listing_id.clone(),
+            sku: self.sku.clone(),
+            offer_id: self.offer_id.clone(),
+            compliance_type: self.compliance_type.clone(),
+            issues: self.violations.iter().flatten().map(violation_issue).collect(),
+        }
+    }
+}
+
+fn violation_issue(detail: &ComplianceDetail) -> ViolationIssue {
+    let recommendations = detail.corrective_recommendations.as_deref();
+    ViolationIssue {
+        reason_code: detail.reason_code.clone(),
+        message: detail.message.clone(),
+        variation_sku: detail.variation.as_deref().and_then(|variation| variation.sku.clone()),
+        suggested_aspects: recommendations
+            .and_then(|r| r.aspect_recommendations.as_ref())
+            .into_iter()
+            .flatten()
+            .filter_map(|aspect| {
+                Some((
+                    aspect.localized_aspect_name.clone()?,
+                    aspect.suggested_values.clone().unwrap_or_default(),
+                ))
+            })
+            .collect(),
+        suggested_epid: recommendations
+            .and_then(|r| r.product_recommendation.as_deref())
+            .and_then(|product| product.epid.clone()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ebay::test_support::mock_ebay;
+    use crate::error::HermesError;
+    use wiremock::matchers::{body_partial_json, method, path};
+    use wiremock::{Mock, ResponseTemplate};
+
+    #[tokio::test]
+    async fn suppress_violations_reports_each_result() {
+        let (server, config) = mock_ebay().await;
+        Mock::given(method("POST"))
+            .and(path("/sell/compliance/v1/suppress_listing_violation"))
+            .and(body_partial_json(serde_json::json!({ "listingId": "110039009999" })))
+            .respond_with(ResponseTemplate::new(204))
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/sell/compliance/v1/suppress_listing_violation"))
+            .and(body_partial_json(serde_json::json!({ "listingId": "110039008888" })))
+            .respond_with(ResponseTemplate::new(400).set_body_json(serde_json::json!({
+                "errors": [{ "errorId": 220004, "message": "The listing is not eligible for suppression" }]
+            })))
+            .mount(&server)
+            .await;
+
+        let client = ComplianceClient::new(config).unwrap();
+        let violations = [("110039009999", "OUTSIDE_EBAY_BUYING_AND_SELLING"), ("110039008888", "HTTPS")];
+        let results = client.suppress_violations("EBAY_US", &violations).await.unwrap();
+        assert_eq!(results.len(), 2);
+        assert!(results[0].is_ok());
+        assert!(matches!(results[1], Err(HermesError::Api { status: 400, .. })));
+    }
+
+    #[test]
+    fn counts_by_type_sums_across_marketplaces() {
+        let summary: ComplianceSummary = serde_json::from_value(serde_json::json!({
+            "violationSummaries": [
+                { "complianceType": "PRODUCT_ADOPTION", "listingCount": 5, "marketplaceId": "EBAY_US" },
+                { "complianceType": "PRODUCT_ADOPTION", "listingCount": 2, "marketplaceId": "EBAY_GB" },
+                { "complianceType": "OUTSIDE_EBAY_BUYING_AND_SELLING", "listingCount": 1, "marketplaceId": "EBAY_US" },
+                { "listingCount": 9, "marketplaceId": "EBAY_US" }
+            ]
+        }))
+        .unwrap();
+
+        let counts = summary.counts_by_type();
+        assert_eq!(counts.len(), 2);
+        assert_eq!(counts["PRODUCT_ADOPTION"], 7);
+        assert_eq!(counts["OUTSIDE_EBAY_BUYING_AND_SELLING"], 1);
+    }
+
+    #[test]
+    fn counts_by_type_handles_empty_summary() {
+        assert!(ComplianceSummary::default().counts_by_type().is_empty());
+    }
+
+    #[test]
+    fn violation_details_flatten_issues_and_guidance() {
+        let violation: ComplianceViolation = serde_json::from_value(serde_json::json!({
+            "complianceType": "ASPECTS_ADOPTION",
+            "listingId": "110039009999",
+            "sku": "SHIRT-001",
+            "violations": [
+                {
+                    "reasonCode": "MISSING_OR_INVALID_REQUIRED_ASPECTS",
+                    "message": "Size is required",
+                    "complianceState": "OUT_OF_COMPLIANCE",
+                    "variation": { "sku": "SHIRT-001-RED" },
+                    "correctiveRecommendations": {
+                        "aspectRecommendations": [
+                            { "localizedAspectName": "Size", "suggestedValues": ["S", "M", "L"] }
+                        ]
+                    }
+                },
+                {
+                    "reasonCode": "PRODUCT_NOT_MATCHED",
+                    "message": "Match the listing to a catalog product",
+                    "correctiveRecommendations": { "productRecommendation": { "epid": "2254319" } }
+                }
+            ]
+        }))
+        .unwrap();
+
+        let details = violation.details();
+        assert_eq!(details.listing_id.as_deref(), Some("110039009999"));
+        assert_eq!(details.compliance_type.as_deref(), Some("ASPECTS_ADOPTION"));
+        assert_eq!(details.issues.len(), 2);
+
+        let missing_size = &details.issues[0];
+        assert_eq!(missing_size.message.as_deref(), Some("Size is required"));
+        assert_eq!(missing_size.variation_sku.as_deref(), Some("SHIRT-001-RED"));
+        assert_eq!(
+            missing_size.suggested_aspects,
+            [("Size".to_string(), vec!["S".to_string(), "M".to_string(), "L".to_string()])]
+        );
+        assert_eq!(details.issues[1].suggested_epid.as_deref(), Some("2254319"));
+        assert!(details.issues[1].suggested_aspects.is_empty());
+    }
+}
@@ -1,13 +1,97 @@
 use crate::config::EbayConfig;
-use crate::error::{HermesError, HermesResult};
+use crate::error::HermesResult;
+use crate::ebay::api_error::raw_error;
+use crate::ebay::telemetry::record_api_call;
 use crate::ebay::auth::EbayAuth;
+use crate::ebay::marketplace::{normalize_marketplace, Marketplace, MarketplaceStyle};
+use crate::ebay::paginate::paginate;
+use crate::money::parse_decimal;
+use futures::future::try_join_all;
+use futures::StreamExt;
+use rust_decimal::Decimal;
+use std::collections::HashMap;
+use std::io::Write;
+use std::pin::pin;
 use std::sync::Arc;
 
 // Import eBay Sell Finances SDK models and APIs
 use hermes_ebay_sell_finances::models::{
-    Payout, Payouts, SellerFundsSummaryResponse, Transactions,
+    Amount, Payout, Payouts, SellerFundsSummaryResponse, Transaction, Transactions,
 };
-use hermes_ebay_sell_finances::apis::configuration::Configuration as FinancesConfiguration;
+use hermes_ebay_sell_finances::apis::urlencode;
+
+/// Payout status, as used in the `get_payouts` `payoutStatus` filter
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PayoutStatus {
+    /// The payout reached the seller's bank account
+    Succeeded,
+    /// The payout failed, whether or not eBay will retry it
+    Failed,
+    /// The payout was returned by the seller's bank
+    Reversed,
+    /// The payout was initiated but has not completed yet
+    Pending,
+}
+
+impl PayoutStatus {
+    /// The `get_payouts` filter selecting this status
+    pub fn filter(&self) -> &'static str {
+        match self {
+            PayoutStatus::Succeeded => "payoutStatus:{SUCCEEDED}",
+            PayoutStatus::Failed => "payoutStatus:{RETRYABLE_FAILED|TERMINAL_FAILED}",
+            PayoutStatus::Reversed => "payoutStatus:{REVERSED}",
+            PayoutStatus::Pending => "payoutStatus:{INITIATED}",
+        }
+    }
+}
+
+/// Bookkeeping totals for a page of [`Transactions`]
+pub trait TransactionsExt {
+    /// Net amount per currency: credits added, debits (fees, refunds, holds) subtracted
+    fn net_total(&self) -> HashMap<String, Decimal>;
+}
+
+impl TransactionsExt for Transactions {
+    fn net_total(&self) -> HashMap<String, Decimal> {
+        let mut totals = HashMap::new();
+        for transaction in self.transactions.iter().flatten() {
+            let Some((value, currency)) = transaction.amount.as_deref().and_then(amount_value) else {
+                continue;
+            };
+            let value = if transaction.booking_entry.as_deref() == Some("DEBIT") { -value.abs() } else { value };
+            *totals.entry(currency).or_insert(Decimal::ZERO) += value;
+        }
+        totals
+    }
+}
+
+/// Bookkeeping totals for a page of [`Payouts`]
+pub trait PayoutsExt {
+    /// Amount per currency of the payouts that reached the seller's bank (status `SUCCEEDED`)
+    fn total_paid_out(&self) -> HashMap<String, Decimal>;
+}
+
+impl PayoutsExt for Payouts {
+    fn total_paid_out(&self) -> HashMap<String, Decimal> {
+        let mut totals = HashMap::new();
+        let succeeded = self
+            .payouts
+            .iter()
+            .flatten()
+            .filter(|payout| payout.payout_status.as_deref() == Some("SUCCEEDED"));
+        for payout in succeeded {
+            if let Some((value, currency)) = payout.amount.as_deref().and_then(amount_value) {
+                *totals.entry(currency).or_insert(Decimal::ZERO) += value;
+            }
+        }
+        totals
+    }
+}
+
+/// An amount's value and currency, skipping amounts eBay sent without either
+fn amount_value(amount: &Amount) -> Option<(Decimal, String)> {
+    Some((parse_decimal(amount.value.as_deref())?, amount.currency.clone()?))
+}
 
 /// eBay Sell Finances API client for comprehensive financial transaction management
 /// 
@@ -49,23 +133,27 @@ impl FinancesClient {
         let token_duration = token_start.elapsed();
         tracing::info!("OAuth token request for get_payout: {:?}", token_duration);
         
-        // Set up configuration
-        let mut config = FinancesConfiguration::new();
-        config.base_path = if self.config.sandbox {
-            "https://api.sandbox.ebay.com/sell/finances/v1".to_string()
-        } else {
-            "https://api.ebay.com/sell/finances/v1".to_string()
-        };
-        config.oauth_access_token = Some(token);
+        let url = format!("{}/sell/finances/v1/payout/{}", self.config.base_url(), urlencode(payout_id));
+        let http = self.config.http_client();
+        
+        // Fail fast while this API's circuit breaker is open
+        let probe = self.config.check_circuit("sell.finances")?;
         
-        // Call the eBay SDK
+        let marketplace_id = normalize_marketplace(marketplace_id, MarketplaceStyle::Underscore);
+        
+        // Call the eBay API
         let ebay_start = std::time::Instant::now();
-        let result = hermes_ebay_sell_finances::apis::payout_api::get_payout(
-            &config,
-            marketplace_id,
-            payout_id,
-        ).await;
+        let result = self.config.retrying("sell.finances", || {
+            self.config.send_json(
+                http
+                    .get(&url)
+                    .bearer_auth(&token)
+                    .header("X-EBAY-C-MARKETPLACE-ID", marketplace_id.as_str()),
+            )
+        }).await;
         let ebay_duration = ebay_start.elapsed();
+        record_api_call("sell.finances", "get_payout", &result, ebay_duration);
+        self.config.record_circuit("sell.finances", &probe, &result);
         tracing::info!("eBay get_payout API call: {:?}", ebay_duration);
         
         match result {
@@ -78,7 +166,7 @@ impl FinancesClient {
             Err(e) => {
                 let total_duration = start_time.elapsed();
                 tracing::error!("eBay get_payout error after {:?}: {:?}", total_duration, e);
-                Err(HermesError::ApiRequest(format!("eBay get_payout failed: {:?}", e)))
+                Err(raw_error("get_payout", &format!("payout_id {}", payout_id), e))
             }
         }
     }
@@ -109,26 +197,28 @@ impl FinancesClient {
         let token_duration = token_start.elapsed();
         tracing::info!("OAuth token request for get_payouts: {:?}", token_duration);
         
-        // Set up configuration
-        let mut config = FinancesConfiguration::new();
-        config.base_path = if self.config.sandbox {
-            "https://api.sandbox.ebay.com/sell/finances/v1".to_string()
-        } else {
-            "https://api.ebay.com/sell/finances/v1".to_string()
-        };
-        config.oauth_access_token = Some(token);
+        let url = format!("{}/sell/finances/v1/payout", self.config.base_url());
+        let http = self.config.http_client();
+        
+        // Fail fast while this API's circuit breaker is open
+        let probe = self.config.check_circuit("sell.finances")?;
+        
+        let marketplace_id = normalize_marketplace(marketplace_id, MarketplaceStyle::Underscore);
         
-        // Call the eBay SDK
+        // Call the eBay API
         let ebay_start = std::time::Instant::now();
-        let result = hermes_ebay_sell_finances::apis::payout_api::get_payouts(
-            &config,
-            marketplace_id,
-            filter,
-            limit,
-            offset,
-            sort,
-        ).await;
+        let result = self.config.retrying("sell.finances", || {
+            self.config.send_json(
+                http
+                    .get(&url)
+                    .bearer_auth(&token)
+                    .header("X-EBAY-C-MARKETPLACE-ID", marketplace_id.as_str())
+                    .query(&[("filter", filter), ("limit", limit), ("offset", offset), ("sort", sort)]),
+            )
+        }).await;
         let ebay_duration = ebay_start.elapsed();
+        record_api_call("sell.finances", "get_payouts", &result, ebay_duration);
+        self.config.record_circuit("sell.finances", &probe, &result);
         tracing::info!("eBay get_payouts API call: {:?}", ebay_duration);
         
         match result {
@@ -141,11 +231,22 @@ impl FinancesClient {
             Err(e) => {
                 let total_duration = start_time.elapsed();
                 tracing::error!("eBay get_payouts error after {:?}: {:?}", total_duration, e);
-                Err(HermesError::ApiRequest(format!("eBay get_payouts failed: {:?}", e)))
+                Err(raw_error("get_payouts", &format!("marketplace_id {}", marketplace_id), e))
             }
         }
     }
 
+    /// Get payouts with a given status
+    /// 
+    /// Returns the first page of matching payouts, e.g. failed payouts for a dashboard.
+    /// 
+    /// # Arguments
+    /// * `marketplace_id` - The marketplace ID (e.g., "EBAY_US")
+    /// * `status` - The payout status to select
+    pub async fn get_payouts_by_status(&self, marketplace_id: &str, status: PayoutStatus) -> HermesResult<Payouts> {
+        self.get_payouts(marketplace_id, Some(status.filter()), None, None, None).await
+    }
+
     /// Get seller funds summary
     /// 
     /// Retrieves a summary of the seller's available funds and financial status.
@@ -164,22 +265,27 @@ impl FinancesClient {
         let token_duration = token_start.elapsed();
         tracing::info!("OAuth token request for get_seller_funds_summary: {:?}", token_duration);
         
-        // Set up configuration
-        let mut config = FinancesConfiguration::new();
-        config.base_path = if self.config.sandbox {
-            "https://api.sandbox.ebay.com/sell/finances/v1".to_string()
-        } else {
-            "https://api.ebay.com/sell/finances/v1".to_string()
-        };
-        config.oauth_access_token = Some(token);
+        let url = format!("{}/sell/finances/v1/seller_funds_summary", self.config.base_url());
+        let http = self.config.http_client();
+        
+        // Fail fast while this API's circuit breaker is open
+        let probe = self.config.check_circuit("sell.finances")?;
+        
+        let marketplace_id = normalize_marketplace(marketplace_id, MarketplaceStyle::Underscore);
         
-        // Call the eBay SDK
+        // Call the eBay API
         let ebay_start = std::time::Instant::now();
-        let result = hermes_ebay_sell_finances::apis::seller_funds_summary_api::get_seller_funds_summary(
-            &config,
-            marketplace_id,
-        ).await;
+        let result = self.config.retrying("sell.finances", || {
+            self.config.send_json(
+                http
+                    .get(&url)
+                    .bearer_auth(&token)
+                    .header("X-EBAY-C-MARKETPLACE-ID", marketplace_id.as_str()),
+            )
+        }).await;
         let ebay_duration = ebay_start.elapsed();
+        record_api_call("sell.finances", "get_seller_funds_summary", &result, ebay_duration);
+        self.config.record_circuit("sell.finances", &probe, &result);
         tracing::info!("eBay get_seller_funds_summary API call: {:?}", ebay_duration);
         
         match result {
@@ -192,11 +298,41 @@ impl FinancesClient {
             Err(e) => {
                 let total_duration = start_time.elapsed();
                 tracing::error!("eBay get_seller_funds_summary error after {:?}: {:?}", total_duration, e);
-                Err(HermesError::ApiRequest(format!("eBay get_seller_funds_summary failed: {:?}", e)))
+                Err(raw_error("get_seller_funds_summary", &format!("marketplace_id {}", marketplace_id), e))
             }
         }
     }
 
+    /// Total available funds across marketplaces, per currency
+    /// 
+    /// Fetches the funds summary of every marketplace concurrently and sums the
+    /// available funds of marketplaces that pay out in the same currency. Amounts in
+    /// different currencies are kept apart rather than converted, and unparseable
+    /// amounts are skipped with a warning.
+    /// 
+    /// # Arguments
+    /// * `marketplaces` - Marketplaces the seller is active on
+    pub async fn total_available_funds(
+        &self,
+        marketplaces: &[Marketplace],
+    ) -> HermesResult<HashMap<String, Decimal>> {
+        let summaries = try_join_all(
+            marketplaces
+                .iter()
+                .map(|marketplace| self.get_seller_funds_summary(marketplace.as_str())),
+        )
+        .await?;
+
+        let mut totals = HashMap::new();
+        for available in summaries.iter().filter_map(|summary| summary.available_funds.as_deref()) {
+            let (Some(currency), Some(value)) = (available.currency.as_ref(), parse_decimal(available.value.as_deref())) else {
+                continue;
+            };
+            *totals.entry(currency.clone()).or_insert(Decimal::ZERO) += value;
+        }
+        Ok(totals)
+    }
+
     /// Get transactions
     /// 
     /// Retrieves a list of transactions with optional filtering, pagination, and sorting.
@@ -223,26 +359,28 @@ impl FinancesClient {
         let token_duration = token_start.elapsed();
         tracing::info!("OAuth token request for get_transactions: {:?}", token_duration);
         
-        // Set up configuration
-        let mut config = FinancesConfiguration::new();
-        config.base_path = if self.config.sandbox {
-            "https://api.sandbox.ebay.com/sell/finances/v1".to_string()
-        } else {
-            "https://api.ebay.com/sell/finances/v1".to_string()
-        };
-        config.oauth_access_token = Some(token);
+        let url = format!("{}/sell/finances/v1/transaction", self.config.base_url());
+        let http = self.config.http_client();
+        
+        // Fail fast while this API's circuit breaker is open
+        let probe = self.config.check_circuit("sell.finances")?;
+        
+        let marketplace_id = normalize_marketplace(marketplace_id, MarketplaceStyle::Underscore);
         
-        // Call the eBay SDK
+        // Call the eBay API
         let ebay_start = std::time::Instant::now();
-        let result = hermes_ebay_sell_finances::apis::transaction_api::get_transactions(
-            &config,
-            marketplace_id,
-            filter,
-            limit,
-            offset,
-            sort,
-        ).await;
+        let result = self.config.retrying("sell.finances", || {
+            self.config.send_json(
+                http
+                    .get(&url)
+                    .bearer_auth(&token)
+                    .header("X-EBAY-C-MARKETPLACE-ID", marketplace_id.as_str())
+                    .query(&[("filter", filter), ("limit", limit), ("offset", offset), ("sort", sort)]),
+            )
+        }).await;
         let ebay_duration = ebay_start.elapsed();
+        record_api_call("sell.finances", "get_transactions", &result, ebay_duration);
+        self.config.record_circuit("sell.finances", &probe, &result);
         tracing::info!("eBay get_transactions API call: {:?}", ebay_duration);
         
         match result {
@@ -255,13 +393,229 @@ impl FinancesClient {
             Err(e) => {
                 let total_duration = start_time.elapsed();
                 tracing::error!("eBay get_transactions error after {:?}: {:?}", total_duration, e);
-                Err(HermesError::ApiRequest(format!("eBay get_transactions failed: {:?}", e)))
+                Err(raw_error("get_transactions", &format!("marketplace_id {}", marketplace_id), e))
             }
         }
     }
 
+    /// Export every matching transaction as CSV
+    /// 
+    /// Writes a header row and one row per transaction with the columns `date`,
+    /// `type`, `order_id`, `amount`, `currency` and `fee`, fetching pages as it goes.
+    /// Debits are written as negative amounts so the column sums to the net. Returns
+    /// the number of transaction rows written.
+    /// 
+    /// # Arguments
+    /// * `marketplace_id` - The marketplace ID (e.g., "EBAY_US")
+    /// * `filter` - Optional filter criteria, as for `get_transactions`
+    /// * `writer` - Destination, e.g. a `std::fs::File`
+    pub async fn export_transactions_csv<W: Write>(
+        &self,
+        marketplace_id: &str,
+        filter: Option<&str>,
+        mut writer: W,
+    ) -> HermesResult<usize> {
+        writeln!(writer, "date,type,order_id,amount,currency,fee")?;
+
+        let mut written = 0;
+        let mut transactions = pin!(paginate(TRANSACTION_PAGE_SIZE, |offset| {
+            let limit = TRANSACTION_PAGE_SIZE.to_string();
+            async move {
+                self.get_transactions(marketplace_id, filter, Some(&limit), Some(&offset.to_string()), None).await
+            }
+        }));
+        while let Some(transaction) = transactions.next().await {
+            writeln!(writer, "{}", transaction_csv_row(&transaction?))?;
+            written += 1;
+        }
+        writer.flush()?;
+        Ok(written)
+    }
+
     // TODO: Additional methods to implement:
     // - get_payout_summary
     // - get_transaction_summary  
     // - get_transfer
-}
\ No newline at end of file
+}
+
+/// Transactions requested per page when exporting
+const TRANSACTION_PAGE_SIZE: usize = 200;
+
+/// One `export_transactions_csv` row
+fn transaction_csv_row(transaction: &Transaction) -> String {
+    let amount = transaction.amount.as_deref();
+    let value = amount.and_then(|amount| amount.value.as_deref()).unwrap_or_default();
+    let value = match transaction.booking_entry.as_deref() {
+        Some("DEBIT") if !value.is_empty() && !value.starts_with('-') => format!("-{}", value),
+        _ => value.to_string(),
+    };
+    let fee = transaction.total_fee_amount.as_deref().and_then(|fee| fee.value.as_deref());
+
+    [
+        transaction.transaction_date.as_deref().unwrap_or_default(),
+        transaction.transaction_type.as_deref().unwrap_or_default(),
+        transaction.order_id.as_deref().unwrap_or_default(),
+        &value,
+        amount.and_then(|amount| amount.currency.as_deref()).unwrap_or_default(),
+        fee.unwrap_or_default(),
+    ]
+    .map(csv_field)
+    .join(",")
+}
+
+/// Quote a CSV field when it contains a separator, quote or line break
+fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ebay::test_support::mock_ebay;
+    use wiremock::matchers::{header, method, path, query_param};
+    use wiremock::{Mock, ResponseTemplate};
+
+    async fn mount_funds(server: &wiremock::MockServer, marketplace_id: &str, value: &str, currency: &str) {
+        Mock::given(method("GET"))
+            .and(path("/sell/finances/v1/seller_funds_summary"))
+            .and(header("X-EBAY-C-MARKETPLACE-ID", marketplace_id))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "availableFunds": { "value": value, "currency": currency },
+                "totalFunds": { "value": value, "currency": currency }
+            })))
+            .mount(server)
+            .await;
+    }
+
+    #[tokio::test]
+    async fn export_transactions_csv_writes_header_and_rows() {
+        let (server, config) = mock_ebay().await;
+        Mock::given(method("GET"))
+            .and(path("/sell/finances/v1/transaction"))
+            .and(query_param("offset", "0"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "total": 2,
+                "transactions": [
+                    {
+                        "transactionDate": "2024-06-02T10:00:00.000Z",
+                        "transactionType": "SALE",
+                        "bookingEntry": "CREDIT",
+                        "orderId": "12-34567-89012",
+                        "amount": { "value": "25.98", "currency": "USD" },
+                        "totalFeeAmount": { "value": "3.41", "currency": "USD" }
+                    },
+                    {
+                        "transactionDate": "2024-06-03T09:00:00.000Z",
+                        "transactionType": "NON_SALE_CHARGE",
+                        "bookingEntry": "DEBIT",
+                        "amount": { "value": "1.50", "currency": "USD" },
+                        "transactionMemo": "Promoted listing fee, June"
+                    }
+                ]
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = FinancesClient::new(config).unwrap();
+        let mut csv = Vec::new();
+        let written = client.export_transactions_csv("EBAY_US", None, &mut csv).await.unwrap();
+        assert_eq!(written, 2);
+
+        let csv = String::from_utf8(csv).unwrap();
+        let lines: Vec<&str> = csv.lines().collect();
+        assert_eq!(
+            lines,
+            [
+                "date,type,order_id,amount,currency,fee",
+                "2024-06-02T10:00:00.000Z,SALE,12-34567-89012,25.98,USD,3.41",
+                "2024-06-03T09:00:00.000Z,NON_SALE_CHARGE,,-1.50,USD,",
+            ]
+        );
+        assert_eq!(csv_field("a,\"b\""), "\"a,\"\"b\"\"\"");
+    }
+
+    #[test]
+    fn payout_status_filters() {
+        assert_eq!(PayoutStatus::Succeeded.filter(), "payoutStatus:{SUCCEEDED}");
+        assert_eq!(PayoutStatus::Failed.filter(), "payoutStatus:{RETRYABLE_FAILED|TERMINAL_FAILED}");
+        assert_eq!(PayoutStatus::Reversed.filter(), "payoutStatus:{REVERSED}");
+        assert_eq!(PayoutStatus::Pending.filter(), "payoutStatus:{INITIATED}");
+    }
+
+    #[tokio::test]
+    async fn get_payouts_by_status_sends_filter() {
+        let (server, config) = mock_ebay().await;
+        Mock::given(method("GET"))
+            .and(path("/sell/finances/v1/payout"))
+            .and(query_param("filter", "payoutStatus:{RETRYABLE_FAILED|TERMINAL_FAILED}"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "payouts": [{ "payoutId": "6000012345", "payoutStatus": "TERMINAL_FAILED" }],
+                "total": 1
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = FinancesClient::new(config).unwrap();
+        let payouts = client.get_payouts_by_status("EBAY_US", PayoutStatus::Failed).await.unwrap();
+        assert_eq!(payouts.total, Some(1));
+    }
+
+    #[test]
+    fn net_total_signs_mixed_transaction_types() {
+        let transactions: Transactions = serde_json::from_value(serde_json::json!({
+            "total": 5,
+            "transactions": [
+                { "transactionType": "SALE", "bookingEntry": "CREDIT", "amount": { "value": "90.00", "currency": "USD" } },
+                { "transactionType": "SALE", "bookingEntry": "CREDIT", "amount": { "value": "45.50", "currency": "USD" } },
+                { "transactionType": "REFUND", "bookingEntry": "DEBIT", "amount": { "value": "20.00", "currency": "USD" } },
+                { "transactionType": "NON_SALE_CHARGE", "bookingEntry": "DEBIT", "amount": { "value": "3.25", "currency": "USD" } },
+                { "transactionType": "SALE", "bookingEntry": "CREDIT", "amount": { "value": "30.00", "currency": "GBP" } }
+            ]
+        }))
+        .unwrap();
+
+        let totals = transactions.net_total();
+        assert_eq!(totals.len(), 2);
+        assert_eq!(totals["USD"], Decimal::new(11225, 2));
+        assert_eq!(totals["GBP"], Decimal::new(3000, 2));
+    }
+
+    #[test]
+    fn total_paid_out_counts_succeeded_payouts() {
+        let payouts: Payouts = serde_json::from_value(serde_json::json!({
+            "total": 3,
+            "payouts": [
+                { "payoutId": "1", "payoutStatus": "SUCCEEDED", "amount": { "value": "100.00", "currency": "USD" } },
+                { "payoutId": "2", "payoutStatus": "SUCCEEDED", "amount": { "value": "25.10", "currency": "USD" } },
+                { "payoutId": "3", "payoutStatus": "RETRYABLE_FAILED", "amount": { "value": "40.00", "currency": "USD" } }
+            ]
+        }))
+        .unwrap();
+
+        assert_eq!(payouts.total_paid_out(), HashMap::from([("USD".to_string(), Decimal::new(12510, 2))]));
+    }
+
+    #[tokio::test]
+    async fn total_available_funds_keys_by_currency() {
+        let (server, config) = mock_ebay().await;
+        mount_funds(&server, "EBAY_US", "120.50", "USD").await;
+        mount_funds(&server, "EBAY_MOTORS_US", "79.50", "USD").await;
+        mount_funds(&server, "EBAY_GB", "42.10", "GBP").await;
+
+        let client = FinancesClient::new(config).unwrap();
+        let totals = client
+            .total_available_funds(&[Marketplace::EbayUs, Marketplace::EbayGb, Marketplace::EbayMotorsUs])
+            .await
+            .unwrap();
+
+        assert_eq!(totals.len(), 2);
+        assert_eq!(totals["USD"], Decimal::new(20000, 2));
+        assert_eq!(totals["GBP"], Decimal::new(4210, 2));
+    }
+}
@@ -1,14 +1,200 @@
 use crate::config::EbayConfig;
 use crate::error::{HermesError, HermesResult};
+use crate::ebay::api_error::raw_error;
+use crate::ebay::telemetry::record_api_call;
 use crate::ebay::auth::EbayAuth;
+use crate::ebay::marketplace::{normalize_marketplace, MarketplaceStyle};
+use crate::ebay::paginate::paginate;
+use crate::money::parse_decimal;
+use crate::orders::{OrderSource, Platform, UnifiedOrder, UnifiedOrderItem, UnifiedOrderStatus};
+use futures::stream::{BoxStream, StreamExt};
+use rust_decimal::Decimal;
+use std::collections::BTreeMap;
 use std::sync::Arc;
 
 // Import eBay Sell Fulfillment SDK models and APIs
 use hermes_ebay_sell_fulfillment::models::{
-    Order, OrderSearchPagedCollection, IssueRefundRequest, 
+    Order, OrderSearchPagedCollection, IssueRefundRequest, LineItemReference, SimpleAmount,
     ShippingFulfillmentDetails, ShippingFulfillment, ShippingFulfillmentPagedCollection,
 };
-use hermes_ebay_sell_fulfillment::apis::configuration::Configuration as FulfillmentConfiguration;
+use hermes_ebay_sell_fulfillment::apis::urlencode;
+
+/// Reason for a refund, sent as eBay's `ReasonForRefundEnum`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RefundReason {
+    /// The buyer cancelled the order
+    BuyerCancel,
+    /// The buyer returned the item(s)
+    BuyerReturn,
+    /// The buyer did not receive the order
+    ItemNotReceived,
+    /// The seller shipped the wrong item
+    SellerWrongItem,
+    /// The seller did not ship part of the order
+    SellerMissingItem,
+    /// The item arrived damaged
+    SellerDamagedItem,
+    Other,
+}
+
+impl RefundReason {
+    /// The `ReasonForRefundEnum` value, e.g. "BUYER_CANCEL"
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            RefundReason::BuyerCancel => "BUYER_CANCEL",
+            RefundReason::BuyerReturn => "BUYER_RETURN",
+            RefundReason::ItemNotReceived => "ITEM_NOT_RECEIVED",
+            RefundReason::SellerWrongItem => "SELLER_WRONG_ITEM",
+            RefundReason::SellerMissingItem => "SELLER_MISSING_ITEM",
+            RefundReason::SellerDamagedItem => "SELLER_DAMAGED_ITEM",
+            RefundReason::Other => "OTHER",
+        }
+    }
+}
+
+/// Fulfillment status of an order, as reported in `orderFulfillmentStatus`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OrderFulfillmentStatus {
+    /// No line items have been shipped
+    NotStarted,
+    /// Some, but not all, line items have been shipped
+    InProgress,
+    /// Every line item has been shipped
+    Fulfilled,
+}
+
+impl OrderFulfillmentStatus {
+    /// The `orderFulfillmentStatus` value, e.g. "NOT_STARTED"
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            OrderFulfillmentStatus::NotStarted => "NOT_STARTED",
+            OrderFulfillmentStatus::InProgress => "IN_PROGRESS",
+            OrderFulfillmentStatus::Fulfilled => "FULFILLED",
+        }
+    }
+
+    /// The `get_orders` filter that includes this status
+    /// 
+    /// eBay only accepts the `{NOT_STARTED|IN_PROGRESS}` and `{FULFILLED|IN_PROGRESS}`
+    /// combinations, so the filter may match a second status as well.
+    pub fn filter(&self) -> &'static str {
+        match self {
+            OrderFulfillmentStatus::NotStarted | OrderFulfillmentStatus::InProgress => {
+                "orderfulfillmentstatus:{NOT_STARTED|IN_PROGRESS}"
+            }
+            OrderFulfillmentStatus::Fulfilled => "orderfulfillmentstatus:{FULFILLED|IN_PROGRESS}",
+        }
+    }
+}
+
+/// Taxes charged on an order, for sales-tax reporting
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct TaxSummary {
+    /// All taxes on the order, including those eBay collects and remits
+    pub total_tax: Decimal,
+    /// Part of `total_tax` that eBay collects and remits itself
+    pub ebay_remitted_tax: Decimal,
+    /// Item cost of the line items that were taxed
+    pub taxable_amount: Decimal,
+    pub currency: Option<String>,
+    /// Tax per jurisdiction-level tax type, e.g. "STATE_SALES_TAX" or "GST"
+    pub by_tax_type: BTreeMap<String, Decimal>,
+}
+
+/// One line of a pick list: what to take off the shelf for an order
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OrderedSku {
+    /// Seller-defined SKU, if the listing had one
+    pub sku: Option<String>,
+    pub title: String,
+    pub quantity: u32,
+    /// Line item to reference when shipping, e.g. in `ship_line_items`
+    pub line_item_id: String,
+}
+
+/// Tax reporting helpers for fulfillment orders
+pub trait OrderTaxExt {
+    /// Aggregate the order's per-line tax breakdown
+    /// 
+    /// Tax types are only reported when the order was fetched with the
+    /// `TAX_BREAKDOWN` field group; otherwise they are grouped under "UNKNOWN".
+    fn tax_summary(&self) -> TaxSummary;
+}
+
+impl OrderTaxExt for Order {
+    fn tax_summary(&self) -> TaxSummary {
+        let mut summary = TaxSummary::default();
+        for line_item in self.line_items.iter().flatten() {
+            let seller_taxes = line_item
+                .taxes
+                .iter()
+                .flatten()
+                .map(|tax| (tax.tax_type.as_deref(), tax.amount.as_deref(), false));
+            let ebay_taxes = line_item
+                .ebay_collect_and_remit_taxes
+                .iter()
+                .flatten()
+                .map(|tax| (tax.tax_type.as_deref(), tax.amount.as_deref(), true));
+
+            let mut taxed = false;
+            for (tax_type, amount, remitted_by_ebay) in seller_taxes.chain(ebay_taxes) {
+                let Some(amount) = amount else { continue };
+                let Some(value) = parse_decimal(amount.value.as_deref()) else { continue };
+                if summary.currency.is_none() {
+                    summary.currency = amount.currency.clone();
+                }
+                summary.total_tax += value;
+                if remitted_by_ebay {
+                    summary.ebay_remitted_tax += value;
+                }
+                *summary.by_tax_type.entry(tax_type.unwrap_or("UNKNOWN").to_string()).or_default() += value;
+                taxed |= !value.is_zero();
+            }
+
+            if taxed {
+                let cost = line_item.line_item_cost.as_deref().and_then(|cost| cost.value.as_deref());
+                summary.taxable_amount += parse_decimal(cost).unwrap_or_default();
+            }
+        }
+        summary
+    }
+}
+
+/// Buyer instructions a seller should read before shipping an order
+pub trait OrderNotesExt {
+    /// Notes the buyer left at checkout, e.g. "please ship to my work address"
+    fn buyer_notes(&self) -> Vec<String>;
+
+    /// Gift message to include in the package, if the buyer sent the order as a gift
+    /// 
+    /// Includes the sender's name when eBay reports one, e.g. "Happy birthday! - Sam".
+    fn gift_message(&self) -> Option<String>;
+}
+
+impl OrderNotesExt for Order {
+    fn buyer_notes(&self) -> Vec<String> {
+        self.buyer_checkout_notes
+            .iter()
+            .map(|notes| notes.trim())
+            .filter(|notes| !notes.is_empty())
+            .map(str::to_string)
+            .collect()
+    }
+
+    fn gift_message(&self) -> Option<String> {
+        self.line_items.iter().flatten().find_map(|line_item| {
+            let gift = line_item.gift_details.as_deref()?;
+            let message = gift.message.as_deref().map(str::trim).filter(|message| !message.is_empty())?;
+            match gift.sender_name.as_deref().map(str::trim).filter(|name| !name.is_empty()) {
+                Some(sender) => Some(format!("{} - {}", message, sender)),
+                None => Some(message.to_string()),
+            }
+        })
+    }
+}
+
+/// Orders requested per page when streaming orders
+const ORDER_PAGE_SIZE: usize = 100;
 
 /// eBay Sell Fulfillment API client for comprehensive order and shipping management
 /// 
@@ -29,6 +215,24 @@ impl FulfillmentClient {
         Ok(Self { config, auth })
     }
 
+    /// Flatten an order's line items into pick-list entries
+    /// 
+    /// Entries keep the order of the line items, one per line item, so SKUs can be
+    /// joined to inventory locations.
+    pub fn order_skus(order: &Order) -> Vec<OrderedSku> {
+        order
+            .line_items
+            .iter()
+            .flatten()
+            .map(|line_item| OrderedSku {
+                sku: line_item.sku.clone().filter(|sku| !sku.is_empty()),
+                title: line_item.title.clone().unwrap_or_default(),
+                quantity: line_item.quantity.unwrap_or(0).max(0) as u32,
+                line_item_id: line_item.line_item_id.clone().unwrap_or_default(),
+            })
+            .collect()
+    }
+
     /// Get orders
     /// 
     /// Retrieves orders for the authenticated seller with optional filtering and pagination.
@@ -55,26 +259,28 @@ impl FulfillmentClient {
         let token_duration = token_start.elapsed();
         tracing::info!("OAuth token request for get_orders: {:?}", token_duration);
         
-        // Set up configuration
-        let mut config = FulfillmentConfiguration::new();
-        config.base_path = if self.config.sandbox {
-            "https://api.sandbox.ebay.com/sell/fulfillment/v1".to_string()
-        } else {
-            "https://api.ebay.com/sell/fulfillment/v1".to_string()
-        };
-        config.oauth_access_token = Some(token);
+        let url = format!("{}/sell/fulfillment/v1/order", self.config.base_url());
+        let http = self.config.http_client();
+        
+        // Fail fast while this API's circuit breaker is open
+        let probe = self.config.check_circuit("sell.fulfillment")?;
         
-        // Call the eBay SDK
+        // Call the eBay API
         let ebay_start = std::time::Instant::now();
-        let result = hermes_ebay_sell_fulfillment::apis::order_api::get_orders(
-            &config,
-            field_groups,
-            filter,
-            limit,
-            offset,
-            order_ids,
-        ).await;
+        let result = self.config.retrying("sell.fulfillment", || {
+            self.config.send_json(
+                http.get(&url).bearer_auth(&token).query(&[
+                    ("fieldGroups", field_groups),
+                    ("filter", filter),
+                    ("limit", limit),
+                    ("offset", offset),
+                    ("orderIds", order_ids),
+                ]),
+            )
+        }).await;
         let ebay_duration = ebay_start.elapsed();
+        record_api_call("sell.fulfillment", "get_orders", &result, ebay_duration);
+        self.config.record_circuit("sell.fulfillment", &probe, &result);
         tracing::info!("eBay get_orders API call: {:?}", ebay_duration);
         
         match result {
@@ -87,11 +293,42 @@ impl FulfillmentClient {
             Err(e) => {
                 let total_duration = start_time.elapsed();
                 tracing::error!("eBay get_orders error after {:?}: {:?}", total_duration, e);
-                Err(HermesError::ApiRequest(format!("eBay get_orders failed: {:?}", e)))
+                Err(raw_error("get_orders", &format!("order_ids {}", order_ids.unwrap_or("none")), e))
             }
         }
     }
 
+    /// Get orders with a given fulfillment status
+    /// 
+    /// Queries `get_orders` with the matching status filter and keeps only orders
+    /// whose status is exactly `status`. `getOrders` has no marketplace filter, so
+    /// when `marketplace_id` is given orders are also filtered locally on their line
+    /// items' listing marketplace. Returns the first page of results.
+    /// 
+    /// # Arguments
+    /// * `marketplace_id` - Optional marketplace to keep orders for (e.g., "EBAY_US")
+    /// * `status` - The fulfillment status to select
+    pub async fn get_orders_by_status(
+        &self,
+        marketplace_id: Option<&str>,
+        status: OrderFulfillmentStatus,
+    ) -> HermesResult<OrderSearchPagedCollection> {
+        let mut page = self.get_orders(None, Some(status.filter()), None, None, None).await?;
+        let marketplace_id = marketplace_id.map(|id| normalize_marketplace(id, MarketplaceStyle::Underscore));
+
+        if let Some(orders) = page.orders.as_mut() {
+            orders.retain(|order| {
+                order.order_fulfillment_status.as_deref() == Some(status.as_str())
+                    && marketplace_id.as_deref().is_none_or(|marketplace_id| {
+                        order.line_items.iter().flatten().any(|line_item| {
+                            line_item.listing_marketplace_id.as_deref() == Some(marketplace_id)
+                        })
+                    })
+            });
+        }
+        Ok(page)
+    }
+
     /// Get order
     /// 
     /// Retrieves a specific order by ID with detailed information.
@@ -112,19 +349,20 @@ impl FulfillmentClient {
         let token_duration = token_start.elapsed();
         tracing::info!("OAuth token request for get_order: {:?}", token_duration);
         
-        // Set up configuration
-        let mut config = FulfillmentConfiguration::new();
-        config.base_path = if self.config.sandbox {
-            "https://api.sandbox.ebay.com/sell/fulfillment/v1".to_string()
-        } else {
-            "https://api.ebay.com/sell/fulfillment/v1".to_string()
-        };
-        config.oauth_access_token = Some(token);
+        let url = format!("{}/sell/fulfillment/v1/order/{}", self.config.base_url(), urlencode(order_id));
+        let http = self.config.http_client();
         
-        // Call the eBay SDK
+        // Fail fast while this API's circuit breaker is open
+        let probe = self.config.check_circuit("sell.fulfillment")?;
+        
+        // Call the eBay API
         let ebay_start = std::time::Instant::now();
-        let result = hermes_ebay_sell_fulfillment::apis::order_api::get_order(&config, order_id, field_groups).await;
+        let result = self.config.retrying("sell.fulfillment", || {
+            self.config.send_json(http.get(&url).bearer_auth(&token).query(&[("fieldGroups", field_groups)]))
+        }).await;
         let ebay_duration = ebay_start.elapsed();
+        record_api_call("sell.fulfillment", "get_order", &result, ebay_duration);
+        self.config.record_circuit("sell.fulfillment", &probe, &result);
         tracing::info!("eBay get_order API call: {:?}", ebay_duration);
         
         match result {
@@ -137,7 +375,7 @@ impl FulfillmentClient {
             Err(e) => {
                 let total_duration = start_time.elapsed();
                 tracing::error!("eBay get_order error after {:?}: {:?}", total_duration, e);
-                Err(HermesError::ApiRequest(format!("eBay get_order failed: {:?}", e)))
+                Err(raw_error("get_order", &format!("order_id {}", order_id), e))
             }
         }
     }
@@ -154,6 +392,11 @@ impl FulfillmentClient {
         order_id: &str,
         refund_request: &IssueRefundRequest,
     ) -> HermesResult<()> {
+        // Dry-run mode logs the request instead of sending it
+        if let Some(response) = self.config.skip_in_dry_run("issue_refund", &(order_id, refund_request)) {
+            return Ok(response);
+        }
+
         let start_time = std::time::Instant::now();
         
         // Get access token
@@ -162,24 +405,18 @@ impl FulfillmentClient {
         let token_duration = token_start.elapsed();
         tracing::info!("OAuth token request for issue_refund: {:?}", token_duration);
         
-        // Set up configuration
-        let mut config = FulfillmentConfiguration::new();
-        config.base_path = if self.config.sandbox {
-            "https://api.sandbox.ebay.com/sell/fulfillment/v1".to_string()
-        } else {
-            "https://api.ebay.com/sell/fulfillment/v1".to_string()
-        };
-        config.oauth_access_token = Some(token);
+        let url = format!("{}/sell/fulfillment/v1/order/{}/issue_refund", self.config.base_url(), urlencode(order_id));
+        let http = self.config.http_client();
+        
+        // Fail fast while this API's circuit breaker is open
+        let probe = self.config.check_circuit("sell.fulfillment")?;
         
-        // Call the eBay SDK
+        // Call the eBay API
         let ebay_start = std::time::Instant::now();
-        let result = hermes_ebay_sell_fulfillment::apis::order_api::issue_refund(
-            &config,
-            order_id,
-            "application/json",
-            Some(refund_request.clone()),
-        ).await;
+        let result = self.config.send(http.post(&url).bearer_auth(&token).json(refund_request)).await;
         let ebay_duration = ebay_start.elapsed();
+        record_api_call("sell.fulfillment", "issue_refund", &result, ebay_duration);
+        self.config.record_circuit("sell.fulfillment", &probe, &result);
         tracing::info!("eBay issue_refund API call: {:?}", ebay_duration);
         
         match result {
@@ -192,11 +429,27 @@ impl FulfillmentClient {
             Err(e) => {
                 let total_duration = start_time.elapsed();
                 tracing::error!("eBay issue_refund error after {:?}: {:?}", total_duration, e);
-                Err(HermesError::ApiRequest(format!("eBay issue_refund failed: {:?}", e)))
+                Err(raw_error("issue_refund", &format!("order_id {}", order_id), e))
             }
         }
     }
 
+    /// Refund an entire order
+    /// 
+    /// Looks up the order total and issues an order-level refund for it, so callers
+    /// don't have to assemble the amount and reason themselves.
+    /// 
+    /// # Arguments
+    /// * `order_id` - The order ID to refund
+    /// * `reason` - Why the order is being refunded
+    pub async fn issue_full_refund(&self, order_id: &str, reason: RefundReason) -> HermesResult<()> {
+        let order = self.get_order(order_id, None).await?;
+        let refund_request = full_refund_request(&order, reason).ok_or_else(|| {
+            HermesError::ApiRequest(format!("eBay get_order returned no total for order {}", order_id))
+        })?;
+        self.issue_refund(order_id, &refund_request).await
+    }
+
     /// Create shipping fulfillment
     /// 
     /// Creates a shipping fulfillment for an order, providing tracking information.
@@ -209,6 +462,11 @@ impl FulfillmentClient {
         order_id: &str,
         fulfillment_details: &ShippingFulfillmentDetails,
     ) -> HermesResult<serde_json::Value> {
+        // Dry-run mode logs the request instead of sending it
+        if let Some(response) = self.config.skip_in_dry_run("create_shipping_fulfillment", &(order_id, fulfillment_details)) {
+            return Ok(response);
+        }
+
         let start_time = std::time::Instant::now();
         
         // Get access token
@@ -217,24 +475,18 @@ impl FulfillmentClient {
         let token_duration = token_start.elapsed();
         tracing::info!("OAuth token request for create_shipping_fulfillment: {:?}", token_duration);
         
-        // Set up configuration
-        let mut config = FulfillmentConfiguration::new();
-        config.base_path = if self.config.sandbox {
-            "https://api.sandbox.ebay.com/sell/fulfillment/v1".to_string()
-        } else {
-            "https://api.ebay.com/sell/fulfillment/v1".to_string()
-        };
-        config.oauth_access_token = Some(token);
+        let url = format!("{}/sell/fulfillment/v1/order/{}/shipping_fulfillment", self.config.base_url(), urlencode(order_id));
+        let http = self.config.http_client();
         
-        // Call the eBay SDK
+        // Fail fast while this API's circuit breaker is open
+        let probe = self.config.check_circuit("sell.fulfillment")?;
+        
+        // Call the eBay API
         let ebay_start = std::time::Instant::now();
-        let result = hermes_ebay_sell_fulfillment::apis::shipping_fulfillment_api::create_shipping_fulfillment(
-            &config,
-            order_id,
-            "application/json",
-            fulfillment_details.clone(),
-        ).await;
+        let result = self.config.send_json(http.post(&url).bearer_auth(&token).json(fulfillment_details)).await;
         let ebay_duration = ebay_start.elapsed();
+        record_api_call("sell.fulfillment", "create_shipping_fulfillment", &result, ebay_duration);
+        self.config.record_circuit("sell.fulfillment", &probe, &result);
         tracing::info!("eBay create_shipping_fulfillment API call: {:?}", ebay_duration);
         
         match result {
@@ -248,11 +500,32 @@ impl FulfillmentClient {
             Err(e) => {
                 let total_duration = start_time.elapsed();
                 tracing::error!("eBay create_shipping_fulfillment error after {:?}: {:?}", total_duration, e);
-                Err(HermesError::ApiRequest(format!("eBay create_shipping_fulfillment failed: {:?}", e)))
+                Err(raw_error("create_shipping_fulfillment", &format!("order_id {}", order_id), e))
             }
         }
     }
 
+    /// Ship some line items of an order in one package
+    /// 
+    /// Creates a shipping fulfillment covering only the given line items and
+    /// quantities, so multi-item orders can be shipped in separate packages.
+    /// 
+    /// # Arguments
+    /// * `order_id` - The order the line items belong to
+    /// * `line_items` - `(line_item_id, quantity)` pairs for the package's contents
+    /// * `tracking_number` - The carrier's tracking number for the package
+    /// * `carrier` - eBay shipping carrier code, e.g. "USPS" or "UPS"
+    pub async fn ship_line_items(
+        &self,
+        order_id: &str,
+        line_items: &[(&str, i32)],
+        tracking_number: &str,
+        carrier: &str,
+    ) -> HermesResult<serde_json::Value> {
+        let details = line_items_fulfillment(line_items, tracking_number, carrier)?;
+        self.create_shipping_fulfillment(order_id, &details).await
+    }
+
     /// Get shipping fulfillments
     /// 
     /// Retrieves all shipping fulfillments for a specific order.
@@ -271,19 +544,20 @@ impl FulfillmentClient {
         let token_duration = token_start.elapsed();
         tracing::info!("OAuth token request for get_shipping_fulfillments: {:?}", token_duration);
         
-        // Set up configuration
-        let mut config = FulfillmentConfiguration::new();
-        config.base_path = if self.config.sandbox {
-            "https://api.sandbox.ebay.com/sell/fulfillment/v1".to_string()
-        } else {
-            "https://api.ebay.com/sell/fulfillment/v1".to_string()
-        };
-        config.oauth_access_token = Some(token);
+        let url = format!("{}/sell/fulfillment/v1/order/{}/shipping_fulfillment", self.config.base_url(), urlencode(order_id));
+        let http = self.config.http_client();
         
-        // Call the eBay SDK
+        // Fail fast while this API's circuit breaker is open
+        let probe = self.config.check_circuit("sell.fulfillment")?;
+        
+        // Call the eBay API
         let ebay_start = std::time::Instant::now();
-        let result = hermes_ebay_sell_fulfillment::apis::shipping_fulfillment_api::get_shipping_fulfillments(&config, order_id).await;
+        let result = self.config.retrying("sell.fulfillment", || {
+            self.config.send_json(http.get(&url).bearer_auth(&token))
+        }).await;
         let ebay_duration = ebay_start.elapsed();
+        record_api_call("sell.fulfillment", "get_shipping_fulfillments", &result, ebay_duration);
+        self.config.record_circuit("sell.fulfillment", &probe, &result);
         tracing::info!("eBay get_shipping_fulfillments API call: {:?}", ebay_duration);
         
         match result {
@@ -296,7 +570,7 @@ impl FulfillmentClient {
             Err(e) => {
                 let total_duration = start_time.elapsed();
                 tracing::error!("eBay get_shipping_fulfillments error after {:?}: {:?}", total_duration, e);
-                Err(HermesError::ApiRequest(format!("eBay get_shipping_fulfillments failed: {:?}", e)))
+                Err(raw_error("get_shipping_fulfillments", &format!("order_id {}", order_id), e))
             }
         }
     }
@@ -321,23 +595,20 @@ impl FulfillmentClient {
         let token_duration = token_start.elapsed();
         tracing::info!("OAuth token request for get_shipping_fulfillment: {:?}", token_duration);
         
-        // Set up configuration
-        let mut config = FulfillmentConfiguration::new();
-        config.base_path = if self.config.sandbox {
-            "https://api.sandbox.ebay.com/sell/fulfillment/v1".to_string()
-        } else {
-            "https://api.ebay.com/sell/fulfillment/v1".to_string()
-        };
-        config.oauth_access_token = Some(token);
+        let url = format!("{}/sell/fulfillment/v1/order/{}/shipping_fulfillment/{}", self.config.base_url(), urlencode(order_id), urlencode(fulfillment_id));
+        let http = self.config.http_client();
         
-        // Call the eBay SDK
+        // Fail fast while this API's circuit breaker is open
+        let probe = self.config.check_circuit("sell.fulfillment")?;
+        
+        // Call the eBay API
         let ebay_start = std::time::Instant::now();
-        let result = hermes_ebay_sell_fulfillment::apis::shipping_fulfillment_api::get_shipping_fulfillment(
-            &config,
-            fulfillment_id,
-            order_id,
-        ).await;
+        let result = self.config.retrying("sell.fulfillment", || {
+            self.config.send_json(http.get(&url).bearer_auth(&token))
+        }).await;
         let ebay_duration = ebay_start.elapsed();
+        record_api_call("sell.fulfillment", "get_shipping_fulfillment", &result, ebay_duration);
+        self.config.record_circuit("sell.fulfillment", &probe, &result);
         tracing::info!("eBay get_shipping_fulfillment API call: {:?}", ebay_duration);
         
         match result {
@@ -350,7 +621,7 @@ impl FulfillmentClient {
             Err(e) => {
                 let total_duration = start_time.elapsed();
                 tracing::error!("eBay get_shipping_fulfillment error after {:?}: {:?}", total_duration, e);
-                Err(HermesError::ApiRequest(format!("eBay get_shipping_fulfillment failed: {:?}", e)))
+                Err(raw_error("get_shipping_fulfillment", &format!("fulfillment_id {}", fulfillment_id), e))
             }
         }
     }
@@ -359,4 +630,313 @@ impl FulfillmentClient {
     // - Payment dispute operations (accept, contest, add_evidence, etc.)
     // - Evidence management (fetch_evidence_content, update_evidence, upload_evidence_file)
     // - Payment dispute queries (get_payment_dispute, get_payment_dispute_summaries, get_activities)
-}
\ No newline at end of file
+}
+
+impl OrderSource for FulfillmentClient {
+    /// Stream orders created at or after `since`, fetching pages lazily
+    fn orders_since<'a>(&'a self, since: &'a str) -> BoxStream<'a, HermesResult<UnifiedOrder>> {
+        let filter = format!("creationdate:[{}..]", since);
+        paginate(ORDER_PAGE_SIZE, move |offset| {
+            let filter = filter.clone();
+            let limit = ORDER_PAGE_SIZE.to_string();
+            async move {
+                self.get_orders(None, Some(&filter), Some(&limit), Some(&offset.to_string()), None).await
+            }
+        })
+        .map(|order| order.map(unified_order))
+        .boxed()
+    }
+}
+
+/// Normalize an eBay order for cross-platform aggregation
+fn unified_order(order: Order) -> UnifiedOrder {
+    let cancelled = order
+        .cancel_status
+        .as_ref()
+        .and_then(|cancel| cancel.cancel_state.as_deref())
+        == Some("CANCELED");
+    let status = match order.order_fulfillment_status.as_deref() {
+        _ if cancelled => UnifiedOrderStatus::Cancelled,
+        Some("NOT_STARTED") => UnifiedOrderStatus::Open,
+        Some("IN_PROGRESS") => UnifiedOrderStatus::InProgress,
+        Some("FULFILLED") => UnifiedOrderStatus::Fulfilled,
+        other => UnifiedOrderStatus::Other(other.unwrap_or_default().to_string()),
+    };
+    let total = order.pricing_summary.and_then(|pricing| pricing.total);
+
+    UnifiedOrder {
+        id: order.order_id.unwrap_or_default(),
+        platform: Platform::Ebay,
+        buyer: order.buyer.and_then(|buyer| buyer.username),
+        items: order
+            .line_items
+            .unwrap_or_default()
+            .into_iter()
+            .map(|line_item| UnifiedOrderItem {
+                sku: line_item.sku,
+                title: line_item.title.unwrap_or_default(),
+                quantity: line_item.quantity.unwrap_or(0).max(0) as u32,
+            })
+            .collect(),
+        total: total.as_ref().and_then(|amount| amount.value.clone()),
+        currency: total.and_then(|amount| amount.currency),
+        status,
+        created: order.creation_date,
+    }
+}
+
+/// Build an order-level refund of the order's full total
+fn full_refund_request(order: &Order, reason: RefundReason) -> Option<IssueRefundRequest> {
+    let total = order.pricing_summary.as_ref()?.total.as_ref()?;
+    Some(IssueRefundRequest {
+        reason_for_refund: Some(reason.as_str().to_string()),
+        order_level_refund_amount: Some(Box::new(SimpleAmount {
+            currency: total.currency.clone(),
+            value: total.value.clone(),
+        })),
+        ..Default::default()
+    })
+}
+
+/// Build a fulfillment for a package holding the given `(line_item_id, quantity)` pairs
+fn line_items_fulfillment(
+    line_items: &[(&str, i32)],
+    tracking_number: &str,
+    carrier: &str,
+) -> HermesResult<ShippingFulfillmentDetails> {
+    if line_items.is_empty() {
+        return Err(HermesError::Configuration("A shipment needs at least one line item".to_string()));
+    }
+    if let Some((line_item_id, quantity)) = line_items.iter().find(|(_, quantity)| *quantity < 1) {
+        return Err(HermesError::Configuration(format!(
+            "Line item {} has quantity {}; shipped quantities must be at least 1",
+            line_item_id, quantity
+        )));
+    }
+
+    Ok(ShippingFulfillmentDetails {
+        line_items: Some(
+            line_items
+                .iter()
+                .map(|(line_item_id, quantity)| LineItemReference {
+                    line_item_id: Some(line_item_id.to_string()),
+                    quantity: Some(*quantity),
+                })
+                .collect(),
+        ),
+        shipping_carrier_code: Some(carrier.to_string()),
+        tracking_number: Some(tracking_number.to_string()),
+        ..Default::default()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ebay::test_support::mock_ebay;
+    use wiremock::matchers::{body_json, method, path, query_param};
+    use wiremock::{Mock, ResponseTemplate};
+
+    #[test]
+    fn tax_summary_aggregates_line_item_breakdown() {
+        let order: Order = serde_json::from_value(serde_json::json!({
+            "orderId": "12-34567-89012",
+            "lineItems": [
+                {
+                    "lineItemId": "1",
+                    "lineItemCost": { "value": "100.00", "currency": "USD" },
+                    "ebayCollectAndRemitTaxes": [
+                        { "taxType": "STATE_SALES_TAX", "amount": { "value": "7.25", "currency": "USD" }, "collectionMethod": "NET" },
+                        { "taxType": "ELECTRONIC_RECYCLING_FEE", "amount": { "value": "3.00", "currency": "USD" } }
+                    ]
+                },
+                {
+                    "lineItemId": "2",
+                    "lineItemCost": { "value": "40.00", "currency": "USD" },
+                    "taxes": [{ "taxType": "STATE_SALES_TAX", "amount": { "value": "2.90", "currency": "USD" } }]
+                },
+                {
+                    "lineItemId": "3",
+                    "lineItemCost": { "value": "15.00", "currency": "USD" }
+                }
+            ]
+        }))
+        .unwrap();
+
+        let summary = order.tax_summary();
+        assert_eq!(summary.total_tax, Decimal::new(1315, 2));
+        assert_eq!(summary.ebay_remitted_tax, Decimal::new(1025, 2));
+        assert_eq!(summary.taxable_amount, Decimal::new(14000, 2));
+        assert_eq!(summary.currency.as_deref(), Some("USD"));
+        assert_eq!(summary.by_tax_type["STATE_SALES_TAX"], Decimal::new(1015, 2));
+        assert_eq!(summary.by_tax_type["ELECTRONIC_RECYCLING_FEE"], Decimal::new(300, 2));
+    }
+
+    #[tokio::test]
+    async fn issue_full_refund_sends_order_level_refund_of_total() {
+        let (server, config) = mock_ebay().await;
+        Mock::given(method("GET"))
+            .and(path("/sell/fulfillment/v1/order/12-34567-89012"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "orderId": "12-34567-89012",
+                "pricingSummary": { "total": { "value": "42.50", "currency": "USD" } }
+            })))
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/sell/fulfillment/v1/order/12-34567-89012/issue_refund"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "refundId": "5000012345",
+                "refundStatus": "PENDING"
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = FulfillmentClient::new(config).unwrap();
+        client.issue_full_refund("12-34567-89012", RefundReason::BuyerCancel).await.unwrap();
+
+        let requests = server.received_requests().await.unwrap();
+        let refund = requests.iter().find(|request| request.url.path().ends_with("/issue_refund")).unwrap();
+        let body: serde_json::Value = serde_json::from_slice(&refund.body).unwrap();
+        assert_eq!(
+            body,
+            serde_json::json!({
+                "reasonForRefund": "BUYER_CANCEL",
+                "orderLevelRefundAmount": { "value": "42.50", "currency": "USD" }
+            })
+        );
+    }
+
+    #[test]
+    fn order_skus_flattens_line_items() {
+        let order: Order = serde_json::from_value(serde_json::json!({
+            "orderId": "12-34567-89012",
+            "lineItems": [
+                { "lineItemId": "10001", "sku": "MUG-RED", "title": "Red mug", "quantity": 2 },
+                { "lineItemId": "10002", "sku": "TEE-M", "title": "T-shirt, medium", "quantity": 1 },
+                { "lineItemId": "10003", "title": "Listing without SKU", "quantity": 3 }
+            ]
+        }))
+        .unwrap();
+
+        let entry = |sku: Option<&str>, title: &str, quantity, line_item_id: &str| OrderedSku {
+            sku: sku.map(str::to_string),
+            title: title.to_string(),
+            quantity,
+            line_item_id: line_item_id.to_string(),
+        };
+        assert_eq!(
+            FulfillmentClient::order_skus(&order),
+            [
+                entry(Some("MUG-RED"), "Red mug", 2, "10001"),
+                entry(Some("TEE-M"), "T-shirt, medium", 1, "10002"),
+                entry(None, "Listing without SKU", 3, "10003"),
+            ]
+        );
+    }
+
+    #[test]
+    fn buyer_notes_and_gift_message_are_extracted() {
+        let order: Order = serde_json::from_value(serde_json::json!({
+            "orderId": "12-34567-89012",
+            "buyerCheckoutNotes": "  Please ship to my work address  ",
+            "lineItems": [
+                { "lineItemId": "10001" },
+                {
+                    "lineItemId": "10002",
+                    "giftDetails": { "message": "Happy birthday!", "senderName": "Sam", "recipientEmail": "alex@example.com" }
+                }
+            ]
+        }))
+        .unwrap();
+        assert_eq!(order.buyer_notes(), ["Please ship to my work address"]);
+        assert_eq!(order.gift_message().as_deref(), Some("Happy birthday! - Sam"));
+
+        let plain: Order = serde_json::from_value(serde_json::json!({ "orderId": "1", "buyerCheckoutNotes": " " })).unwrap();
+        assert!(plain.buyer_notes().is_empty());
+        assert_eq!(plain.gift_message(), None);
+    }
+
+    #[tokio::test]
+    async fn ship_line_items_sends_only_the_given_line_items() {
+        let (server, config) = mock_ebay().await;
+        Mock::given(method("POST"))
+            .and(path("/sell/fulfillment/v1/order/12-34567-89012/shipping_fulfillment"))
+            .and(body_json(serde_json::json!({
+                "lineItems": [
+                    { "lineItemId": "10001", "quantity": 1 },
+                    { "lineItemId": "10003", "quantity": 2 }
+                ],
+                "shippingCarrierCode": "USPS",
+                "trackingNumber": "9400111899223197428490"
+            })))
+            .respond_with(ResponseTemplate::new(201).set_body_json(serde_json::json!({})))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = FulfillmentClient::new(config).unwrap();
+        client
+            .ship_line_items("12-34567-89012", &[("10001", 1), ("10003", 2)], "9400111899223197428490", "USPS")
+            .await
+            .unwrap();
+
+        let err = client.ship_line_items("12-34567-89012", &[("10002", 0)], "1Z999", "UPS").await.unwrap_err();
+        assert!(matches!(err, HermesError::Configuration(_)));
+    }
+
+    #[test]
+    fn order_fulfillment_status_composes_supported_filters() {
+        assert_eq!(
+            OrderFulfillmentStatus::NotStarted.filter(),
+            "orderfulfillmentstatus:{NOT_STARTED|IN_PROGRESS}"
+        );
+        assert_eq!(
+            OrderFulfillmentStatus::InProgress.filter(),
+            "orderfulfillmentstatus:{NOT_STARTED|IN_PROGRESS}"
+        );
+        assert_eq!(
+            OrderFulfillmentStatus::Fulfilled.filter(),
+            "orderfulfillmentstatus:{FULFILLED|IN_PROGRESS}"
+        );
+    }
+
+    #[tokio::test]
+    async fn get_orders_by_status_keeps_exact_status_and_marketplace() {
+        let (server, config) = mock_ebay().await;
+        Mock::given(method("GET"))
+            .and(path("/sell/fulfillment/v1/order"))
+            .and(query_param("filter", "orderfulfillmentstatus:{NOT_STARTED|IN_PROGRESS}"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "total": 3,
+                "orders": [
+                    {
+                        "orderId": "1",
+                        "orderFulfillmentStatus": "NOT_STARTED",
+                        "lineItems": [{ "listingMarketplaceId": "EBAY_US" }]
+                    },
+                    {
+                        "orderId": "2",
+                        "orderFulfillmentStatus": "IN_PROGRESS",
+                        "lineItems": [{ "listingMarketplaceId": "EBAY_US" }]
+                    },
+                    {
+                        "orderId": "3",
+                        "orderFulfillmentStatus": "NOT_STARTED",
+                        "lineItems": [{ "listingMarketplaceId": "EBAY_GB" }]
+                    }
+                ]
+            })))
+            .mount(&server)
+            .await;
+
+        let client = FulfillmentClient::new(config).unwrap();
+        let page = client
+            .get_orders_by_status(Some("EBAY-US"), OrderFulfillmentStatus::NotStarted)
+            .await
+            .unwrap();
+        let order_ids: Vec<_> = page.orders.unwrap().into_iter().filter_map(|order| order.order_id).collect();
+        assert_eq!(order_ids, ["1"]);
+    }
+}
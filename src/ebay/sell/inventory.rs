@@ -1,14 +1,49 @@
-use crate::config::EbayConfig;
+use crate::config::{EbayConfig, DRY_RUN_ID};
 use crate::error::{HermesError, HermesResult};
+use crate::ebay::api_error::raw_error;
+use crate::ebay::telemetry::record_api_call;
 use crate::ebay::auth::EbayAuth;
+use crate::ebay::commerce::taxonomy::AspectMetadataExt;
+use crate::ebay::marketplace::{normalize_marketplace, Marketplace, MarketplaceStyle};
+use crate::ebay::paginate::{paginate, Paged};
+use crate::listing::{ListingId, MarketplaceListing, UnifiedProduct};
+use crate::money::{parse_decimal, Money};
+use async_trait::async_trait;
+use futures::stream::{self, Stream, StreamExt};
+use rust_decimal::Decimal;
+use serde::Serialize;
+use std::collections::HashMap;
+use std::pin::pin;
 use std::sync::Arc;
+use tokio::io::{AsyncWrite, AsyncWriteExt};
 
 // Import eBay Sell Inventory SDK models and APIs
 use hermes_ebay_sell_inventory::models::{
-    InventoryItem, EbayOfferDetailsWithKeys, OfferResponse, Offers, PublishResponse,
-    BaseResponse, InventoryItemWithSkuLocaleGroupid,
+    InventoryItem, InventoryItems, EbayOfferDetailsWithAll, EbayOfferDetailsWithKeys, OfferResponse, Offers, PublishResponse,
+    BaseResponse, InventoryItemWithSkuLocaleGroupid, Amount, BulkGetInventoryItem,
+    BulkGetInventoryItemResponse, GetInventoryItem, InventoryItemGroup, InventoryItemWithSkuLocaleGroupKeys, Availability, PricingSummary,
+    Product, ShipToLocationAvailability, ListingPolicies, FeesSummaryResponse, OfferKeysWithId, OfferKeyWithId,
 };
-use hermes_ebay_sell_inventory::apis::configuration::Configuration as InventoryConfiguration;
+use hermes_ebay_sell_inventory::apis::urlencode;
+use hermes_ebay_commerce_taxonomy::models::AspectMetadata;
+
+/// Maximum number of SKUs eBay accepts in one bulk_get_inventory_item call
+const BULK_GET_INVENTORY_ITEM_LIMIT: usize = 25;
+
+/// Page size used when walking every inventory item
+const INVENTORY_PAGE_SIZE: usize = 100;
+
+/// Maximum number of fee lookups issued concurrently by `estimate_fees`
+const MAX_CONCURRENT_FEE_REQUESTS: usize = 5;
+
+/// Image count at which a listing gets full marks for images in `quality_score`
+const RECOMMENDED_IMAGE_COUNT: usize = 5;
+
+/// Content language used for listings created from a `UnifiedProduct`
+const UNIFIED_CONTENT_LANGUAGE: &str = "en-US";
+
+/// Error eBay returns when publishing an offer that is already published, with its `domain`
+const ALREADY_PUBLISHED_ERROR: (&str, i64) = ("API_INVENTORY", 25016);
 
 /// eBay Sell Inventory API client for comprehensive item and offer management
 /// 
@@ -29,6 +64,11 @@ impl InventoryClient {
         Ok(Self { config, auth })
     }
 
+    /// Create an Inventory API client that shares another client's auth
+    pub(crate) fn with_auth(config: EbayConfig, auth: Arc<EbayAuth>) -> Self {
+        Self { config, auth }
+    }
+
     /// Create or replace inventory item
     /// 
     /// Creates a new inventory item or replaces an existing one with the specified SKU.
@@ -44,6 +84,11 @@ impl InventoryClient {
         inventory_item: &InventoryItem,
         content_language: &str,
     ) -> HermesResult<BaseResponse> {
+        // Dry-run mode logs the request instead of sending it
+        if let Some(response) = self.config.skip_in_dry_run("create_or_replace_inventory_item", &(sku, inventory_item)) {
+            return Ok(response);
+        }
+
         let start_time = std::time::Instant::now();
         
         // Get access token
@@ -52,25 +97,24 @@ impl InventoryClient {
         let token_duration = token_start.elapsed();
         tracing::info!("OAuth token request for create_or_replace_inventory_item: {:?}", token_duration);
         
-        // Set up configuration
-        let mut config = InventoryConfiguration::new();
-        config.base_path = if self.config.sandbox {
-            "https://api.sandbox.ebay.com/sell/inventory/v1".to_string()
-        } else {
-            "https://api.ebay.com/sell/inventory/v1".to_string()
-        };
-        config.oauth_access_token = Some(token);
+        let url = format!("{}/sell/inventory/v1/inventory_item/{}", self.config.base_url(), urlencode(sku));
+        let http = self.config.http_client();
+        
+        // Fail fast while this API's circuit breaker is open
+        let probe = self.config.check_circuit("sell.inventory")?;
         
-        // Call the eBay SDK
+        // Call the eBay API
         let ebay_start = std::time::Instant::now();
-        let result = hermes_ebay_sell_inventory::apis::inventory_item_api::create_or_replace_inventory_item(
-            &config,
-            content_language,
-            sku,
-            "application/json",
-            inventory_item.clone(),
+        let result = self.config.send_json(
+            http
+                .put(&url)
+                .bearer_auth(&token)
+                .header(reqwest::header::CONTENT_LANGUAGE, content_language)
+                .json(inventory_item),
         ).await;
         let ebay_duration = ebay_start.elapsed();
+        record_api_call("sell.inventory", "create_or_replace_inventory_item", &result, ebay_duration);
+        self.config.record_circuit("sell.inventory", &probe, &result);
         tracing::info!("eBay create_or_replace_inventory_item API call: {:?}", ebay_duration);
         
         match result {
@@ -83,14 +127,17 @@ impl InventoryClient {
             Err(e) => {
                 let total_duration = start_time.elapsed();
                 tracing::error!("eBay create_or_replace_inventory_item error after {:?}: {:?}", total_duration, e);
-                Err(HermesError::ApiRequest(format!("eBay create_or_replace_inventory_item failed: {:?}", e)))
+                Err(raw_error("create_or_replace_inventory_item", &format!("sku {}", sku), e))
             }
         }
     }
 
     /// Get inventory item
     /// 
-    /// Retrieves an existing inventory item by SKU.
+    /// Retrieves an existing inventory item by SKU. eBay stores a single locale per
+    /// inventory item (returned in `locale`), so there is no locale parameter; use
+    /// [`get_inventory_items_by_group`](Self::get_inventory_items_by_group) to pick
+    /// the SKUs of a given locale.
     /// 
     /// # Arguments
     /// * `sku` - The seller-defined SKU for the inventory item
@@ -103,19 +150,20 @@ impl InventoryClient {
         let token_duration = token_start.elapsed();
         tracing::info!("OAuth token request for get_inventory_item: {:?}", token_duration);
         
-        // Set up configuration
-        let mut config = InventoryConfiguration::new();
-        config.base_path = if self.config.sandbox {
-            "https://api.sandbox.ebay.com/sell/inventory/v1".to_string()
-        } else {
-            "https://api.ebay.com/sell/inventory/v1".to_string()
-        };
-        config.oauth_access_token = Some(token);
+        let url = format!("{}/sell/inventory/v1/inventory_item/{}", self.config.base_url(), urlencode(sku));
+        let http = self.config.http_client();
         
-        // Call the eBay SDK
+        // Fail fast while this API's circuit breaker is open
+        let probe = self.config.check_circuit("sell.inventory")?;
+        
+        // Call the eBay API
         let ebay_start = std::time::Instant::now();
-        let result = hermes_ebay_sell_inventory::apis::inventory_item_api::get_inventory_item(&config, sku).await;
+        let result = self.config.retrying("sell.inventory", || {
+            self.config.send_json(http.get(&url).bearer_auth(&token))
+        }).await;
         let ebay_duration = ebay_start.elapsed();
+        record_api_call("sell.inventory", "get_inventory_item", &result, ebay_duration);
+        self.config.record_circuit("sell.inventory", &probe, &result);
         tracing::info!("eBay get_inventory_item API call: {:?}", ebay_duration);
         
         match result {
@@ -128,11 +176,294 @@ impl InventoryClient {
             Err(e) => {
                 let total_duration = start_time.elapsed();
                 tracing::error!("eBay get_inventory_item error after {:?}: {:?}", total_duration, e);
-                Err(HermesError::ApiRequest(format!("eBay get_inventory_item failed: {:?}", e)))
+                Err(raw_error("get_inventory_item", &format!("sku {}", sku), e))
+            }
+        }
+    }
+
+    /// Check whether an inventory item exists
+    /// 
+    /// Returns false when eBay reports the SKU as not found, so callers can choose
+    /// between creating and updating. Any other failure is returned as an error.
+    /// 
+    /// # Arguments
+    /// * `sku` - The seller-defined SKU to look up
+    pub async fn inventory_item_exists(&self, sku: &str) -> HermesResult<bool> {
+        match self.get_inventory_item(sku).await {
+            Ok(_) => Ok(true),
+            Err(HermesError::NotFound { .. }) => Ok(false),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Get inventory items
+    /// 
+    /// Retrieves a page of the seller's inventory items.
+    /// 
+    /// # Arguments
+    /// * `limit` - Optional page size (up to 200)
+    /// * `offset` - Optional offset for pagination
+    pub async fn get_inventory_items(
+        &self,
+        limit: Option<&str>,
+        offset: Option<&str>,
+    ) -> HermesResult<InventoryItems> {
+        let start_time = std::time::Instant::now();
+        
+        // Get access token
+        let token_start = std::time::Instant::now();
+        let token = self.auth.get_access_token().await?;
+        let token_duration = token_start.elapsed();
+        tracing::info!("OAuth token request for get_inventory_items: {:?}", token_duration);
+        
+        let url = format!("{}/sell/inventory/v1/inventory_item", self.config.base_url());
+        let http = self.config.http_client();
+        
+        // Fail fast while this API's circuit breaker is open
+        let probe = self.config.check_circuit("sell.inventory")?;
+        
+        // Call the eBay API
+        let ebay_start = std::time::Instant::now();
+        let result = self.config.retrying("sell.inventory", || {
+            self.config.send_json(http.get(&url).bearer_auth(&token).query(&[("limit", limit), ("offset", offset)]))
+        }).await;
+        let ebay_duration = ebay_start.elapsed();
+        record_api_call("sell.inventory", "get_inventory_items", &result, ebay_duration);
+        self.config.record_circuit("sell.inventory", &probe, &result);
+        tracing::info!("eBay get_inventory_items API call: {:?}", ebay_duration);
+        
+        match result {
+            Ok(response) => {
+                let total_duration = start_time.elapsed();
+                let our_processing = total_duration - token_duration - ebay_duration;
+                tracing::info!("get_inventory_items total: {:?} | Our processing: {:?}", total_duration, our_processing);
+                Ok(response)
+            },
+            Err(e) => {
+                let total_duration = start_time.elapsed();
+                tracing::error!("eBay get_inventory_items error after {:?}: {:?}", total_duration, e);
+                Err(raw_error("get_inventory_items", &format!("inventory items at offset {}", offset.unwrap_or("0")), e))
             }
         }
     }
 
+    /// Offers that reference a business policy
+    /// 
+    /// Walks every inventory item and its offers, returning the IDs of offers whose
+    /// return, payment, fulfillment or take-back policy is `policy_id`. This costs
+    /// one call per SKU, so it is meant for occasional maintenance tasks.
+    /// 
+    /// # Arguments
+    /// * `policy_id` - The business policy ID to look for
+    pub async fn offers_using_policy(&self, policy_id: &str) -> HermesResult<Vec<String>> {
+        let mut offer_ids = Vec::new();
+        let mut items = pin!(self.inventory_items_stream());
+        while let Some(item) = items.next().await {
+            let Some(sku) = item?.sku else {
+                continue;
+            };
+            for offer in self.get_offers_for_sku(&sku).await? {
+                let uses_policy = offer.listing_policies.as_deref().is_some_and(|policies| {
+                    [
+                        &policies.return_policy_id,
+                        &policies.payment_policy_id,
+                        &policies.fulfillment_policy_id,
+                        &policies.take_back_policy_id,
+                    ]
+                    .into_iter()
+                    .any(|id| id.as_deref() == Some(policy_id))
+                });
+                if uses_policy {
+                    offer_ids.extend(offer.offer_id);
+                }
+            }
+        }
+        Ok(offer_ids)
+    }
+
+    /// Stream every inventory item, fetching pages lazily
+    /// 
+    /// The stream ends after the last page or after yielding the first error.
+    pub fn inventory_items_stream(&self) -> impl Stream<Item = HermesResult<InventoryItemWithSkuLocaleGroupid>> + '_ {
+        paginate(INVENTORY_PAGE_SIZE, move |offset| {
+            let limit = INVENTORY_PAGE_SIZE.to_string();
+            async move { self.get_inventory_items(Some(&limit), Some(&offset.to_string())).await }
+        })
+    }
+
+    /// Export every inventory item with its offers as newline-delimited JSON
+    /// 
+    /// Writes one object per inventory item, `{"inventoryItem": {...}, "offers": [...]}`,
+    /// fetching pages as it goes so memory use stays flat for large inventories.
+    /// Returns the number of lines written.
+    /// 
+    /// # Arguments
+    /// * `writer` - Destination, e.g. a `tokio::fs::File`
+    pub async fn export_inventory_ndjson<W: AsyncWrite + Unpin>(&self, mut writer: W) -> HermesResult<usize> {
+        let mut written = 0;
+        let mut items = pin!(self.inventory_items_stream());
+        while let Some(item) = items.next().await {
+            let item = item?;
+            let offers = match item.sku.as_deref() {
+                Some(sku) => self.get_offers_for_sku(sku).await?,
+                None => Vec::new(),
+            };
+
+            let mut line = serde_json::to_vec(&InventoryExportRecord { inventory_item: &item, offers: &offers })?;
+            line.push(b'\n');
+            writer.write_all(&line).await?;
+            written += 1;
+        }
+        writer.flush().await?;
+        Ok(written)
+    }
+
+    /// Get the offers of a SKU
+    /// 
+    /// Like `get_offers` filtered on `sku`, but returns the offers themselves and an
+    /// empty list for SKUs without offers (which eBay reports as not found).
+    /// 
+    /// # Arguments
+    /// * `sku` - The seller-defined SKU
+    pub async fn get_offers_for_sku(&self, sku: &str) -> HermesResult<Vec<EbayOfferDetailsWithAll>> {
+        match self.get_offers(None, Some(sku), None, None).await {
+            Ok(offers) => Ok(offers.into_items()),
+            // eBay reports SKUs without offers as not found
+            Err(HermesError::NotFound { .. }) => Ok(Vec::new()),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Bulk get inventory items
+    /// 
+    /// Retrieves up to 25 inventory items by SKU in a single call.
+    /// 
+    /// # Arguments
+    /// * `skus` - The SKUs to retrieve (at most 25)
+    pub async fn bulk_get_inventory_item(&self, skus: &[&str]) -> HermesResult<BulkGetInventoryItemResponse> {
+        let start_time = std::time::Instant::now();
+        
+        // Get access token
+        let token_start = std::time::Instant::now();
+        let token = self.auth.get_access_token().await?;
+        let token_duration = token_start.elapsed();
+        tracing::info!("OAuth token request for bulk_get_inventory_item: {:?}", token_duration);
+        
+        let url = format!("{}/sell/inventory/v1/bulk_get_inventory_item", self.config.base_url());
+        let http = self.config.http_client();
+        
+        // Fail fast while this API's circuit breaker is open
+        let probe = self.config.check_circuit("sell.inventory")?;
+        
+        // Call the eBay API
+        let ebay_start = std::time::Instant::now();
+        let result = self.config.send_json(
+            http.post(&url).bearer_auth(&token).json(&BulkGetInventoryItem {
+                requests: Some(skus.iter().map(|sku| GetInventoryItem { sku: Some(sku.to_string()) }).collect()),
+            }),
+        ).await;
+        let ebay_duration = ebay_start.elapsed();
+        record_api_call("sell.inventory", "bulk_get_inventory_item", &result, ebay_duration);
+        self.config.record_circuit("sell.inventory", &probe, &result);
+        tracing::info!("eBay bulk_get_inventory_item API call: {:?}", ebay_duration);
+        
+        match result {
+            Ok(response) => {
+                let total_duration = start_time.elapsed();
+                let our_processing = total_duration - token_duration - ebay_duration;
+                tracing::info!("bulk_get_inventory_item total: {:?} | Our processing: {:?}", total_duration, our_processing);
+                Ok(response)
+            },
+            Err(e) => {
+                let total_duration = start_time.elapsed();
+                tracing::error!("eBay bulk_get_inventory_item error after {:?}: {:?}", total_duration, e);
+                Err(raw_error("bulk_get_inventory_item", &format!("skus {}", skus.join(",")), e))
+            }
+        }
+    }
+
+    /// Get inventory item group
+    /// 
+    /// Retrieves an inventory item group, including the SKUs of its variations.
+    /// 
+    /// # Arguments
+    /// * `inventory_item_group_key` - The seller-defined key of the group
+    pub async fn get_inventory_item_group(&self, inventory_item_group_key: &str) -> HermesResult<InventoryItemGroup> {
+        let start_time = std::time::Instant::now();
+        
+        // Get access token
+        let token_start = std::time::Instant::now();
+        let token = self.auth.get_access_token().await?;
+        let token_duration = token_start.elapsed();
+        tracing::info!("OAuth token request for get_inventory_item_group: {:?}", token_duration);
+        
+        let url = format!("{}/sell/inventory/v1/inventory_item_group/{}", self.config.base_url(), urlencode(inventory_item_group_key));
+        let http = self.config.http_client();
+        
+        // Fail fast while this API's circuit breaker is open
+        let probe = self.config.check_circuit("sell.inventory")?;
+        
+        // Call the eBay API
+        let ebay_start = std::time::Instant::now();
+        let result = self.config.retrying("sell.inventory", || {
+            self.config.send_json(http.get(&url).bearer_auth(&token))
+        }).await;
+        let ebay_duration = ebay_start.elapsed();
+        record_api_call("sell.inventory", "get_inventory_item_group", &result, ebay_duration);
+        self.config.record_circuit("sell.inventory", &probe, &result);
+        tracing::info!("eBay get_inventory_item_group API call: {:?}", ebay_duration);
+        
+        match result {
+            Ok(response) => {
+                let total_duration = start_time.elapsed();
+                let our_processing = total_duration - token_duration - ebay_duration;
+                tracing::info!("get_inventory_item_group total: {:?} | Our processing: {:?}", total_duration, our_processing);
+                Ok(response)
+            },
+            Err(e) => {
+                let total_duration = start_time.elapsed();
+                tracing::error!("eBay get_inventory_item_group error after {:?}: {:?}", total_duration, e);
+                Err(raw_error("get_inventory_item_group", &format!("inventory_item_group_key {}", inventory_item_group_key), e))
+            }
+        }
+    }
+
+    /// Get all inventory items in a group
+    /// 
+    /// Looks up the group's variant SKUs and retrieves them in batches of 25. SKUs
+    /// eBay reports as missing are skipped.
+    /// 
+    /// # Arguments
+    /// * `inventory_item_group_key` - The seller-defined key of the group
+    /// * `locale` - Optional locale filter (e.g. "de-DE" or "de_DE")
+    pub async fn get_inventory_items_by_group(
+        &self,
+        inventory_item_group_key: &str,
+        locale: Option<&str>,
+    ) -> HermesResult<Vec<InventoryItemWithSkuLocaleGroupKeys>> {
+        let group = self.get_inventory_item_group(inventory_item_group_key).await?;
+        let skus = group.variant_skus.unwrap_or_default();
+        let locale = locale.map(normalize_locale);
+
+        let mut items = Vec::with_capacity(skus.len());
+        for chunk in skus.chunks(BULK_GET_INVENTORY_ITEM_LIMIT) {
+            let chunk: Vec<&str> = chunk.iter().map(String::as_str).collect();
+            let response = self.bulk_get_inventory_item(&chunk).await?;
+            items.extend(
+                response
+                    .responses
+                    .unwrap_or_default()
+                    .into_iter()
+                    .filter_map(|response| response.inventory_item.map(|item| *item))
+                    .filter(|item| match &locale {
+                        Some(locale) => item.locale.as_deref().map(normalize_locale).as_ref() == Some(locale),
+                        None => true,
+                    }),
+            );
+        }
+        Ok(items)
+    }
+
     /// Delete inventory item
     /// 
     /// Deletes an inventory item by SKU. Note that items with active offers cannot be deleted.
@@ -140,6 +471,11 @@ impl InventoryClient {
     /// # Arguments
     /// * `sku` - The seller-defined SKU for the inventory item to delete
     pub async fn delete_inventory_item(&self, sku: &str) -> HermesResult<()> {
+        // Dry-run mode logs the request instead of sending it
+        if let Some(response) = self.config.skip_in_dry_run("delete_inventory_item", &sku) {
+            return Ok(response);
+        }
+
         let start_time = std::time::Instant::now();
         
         // Get access token
@@ -148,19 +484,18 @@ impl InventoryClient {
         let token_duration = token_start.elapsed();
         tracing::info!("OAuth token request for delete_inventory_item: {:?}", token_duration);
         
-        // Set up configuration
-        let mut config = InventoryConfiguration::new();
-        config.base_path = if self.config.sandbox {
-            "https://api.sandbox.ebay.com/sell/inventory/v1".to_string()
-        } else {
-            "https://api.ebay.com/sell/inventory/v1".to_string()
-        };
-        config.oauth_access_token = Some(token);
+        let url = format!("{}/sell/inventory/v1/inventory_item/{}", self.config.base_url(), urlencode(sku));
+        let http = self.config.http_client();
+        
+        // Fail fast while this API's circuit breaker is open
+        let probe = self.config.check_circuit("sell.inventory")?;
         
-        // Call the eBay SDK
+        // Call the eBay API
         let ebay_start = std::time::Instant::now();
-        let result = hermes_ebay_sell_inventory::apis::inventory_item_api::delete_inventory_item(&config, sku).await;
+        let result = self.config.send(http.delete(&url).bearer_auth(&token)).await;
         let ebay_duration = ebay_start.elapsed();
+        record_api_call("sell.inventory", "delete_inventory_item", &result, ebay_duration);
+        self.config.record_circuit("sell.inventory", &probe, &result);
         tracing::info!("eBay delete_inventory_item API call: {:?}", ebay_duration);
         
         match result {
@@ -173,7 +508,7 @@ impl InventoryClient {
             Err(e) => {
                 let total_duration = start_time.elapsed();
                 tracing::error!("eBay delete_inventory_item error after {:?}: {:?}", total_duration, e);
-                Err(HermesError::ApiRequest(format!("eBay delete_inventory_item failed: {:?}", e)))
+                Err(raw_error("delete_inventory_item", &format!("sku {}", sku), e))
             }
         }
     }
@@ -181,6 +516,7 @@ impl InventoryClient {
     /// Create offer
     /// 
     /// Creates a marketplace offer for an inventory item, making it available for purchase.
+    /// In dry-run mode the returned offer ID is [`DRY_RUN_ID`].
     /// 
     /// # Arguments
     /// * `offer_details` - The offer details including pricing, marketplace, and policies
@@ -190,6 +526,11 @@ impl InventoryClient {
         offer_details: &EbayOfferDetailsWithKeys,
         content_language: &str,
     ) -> HermesResult<OfferResponse> {
+        // Dry-run mode logs the request instead of sending it
+        if let Some(response) = self.config.skip_in_dry_run("create_offer", &offer_details) {
+            return Ok(OfferResponse { offer_id: Some(DRY_RUN_ID.to_string()), ..response });
+        }
+
         let start_time = std::time::Instant::now();
         
         // Get access token
@@ -198,24 +539,24 @@ impl InventoryClient {
         let token_duration = token_start.elapsed();
         tracing::info!("OAuth token request for create_offer: {:?}", token_duration);
         
-        // Set up configuration
-        let mut config = InventoryConfiguration::new();
-        config.base_path = if self.config.sandbox {
-            "https://api.sandbox.ebay.com/sell/inventory/v1".to_string()
-        } else {
-            "https://api.ebay.com/sell/inventory/v1".to_string()
-        };
-        config.oauth_access_token = Some(token);
+        let url = format!("{}/sell/inventory/v1/offer", self.config.base_url());
+        let http = self.config.http_client();
         
-        // Call the eBay SDK
+        // Fail fast while this API's circuit breaker is open
+        let probe = self.config.check_circuit("sell.inventory")?;
+        
+        // Call the eBay API
         let ebay_start = std::time::Instant::now();
-        let result = hermes_ebay_sell_inventory::apis::offer_api::create_offer(
-            &config,
-            content_language,
-            "application/json",
-            offer_details.clone(),
+        let result = self.config.send_json(
+            http
+                .post(&url)
+                .bearer_auth(&token)
+                .header(reqwest::header::CONTENT_LANGUAGE, content_language)
+                .json(offer_details),
         ).await;
         let ebay_duration = ebay_start.elapsed();
+        record_api_call("sell.inventory", "create_offer", &result, ebay_duration);
+        self.config.record_circuit("sell.inventory", &probe, &result);
         tracing::info!("eBay create_offer API call: {:?}", ebay_duration);
         
         match result {
@@ -228,7 +569,7 @@ impl InventoryClient {
             Err(e) => {
                 let total_duration = start_time.elapsed();
                 tracing::error!("eBay create_offer error after {:?}: {:?}", total_duration, e);
-                Err(HermesError::ApiRequest(format!("eBay create_offer failed: {:?}", e)))
+                Err(raw_error("create_offer", &format!("sku {}", offer_details.sku.as_deref().unwrap_or_default()), e))
             }
         }
     }
@@ -257,26 +598,29 @@ impl InventoryClient {
         let token_duration = token_start.elapsed();
         tracing::info!("OAuth token request for get_offers: {:?}", token_duration);
         
-        // Set up configuration
-        let mut config = InventoryConfiguration::new();
-        config.base_path = if self.config.sandbox {
-            "https://api.sandbox.ebay.com/sell/inventory/v1".to_string()
-        } else {
-            "https://api.ebay.com/sell/inventory/v1".to_string()
-        };
-        config.oauth_access_token = Some(token);
+        let url = format!("{}/sell/inventory/v1/offer", self.config.base_url());
+        let http = self.config.http_client();
+        
+        // Fail fast while this API's circuit breaker is open
+        let probe = self.config.check_circuit("sell.inventory")?;
         
-        // Call the eBay SDK
+        let marketplace_id = marketplace_id.map(|id| normalize_marketplace(id, MarketplaceStyle::Underscore));
+        
+        // Call the eBay API
         let ebay_start = std::time::Instant::now();
-        let result = hermes_ebay_sell_inventory::apis::offer_api::get_offers(
-            &config,
-            Some("application/json"),
-            limit,
-            marketplace_id,
-            offset,
-            sku,
-        ).await;
+        let result = self.config.retrying("sell.inventory", || {
+            self.config.send_json(
+                http.get(&url).bearer_auth(&token).query(&[
+                    ("limit", limit),
+                    ("marketplace_id", marketplace_id.as_deref()),
+                    ("offset", offset),
+                    ("sku", sku),
+                ]),
+            )
+        }).await;
         let ebay_duration = ebay_start.elapsed();
+        record_api_call("sell.inventory", "get_offers", &result, ebay_duration);
+        self.config.record_circuit("sell.inventory", &probe, &result);
         tracing::info!("eBay get_offers API call: {:?}", ebay_duration);
         
         match result {
@@ -289,7 +633,53 @@ impl InventoryClient {
             Err(e) => {
                 let total_duration = start_time.elapsed();
                 tracing::error!("eBay get_offers error after {:?}: {:?}", total_duration, e);
-                Err(HermesError::ApiRequest(format!("eBay get_offers failed: {:?}", e)))
+                Err(raw_error("get_offers", &format!("sku {}", sku.unwrap_or("none")), e))
+            }
+        }
+    }
+
+    /// Get offer
+    /// 
+    /// Retrieves a single offer, including its listing details once published.
+    /// 
+    /// # Arguments
+    /// * `offer_id` - The offer ID to retrieve
+    pub async fn get_offer(&self, offer_id: &str) -> HermesResult<EbayOfferDetailsWithAll> {
+        let start_time = std::time::Instant::now();
+        
+        // Get access token
+        let token_start = std::time::Instant::now();
+        let token = self.auth.get_access_token().await?;
+        let token_duration = token_start.elapsed();
+        tracing::info!("OAuth token request for get_offer: {:?}", token_duration);
+        
+        let url = format!("{}/sell/inventory/v1/offer/{}", self.config.base_url(), urlencode(offer_id));
+        let http = self.config.http_client();
+        
+        // Fail fast while this API's circuit breaker is open
+        let probe = self.config.check_circuit("sell.inventory")?;
+        
+        // Call the eBay API
+        let ebay_start = std::time::Instant::now();
+        let result = self.config.retrying("sell.inventory", || {
+            self.config.send_json(http.get(&url).bearer_auth(&token))
+        }).await;
+        let ebay_duration = ebay_start.elapsed();
+        record_api_call("sell.inventory", "get_offer", &result, ebay_duration);
+        self.config.record_circuit("sell.inventory", &probe, &result);
+        tracing::info!("eBay get_offer API call: {:?}", ebay_duration);
+        
+        match result {
+            Ok(response) => {
+                let total_duration = start_time.elapsed();
+                let our_processing = total_duration - token_duration - ebay_duration;
+                tracing::info!("get_offer total: {:?} | Our processing: {:?}", total_duration, our_processing);
+                Ok(response)
+            },
+            Err(e) => {
+                let total_duration = start_time.elapsed();
+                tracing::error!("eBay get_offer error after {:?}: {:?}", total_duration, e);
+                Err(raw_error("get_offer", &format!("offer_id {}", offer_id), e))
             }
         }
     }
@@ -297,10 +687,16 @@ impl InventoryClient {
     /// Publish offer
     /// 
     /// Publishes an offer to the marketplace, making it live and available for purchase.
+    /// In dry-run mode the returned listing ID is [`DRY_RUN_ID`].
     /// 
     /// # Arguments
     /// * `offer_id` - The offer ID to publish
     pub async fn publish_offer(&self, offer_id: &str) -> HermesResult<PublishResponse> {
+        // Dry-run mode logs the request instead of sending it
+        if let Some(response) = self.config.skip_in_dry_run("publish_offer", &offer_id) {
+            return Ok(PublishResponse { listing_id: Some(DRY_RUN_ID.to_string()), ..response });
+        }
+
         let start_time = std::time::Instant::now();
         
         // Get access token
@@ -309,19 +705,18 @@ impl InventoryClient {
         let token_duration = token_start.elapsed();
         tracing::info!("OAuth token request for publish_offer: {:?}", token_duration);
         
-        // Set up configuration
-        let mut config = InventoryConfiguration::new();
-        config.base_path = if self.config.sandbox {
-            "https://api.sandbox.ebay.com/sell/inventory/v1".to_string()
-        } else {
-            "https://api.ebay.com/sell/inventory/v1".to_string()
-        };
-        config.oauth_access_token = Some(token);
+        let url = format!("{}/sell/inventory/v1/offer/{}/publish", self.config.base_url(), urlencode(offer_id));
+        let http = self.config.http_client();
+        
+        // Fail fast while this API's circuit breaker is open
+        let probe = self.config.check_circuit("sell.inventory")?;
         
-        // Call the eBay SDK
+        // Call the eBay API
         let ebay_start = std::time::Instant::now();
-        let result = hermes_ebay_sell_inventory::apis::offer_api::publish_offer(&config, offer_id).await;
+        let result = self.config.send_json(http.post(&url).bearer_auth(&token)).await;
         let ebay_duration = ebay_start.elapsed();
+        record_api_call("sell.inventory", "publish_offer", &result, ebay_duration);
+        self.config.record_circuit("sell.inventory", &probe, &result);
         tracing::info!("eBay publish_offer API call: {:?}", ebay_duration);
         
         match result {
@@ -334,11 +729,40 @@ impl InventoryClient {
             Err(e) => {
                 let total_duration = start_time.elapsed();
                 tracing::error!("eBay publish_offer error after {:?}: {:?}", total_duration, e);
-                Err(HermesError::ApiRequest(format!("eBay publish_offer failed: {:?}", e)))
+                Err(raw_error("publish_offer", &format!("offer_id {}", offer_id), e))
             }
         }
     }
 
+    /// Publish an offer, tolerating offers that are already live
+    /// 
+    /// Returns the listing ID. When eBay rejects the publish because the offer is
+    /// already published, the existing listing ID is read from the offer instead,
+    /// so listing pipelines can be re-run safely. In dry-run mode the listing ID is
+    /// [`DRY_RUN_ID`].
+    /// 
+    /// # Arguments
+    /// * `offer_id` - The offer ID to publish
+    pub async fn publish_offer_idempotent(&self, offer_id: &str) -> HermesResult<String> {
+        // Dry-run mode logs the request instead of sending it
+        if self.config.skip_in_dry_run::<()>("publish_offer_idempotent", &offer_id).is_some() {
+            return Ok(DRY_RUN_ID.to_string());
+        }
+
+        let listing_id = match self.publish_offer(offer_id).await {
+            Ok(response) => PublishResponseExt::listing_id(&response).map(str::to_string),
+            Err(e) if is_already_published(&e) => {
+                tracing::info!("Offer {} is already published; looking up its listing", offer_id);
+                let offer = self.get_offer(offer_id).await?;
+                offer.listing.and_then(|listing| listing.listing_id).filter(|id| !id.is_empty())
+            }
+            Err(e) => return Err(e),
+        };
+        listing_id.ok_or_else(|| {
+            HermesError::ApiRequest(format!("eBay returned no listing ID for published offer {}", offer_id))
+        })
+    }
+
     /// Withdraw offer
     /// 
     /// Withdraws an offer from the marketplace, ending the listing.
@@ -346,6 +770,11 @@ impl InventoryClient {
     /// # Arguments
     /// * `offer_id` - The offer ID to withdraw
     pub async fn withdraw_offer(&self, offer_id: &str) -> HermesResult<()> {
+        // Dry-run mode logs the request instead of sending it
+        if let Some(response) = self.config.skip_in_dry_run("withdraw_offer", &offer_id) {
+            return Ok(response);
+        }
+
         let start_time = std::time::Instant::now();
         
         // Get access token
@@ -354,19 +783,18 @@ impl InventoryClient {
         let token_duration = token_start.elapsed();
         tracing::info!("OAuth token request for withdraw_offer: {:?}", token_duration);
         
-        // Set up configuration
-        let mut config = InventoryConfiguration::new();
-        config.base_path = if self.config.sandbox {
-            "https://api.sandbox.ebay.com/sell/inventory/v1".to_string()
-        } else {
-            "https://api.ebay.com/sell/inventory/v1".to_string()
-        };
-        config.oauth_access_token = Some(token);
+        let url = format!("{}/sell/inventory/v1/offer/{}/withdraw", self.config.base_url(), urlencode(offer_id));
+        let http = self.config.http_client();
+        
+        // Fail fast while this API's circuit breaker is open
+        let probe = self.config.check_circuit("sell.inventory")?;
         
-        // Call the eBay SDK
+        // Call the eBay API
         let ebay_start = std::time::Instant::now();
-        let result = hermes_ebay_sell_inventory::apis::offer_api::withdraw_offer(&config, offer_id).await;
+        let result = self.config.send(http.post(&url).bearer_auth(&token)).await;
         let ebay_duration = ebay_start.elapsed();
+        record_api_call("sell.inventory", "withdraw_offer", &result, ebay_duration);
+        self.config.record_circuit("sell.inventory", &probe, &result);
         tracing::info!("eBay withdraw_offer API call: {:?}", ebay_duration);
         
         match result {
@@ -379,11 +807,86 @@ impl InventoryClient {
             Err(e) => {
                 let total_duration = start_time.elapsed();
                 tracing::error!("eBay withdraw_offer error after {:?}: {:?}", total_duration, e);
-                Err(HermesError::ApiRequest(format!("eBay withdraw_offer failed: {:?}", e)))
+                Err(raw_error("withdraw_offer", &format!("offer_id {}", offer_id), e))
             }
         }
     }
 
+    /// Get listing fees
+    /// 
+    /// Retrieves the fees expected to be charged when the given unpublished offers
+    /// are published. eBay totals the fees per marketplace, not per offer.
+    /// 
+    /// # Arguments
+    /// * `offer_ids` - IDs of unpublished offers (at most 250)
+    pub async fn get_listing_fees(&self, offer_ids: &[&str]) -> HermesResult<FeesSummaryResponse> {
+        let start_time = std::time::Instant::now();
+        
+        // Get access token
+        let token_start = std::time::Instant::now();
+        let token = self.auth.get_access_token().await?;
+        let token_duration = token_start.elapsed();
+        tracing::info!("OAuth token request for get_listing_fees: {:?}", token_duration);
+        
+        let url = format!("{}/sell/inventory/v1/offer/get_listing_fees", self.config.base_url());
+        let http = self.config.http_client();
+        
+        // Fail fast while this API's circuit breaker is open
+        let probe = self.config.check_circuit("sell.inventory")?;
+        
+        let offers = OfferKeysWithId {
+            offers: Some(
+                offer_ids
+                    .iter()
+                    .map(|offer_id| OfferKeyWithId { offer_id: Some(offer_id.to_string()) })
+                    .collect(),
+            ),
+        };
+        
+        // Call the eBay API
+        let ebay_start = std::time::Instant::now();
+        let result = self.config.send_json(http.post(&url).bearer_auth(&token).json(&offers)).await;
+        let ebay_duration = ebay_start.elapsed();
+        record_api_call("sell.inventory", "get_listing_fees", &result, ebay_duration);
+        self.config.record_circuit("sell.inventory", &probe, &result);
+        tracing::info!("eBay get_listing_fees API call: {:?}", ebay_duration);
+        
+        match result {
+            Ok(response) => {
+                let total_duration = start_time.elapsed();
+                let our_processing = total_duration - token_duration - ebay_duration;
+                tracing::info!("get_listing_fees total: {:?} | Our processing: {:?}", total_duration, our_processing);
+                Ok(response)
+            },
+            Err(e) => {
+                let total_duration = start_time.elapsed();
+                tracing::error!("eBay get_listing_fees error after {:?}: {:?}", total_duration, e);
+                Err(raw_error("get_listing_fees", &format!("offer_ids {}", offer_ids.join(",")), e))
+            }
+        }
+    }
+
+    /// Estimate the fees of publishing each offer
+    /// 
+    /// Because `get_listing_fees` totals fees per marketplace, each offer is looked
+    /// up separately (a few at a time) to get its own breakdown. Results are in the
+    /// order of `offer_ids`.
+    /// 
+    /// # Arguments
+    /// * `offer_ids` - IDs of unpublished offers
+    pub async fn estimate_fees(&self, offer_ids: &[&str]) -> HermesResult<Vec<FeeEstimate>> {
+        stream::iter(offer_ids)
+            .map(|offer_id| async move {
+                let response = self.get_listing_fees(&[offer_id]).await?;
+                Ok(FeeEstimate::from_summary(offer_id, response))
+            })
+            .buffered(MAX_CONCURRENT_FEE_REQUESTS)
+            .collect::<Vec<HermesResult<FeeEstimate>>>()
+            .await
+            .into_iter()
+            .collect()
+    }
+
     // TODO: Additional methods to implement (30+ total):
     // - update_offer, delete_offer, get_offer
     // - bulk_create_offer, bulk_publish_offer
@@ -392,5 +895,891 @@ impl InventoryClient {
     // - inventory_item_group operations (create, get, delete)
     // - inventory_location operations (create, get, update, delete, enable, disable)
     // - listing operations (migrate, sku mapping)
-    // - get_listing_fees
-}
\ No newline at end of file
+}
+
+#[async_trait]
+impl MarketplaceListing for InventoryClient {
+    /// Create an inventory item and an unpublished fixed-price offer for `product`
+    /// 
+    /// The offer targets the configured marketplace and is returned as the listing ID;
+    /// call `publish_offer` once a category and business policies have been added.
+    async fn create_listing(&self, product: &UnifiedProduct) -> HermesResult<ListingId> {
+        self.create_or_replace_inventory_item(
+            &product.sku,
+            &unified_inventory_item(product),
+            UNIFIED_CONTENT_LANGUAGE,
+        ).await?;
+
+        let offer = unified_offer(product, self.config.marketplace.as_str());
+        let response = self.create_offer(&offer, UNIFIED_CONTENT_LANGUAGE).await?;
+        response
+            .offer_id
+            .map(ListingId)
+            .ok_or_else(|| HermesError::ApiRequest("eBay create_offer returned no offer ID".to_string()))
+    }
+}
+
+/// One line of `export_inventory_ndjson`
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct InventoryExportRecord<'a> {
+    inventory_item: &'a InventoryItemWithSkuLocaleGroupid,
+    offers: &'a [EbayOfferDetailsWithAll],
+}
+
+/// Convenience accessors for a [`PublishResponse`]
+pub trait PublishResponseExt {
+    /// ID of the live eBay listing created by publishing the offer
+    fn listing_id(&self) -> Option<&str>;
+
+    /// Buyer-facing URL of the published listing, e.g. "https://www.ebay.com/itm/1234"
+    fn listing_url(&self, marketplace: Marketplace) -> Option<String>;
+}
+
+impl PublishResponseExt for PublishResponse {
+    fn listing_id(&self) -> Option<&str> {
+        self.listing_id.as_deref().filter(|id| !id.is_empty())
+    }
+
+    fn listing_url(&self, marketplace: Marketplace) -> Option<String> {
+        PublishResponseExt::listing_id(self).map(|id| marketplace.listing_url(id))
+    }
+}
+
+/// Expected fees of publishing one offer
+#[derive(Debug, Clone, PartialEq)]
+pub struct FeeEstimate {
+    pub offer_id: String,
+    pub marketplace_id: Option<String>,
+    /// Individual fees, e.g. "INSERTION_FEE"; zero-value fees are included
+    pub fees: Vec<FeeLine>,
+    /// Sum of the fees after promotional discounts
+    pub total: Decimal,
+    pub currency: Option<String>,
+}
+
+/// One fee of a [`FeeEstimate`]
+#[derive(Debug, Clone, PartialEq)]
+pub struct FeeLine {
+    pub fee_type: String,
+    pub amount: Decimal,
+    /// Discount eBay applies to this fee, if any
+    pub promotional_discount: Option<Decimal>,
+}
+
+impl FeeEstimate {
+    fn from_summary(offer_id: &str, response: FeesSummaryResponse) -> Self {
+        let summary = response.fee_summaries.unwrap_or_default().into_iter().next().unwrap_or_default();
+        let mut currency = None;
+        let fees: Vec<FeeLine> = summary
+            .fees
+            .unwrap_or_default()
+            .into_iter()
+            .map(|fee| {
+                if currency.is_none() {
+                    currency = fee.amount.as_deref().and_then(|amount| amount.currency.clone());
+                }
+                FeeLine {
+                    fee_type: fee.fee_type.unwrap_or_default(),
+                    amount: parse_decimal(fee.amount.as_deref().and_then(|amount| amount.value.as_deref()))
+                        .unwrap_or_default(),
+                    promotional_discount: parse_decimal(
+                        fee.promotional_discount.as_deref().and_then(|discount| discount.value.as_deref()),
+                    ),
+                }
+            })
+            .collect();
+        let total = fees.iter().map(|fee| fee.amount - fee.promotional_discount.unwrap_or_default()).sum();
+
+        Self {
+            offer_id: offer_id.to_string(),
+            marketplace_id: summary.marketplace_id,
+            fees,
+            total,
+            currency,
+        }
+    }
+}
+
+/// Whether eBay rejected a publish because the offer is already live
+/// 
+/// Matched on the error ID (per `domain` when eBay reports one); errors without an
+/// ID fall back to the message text.
+fn is_already_published(error: &HermesError) -> bool {
+    let HermesError::Api { errors, .. } = error else {
+        return false;
+    };
+    let (domain, error_id) = ALREADY_PUBLISHED_ERROR;
+    errors.iter().any(|error| match error.error_id {
+        Some(id) => id == error_id && error.domain.as_deref().is_none_or(|d| d == domain),
+        None => [error.message.as_deref(), error.long_message.as_deref()]
+            .into_iter()
+            .flatten()
+            .any(|text| text.to_ascii_lowercase().contains("already published")),
+    })
+}
+
+/// Heuristic completeness of an inventory item, from [`quality_score`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct QualityReport {
+    /// 0 (empty) to 100 (complete)
+    pub score: u8,
+    /// Required aspects of the category without a value
+    pub missing_required: Vec<String>,
+    /// Recommended aspects of the category without a value
+    pub missing_recommended: Vec<String>,
+    pub image_count: usize,
+    /// Actionable fixes, most important first
+    pub suggestions: Vec<String>,
+}
+
+/// Score how complete an inventory item is before its offer is published
+/// 
+/// Required aspects count for 50 points, recommended aspects for 20, images for 20
+/// (full marks from five images) and a title and description for 5 each. Pricing
+/// lives on the offer and is not scored.
+/// 
+/// # Arguments
+/// * `item` - The inventory item to score
+/// * `aspects_meta` - Item aspects of the category it will be listed in
+pub fn quality_score(item: &InventoryItem, aspects_meta: &AspectMetadata) -> QualityReport {
+    let product = item.product.as_deref().cloned().unwrap_or_default();
+    // The generated model carries the aspects object as JSON text
+    let aspects: HashMap<String, Vec<String>> =
+        product.aspects.as_deref().and_then(|aspects| serde_json::from_str(aspects).ok()).unwrap_or_default();
+    let is_filled = |name: &str| aspects.get(name).is_some_and(|values| values.iter().any(|value| !value.trim().is_empty()));
+    let missing = |names: Vec<String>| -> (usize, Vec<String>) {
+        let total = names.len();
+        (total, names.into_iter().filter(|name| !is_filled(name)).collect())
+    };
+
+    let (required_total, missing_required) =
+        missing(aspects_meta.required_aspects().into_iter().map(|aspect| aspect.name).collect());
+    let (recommended_total, missing_recommended) =
+        missing(aspects_meta.recommended_aspects().into_iter().map(|aspect| aspect.name).collect());
+    let image_count = product.image_urls.as_ref().map_or(0, Vec::len);
+    let has_title = product.title.as_deref().is_some_and(|title| !title.trim().is_empty());
+    let has_description = product.description.as_deref().is_some_and(|description| !description.trim().is_empty());
+
+    // Share of `weight` earned for `filled` out of `total` (full marks when nothing is expected)
+    let share = |weight: usize, filled: usize, total: usize| (weight * filled).checked_div(total).unwrap_or(weight);
+    let score = share(50, required_total - missing_required.len(), required_total)
+        + share(20, recommended_total - missing_recommended.len(), recommended_total)
+        + share(20, image_count.min(RECOMMENDED_IMAGE_COUNT), RECOMMENDED_IMAGE_COUNT)
+        + if has_title { 5 } else { 0 }
+        + if has_description { 5 } else { 0 };
+
+    let mut suggestions = Vec::new();
+    if !missing_required.is_empty() {
+        suggestions.push(format!("Fill in the required aspects: {}", missing_required.join(", ")));
+    }
+    if !has_title {
+        suggestions.push("Add a title".to_string());
+    }
+    if image_count < RECOMMENDED_IMAGE_COUNT {
+        suggestions.push(format!(
+            "Add {} more image(s); listings with at least {} images score best",
+            RECOMMENDED_IMAGE_COUNT - image_count,
+            RECOMMENDED_IMAGE_COUNT
+        ));
+    }
+    if !has_description {
+        suggestions.push("Add a description".to_string());
+    }
+    if !missing_recommended.is_empty() {
+        suggestions.push(format!("Consider the recommended aspects: {}", missing_recommended.join(", ")));
+    }
+
+    QualityReport { score: score as u8, missing_required, missing_recommended, image_count, suggestions }
+}
+
+/// Listing format of an offer
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Format {
+    #[default]
+    FixedPrice,
+    Auction,
+}
+
+impl Format {
+    /// The `FormatTypeEnum` value, e.g. "FIXED_PRICE"
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Format::FixedPrice => "FIXED_PRICE",
+            Format::Auction => "AUCTION",
+        }
+    }
+}
+
+/// Builder for the [`EbayOfferDetailsWithKeys`] passed to `create_offer`
+/// 
+/// `build` checks that the fields eBay needs to publish the offer are set: price,
+/// category, and the fulfillment, payment and return policies.
+/// 
+/// ```
+/// use hermes_sdk::ebay::marketplace::Marketplace;
+/// use hermes_sdk::ebay::sell::inventory::{Format, OfferBuilder};
+/// use rust_decimal::Decimal;
+///
+/// let offer = OfferBuilder::new("MUG-001", Marketplace::EbayUs)
+///     .format(Format::FixedPrice)
+///     .price(Decimal::new(2450, 2), "USD")
+///     .category_id("20625")
+///     .fulfillment_policy("6196932000")
+///     .payment_policy("6196938000")
+///     .return_policy("6196944000")
+///     .available_quantity(3)
+///     .build()
+///     .unwrap();
+/// assert_eq!(offer.format.as_deref(), Some("FIXED_PRICE"));
+/// ```
+#[derive(Debug, Clone)]
+pub struct OfferBuilder {
+    sku: String,
+    marketplace: Marketplace,
+    format: Format,
+    price: Option<Money>,
+    category_id: Option<String>,
+    fulfillment_policy_id: Option<String>,
+    payment_policy_id: Option<String>,
+    return_policy_id: Option<String>,
+    available_quantity: Option<u32>,
+    merchant_location_key: Option<String>,
+    listing_description: Option<String>,
+}
+
+impl OfferBuilder {
+    /// Start an offer for `sku` on `marketplace`, defaulting to fixed price
+    pub fn new(sku: &str, marketplace: Marketplace) -> Self {
+        Self {
+            sku: sku.to_string(),
+            marketplace,
+            format: Format::default(),
+            price: None,
+            category_id: None,
+            fulfillment_policy_id: None,
+            payment_policy_id: None,
+            return_policy_id: None,
+            available_quantity: None,
+            merchant_location_key: None,
+            listing_description: None,
+        }
+    }
+
+    pub fn format(mut self, format: Format) -> Self {
+        self.format = format;
+        self
+    }
+
+    /// Fixed price, or the starting bid for auctions
+    /// 
+    /// The amount is rounded to the currency's decimal places, as for [`price_money`](Self::price_money).
+    pub fn price(self, amount: Decimal, currency: &str) -> Self {
+        self.price_money(Money::new(amount, currency))
+    }
+
+    /// Fixed price or starting bid as [`Money`], sent with the currency's precision
+    /// (e.g. "24.50" for USD, "1500" for JPY)
+    pub fn price_money(mut self, price: Money) -> Self {
+        self.price = Some(price);
+        self
+    }
+
+    /// Primary eBay category the item is listed in
+    pub fn category_id(mut self, category_id: &str) -> Self {
+        self.category_id = Some(category_id.to_string());
+        self
+    }
+
+    pub fn fulfillment_policy(mut self, policy_id: &str) -> Self {
+        self.fulfillment_policy_id = Some(policy_id.to_string());
+        self
+    }
+
+    pub fn payment_policy(mut self, policy_id: &str) -> Self {
+        self.payment_policy_id = Some(policy_id.to_string());
+        self
+    }
+
+    pub fn return_policy(mut self, policy_id: &str) -> Self {
+        self.return_policy_id = Some(policy_id.to_string());
+        self
+    }
+
+    /// Quantity offered on this marketplace
+    pub fn available_quantity(mut self, quantity: u32) -> Self {
+        self.available_quantity = Some(quantity);
+        self
+    }
+
+    /// Inventory location the item ships from
+    pub fn merchant_location_key(mut self, key: &str) -> Self {
+        self.merchant_location_key = Some(key.to_string());
+        self
+    }
+
+    /// Listing description, overriding the inventory item's product description
+    pub fn listing_description(mut self, description: &str) -> Self {
+        self.listing_description = Some(description.to_string());
+        self
+    }
+
+    /// Build the offer, failing if a field required to publish it is missing
+    pub fn build(self) -> HermesResult<EbayOfferDetailsWithKeys> {
+        let missing: Vec<&str> = [
+            ("price", self.price.is_none()),
+            ("category_id", self.category_id.is_none()),
+            ("fulfillment_policy", self.fulfillment_policy_id.is_none()),
+            ("payment_policy", self.payment_policy_id.is_none()),
+            ("return_policy", self.return_policy_id.is_none()),
+        ]
+        .into_iter()
+        .filter_map(|(field, is_missing)| is_missing.then_some(field))
+        .collect();
+        if self.sku.is_empty() || !missing.is_empty() {
+            let missing = if self.sku.is_empty() { [&["sku"][..], &missing].concat() } else { missing };
+            return Err(HermesError::Configuration(format!("Offer is missing required fields: {}", missing.join(", "))));
+        }
+
+        let amount = self.price.map(|price| Box::new(Amount { value: Some(price.value()), currency: Some(price.currency) }));
+        let pricing_summary = match self.format {
+            Format::FixedPrice => PricingSummary { price: amount, ..Default::default() },
+            Format::Auction => PricingSummary { auction_start_price: amount, ..Default::default() },
+        };
+
+        Ok(EbayOfferDetailsWithKeys {
+            sku: Some(self.sku),
+            marketplace_id: Some(self.marketplace.as_str().to_string()),
+            format: Some(self.format.as_str().to_string()),
+            available_quantity: self.available_quantity.map(|quantity| quantity as i32),
+            category_id: self.category_id,
+            listing_description: self.listing_description,
+            merchant_location_key: self.merchant_location_key,
+            pricing_summary: Some(Box::new(pricing_summary)),
+            listing_policies: Some(Box::new(ListingPolicies {
+                fulfillment_policy_id: self.fulfillment_policy_id,
+                payment_policy_id: self.payment_policy_id,
+                return_policy_id: self.return_policy_id,
+                ..Default::default()
+            })),
+            ..Default::default()
+        })
+    }
+}
+
+/// Normalize a locale for comparison ("de-DE" and "de_de" both become "de_DE")
+fn normalize_locale(locale: &str) -> String {
+    match locale.replace('-', "_").split_once('_') {
+        Some((language, region)) => format!("{}_{}", language.to_lowercase(), region.to_uppercase()),
+        None => locale.to_lowercase(),
+    }
+}
+
+/// Translate a `UnifiedProduct` into an eBay inventory item
+fn unified_inventory_item(product: &UnifiedProduct) -> InventoryItem {
+    InventoryItem {
+        availability: Some(Box::new(Availability {
+            ship_to_location_availability: Some(Box::new(ShipToLocationAvailability {
+                quantity: Some(product.quantity as i32),
+                ..Default::default()
+            })),
+            ..Default::default()
+        })),
+        product: Some(Box::new(Product {
+            title: Some(product.title.clone()),
+            description: Some(product.description.clone()),
+            image_urls: Some(product.image_urls.clone()),
+            ..Default::default()
+        })),
+        ..Default::default()
+    }
+}
+
+/// Translate a `UnifiedProduct` into a fixed-price eBay offer
+fn unified_offer(product: &UnifiedProduct, marketplace_id: &str) -> EbayOfferDetailsWithKeys {
+    EbayOfferDetailsWithKeys {
+        sku: Some(product.sku.clone()),
+        marketplace_id: Some(marketplace_id.to_string()),
+        format: Some("FIXED_PRICE".to_string()),
+        available_quantity: Some(product.quantity as i32),
+        listing_description: Some(product.description.clone()),
+        pricing_summary: Some(Box::new(PricingSummary {
+            price: Some(Box::new(Amount {
+                currency: Some(product.currency.clone()),
+                value: Some(product.price.clone()),
+            })),
+            ..Default::default()
+        })),
+        ..Default::default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ebay::test_support::mock_ebay;
+    use wiremock::matchers::{body_partial_json, method, path, query_param};
+    use wiremock::{Mock, ResponseTemplate};
+
+    fn product() -> UnifiedProduct {
+        UnifiedProduct {
+            sku: "MUG-001".to_string(),
+            title: "Stoneware mug".to_string(),
+            description: "Hand-thrown, 12oz".to_string(),
+            price: "24.50".to_string(),
+            currency: "USD".to_string(),
+            quantity: 3,
+            image_urls: vec!["https://example.com/mug.jpg".to_string()],
+        }
+    }
+
+    fn mug_aspects() -> AspectMetadata {
+        serde_json::from_value(serde_json::json!({
+            "aspects": [
+                { "localizedAspectName": "Brand", "aspectConstraint": { "aspectRequired": true, "aspectUsage": "RECOMMENDED" } },
+                { "localizedAspectName": "Type", "aspectConstraint": { "aspectRequired": true, "aspectUsage": "RECOMMENDED" } },
+                { "localizedAspectName": "Color", "aspectConstraint": { "aspectRequired": false, "aspectUsage": "RECOMMENDED" } },
+                { "localizedAspectName": "Theme", "aspectConstraint": { "aspectRequired": false, "aspectUsage": "OPTIONAL" } }
+            ]
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn quality_score_rates_sparse_and_complete_items() {
+        let sparse = InventoryItem {
+            product: Some(Box::new(Product {
+                title: Some("Mug".to_string()),
+                aspects: Some(r#"{"Brand":["Acme"]}"#.to_string()),
+                image_urls: Some(vec!["https://example.com/1.jpg".to_string()]),
+                ..Default::default()
+            })),
+            ..Default::default()
+        };
+        let report = quality_score(&sparse, &mug_aspects());
+        assert_eq!(report.missing_required, ["Type"]);
+        assert_eq!(report.missing_recommended, ["Color"]);
+        assert_eq!(report.image_count, 1);
+        // 25 (half the required aspects) + 0 + 4 (one image of five) + 5 (title)
+        assert_eq!(report.score, 34);
+        assert_eq!(report.suggestions[0], "Fill in the required aspects: Type");
+        assert!(report.suggestions.contains(&"Add a description".to_string()));
+
+        let complete = InventoryItem {
+            product: Some(Box::new(Product {
+                title: Some("Stoneware mug".to_string()),
+                description: Some("Hand-thrown, 12oz".to_string()),
+                aspects: Some(r#"{"Brand":["Acme"],"Type":["Coffee Mug"],"Color":["Blue"]}"#.to_string()),
+                image_urls: Some((1..=6).map(|n| format!("https://example.com/{}.jpg", n)).collect()),
+                ..Default::default()
+            })),
+            ..Default::default()
+        };
+        let report = quality_score(&complete, &mug_aspects());
+        assert_eq!(report.score, 100);
+        assert!(report.suggestions.is_empty());
+    }
+
+    #[tokio::test]
+    async fn dry_run_skips_mutating_calls() {
+        let (server, config) = mock_ebay().await;
+        let client = InventoryClient::new(config.with_dry_run(true)).unwrap();
+
+        let offer = unified_offer(&product(), "EBAY_US");
+        let response = client.create_offer(&offer, UNIFIED_CONTENT_LANGUAGE).await.unwrap();
+        assert_eq!(response.offer_id.as_deref(), Some(DRY_RUN_ID));
+        assert_eq!(client.publish_offer_idempotent("5001").await.unwrap(), DRY_RUN_ID);
+        client.withdraw_offer("5001").await.unwrap();
+        assert_eq!(client.create_listing(&product()).await.unwrap(), ListingId(DRY_RUN_ID.to_string()));
+
+        assert!(server.received_requests().await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn malformed_offer_keeps_its_body() {
+        let (server, config) = mock_ebay().await;
+        let malformed = r#"{"offerId":5001,"sku":"MUG-001"}"#;
+        Mock::given(method("GET"))
+            .and(path("/sell/inventory/v1/offer/5001"))
+            .respond_with(ResponseTemplate::new(200).set_body_raw(malformed, "application/json"))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = InventoryClient::new(config).unwrap();
+        let err = client.get_offer("5001").await.unwrap_err();
+        assert!(matches!(err, HermesError::Deserialize { ref body, .. } if body == malformed));
+    }
+
+    #[tokio::test]
+    async fn missing_offers_name_the_requested_sku() {
+        let (server, config) = mock_ebay().await;
+        Mock::given(method("GET"))
+            .and(path("/sell/inventory/v1/offer"))
+            .and(query_param("sku", "MUG-404"))
+            .respond_with(ResponseTemplate::new(404).set_body_json(serde_json::json!({
+                "errors": [{ "errorId": 25713, "domain": "API_INVENTORY", "message": "This Offer is not available" }]
+            })))
+            .mount(&server)
+            .await;
+
+        let client = InventoryClient::new(config).unwrap();
+        let err = client.get_offers(None, Some("MUG-404"), None, None).await.unwrap_err();
+        assert!(matches!(err, HermesError::NotFound { ref resource } if resource == "sku MUG-404"));
+    }
+
+    #[tokio::test]
+    async fn estimate_fees_returns_breakdown_per_offer() {
+        let (server, config) = mock_ebay().await;
+        for (offer_id, insertion_fee) in [("5001", "0.35"), ("5002", "0.0")] {
+            Mock::given(method("POST"))
+                .and(path("/sell/inventory/v1/offer/get_listing_fees"))
+                .and(body_partial_json(serde_json::json!({ "offers": [{ "offerId": offer_id }] })))
+                .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                    "feeSummaries": [{
+                        "marketplaceId": "EBAY_US",
+                        "fees": [
+                            { "feeType": "INSERTION_FEE", "amount": { "value": insertion_fee, "currency": "USD" } },
+                            {
+                                "feeType": "SUBTITLE_FEE",
+                                "amount": { "value": "1.50", "currency": "USD" },
+                                "promotionalDiscount": { "value": "0.50", "currency": "USD" }
+                            }
+                        ]
+                    }]
+                })))
+                .expect(1)
+                .mount(&server)
+                .await;
+        }
+
+        let client = InventoryClient::new(config).unwrap();
+        let estimates = client.estimate_fees(&["5001", "5002"]).await.unwrap();
+
+        assert_eq!(estimates.len(), 2);
+        assert_eq!(estimates[0].offer_id, "5001");
+        assert_eq!(estimates[0].marketplace_id.as_deref(), Some("EBAY_US"));
+        assert_eq!(estimates[0].fees[0].fee_type, "INSERTION_FEE");
+        assert_eq!(estimates[0].fees[1].promotional_discount, Some(Decimal::new(50, 2)));
+        assert_eq!(estimates[0].total, Decimal::new(135, 2));
+        assert_eq!(estimates[0].currency.as_deref(), Some("USD"));
+        assert_eq!(estimates[1].total, Decimal::new(100, 2));
+    }
+
+    #[test]
+    fn offer_builder_builds_fixed_price_offer() {
+        let offer = OfferBuilder::new("MUG-001", Marketplace::EbayGb)
+            .format(Format::FixedPrice)
+            .price(Decimal::new(1899, 2), "GBP")
+            .category_id("20625")
+            .fulfillment_policy("ful-1")
+            .payment_policy("pay-1")
+            .return_policy("ret-1")
+            .available_quantity(4)
+            .merchant_location_key("warehouse-1")
+            .build()
+            .unwrap();
+
+        assert_eq!(offer.sku.as_deref(), Some("MUG-001"));
+        assert_eq!(offer.marketplace_id.as_deref(), Some("EBAY_GB"));
+        assert_eq!(offer.format.as_deref(), Some("FIXED_PRICE"));
+        assert_eq!(offer.available_quantity, Some(4));
+        assert_eq!(offer.category_id.as_deref(), Some("20625"));
+        let price = offer.pricing_summary.unwrap().price.unwrap();
+        assert_eq!((price.value.as_deref(), price.currency.as_deref()), (Some("18.99"), Some("GBP")));
+        let policies = offer.listing_policies.unwrap();
+        assert_eq!(policies.fulfillment_policy_id.as_deref(), Some("ful-1"));
+        assert_eq!(policies.payment_policy_id.as_deref(), Some("pay-1"));
+        assert_eq!(policies.return_policy_id.as_deref(), Some("ret-1"));
+
+        let err = OfferBuilder::new("MUG-001", Marketplace::EbayGb).category_id("20625").build().unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid configuration: Offer is missing required fields: price, fulfillment_policy, payment_policy, return_policy"
+        );
+    }
+
+    #[test]
+    fn offer_builder_formats_price_for_currency() {
+        let offer_price = |price: Money| {
+            let offer = OfferBuilder::new("MUG-001", Marketplace::EbayUs)
+                .price_money(price)
+                .category_id("20625")
+                .fulfillment_policy("ful-1")
+                .payment_policy("pay-1")
+                .return_policy("ret-1")
+                .build()
+                .unwrap();
+            let price = offer.pricing_summary.unwrap().price.unwrap();
+            (price.value.unwrap(), price.currency.unwrap())
+        };
+
+        assert_eq!(offer_price(Money::new(Decimal::new(245, 1), "USD")), ("24.50".to_string(), "USD".to_string()));
+        assert_eq!(offer_price(Money::from_f64(1500.4, "JPY").unwrap()), ("1500".to_string(), "JPY".to_string()));
+    }
+
+    #[test]
+    fn unified_product_translates_to_inventory_item_and_offer() {
+        let item = unified_inventory_item(&product());
+        let item_product = item.product.unwrap();
+        assert_eq!(item_product.title.as_deref(), Some("Stoneware mug"));
+        assert_eq!(item_product.image_urls.unwrap(), ["https://example.com/mug.jpg"]);
+        let quantity = item.availability.unwrap().ship_to_location_availability.unwrap().quantity;
+        assert_eq!(quantity, Some(3));
+
+        let offer = unified_offer(&product(), "EBAY_GB");
+        assert_eq!(offer.sku.as_deref(), Some("MUG-001"));
+        assert_eq!(offer.marketplace_id.as_deref(), Some("EBAY_GB"));
+        assert_eq!(offer.format.as_deref(), Some("FIXED_PRICE"));
+        assert_eq!(offer.available_quantity, Some(3));
+        let price = offer.pricing_summary.unwrap().price.unwrap();
+        assert_eq!(price.value.as_deref(), Some("24.50"));
+        assert_eq!(price.currency.as_deref(), Some("USD"));
+    }
+
+    #[tokio::test]
+    async fn create_listing_creates_item_then_offer() {
+        let (server, config) = mock_ebay().await;
+        Mock::given(method("PUT"))
+            .and(path("/sell/inventory/v1/inventory_item/MUG-001"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({})))
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/sell/inventory/v1/offer"))
+            .and(body_partial_json(serde_json::json!({ "sku": "MUG-001", "marketplaceId": "EBAY_DE" })))
+            .respond_with(ResponseTemplate::new(201).set_body_json(serde_json::json!({ "offerId": "9876" })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = InventoryClient::new(config.with_marketplace(Marketplace::EbayDe)).unwrap();
+        let listing_id = client.create_listing(&product()).await.unwrap();
+        assert_eq!(listing_id, ListingId("9876".to_string()));
+    }
+
+    #[tokio::test]
+    async fn get_inventory_items_by_group_filters_by_locale() {
+        let (server, config) = mock_ebay().await;
+        Mock::given(method("GET"))
+            .and(path("/sell/inventory/v1/inventory_item_group/SHIRT"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "inventoryItemGroupKey": "SHIRT",
+                "variantSKUs": ["SHIRT-S", "SHIRT-M", "SHIRT-L"]
+            })))
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/sell/inventory/v1/bulk_get_inventory_item"))
+            .and(body_partial_json(serde_json::json!({
+                "requests": [{ "sku": "SHIRT-S" }, { "sku": "SHIRT-M" }, { "sku": "SHIRT-L" }]
+            })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "responses": [
+                    { "sku": "SHIRT-S", "statusCode": 200, "inventoryItem": { "sku": "SHIRT-S", "locale": "de_DE" } },
+                    { "sku": "SHIRT-M", "statusCode": 200, "inventoryItem": { "sku": "SHIRT-M", "locale": "en_US" } },
+                    { "sku": "SHIRT-L", "statusCode": 404, "errors": [{ "errorId": 25702 }] }
+                ]
+            })))
+            .mount(&server)
+            .await;
+
+        let client = InventoryClient::new(config).unwrap();
+        let all = client.get_inventory_items_by_group("SHIRT", None).await.unwrap();
+        let skus: Vec<_> = all.iter().filter_map(|item| item.sku.as_deref()).collect();
+        assert_eq!(skus, ["SHIRT-S", "SHIRT-M"]);
+
+        let german = client.get_inventory_items_by_group("SHIRT", Some("de-DE")).await.unwrap();
+        assert_eq!(german.len(), 1);
+        assert_eq!(german[0].sku.as_deref(), Some("SHIRT-S"));
+    }
+
+    #[tokio::test]
+    async fn get_offers_for_sku_is_empty_without_offers() {
+        let (server, config) = mock_ebay().await;
+        Mock::given(method("GET"))
+            .and(path("/sell/inventory/v1/offer"))
+            .and(query_param("sku", "MUG-001"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({ "total": 0 })))
+            .mount(&server)
+            .await;
+
+        let client = InventoryClient::new(config).unwrap();
+        assert!(client.get_offers_for_sku("MUG-001").await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn inventory_item_exists_distinguishes_missing_from_failures() {
+        let (server, config) = mock_ebay().await;
+        Mock::given(method("GET"))
+            .and(path("/sell/inventory/v1/inventory_item/MUG-001"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({ "sku": "MUG-001" })))
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/sell/inventory/v1/inventory_item/GONE"))
+            .respond_with(ResponseTemplate::new(404))
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/sell/inventory/v1/inventory_item/BROKEN"))
+            .respond_with(ResponseTemplate::new(500))
+            .mount(&server)
+            .await;
+
+        let client = InventoryClient::new(config).unwrap();
+        assert!(client.inventory_item_exists("MUG-001").await.unwrap());
+        assert!(!client.inventory_item_exists("GONE").await.unwrap());
+        let err = client.inventory_item_exists("BROKEN").await.unwrap_err();
+        assert!(matches!(err, HermesError::Api { status: 500, .. }));
+    }
+
+    #[tokio::test]
+    async fn not_found_is_reported_for_404_and_error_id_bodies() {
+        let (server, config) = mock_ebay().await;
+        Mock::given(method("GET"))
+            .and(path("/sell/inventory/v1/inventory_item/GONE"))
+            .respond_with(ResponseTemplate::new(404))
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/sell/inventory/v1/inventory_item/MUG-001"))
+            .respond_with(ResponseTemplate::new(400).set_body_json(serde_json::json!({
+                "errors": [{
+                    "errorId": 25702,
+                    "domain": "API_INVENTORY",
+                    "category": "REQUEST",
+                    "message": "SKU MUG-001 is not available in the system"
+                }]
+            })))
+            .mount(&server)
+            .await;
+
+        let client = InventoryClient::new(config).unwrap();
+        let err = client.get_inventory_item("GONE").await.unwrap_err();
+        assert!(matches!(err, HermesError::NotFound { ref resource } if resource == "sku GONE"));
+        let err = client.get_inventory_item("MUG-001").await.unwrap_err();
+        assert!(matches!(err, HermesError::NotFound { ref resource } if resource == "sku MUG-001"));
+    }
+
+    #[test]
+    fn publish_response_exposes_listing_id_and_url() {
+        let response: PublishResponse =
+            serde_json::from_value(serde_json::json!({ "listingId": "110554789012" })).unwrap();
+        assert_eq!(PublishResponseExt::listing_id(&response), Some("110554789012"));
+        assert_eq!(
+            response.listing_url(Marketplace::EbayUs).as_deref(),
+            Some("https://www.ebay.com/itm/110554789012")
+        );
+        assert_eq!(
+            response.listing_url(Marketplace::EbayGb).as_deref(),
+            Some("https://www.ebay.co.uk/itm/110554789012")
+        );
+        assert_eq!(PublishResponse::default().listing_url(Marketplace::EbayUs), None);
+    }
+
+    #[tokio::test]
+    async fn publish_offer_idempotent_accepts_already_published_offers() {
+        let (server, config) = mock_ebay().await;
+        Mock::given(method("POST"))
+            .and(path("/sell/inventory/v1/offer/5001/publish"))
+            .respond_with(ResponseTemplate::new(400).set_body_json(serde_json::json!({
+                "errors": [{
+                    "errorId": 25016,
+                    "domain": "API_INVENTORY",
+                    "category": "REQUEST",
+                    "message": "The offer is already published"
+                }]
+            })))
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/sell/inventory/v1/offer/5001"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "offerId": "5001",
+                "status": "PUBLISHED",
+                "listing": { "listingId": "110554789012", "listingStatus": "ACTIVE" }
+            })))
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/sell/inventory/v1/offer/5002/publish"))
+            .respond_with(ResponseTemplate::new(400).set_body_json(serde_json::json!({
+                "errors": [{ "errorId": 25002, "message": "Missing return policy" }]
+            })))
+            .mount(&server)
+            .await;
+
+        let client = InventoryClient::new(config).unwrap();
+        assert_eq!(client.publish_offer_idempotent("5001").await.unwrap(), "110554789012");
+        let err = client.publish_offer_idempotent("5002").await.unwrap_err();
+        assert_eq!(err.api_messages(), ["Missing return policy"]);
+    }
+
+    #[test]
+    fn already_published_matches_error_id_before_text() {
+        let api_error = |errors: serde_json::Value| HermesError::Api {
+            operation: "publish_offer".to_string(),
+            status: 400,
+            errors: serde_json::from_value(errors).unwrap(),
+            body: String::new(),
+        };
+        let by_id = api_error(serde_json::json!([{ "errorId": 25016, "domain": "API_INVENTORY", "message": "Offer live" }]));
+        assert!(is_already_published(&by_id));
+
+        let other_domain = api_error(serde_json::json!([{ "errorId": 25016, "domain": "API_ACCOUNT" }]));
+        assert!(!is_already_published(&other_domain));
+
+        // Text only counts when eBay sent no error ID
+        let other_id = api_error(serde_json::json!([{ "errorId": 25002, "message": "Offer already published" }]));
+        assert!(!is_already_published(&other_id));
+        let text_only = api_error(serde_json::json!([{ "message": "The offer is already published" }]));
+        assert!(is_already_published(&text_only));
+    }
+
+    #[tokio::test]
+    async fn export_inventory_ndjson_writes_one_line_per_item() {
+        let (server, config) = mock_ebay().await;
+        Mock::given(method("GET"))
+            .and(path("/sell/inventory/v1/inventory_item"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "total": 2,
+                "inventoryItems": [
+                    { "sku": "MUG-001", "product": { "title": "Coffee mug" } },
+                    { "sku": "MUG-002", "product": { "title": "Tea mug" } }
+                ]
+            })))
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/sell/inventory/v1/offer"))
+            .and(query_param("sku", "MUG-001"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "offers": [{ "offerId": "5001", "sku": "MUG-001" }]
+            })))
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/sell/inventory/v1/offer"))
+            .and(query_param("sku", "MUG-002"))
+            .respond_with(ResponseTemplate::new(404))
+            .mount(&server)
+            .await;
+
+        let client = InventoryClient::new(config).unwrap();
+        let mut output = Vec::new();
+        let written = client.export_inventory_ndjson(&mut output).await.unwrap();
+        assert_eq!(written, 2);
+
+        let lines: Vec<serde_json::Value> = String::from_utf8(output)
+            .unwrap()
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
+        assert_eq!(lines.len(), 2);
+        assert_eq!(lines[0]["inventoryItem"]["sku"], "MUG-001");
+        assert_eq!(lines[0]["offers"][0]["offerId"], "5001");
+        assert_eq!(lines[1]["offers"], serde_json::json!([]));
+    }
+}
@@ -1,14 +1,49 @@
 use crate::config::EbayConfig;
-use crate::error::{HermesError, HermesResult};
+use crate::error::HermesResult;
+use crate::ebay::api_error::raw_error;
+use crate::ebay::telemetry::record_api_call;
 use crate::ebay::auth::EbayAuth;
+use crate::ebay::marketplace::{normalize_marketplace, MarketplaceStyle};
 use std::sync::Arc;
 
 // Import eBay Sell Metadata SDK models and APIs
 use hermes_ebay_sell_metadata::models::{
-    CategoryPolicyResponse, ItemConditionPolicyResponse,
+    CategoryPolicyResponse, ItemCondition, ItemConditionPolicyResponse,
     ReturnPolicyResponse, ShippingPoliciesResponse, GetCurrenciesResponse,
 };
-use hermes_ebay_sell_metadata::apis::configuration::Configuration as MetadataConfiguration;
+use hermes_ebay_sell_metadata::apis::urlencode;
+
+/// A currency, as shown in a currency picker
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CurrencyInfo {
+    /// ISO 4217 currency code, e.g. "USD"
+    pub code: String,
+    /// eBay's description of the currency, e.g. "US Dollar"
+    pub display_name: String,
+}
+
+/// Picker-friendly access to a [`GetCurrenciesResponse`]
+pub trait CurrenciesResponseExt {
+    /// The marketplace's currencies
+    /// 
+    /// eBay currently reports only the marketplace's default currency. The display
+    /// name falls back to the code when eBay sends no description.
+    fn currencies(&self) -> Vec<CurrencyInfo>;
+}
+
+impl CurrenciesResponseExt for GetCurrenciesResponse {
+    fn currencies(&self) -> Vec<CurrencyInfo> {
+        self.default_currency
+            .as_deref()
+            .and_then(|currency| {
+                let code = currency.code.clone()?;
+                let display_name = currency.description.clone().unwrap_or_else(|| code.clone());
+                Some(CurrencyInfo { code, display_name })
+            })
+            .into_iter()
+            .collect()
+    }
+}
 
 /// eBay Sell Metadata API client for comprehensive marketplace metadata and policy management
 /// 
@@ -52,23 +87,22 @@ impl MetadataClient {
         let token_duration = token_start.elapsed();
         tracing::info!("OAuth token request for get_category_policies: {:?}", token_duration);
         
-        // Set up configuration
-        let mut config = MetadataConfiguration::new();
-        config.base_path = if self.config.sandbox {
-            "https://api.sandbox.ebay.com/sell/metadata/v1".to_string()
-        } else {
-            "https://api.ebay.com/sell/metadata/v1".to_string()
-        };
-        config.oauth_access_token = Some(token);
+        let http = self.config.http_client();
+        
+        // Fail fast while this API's circuit breaker is open
+        let probe = self.config.check_circuit("sell.metadata")?;
         
-        // Call the eBay SDK
+        let marketplace_id = normalize_marketplace(marketplace_id, MarketplaceStyle::Underscore);
+        let url = format!("{}/sell/metadata/v1/marketplace/{}/get_category_policies", self.config.base_url(), urlencode(&marketplace_id));
+        
+        // Call the eBay API
         let ebay_start = std::time::Instant::now();
-        let result = hermes_ebay_sell_metadata::apis::marketplace_api::get_category_policies(
-            &config,
-            marketplace_id,
-            filter,
-        ).await;
+        let result = self.config.retrying("sell.metadata", || {
+            self.config.send_json(http.get(&url).bearer_auth(&token).query(&[("filter", filter)]))
+        }).await;
         let ebay_duration = ebay_start.elapsed();
+        record_api_call("sell.metadata", "get_category_policies", &result, ebay_duration);
+        self.config.record_circuit("sell.metadata", &probe, &result);
         tracing::info!("eBay get_category_policies API call: {:?}", ebay_duration);
         
         match result {
@@ -81,7 +115,7 @@ impl MetadataClient {
             Err(e) => {
                 let total_duration = start_time.elapsed();
                 tracing::error!("eBay get_category_policies error after {:?}: {:?}", total_duration, e);
-                Err(HermesError::ApiRequest(format!("eBay get_category_policies failed: {:?}", e)))
+                Err(raw_error("get_category_policies", &format!("marketplace_id {}", marketplace_id), e))
             }
         }
     }
@@ -107,24 +141,22 @@ impl MetadataClient {
         let token_duration = token_start.elapsed();
         tracing::info!("OAuth token request for get_item_condition_policies: {:?}", token_duration);
         
-        // Set up configuration
-        let mut config = MetadataConfiguration::new();
-        config.base_path = if self.config.sandbox {
-            "https://api.sandbox.ebay.com/sell/metadata/v1".to_string()
-        } else {
-            "https://api.ebay.com/sell/metadata/v1".to_string()
-        };
-        config.oauth_access_token = Some(token);
+        let http = self.config.http_client();
+        
+        // Fail fast while this API's circuit breaker is open
+        let probe = self.config.check_circuit("sell.metadata")?;
+        
+        let marketplace_id = normalize_marketplace(marketplace_id, MarketplaceStyle::Underscore);
+        let url = format!("{}/sell/metadata/v1/marketplace/{}/get_item_condition_policies", self.config.base_url(), urlencode(&marketplace_id));
         
-        // Call the eBay SDK
+        // Call the eBay API
         let ebay_start = std::time::Instant::now();
-        let result = hermes_ebay_sell_metadata::apis::marketplace_api::get_item_condition_policies(
-            &config,
-            marketplace_id,
-            filter,
-            None, // accept_encoding
-        ).await;
+        let result = self.config.retrying("sell.metadata", || {
+            self.config.send_json(http.get(&url).bearer_auth(&token).query(&[("filter", filter)]))
+        }).await;
         let ebay_duration = ebay_start.elapsed();
+        record_api_call("sell.metadata", "get_item_condition_policies", &result, ebay_duration);
+        self.config.record_circuit("sell.metadata", &probe, &result);
         tracing::info!("eBay get_item_condition_policies API call: {:?}", ebay_duration);
         
         match result {
@@ -137,7 +169,7 @@ impl MetadataClient {
             Err(e) => {
                 let total_duration = start_time.elapsed();
                 tracing::error!("eBay get_item_condition_policies error after {:?}: {:?}", total_duration, e);
-                Err(HermesError::ApiRequest(format!("eBay get_item_condition_policies failed: {:?}", e)))
+                Err(raw_error("get_item_condition_policies", &format!("marketplace_id {}", marketplace_id), e))
             }
         }
     }
@@ -163,24 +195,22 @@ impl MetadataClient {
         let token_duration = token_start.elapsed();
         tracing::info!("OAuth token request for get_return_policies: {:?}", token_duration);
         
-        // Set up configuration
-        let mut config = MetadataConfiguration::new();
-        config.base_path = if self.config.sandbox {
-            "https://api.sandbox.ebay.com/sell/metadata/v1".to_string()
-        } else {
-            "https://api.ebay.com/sell/metadata/v1".to_string()
-        };
-        config.oauth_access_token = Some(token);
+        let http = self.config.http_client();
         
-        // Call the eBay SDK
+        // Fail fast while this API's circuit breaker is open
+        let probe = self.config.check_circuit("sell.metadata")?;
+        
+        let marketplace_id = normalize_marketplace(marketplace_id, MarketplaceStyle::Underscore);
+        let url = format!("{}/sell/metadata/v1/marketplace/{}/get_return_policies", self.config.base_url(), urlencode(&marketplace_id));
+        
+        // Call the eBay API
         let ebay_start = std::time::Instant::now();
-        let result = hermes_ebay_sell_metadata::apis::marketplace_api::get_return_policies(
-            &config,
-            marketplace_id,
-            filter,
-            None, // accept_encoding
-        ).await;
+        let result = self.config.retrying("sell.metadata", || {
+            self.config.send_json(http.get(&url).bearer_auth(&token).query(&[("filter", filter)]))
+        }).await;
         let ebay_duration = ebay_start.elapsed();
+        record_api_call("sell.metadata", "get_return_policies", &result, ebay_duration);
+        self.config.record_circuit("sell.metadata", &probe, &result);
         tracing::info!("eBay get_return_policies API call: {:?}", ebay_duration);
         
         match result {
@@ -193,7 +223,7 @@ impl MetadataClient {
             Err(e) => {
                 let total_duration = start_time.elapsed();
                 tracing::error!("eBay get_return_policies error after {:?}: {:?}", total_duration, e);
-                Err(HermesError::ApiRequest(format!("eBay get_return_policies failed: {:?}", e)))
+                Err(raw_error("get_return_policies", &format!("marketplace_id {}", marketplace_id), e))
             }
         }
     }
@@ -219,23 +249,22 @@ impl MetadataClient {
         let token_duration = token_start.elapsed();
         tracing::info!("OAuth token request for get_shipping_policies: {:?}", token_duration);
         
-        // Set up configuration
-        let mut config = MetadataConfiguration::new();
-        config.base_path = if self.config.sandbox {
-            "https://api.sandbox.ebay.com/sell/metadata/v1".to_string()
-        } else {
-            "https://api.ebay.com/sell/metadata/v1".to_string()
-        };
-        config.oauth_access_token = Some(token);
+        let http = self.config.http_client();
+        
+        // Fail fast while this API's circuit breaker is open
+        let probe = self.config.check_circuit("sell.metadata")?;
         
-        // Call the eBay SDK
+        let marketplace_id = normalize_marketplace(marketplace_id, MarketplaceStyle::Underscore);
+        let url = format!("{}/sell/metadata/v1/marketplace/{}/get_shipping_policies", self.config.base_url(), urlencode(&marketplace_id));
+        
+        // Call the eBay API
         let ebay_start = std::time::Instant::now();
-        let result = hermes_ebay_sell_metadata::apis::marketplace_api::get_shipping_policies(
-            &config,
-            marketplace_id,
-            filter,
-        ).await;
+        let result = self.config.retrying("sell.metadata", || {
+            self.config.send_json(http.get(&url).bearer_auth(&token).query(&[("filter", filter)]))
+        }).await;
         let ebay_duration = ebay_start.elapsed();
+        record_api_call("sell.metadata", "get_shipping_policies", &result, ebay_duration);
+        self.config.record_circuit("sell.metadata", &probe, &result);
         tracing::info!("eBay get_shipping_policies API call: {:?}", ebay_duration);
         
         match result {
@@ -248,7 +277,7 @@ impl MetadataClient {
             Err(e) => {
                 let total_duration = start_time.elapsed();
                 tracing::error!("eBay get_shipping_policies error after {:?}: {:?}", total_duration, e);
-                Err(HermesError::ApiRequest(format!("eBay get_shipping_policies failed: {:?}", e)))
+                Err(raw_error("get_shipping_policies", &format!("marketplace_id {}", marketplace_id), e))
             }
         }
     }
@@ -274,23 +303,26 @@ impl MetadataClient {
         let token_duration = token_start.elapsed();
         tracing::info!("OAuth token request for get_currencies: {:?}", token_duration);
         
-        // Set up configuration
-        let mut config = MetadataConfiguration::new();
-        config.base_path = if self.config.sandbox {
-            "https://api.sandbox.ebay.com/sell/metadata/v1".to_string()
-        } else {
-            "https://api.ebay.com/sell/metadata/v1".to_string()
-        };
-        config.oauth_access_token = Some(token);
+        let http = self.config.http_client();
+        
+        // Fail fast while this API's circuit breaker is open
+        let probe = self.config.check_circuit("sell.metadata")?;
+        
+        let marketplace_id = normalize_marketplace(marketplace_id, MarketplaceStyle::Underscore);
+        let url = format!("{}/sell/metadata/v1/marketplace/{}/get_currencies", self.config.base_url(), urlencode(&marketplace_id));
         
-        // Call the eBay SDK
+        // Call the eBay API
         let ebay_start = std::time::Instant::now();
-        let result = hermes_ebay_sell_metadata::apis::marketplace_api::get_currencies(
-            &config,
-            marketplace_id,
-            accept_language,
-        ).await;
+        let result = self.config.retrying("sell.metadata", || {
+            let mut request = http.get(&url).bearer_auth(&token);
+            if let Some(accept_language) = accept_language {
+                request = request.header(reqwest::header::ACCEPT_LANGUAGE, accept_language);
+            }
+            self.config.send_json(request)
+        }).await;
         let ebay_duration = ebay_start.elapsed();
+        record_api_call("sell.metadata", "get_currencies", &result, ebay_duration);
+        self.config.record_circuit("sell.metadata", &probe, &result);
         tracing::info!("eBay get_currencies API call: {:?}", ebay_duration);
         
         match result {
@@ -303,11 +335,71 @@ impl MetadataClient {
             Err(e) => {
                 let total_duration = start_time.elapsed();
                 tracing::error!("eBay get_currencies error after {:?}: {:?}", total_duration, e);
-                Err(HermesError::ApiRequest(format!("eBay get_currencies failed: {:?}", e)))
+                Err(raw_error("get_currencies", &format!("marketplace_id {}", marketplace_id), e))
             }
         }
     }
 
+    /// Get the display name of an item condition in a category
+    /// 
+    /// Returns e.g. "New" for condition ID "1000", or `None` if the category does not
+    /// support the condition. Condition names vary by category, so the category's
+    /// condition policy is consulted.
+    /// 
+    /// # Arguments
+    /// * `marketplace_id` - The marketplace ID (e.g., "EBAY_US")
+    /// * `category_id` - The leaf category ID
+    /// * `condition_id` - The numeric condition ID, e.g. "3000"
+    pub async fn condition_name(
+        &self,
+        marketplace_id: &str,
+        category_id: &str,
+        condition_id: &str,
+    ) -> HermesResult<Option<String>> {
+        let conditions = self.category_conditions(marketplace_id, category_id).await?;
+        Ok(conditions
+            .into_iter()
+            .find(|condition| condition.condition_id.as_deref() == Some(condition_id))
+            .and_then(|condition| condition.condition_description))
+    }
+
+    /// Get the ID of an item condition in a category from its display name
+    /// 
+    /// The reverse of [`condition_name`](Self::condition_name). Names are matched
+    /// case-insensitively.
+    /// 
+    /// # Arguments
+    /// * `marketplace_id` - The marketplace ID (e.g., "EBAY_US")
+    /// * `category_id` - The leaf category ID
+    /// * `name` - The condition name, e.g. "Used"
+    pub async fn condition_id(
+        &self,
+        marketplace_id: &str,
+        category_id: &str,
+        name: &str,
+    ) -> HermesResult<Option<String>> {
+        let conditions = self.category_conditions(marketplace_id, category_id).await?;
+        Ok(conditions
+            .into_iter()
+            .find(|condition| {
+                condition.condition_description.as_deref().is_some_and(|description| description.eq_ignore_ascii_case(name))
+            })
+            .and_then(|condition| condition.condition_id))
+    }
+
+    // The item conditions a category supports
+    async fn category_conditions(&self, marketplace_id: &str, category_id: &str) -> HermesResult<Vec<ItemCondition>> {
+        let filter = format!("categoryIds:{{{}}}", category_id);
+        let response = self.get_item_condition_policies(marketplace_id, Some(&filter)).await?;
+        Ok(response
+            .item_condition_policies
+            .unwrap_or_default()
+            .into_iter()
+            .filter(|policy| policy.category_id.as_deref() == Some(category_id))
+            .flat_map(|policy| policy.item_conditions.unwrap_or_default())
+            .collect())
+    }
+
     // TODO: Additional methods to implement (15+ more):
     // - get_listing_structure_policies
     // - get_sales_tax_jurisdictions
@@ -322,4 +414,52 @@ impl MetadataClient {
     // - get_regulatory_policies
     // - get_site_visibility_policies
     // - Compatibility APIs (get_compatibilities_by_specification, etc.)
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ebay::test_support::mock_ebay;
+    use wiremock::matchers::{method, path, query_param};
+    use wiremock::{Mock, ResponseTemplate};
+
+    #[test]
+    fn currencies_lists_default_currency() {
+        let response: GetCurrenciesResponse = serde_json::from_value(serde_json::json!({
+            "marketplaceId": "EBAY_GB",
+            "defaultCurrency": { "code": "GBP", "description": "British Pound" }
+        }))
+        .unwrap();
+        assert_eq!(
+            response.currencies(),
+            [CurrencyInfo { code: "GBP".to_string(), display_name: "British Pound".to_string() }]
+        );
+        assert!(GetCurrenciesResponse::default().currencies().is_empty());
+    }
+
+    #[tokio::test]
+    async fn condition_ids_and_names_map_both_ways() {
+        let (server, config) = mock_ebay().await;
+        Mock::given(method("GET"))
+            .and(path("/sell/metadata/v1/marketplace/EBAY_US/get_item_condition_policies"))
+            .and(query_param("filter", "categoryIds:{20625}"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "itemConditionPolicies": [{
+                    "categoryId": "20625",
+                    "categoryTreeId": "0",
+                    "itemConditionRequired": true,
+                    "itemConditions": [
+                        { "conditionId": "1000", "conditionDescription": "New", "usage": "EVERYWHERE" },
+                        { "conditionId": "3000", "conditionDescription": "Used", "usage": "EVERYWHERE" }
+                    ]
+                }]
+            })))
+            .mount(&server)
+            .await;
+
+        let client = MetadataClient::new(config).unwrap();
+        assert_eq!(client.condition_name("EBAY_US", "20625", "3000").await.unwrap().as_deref(), Some("Used"));
+        assert_eq!(client.condition_name("EBAY_US", "20625", "7000").await.unwrap(), None);
+        assert_eq!(client.condition_id("EBAY_US", "20625", "new").await.unwrap().as_deref(), Some("1000"));
+    }
+}
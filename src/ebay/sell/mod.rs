@@ -13,12 +13,14 @@ pub mod recommendation;
 pub mod account;
 
 // Re-export commonly used types
-pub use analytics::AnalyticsClient;
-pub use compliance::ComplianceClient;
-pub use finances::FinancesClient;
-pub use fulfillment::FulfillmentClient;
-pub use inventory::InventoryClient;
-pub use metadata::MetadataClient;
-pub use negotiation::NegotiationClient;
+pub use analytics::{AnalyticsClient, MetricTrend, TrendDirection};
+pub use compliance::{
+    ComplianceClient, ComplianceSummaryExt, ComplianceViolationExt, ViolationDetail, ViolationIssue,
+};
+pub use finances::{FinancesClient, PayoutStatus, PayoutsExt, TransactionsExt};
+pub use fulfillment::{FulfillmentClient, OrderFulfillmentStatus, OrderNotesExt, OrderedSku, OrderTaxExt, RefundReason, TaxSummary};
+pub use inventory::{quality_score, FeeEstimate, FeeLine, Format, InventoryClient, OfferBuilder, PublishResponseExt, QualityReport};
+pub use metadata::{CurrenciesResponseExt, CurrencyInfo, MetadataClient};
+pub use negotiation::{NegotiationClient, OfferAction, OfferStatusExt};
 pub use recommendation::RecommendationClient;
-pub use account::AccountClient;
\ No newline at end of file
+pub use account::{AccountClient, MissingPolicy, PolicyKind, PolicyValidation};
\ No newline at end of file
@@ -1,13 +1,40 @@
 use crate::config::EbayConfig;
 use crate::error::{HermesError, HermesResult};
+use crate::ebay::api_error::raw_error;
+use crate::ebay::telemetry::record_api_call;
 use crate::ebay::auth::EbayAuth;
+use crate::ebay::marketplace::{normalize_marketplace, Marketplace, MarketplaceStyle};
+use crate::money::Money;
+use std::collections::HashMap;
 use std::sync::Arc;
 
-// Import eBay Sell Negotiation SDK models and APIs
+// Import eBay Sell Negotiation SDK models
 use hermes_ebay_sell_negotiation::models::{
-    PagedEligibleItemCollection, CreateOffersRequest,
+    PagedEligibleItemCollection, CreateOffersRequest, SendOfferToInterestedBuyersCollectionResponse,
 };
-use hermes_ebay_sell_negotiation::apis::configuration::Configuration as NegotiationConfiguration;
+
+/// Trading API schema version sent with `RespondToBestOffer`
+const TRADING_COMPATIBILITY_LEVEL: &str = "1349";
+
+/// How to answer a buyer's best offer
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OfferAction {
+    Accept,
+    /// Counter with a different price, which the buyer can accept or decline
+    Counter,
+    Decline,
+}
+
+impl OfferAction {
+    /// The Trading API action name, e.g. "Accept"
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            OfferAction::Accept => "Accept",
+            OfferAction::Counter => "Counter",
+            OfferAction::Decline => "Decline",
+        }
+    }
+}
 
 /// eBay Sell Negotiation API client for best offer and negotiation management
 /// 
@@ -16,6 +43,15 @@ use hermes_ebay_sell_negotiation::apis::configuration::Configuration as Negotiat
 /// - **Offer Management**: Send offers to interested buyers
 /// - **Buyer-Seller Negotiations**: Manage negotiation workflows
 /// - **Pricing Strategies**: Implement dynamic pricing through negotiations
+///
+/// The Negotiation API only exposes `findEligibleItems` and `sendOfferToInterestedBuyers`;
+/// there is no endpoint to list previously sent offers or poll their status later. The
+/// per-offer status eBay returns when offers are sent is available through
+/// [`NegotiationClient::send_offers_with_status`] and [`OfferStatusExt`].
+///
+/// It has no endpoint for answering offers buyers make either. Those are handled by
+/// the Trading API's `RespondToBestOffer` call, which [`NegotiationClient::respond_to_offer`]
+/// makes directly since no generated crate exists for the Trading API.
 pub struct NegotiationClient {
     config: EbayConfig,
     auth: Arc<EbayAuth>,
@@ -51,24 +87,28 @@ impl NegotiationClient {
         let token_duration = token_start.elapsed();
         tracing::info!("OAuth token request for find_eligible_items: {:?}", token_duration);
         
-        // Set up configuration
-        let mut config = NegotiationConfiguration::new();
-        config.base_path = if self.config.sandbox {
-            "https://api.sandbox.ebay.com/sell/negotiation/v1".to_string()
-        } else {
-            "https://api.ebay.com/sell/negotiation/v1".to_string()
-        };
-        config.oauth_access_token = Some(token);
+        let url = format!("{}/sell/negotiation/v1/find_eligible_items", self.config.base_url());
+        let http = self.config.http_client();
+        
+        // Fail fast while this API's circuit breaker is open
+        let probe = self.config.check_circuit("sell.negotiation")?;
+        
+        let marketplace_id = normalize_marketplace(marketplace_id, MarketplaceStyle::Underscore);
         
-        // Call the eBay SDK
+        // Call the eBay API
         let ebay_start = std::time::Instant::now();
-        let result = hermes_ebay_sell_negotiation::apis::offer_api::find_eligible_items(
-            &config,
-            marketplace_id,
-            limit,
-            offset,
-        ).await;
+        let result = self.config.retrying("sell.negotiation", || {
+            self.config.send_json(
+                http
+                    .get(&url)
+                    .bearer_auth(&token)
+                    .header("X-EBAY-C-MARKETPLACE-ID", marketplace_id.as_str())
+                    .query(&[("limit", limit), ("offset", offset)]),
+            )
+        }).await;
         let ebay_duration = ebay_start.elapsed();
+        record_api_call("sell.negotiation", "find_eligible_items", &result, ebay_duration);
+        self.config.record_circuit("sell.negotiation", &probe, &result);
         tracing::info!("eBay find_eligible_items API call: {:?}", ebay_duration);
         
         match result {
@@ -81,7 +121,7 @@ impl NegotiationClient {
             Err(e) => {
                 let total_duration = start_time.elapsed();
                 tracing::error!("eBay find_eligible_items error after {:?}: {:?}", total_duration, e);
-                Err(HermesError::ApiRequest(format!("eBay find_eligible_items failed: {:?}", e)))
+                Err(raw_error("find_eligible_items", &format!("marketplace_id {}", marketplace_id), e))
             }
         }
     }
@@ -99,46 +139,377 @@ impl NegotiationClient {
         marketplace_id: &str,
         create_offers_request: &CreateOffersRequest,
     ) -> HermesResult<()> {
+        self.send_offers_with_status(marketplace_id, create_offers_request).await?;
+        Ok(())
+    }
+
+    /// Send offer to interested buyers and return the created offers
+    /// 
+    /// Same as [`send_offer_to_interested_buyers`](Self::send_offer_to_interested_buyers),
+    /// but keeps eBay's response so the status of each offer (`PENDING`, `COUNTERED`,
+    /// `ACCEPTED`, `DECLINED`) can be inspected with [`OfferStatusExt`].
+    /// 
+    /// # Arguments
+    /// * `marketplace_id` - The marketplace ID (e.g., "EBAY_US")
+    /// * `create_offers_request` - The offer details to send to buyers
+    pub async fn send_offers_with_status(
+        &self,
+        marketplace_id: &str,
+        create_offers_request: &CreateOffersRequest,
+    ) -> HermesResult<SendOfferToInterestedBuyersCollectionResponse> {
+        // Dry-run mode logs the request instead of sending it
+        if let Some(response) = self.config.skip_in_dry_run("send_offers_with_status", &(marketplace_id, create_offers_request)) {
+            return Ok(response);
+        }
+
         let start_time = std::time::Instant::now();
         
         // Get access token
         let token_start = std::time::Instant::now();
         let token = self.auth.get_access_token().await?;
         let token_duration = token_start.elapsed();
-        tracing::info!("OAuth token request for send_offer_to_interested_buyers: {:?}", token_duration);
+        tracing::info!("OAuth token request for send_offers_with_status: {:?}", token_duration);
         
-        // Set up configuration
-        let mut config = NegotiationConfiguration::new();
-        config.base_path = if self.config.sandbox {
-            "https://api.sandbox.ebay.com/sell/negotiation/v1".to_string()
-        } else {
-            "https://api.ebay.com/sell/negotiation/v1".to_string()
-        };
-        config.oauth_access_token = Some(token);
+        let url = format!("{}/sell/negotiation/v1/send_offer_to_interested_buyers", self.config.base_url());
+        let http = self.config.http_client();
+        
+        // Fail fast while this API's circuit breaker is open
+        let probe = self.config.check_circuit("sell.negotiation")?;
         
-        // Call the eBay SDK
+        // Call the eBay API
         let ebay_start = std::time::Instant::now();
-        let result = hermes_ebay_sell_negotiation::apis::offer_api::send_offer_to_interested_buyers(
-            &config,
-            marketplace_id,
-            "application/json",
-            Some(create_offers_request.clone()),
+        let result = self.config.send_json(
+            http
+                .post(&url)
+                .bearer_auth(&token)
+                .header("X-EBAY-C-MARKETPLACE-ID", normalize_marketplace(marketplace_id, MarketplaceStyle::Underscore))
+                .json(create_offers_request),
         ).await;
         let ebay_duration = ebay_start.elapsed();
+        record_api_call("sell.negotiation", "send_offers_with_status", &result, ebay_duration);
+        self.config.record_circuit("sell.negotiation", &probe, &result);
         tracing::info!("eBay send_offer_to_interested_buyers API call: {:?}", ebay_duration);
         
         match result {
-            Ok(_) => {
+            Ok(response) => {
                 let total_duration = start_time.elapsed();
                 let our_processing = total_duration - token_duration - ebay_duration;
-                tracing::info!("send_offer_to_interested_buyers total: {:?} | Our processing: {:?}", total_duration, our_processing);
-                Ok(())
+                tracing::info!("send_offers_with_status total: {:?} | Our processing: {:?}", total_duration, our_processing);
+                Ok(response)
             },
             Err(e) => {
                 let total_duration = start_time.elapsed();
                 tracing::error!("eBay send_offer_to_interested_buyers error after {:?}: {:?}", total_duration, e);
-                Err(HermesError::ApiRequest(format!("eBay send_offer_to_interested_buyers failed: {:?}", e)))
+                Err(raw_error("send_offer_to_interested_buyers", &format!("marketplace_id {}", marketplace_id), e))
             }
         }
     }
-}
\ No newline at end of file
+
+    /// Accept, counter or decline a best offer made by a buyer
+    ///
+    /// The Negotiation API cannot answer buyer offers, so this calls the Trading API's
+    /// `RespondToBestOffer`, which also needs the ID of the listing the offer was made on.
+    /// Offer IDs come from the Trading API's `GetBestOffers` call or eBay's best offer
+    /// notifications, neither of which this crate wraps yet.
+    ///
+    /// # Arguments
+    /// * `marketplace_id` - The marketplace the listing is on (e.g., "EBAY_US")
+    /// * `item_id` - The legacy (numeric) ID of the listing
+    /// * `offer_id` - The ID of the buyer's best offer
+    /// * `action` - Whether to accept, counter or decline the offer
+    /// * `counter_price` - The counteroffer price; required for [`OfferAction::Counter`] (`HermesError::Configuration`
+    ///   without it) and ignored otherwise
+    pub async fn respond_to_offer(
+        &self,
+        marketplace_id: &str,
+        item_id: &str,
+        offer_id: &str,
+        action: OfferAction,
+        counter_price: Option<Money>,
+    ) -> HermesResult<()> {
+        let body = respond_to_offer_body(item_id, offer_id, action, counter_price.as_ref())?;
+        let site_id = normalize_marketplace(marketplace_id, MarketplaceStyle::Underscore)
+            .parse::<Marketplace>()?
+            .trading_site_id();
+
+        // Dry-run mode logs the request instead of sending it
+        if self.config.skip_in_dry_run::<()>("respond_to_offer", &body).is_some() {
+            return Ok(());
+        }
+
+        let start_time = std::time::Instant::now();
+
+        // Get access token
+        let token_start = std::time::Instant::now();
+        let token = self.auth.get_access_token().await?;
+        let token_duration = token_start.elapsed();
+        tracing::info!("OAuth token request for respond_to_offer: {:?}", token_duration);
+
+        // Fail fast while this API's circuit breaker is open
+        let probe = self.config.check_circuit("trading")?;
+
+        let request = self
+            .config
+            .http_client()
+            .post(format!("{}/ws/api.dll", self.config.base_url()))
+            .header("X-EBAY-API-CALL-NAME", "RespondToBestOffer")
+            .header("X-EBAY-API-COMPATIBILITY-LEVEL", TRADING_COMPATIBILITY_LEVEL)
+            .header("X-EBAY-API-SITEID", site_id.to_string())
+            .header("X-EBAY-API-IAF-TOKEN", token)
+            .header(reqwest::header::CONTENT_TYPE, "text/xml")
+            .body(body);
+        let ebay_start = std::time::Instant::now();
+        let result = self.config.send(request).await;
+        let ebay_duration = ebay_start.elapsed();
+        record_api_call("trading", "respond_to_offer", &result, ebay_duration);
+        self.config.record_circuit("trading", &probe, &result);
+        tracing::info!("eBay RespondToBestOffer API call: {:?}", ebay_duration);
+
+        let body = match result {
+            Ok(body) => body,
+            Err(e) => {
+                tracing::error!("eBay RespondToBestOffer error after {:?}: {:?}", start_time.elapsed(), e);
+                return Err(raw_error("respond_to_offer", offer_id, e));
+            }
+        };
+
+        // The Trading API reports failures in the body of a 200 response
+        if let Some(message) = trading_failure(&body) {
+            tracing::error!("eBay RespondToBestOffer failed after {:?}: {}", start_time.elapsed(), message);
+            return Err(HermesError::ApiRequest(format!("eBay respond_to_offer failed: {}", message)));
+        }
+        tracing::info!("respond_to_offer total: {:?}", start_time.elapsed());
+        Ok(())
+    }
+}
+
+/// Build the XML body of a `RespondToBestOffer` request
+fn respond_to_offer_body(
+    item_id: &str,
+    offer_id: &str,
+    action: OfferAction,
+    counter_price: Option<&Money>,
+) -> HermesResult<String> {
+    let counter = match (action, counter_price) {
+        (OfferAction::Counter, Some(price)) => format!(
+            "<CounterOfferPrice currencyID=\"{}\">{}</CounterOfferPrice><CounterOfferQuantity>1</CounterOfferQuantity>",
+            xml_escape(&price.currency),
+            price.value(),
+        ),
+        (OfferAction::Counter, None) => {
+            return Err(HermesError::Configuration("A counteroffer needs a counter price".to_string()));
+        }
+        _ => String::new(),
+    };
+    Ok(format!(
+        "<?xml version=\"1.0\" encoding=\"utf-8\"?>\
+         <RespondToBestOfferRequest xmlns=\"urn:ebay:apis:eBLBaseComponents\">\
+         <ItemID>{}</ItemID><BestOfferID>{}</BestOfferID><Action>{}</Action>{}\
+         </RespondToBestOfferRequest>",
+        xml_escape(item_id),
+        xml_escape(offer_id),
+        action.as_str(),
+        counter,
+    ))
+}
+
+fn xml_escape(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+/// Error message of a Trading API response whose `Ack` is `Failure` or `PartialFailure`
+fn trading_failure(body: &str) -> Option<String> {
+    if !matches!(xml_text(body, "Ack"), Some("Failure" | "PartialFailure")) {
+        return None;
+    }
+    let message = xml_text(body, "LongMessage").or_else(|| xml_text(body, "ShortMessage"));
+    Some(message.unwrap_or("unknown error").to_string())
+}
+
+/// Text of the first `<tag>` element in `body`
+fn xml_text<'a>(body: &'a str, tag: &str) -> Option<&'a str> {
+    let open = format!("<{}>", tag);
+    let start = body.find(&open)? + open.len();
+    let end = body[start..].find("</")?;
+    Some(&body[start..start + end])
+}
+
+/// Status accessors for the offers returned by [`NegotiationClient::send_offers_with_status`]
+pub trait OfferStatusExt {
+    /// Number of offers per status (e.g. "PENDING", "ACCEPTED")
+    fn status_counts(&self) -> HashMap<String, usize>;
+
+    /// Share of offers the buyer has accepted, or `None` when no offers were returned
+    fn acceptance_rate(&self) -> Option<f64>;
+}
+
+impl OfferStatusExt for SendOfferToInterestedBuyersCollectionResponse {
+    fn status_counts(&self) -> HashMap<String, usize> {
+        let mut counts = HashMap::new();
+        for offer in self.offers.iter().flatten() {
+            let status = offer.offer_status.clone().unwrap_or_else(|| "UNKNOWN".to_string());
+            *counts.entry(status).or_insert(0) += 1;
+        }
+        counts
+    }
+
+    fn acceptance_rate(&self) -> Option<f64> {
+        let total = self.offers.as_ref().map_or(0, Vec::len);
+        if total == 0 {
+            return None;
+        }
+        let accepted = self.status_counts().get("ACCEPTED").copied().unwrap_or(0);
+        Some(accepted as f64 / total as f64)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ebay::test_support::mock_ebay;
+    use rust_decimal::Decimal;
+    use wiremock::matchers::{body_string_contains, header, method, path};
+    use wiremock::{Mock, ResponseTemplate};
+
+    #[tokio::test]
+    async fn send_offers_with_status_reports_offer_statuses() {
+        let (server, config) = mock_ebay().await;
+        Mock::given(method("POST"))
+            .and(path("/sell/negotiation/v1/send_offer_to_interested_buyers"))
+            .and(header("X-EBAY-C-MARKETPLACE-ID", "EBAY_US"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "offers": [
+                    { "offerId": "1", "offerStatus": "ACCEPTED" },
+                    { "offerId": "2", "offerStatus": "PENDING" },
+                    { "offerId": "3", "offerStatus": "DECLINED" },
+                    { "offerId": "4", "offerStatus": "ACCEPTED" }
+                ]
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = NegotiationClient::new(config).unwrap();
+        let response = client
+            .send_offers_with_status("EBAY-US", &CreateOffersRequest::default())
+            .await
+            .unwrap();
+
+        let counts = response.status_counts();
+        assert_eq!(counts["ACCEPTED"], 2);
+        assert_eq!(counts["PENDING"], 1);
+        assert_eq!(counts["DECLINED"], 1);
+        assert_eq!(response.acceptance_rate(), Some(0.5));
+    }
+
+    #[tokio::test]
+    async fn find_eligible_items_returns_listings() {
+        let (server, config) = mock_ebay().await;
+        Mock::given(method("GET"))
+            .and(path("/sell/negotiation/v1/find_eligible_items"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "eligibleItems": [{ "listingId": "110001" }],
+                "total": 1
+            })))
+            .mount(&server)
+            .await;
+
+        let client = NegotiationClient::new(config).unwrap();
+        let items = client.find_eligible_items("EBAY_US", None, None).await.unwrap();
+        assert_eq!(items.total, Some(1));
+    }
+
+    #[test]
+    fn acceptance_rate_is_none_without_offers() {
+        let response = SendOfferToInterestedBuyersCollectionResponse::default();
+        assert!(response.status_counts().is_empty());
+        assert_eq!(response.acceptance_rate(), None);
+    }
+
+    #[tokio::test]
+    async fn respond_to_offer_sends_counteroffer_to_trading_api() {
+        let (server, config) = mock_ebay().await;
+        Mock::given(method("POST"))
+            .and(path("/ws/api.dll"))
+            .and(header("X-EBAY-API-CALL-NAME", "RespondToBestOffer"))
+            .and(header("X-EBAY-API-SITEID", "3"))
+            .and(header("X-EBAY-API-IAF-TOKEN", "test-token"))
+            .and(body_string_contains("<ItemID>110001</ItemID><BestOfferID>5001</BestOfferID><Action>Counter</Action>"))
+            .and(body_string_contains(r#"<CounterOfferPrice currencyID="GBP">42.50</CounterOfferPrice>"#))
+            .respond_with(ResponseTemplate::new(200).set_body_string(
+                "<RespondToBestOfferResponse><Ack>Success</Ack></RespondToBestOfferResponse>",
+            ))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = NegotiationClient::new(config).unwrap();
+        let price = Money::new(Decimal::new(425, 1), "GBP");
+        client
+            .respond_to_offer("EBAY-GB", "110001", "5001", OfferAction::Counter, Some(price))
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn respond_to_offer_reports_trading_failures() {
+        let (server, config) = mock_ebay().await;
+        Mock::given(method("POST"))
+            .and(path("/ws/api.dll"))
+            .respond_with(ResponseTemplate::new(200).set_body_string(
+                "<RespondToBestOfferResponse><Ack>Failure</Ack><Errors>\
+                 <ShortMessage>Invalid offer.</ShortMessage><LongMessage>The best offer has expired.</LongMessage>\
+                 </Errors></RespondToBestOfferResponse>",
+            ))
+            .mount(&server)
+            .await;
+
+        let client = NegotiationClient::new(config).unwrap();
+        let error = client
+            .respond_to_offer("EBAY_US", "110001", "5001", OfferAction::Accept, None)
+            .await
+            .unwrap_err();
+        assert!(error.to_string().contains("The best offer has expired."));
+    }
+
+    #[test]
+    fn partial_failures_are_trading_failures() {
+        let partial = "<RespondToBestOfferResponse><Ack>PartialFailure</Ack><Errors>\
+                       <ShortMessage>Offer not found.</ShortMessage></Errors></RespondToBestOfferResponse>";
+        assert_eq!(trading_failure(partial).as_deref(), Some("Offer not found."));
+        assert_eq!(trading_failure("<Ack>Warning</Ack>"), None);
+    }
+
+    #[tokio::test]
+    async fn counteroffer_without_price_is_a_configuration_error() {
+        let (server, config) = mock_ebay().await;
+        let client = NegotiationClient::new(config).unwrap();
+        let error = client
+            .respond_to_offer("EBAY_US", "110001", "5001", OfferAction::Counter, None)
+            .await
+            .unwrap_err();
+        assert!(matches!(error, HermesError::Configuration(_)));
+        assert!(server.received_requests().await.unwrap().is_empty());
+    }
+
+    #[test]
+    fn respond_to_offer_bodies_match_action() {
+        let accept = respond_to_offer_body("110001", "5001", OfferAction::Accept, None).unwrap();
+        assert!(accept.contains("<BestOfferID>5001</BestOfferID><Action>Accept</Action></RespondToBestOfferRequest>"));
+
+        let decline = respond_to_offer_body("110001", "5001", OfferAction::Decline, None).unwrap();
+        assert!(decline.contains("<Action>Decline</Action></RespondToBestOfferRequest>"));
+
+        let price = Money::new(Decimal::new(1500, 0), "JPY");
+        let counter = respond_to_offer_body("110001", "5001", OfferAction::Counter, Some(&price)).unwrap();
+        assert!(counter.contains(
+            r#"<Action>Counter</Action><CounterOfferPrice currencyID="JPY">1500</CounterOfferPrice><CounterOfferQuantity>1</CounterOfferQuantity>"#
+        ));
+
+        assert!(respond_to_offer_body("110001", "5001", OfferAction::Counter, None).is_err());
+    }
+}
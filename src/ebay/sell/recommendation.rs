@@ -1,13 +1,15 @@
 use crate::config::EbayConfig;
-use crate::error::{HermesError, HermesResult};
+use crate::error::HermesResult;
+use crate::ebay::api_error::raw_error;
+use crate::ebay::telemetry::record_api_call;
 use crate::ebay::auth::EbayAuth;
+use crate::ebay::marketplace::{normalize_marketplace, MarketplaceStyle};
 use std::sync::Arc;
 
-// Import eBay Sell Recommendation SDK models and APIs
+// Import eBay Sell Recommendation SDK models
 use hermes_ebay_sell_recommendation::models::{
     PagedListingRecommendationCollection, FindListingRecommendationRequest,
 };
-use hermes_ebay_sell_recommendation::apis::configuration::Configuration as RecommendationConfiguration;
 
 /// eBay Sell Recommendation API client for intelligent listing optimization
 /// 
@@ -55,26 +57,24 @@ impl RecommendationClient {
         let token_duration = token_start.elapsed();
         tracing::info!("OAuth token request for find_listing_recommendations: {:?}", token_duration);
         
-        // Set up configuration
-        let mut config = RecommendationConfiguration::new();
-        config.base_path = if self.config.sandbox {
-            "https://api.sandbox.ebay.com/sell/recommendation/v1".to_string()
-        } else {
-            "https://api.ebay.com/sell/recommendation/v1".to_string()
-        };
-        config.oauth_access_token = Some(token);
+        let url = format!("{}/sell/recommendation/v1/find", self.config.base_url());
+        let http = self.config.http_client();
         
-        // Call the eBay SDK
+        // Fail fast while this API's circuit breaker is open
+        let probe = self.config.check_circuit("sell.recommendation")?;
+        
+        // Call the eBay API
         let ebay_start = std::time::Instant::now();
-        let result = hermes_ebay_sell_recommendation::apis::listing_recommendation_api::find_listing_recommendations(
-            &config,
-            marketplace_id,
-            filter,
-            limit,
-            offset,
-            Some(request.clone()),
+        let result = self.config.send_json(
+            http.post(&url)
+                .bearer_auth(&token)
+                .query(&[("filter", filter), ("limit", limit), ("offset", offset)])
+                .header("X-EBAY-C-MARKETPLACE-ID", normalize_marketplace(marketplace_id, MarketplaceStyle::Underscore))
+                .json(request),
         ).await;
         let ebay_duration = ebay_start.elapsed();
+        record_api_call("sell.recommendation", "find_listing_recommendations", &result, ebay_duration);
+        self.config.record_circuit("sell.recommendation", &probe, &result);
         tracing::info!("eBay find_listing_recommendations API call: {:?}", ebay_duration);
         
         match result {
@@ -87,7 +87,7 @@ impl RecommendationClient {
             Err(e) => {
                 let total_duration = start_time.elapsed();
                 tracing::error!("eBay find_listing_recommendations error after {:?}: {:?}", total_duration, e);
-                Err(HermesError::ApiRequest(format!("eBay find_listing_recommendations failed: {:?}", e)))
+                Err(raw_error("find_listing_recommendations", &format!("marketplace_id {}", marketplace_id), e))
             }
         }
     }
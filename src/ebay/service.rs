@@ -0,0 +1,85 @@
+//! eBay REST API families
+//!
+//! Identifies an API for requests made outside the typed clients, such as
+//! [`EbayClient::call_raw`](crate::ebay::EbayClient::call_raw).
+
+/// An eBay REST API family, e.g. the Buy Browse API
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum EbayService {
+    BuyBrowse,
+    BuyFeed,
+    BuyMarketing,
+    BuyOffer,
+    BuyOrder,
+    SellAccount,
+    SellAnalytics,
+    SellCompliance,
+    SellFinances,
+    SellFulfillment,
+    SellInventory,
+    SellLogistics,
+    SellMetadata,
+    SellNegotiation,
+    SellRecommendation,
+    CommerceCatalog,
+    CommerceIdentity,
+    CommerceTaxonomy,
+    CommerceTranslation,
+    DeveloperAnalytics,
+}
+
+impl EbayService {
+    /// API root below the environment's base URL, without the version segment
+    ///
+    /// e.g. "/buy/browse"; request paths start with the version ("/v1/item/...").
+    pub fn root_path(&self) -> &'static str {
+        match self {
+            EbayService::BuyBrowse => "/buy/browse",
+            EbayService::BuyFeed => "/buy/feed",
+            EbayService::BuyMarketing => "/buy/marketing",
+            EbayService::BuyOffer => "/buy/offer",
+            EbayService::BuyOrder => "/buy/order",
+            EbayService::SellAccount => "/sell/account",
+            EbayService::SellAnalytics => "/sell/analytics",
+            EbayService::SellCompliance => "/sell/compliance",
+            EbayService::SellFinances => "/sell/finances",
+            EbayService::SellFulfillment => "/sell/fulfillment",
+            EbayService::SellInventory => "/sell/inventory",
+            EbayService::SellLogistics => "/sell/logistics",
+            EbayService::SellMetadata => "/sell/metadata",
+            EbayService::SellNegotiation => "/sell/negotiation",
+            EbayService::SellRecommendation => "/sell/recommendation",
+            EbayService::CommerceCatalog => "/commerce/catalog",
+            EbayService::CommerceIdentity => "/commerce/identity",
+            EbayService::CommerceTaxonomy => "/commerce/taxonomy",
+            EbayService::CommerceTranslation => "/commerce/translation",
+            EbayService::DeveloperAnalytics => "/developer/analytics",
+        }
+    }
+
+    /// Name used for metrics labels and circuit breaking, e.g. "buy.browse"
+    pub fn api_name(&self) -> &'static str {
+        match self {
+            EbayService::BuyBrowse => "buy.browse",
+            EbayService::BuyFeed => "buy.feed",
+            EbayService::BuyMarketing => "buy.marketing",
+            EbayService::BuyOffer => "buy.offer",
+            EbayService::BuyOrder => "buy.order",
+            EbayService::SellAccount => "sell.account",
+            EbayService::SellAnalytics => "sell.analytics",
+            EbayService::SellCompliance => "sell.compliance",
+            EbayService::SellFinances => "sell.finances",
+            EbayService::SellFulfillment => "sell.fulfillment",
+            EbayService::SellInventory => "sell.inventory",
+            EbayService::SellLogistics => "sell.logistics",
+            EbayService::SellMetadata => "sell.metadata",
+            EbayService::SellNegotiation => "sell.negotiation",
+            EbayService::SellRecommendation => "sell.recommendation",
+            EbayService::CommerceCatalog => "commerce.catalog",
+            EbayService::CommerceIdentity => "commerce.identity",
+            EbayService::CommerceTaxonomy => "commerce.taxonomy",
+            EbayService::CommerceTranslation => "commerce.translation",
+            EbayService::DeveloperAnalytics => "developer.analytics",
+        }
+    }
+}
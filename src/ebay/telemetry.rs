@@ -0,0 +1,101 @@
+//! Per-call metrics for eBay API requests
+//!
+//! With the `metrics` feature enabled, every wrapped eBay API call records, through
+//! the [`metrics`](https://docs.rs/metrics) facade:
+//!
+//! - `hermes_ebay_requests_total{api, method, status}` - counter of completed calls,
+//!   where `status` is `ok`, the HTTP status code of a failed response, or `error`
+//!   when no response was received
+//! - `hermes_ebay_request_duration_seconds{api, method}` - histogram of eBay call time
+//!
+//! Install any `metrics` recorder (e.g. `metrics-exporter-prometheus`) to export them.
+//! Without the feature, recording compiles to nothing.
+
+use crate::ebay::api_error::ApiResponseError;
+use std::time::Duration;
+
+/// Record the outcome and duration of one eBay API call
+///
+/// # Arguments
+/// * `api` - API family, e.g. "buy.browse"
+/// * `method` - Wrapper method name, e.g. "get_item"
+/// * `result` - The generated call's result
+/// * `duration` - Time spent in the eBay call
+#[cfg(feature = "metrics")]
+pub(crate) fn record_api_call<T, E: ApiResponseError>(
+    api: &'static str,
+    method: &'static str,
+    result: &Result<T, E>,
+    duration: Duration,
+) {
+    let status = match result {
+        Ok(_) => "ok".to_string(),
+        Err(e) => e
+            .response()
+            .map(|(status, _)| status.to_string())
+            .unwrap_or_else(|| "error".to_string()),
+    };
+    metrics::counter!(
+        "hermes_ebay_requests_total",
+        "api" => api,
+        "method" => method,
+        "status" => status
+    )
+    .increment(1);
+    metrics::histogram!(
+        "hermes_ebay_request_duration_seconds",
+        "api" => api,
+        "method" => method
+    )
+    .record(duration.as_secs_f64());
+}
+
+#[cfg(not(feature = "metrics"))]
+#[inline(always)]
+pub(crate) fn record_api_call<T, E: ApiResponseError>(
+    _api: &'static str,
+    _method: &'static str,
+    _result: &Result<T, E>,
+    _duration: Duration,
+) {
+}
+
+#[cfg(all(test, feature = "metrics"))]
+mod tests {
+    use crate::ebay::test_support::mock_ebay;
+    use crate::ebay::EbayClient;
+    use metrics_util::debugging::{DebugValue, DebuggingRecorder};
+    use wiremock::matchers::{method, path};
+    use wiremock::{Mock, ResponseTemplate};
+
+    #[test]
+    fn request_counter_increments_after_call() {
+        let recorder = DebuggingRecorder::new();
+        let snapshotter = recorder.snapshotter();
+        let runtime = tokio::runtime::Builder::new_current_thread().enable_all().build().unwrap();
+
+        metrics::with_local_recorder(&recorder, || {
+            runtime.block_on(async {
+                let (server, config) = mock_ebay().await;
+                Mock::given(method("GET"))
+                    .and(path("/buy/browse/v1/item/123"))
+                    .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({ "itemId": "123" })))
+                    .mount(&server)
+                    .await;
+
+                let client = EbayClient::new(config).unwrap();
+                client.get_item("123", None).await.unwrap();
+            })
+        });
+
+        let counter = snapshotter
+            .snapshot()
+            .into_vec()
+            .into_iter()
+            .find(|(key, _, _, _)| key.key().name() == "hermes_ebay_requests_total")
+            .expect("request counter recorded");
+        let labels: Vec<_> = counter.0.key().labels().map(|label| (label.key(), label.value())).collect();
+        assert_eq!(labels, [("api", "buy.browse"), ("method", "get_item"), ("status", "ok")]);
+        assert_eq!(counter.3, DebugValue::Counter(1));
+    }
+}
@@ -0,0 +1,25 @@
+//! Shared helpers for unit tests that run clients against a mock eBay host
+
+use crate::config::EbayConfig;
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+/// Start a mock eBay host that issues OAuth tokens, and a config pointing at it
+pub(crate) async fn mock_ebay() -> (MockServer, EbayConfig) {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/identity/v1/oauth2/token"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "access_token": "test-token",
+            "token_type": "Application Access Token",
+            "expires_in": 7200
+        })))
+        .mount(&server)
+        .await;
+
+    let config = EbayConfig::new()
+        .with_app_id("test-app-id")
+        .with_cert_id("test-cert-id")
+        .with_api_base_url(&server.uri());
+    (server, config)
+}
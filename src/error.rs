@@ -1,3 +1,4 @@
+use serde::Deserialize;
 use thiserror::Error;
 
 /// Error type for Hermes SDK operations
@@ -9,9 +10,58 @@ pub enum HermesError {
     #[error("API request failed: {0}")]
     ApiRequest(String),
 
+    #[error("eBay {operation} failed with HTTP {status}: {}", summarize(.errors, .body))]
+    Api {
+        operation: String,
+        status: u16,
+        /// Entries of the `errors` array in eBay's response body
+        errors: Vec<ApiErrorMessage>,
+        body: String,
+    },
+
+    /// eBay's response did not match the expected model
+    ///
+    /// `body` holds the raw response so schema drift can be reported.
+    #[error("Failed to deserialize eBay response: {reason}")]
+    Deserialize { reason: String, body: String },
+
+    /// A response body was larger than `EbayConfig::max_response_bytes`
+    #[error("eBay response exceeded the limit of {limit} bytes")]
+    ResponseTooLarge { limit: usize },
+
+    /// A feed session downloaded more than its configured cap
+    #[error("Feed download exceeded the cap of {limit} bytes")]
+    QuotaExceeded { limit: u64 },
+
+    #[error("Resource not found: {resource}")]
+    NotFound { resource: String },
+
+    #[error("Policy {policy_id} is in use by offers: {}", .offer_ids.join(", "))]
+    PolicyInUse {
+        policy_id: String,
+        offer_ids: Vec<String>,
+    },
+
+    /// The seller's refresh token was revoked or expired; they must grant consent again
+    #[error("eBay user authorization must be renewed")]
+    ReauthorizationRequired {
+        /// Consent URL to send the seller to, when an RuName is configured
+        auth_url: Option<String>,
+    },
+
+    /// eBay is down for maintenance; back off longer than for a transient 503
+    #[error("eBay is unavailable for maintenance")]
+    ServiceUnavailable {
+        /// How long eBay asked callers to wait, if it said
+        retry_after: Option<std::time::Duration>,
+    },
+
     #[error("Rate limit exceeded: {0}")]
     RateLimit(String),
 
+    #[error("Circuit open for eBay API: {0}")]
+    CircuitOpen(String),
+
     #[error("Invalid configuration: {0}")]
     Configuration(String),
 
@@ -28,6 +78,45 @@ pub enum HermesError {
     Unknown(String),
 }
 
+/// One entry of the `errors` array eBay returns with a failed request
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ApiErrorMessage {
+    pub error_id: Option<i64>,
+    /// API that raised the error, e.g. "API_INVENTORY"
+    pub domain: Option<String>,
+    /// "REQUEST", "APPLICATION" or "BUSINESS"
+    pub category: Option<String>,
+    pub message: Option<String>,
+    pub long_message: Option<String>,
+}
+
+impl ApiErrorMessage {
+    /// The most descriptive text eBay gave for this error
+    pub fn text(&self) -> Option<&str> {
+        self.long_message.as_deref().or(self.message.as_deref())
+    }
+}
+
+impl HermesError {
+    /// Human-readable messages from an eBay error response; empty for other errors
+    pub fn api_messages(&self) -> Vec<&str> {
+        match self {
+            HermesError::Api { errors, .. } => errors.iter().filter_map(ApiErrorMessage::text).collect(),
+            _ => Vec::new(),
+        }
+    }
+}
+
+fn summarize(errors: &[ApiErrorMessage], body: &str) -> String {
+    let messages: Vec<&str> = errors.iter().filter_map(ApiErrorMessage::text).collect();
+    if messages.is_empty() {
+        body.to_string()
+    } else {
+        messages.join("; ")
+    }
+}
+
 /// Result type for Hermes SDK operations
 pub type HermesResult<T> = Result<T, HermesError>;
 
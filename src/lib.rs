@@ -6,7 +6,7 @@
 //! 
 //! - **eBay APIs**: Complete coverage of eBay Buy, Sell, and Commerce APIs
 //! - **Etsy APIs**: Full Etsy marketplace API integration  
-//! - **Stripe APIs**: Payment processing and subscription management
+//! - **Stripe APIs**: Payment processing and subscription management (planned; not yet enabled)
 //! - **Async/Await**: Built on Tokio for high-performance async operations
 //! - **Type Safety**: Full type safety with generated models
 //! - **Error Handling**: Comprehensive error types and handling
@@ -27,7 +27,7 @@
 //!     let mut client = EbayClient::new(config)?;
 //!     
 //!     // Search for items
-//!     let items = client.search_items("laptop", Some(50)).await?;
+//!     let items = client.search_items("laptop", Some(50), false).await?;
 //!     println!("Found {} items", items.item_summaries.len());
 //!     
 //!     Ok(())
@@ -41,14 +41,31 @@
 pub mod ebay;
 // TODO: Enable when SDKs are ready
 // pub mod etsy;
+//   Planned once `hermes-etsy` is available: listing writes (`create_draft_listing`,
+//   `update_listing`, `update_listing_inventory`), which need the `listings_w` OAuth scope,
+//   and a `MarketplaceListing` impl creating Etsy draft listings. Every Etsy request is to
+//   go through a limiter fed by `X-Limit-Per-Second`/`X-Remaining-This-Second` (and the
+//   daily equivalents) that self-throttles before Etsy's 10/sec and 10k/day caps.
+//   Auth is to be an `EtsyAuth` mirroring `EbayAuth`: cached access tokens refreshed
+//   before their one-hour expiry, with the refresh token Etsy rotates on every refresh
+//   written back through a `TokenStore` before the new access token is used.
 // pub mod stripe;
+//   Planned once `hermes-stripe` is available: customers (`create_customer`),
+//   subscriptions (`create_subscription`, `cancel_subscription`) and invoices
+//   (`list_invoices`), all form-encoded with bearer auth from `StripeConfig::secret_key`.
 pub mod error;
 pub mod config;
+pub mod listing;
+pub mod money;
+pub mod orders;
 
 // Re-export commonly used types
 pub use ebay::EbayClient;
-pub use error::{HermesError, HermesResult};
-pub use config::{Config, EbayConfig, EtsyConfig, StripeConfig};
+pub use error::{ApiErrorMessage, HermesError, HermesResult};
+pub use config::{Config, EbayConfig, EbayCredentials, EtsyConfig, StripeConfig, DRY_RUN_ID};
+pub use listing::{ListingId, MarketplaceListing, UnifiedProduct};
+pub use money::Money;
+pub use orders::{unified_orders, OrderSource, UnifiedOrder};
 
 /// Result type for Hermes SDK operations
 pub type Result<T> = HermesResult<T>;
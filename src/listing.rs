@@ -0,0 +1,47 @@
+//! Marketplace-agnostic listing creation
+//!
+//! Sellers who list the same product on several marketplaces describe it once as a
+//! [`UnifiedProduct`] and hand it to any [`MarketplaceListing`] implementation, which
+//! translates it into that platform's request models.
+
+use crate::error::HermesResult;
+use async_trait::async_trait;
+use std::fmt;
+
+/// A product described independently of any marketplace
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct UnifiedProduct {
+    /// Seller-defined SKU, used as the platform's inventory key where one exists
+    pub sku: String,
+    pub title: String,
+    pub description: String,
+    /// Unit price as a decimal string, e.g. "19.99"
+    pub price: String,
+    /// ISO 4217 currency code, e.g. "USD"
+    pub currency: String,
+    pub quantity: u32,
+    /// Publicly reachable image URLs; the first one is the primary image
+    pub image_urls: Vec<String>,
+}
+
+/// Identifier of a listing created through [`MarketplaceListing`]
+///
+/// What the ID refers to is platform specific (for eBay it is the offer ID).
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ListingId(pub String);
+
+impl fmt::Display for ListingId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+/// A marketplace that can create listings from a [`UnifiedProduct`]
+///
+/// Listings are created unpublished (an eBay offer, an Etsy draft) so they can be
+/// reviewed or completed with platform-specific details before going live.
+#[async_trait]
+pub trait MarketplaceListing {
+    /// Create an unpublished listing for `product`
+    async fn create_listing(&self, product: &UnifiedProduct) -> HermesResult<ListingId>;
+}
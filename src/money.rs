@@ -0,0 +1,111 @@
+//! Monetary amounts formatted for marketplace requests
+//!
+//! Marketplaces take prices as decimal strings and reject ones with more decimal
+//! places than the currency has (e.g. "1500.00" for JPY). A [`Money`] value is
+//! rounded to its currency's minor unit when formatted, so callers can work with
+//! `Decimal` or `f64` amounts.
+
+use crate::error::{HermesError, HermesResult};
+use rust_decimal::{Decimal, RoundingStrategy};
+use std::fmt;
+use std::str::FromStr;
+
+/// Currencies without minor units (ISO 4217 exponent 0)
+const ZERO_DECIMAL_CURRENCIES: &[&str] = &["CLP", "ISK", "JPY", "KRW", "PYG", "UGX", "VND", "XAF", "XOF"];
+
+/// Currencies with three decimal places (ISO 4217 exponent 3)
+const THREE_DECIMAL_CURRENCIES: &[&str] = &["BHD", "JOD", "KWD", "OMR", "TND"];
+
+/// An amount in a currency
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Money {
+    pub amount: Decimal,
+    /// ISO 4217 currency code, e.g. "USD"
+    pub currency: String,
+}
+
+impl Money {
+    /// Create an amount in `currency`, e.g. `Money::new(Decimal::new(1999, 2), "USD")`
+    pub fn new(amount: Decimal, currency: &str) -> Self {
+        Self { amount, currency: currency.trim().to_ascii_uppercase() }
+    }
+
+    /// Create an amount from a float, failing for NaN and infinite values
+    pub fn from_f64(amount: f64, currency: &str) -> HermesResult<Self> {
+        let amount = Decimal::try_from(amount)
+            .map_err(|e| HermesError::Configuration(format!("Invalid {} amount {}: {}", currency, amount, e)))?;
+        Ok(Self::new(amount, currency))
+    }
+
+    /// Decimal places of the currency's minor unit (2 unless known otherwise)
+    pub fn decimal_places(&self) -> u32 {
+        if ZERO_DECIMAL_CURRENCIES.contains(&self.currency.as_str()) {
+            0
+        } else if THREE_DECIMAL_CURRENCIES.contains(&self.currency.as_str()) {
+            3
+        } else {
+            2
+        }
+    }
+
+    /// The amount as marketplaces expect it, e.g. "19.99" for USD or "1500" for JPY
+    ///
+    /// Extra places are rounded half away from zero.
+    pub fn value(&self) -> String {
+        let places = self.decimal_places();
+        let rounded = self.amount.round_dp_with_strategy(places, RoundingStrategy::MidpointAwayFromZero);
+        format!("{:.*}", places as usize, rounded)
+    }
+}
+
+impl fmt::Display for Money {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {}", self.value(), self.currency)
+    }
+}
+
+/// Parse an amount a marketplace sent as a decimal string, e.g. "19.99"
+///
+/// Missing amounts give `None`. So do malformed ones, after a warning, so that one
+/// bad amount is left out of a total rather than failing it.
+pub(crate) fn parse_decimal(value: Option<&str>) -> Option<Decimal> {
+    let value = value?;
+    match Decimal::from_str(value.trim()) {
+        Ok(parsed) => Some(parsed),
+        Err(e) => {
+            tracing::warn!("Skipping unparseable amount {:?}: {}", value, e);
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn money_formats_usd_with_two_places() {
+        assert_eq!(Money::new(Decimal::new(1999, 2), "USD").value(), "19.99");
+        assert_eq!(Money::new(Decimal::new(20, 0), "usd").value(), "20.00");
+        assert_eq!(Money::new(Decimal::new(12345, 3), "USD").value(), "12.35");
+        assert_eq!(Money::from_f64(0.1 + 0.2, "USD").unwrap().value(), "0.30");
+        assert!(Money::from_f64(f64::NAN, "USD").is_err());
+    }
+
+    #[test]
+    fn parse_decimal_skips_missing_and_malformed_amounts() {
+        assert_eq!(parse_decimal(Some("19.99")), Some(Decimal::new(1999, 2)));
+        assert_eq!(parse_decimal(Some(" -5 ")), Some(Decimal::new(-5, 0)));
+        assert_eq!(parse_decimal(Some("12,50")), None);
+        assert_eq!(parse_decimal(Some("")), None);
+        assert_eq!(parse_decimal(None), None);
+    }
+
+    #[test]
+    fn money_formats_jpy_without_decimals() {
+        let price = Money::new(Decimal::new(15005, 1), "JPY");
+        assert_eq!(price.value(), "1501");
+        assert_eq!(price.to_string(), "1501 JPY");
+        assert_eq!(Money::from_f64(1500.0, "JPY").unwrap().value(), "1500");
+    }
+}
@@ -0,0 +1,180 @@
+//! Marketplace-agnostic order aggregation
+//!
+//! Multi-channel sellers want one order feed. Each platform's orders (eBay orders,
+//! Etsy receipts) are normalized into a [`UnifiedOrder`] by an [`OrderSource`], and
+//! [`unified_orders`] merges any number of sources into a single stream.
+//!
+//! Aggregation is read-only. eBay's `FulfillmentClient` is an order source; Etsy
+//! receipts join once the Etsy client is available.
+
+use crate::error::HermesResult;
+use futures::stream::{self, BoxStream, Stream};
+use std::fmt;
+
+/// Marketplace an order was placed on
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Platform {
+    Ebay,
+    Etsy,
+}
+
+impl Platform {
+    /// Lowercase platform name, e.g. "ebay"
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Platform::Ebay => "ebay",
+            Platform::Etsy => "etsy",
+        }
+    }
+}
+
+impl fmt::Display for Platform {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Where an order is in its lifecycle, across platforms
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum UnifiedOrderStatus {
+    /// Paid (or awaiting payment) and not yet shipped
+    Open,
+    /// Partly shipped
+    InProgress,
+    /// Fully shipped
+    Fulfilled,
+    /// Cancelled by the buyer or seller
+    Cancelled,
+    /// A platform status with no unified equivalent, kept verbatim
+    Other(String),
+}
+
+/// One line of a [`UnifiedOrder`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnifiedOrderItem {
+    /// Seller-defined SKU, if the listing had one
+    pub sku: Option<String>,
+    pub title: String,
+    pub quantity: u32,
+}
+
+/// An order normalized across marketplaces
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnifiedOrder {
+    /// The platform's order ID (eBay order ID, Etsy receipt ID)
+    pub id: String,
+    pub platform: Platform,
+    /// Buyer's platform username, if shared with the seller
+    pub buyer: Option<String>,
+    pub items: Vec<UnifiedOrderItem>,
+    /// Order total as a decimal string, e.g. "19.99"
+    pub total: Option<String>,
+    /// ISO 4217 currency code of `total`, e.g. "USD"
+    pub currency: Option<String>,
+    pub status: UnifiedOrderStatus,
+    /// When the order was placed (ISO 8601)
+    pub created: Option<String>,
+}
+
+/// A marketplace whose orders can be listed as [`UnifiedOrder`]s
+pub trait OrderSource {
+    /// Stream the orders placed at or after `since` (ISO 8601, e.g. "2024-06-01T00:00:00.000Z")
+    ///
+    /// The stream ends after the last order or after yielding the first error.
+    fn orders_since<'a>(&'a self, since: &'a str) -> BoxStream<'a, HermesResult<UnifiedOrder>>;
+}
+
+/// Merge the orders of several marketplaces into one stream
+///
+/// Sources are polled concurrently, so orders arrive as each platform returns them
+/// rather than in date order. An error from one source is yielded in the stream
+/// without stopping the others.
+///
+/// # Arguments
+/// * `sources` - Order sources to merge, e.g. an eBay `FulfillmentClient`
+/// * `since` - Earliest order creation time (ISO 8601)
+pub fn unified_orders<'a>(
+    sources: &[&'a dyn OrderSource],
+    since: &'a str,
+) -> impl Stream<Item = HermesResult<UnifiedOrder>> + 'a {
+    stream::select_all(sources.iter().map(|source| source.orders_since(since)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ebay::sell::FulfillmentClient;
+    use crate::ebay::test_support::mock_ebay;
+    use futures::StreamExt;
+    use wiremock::matchers::{method, path, query_param};
+    use wiremock::{Mock, ResponseTemplate};
+
+    // Stands in for Etsy receipts until the Etsy client exists
+    struct FixedOrders(Vec<UnifiedOrder>);
+
+    impl OrderSource for FixedOrders {
+        fn orders_since<'a>(&'a self, _since: &'a str) -> BoxStream<'a, HermesResult<UnifiedOrder>> {
+            Box::pin(stream::iter(self.0.iter().cloned().map(Ok)))
+        }
+    }
+
+    #[tokio::test]
+    async fn unified_orders_merges_ebay_and_etsy() {
+        let (server, config) = mock_ebay().await;
+        Mock::given(method("GET"))
+            .and(path("/sell/fulfillment/v1/order"))
+            .and(query_param("filter", "creationdate:[2024-06-01T00:00:00.000Z..]"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "total": 1,
+                "orders": [{
+                    "orderId": "12-34567-89012",
+                    "creationDate": "2024-06-02T10:00:00.000Z",
+                    "orderFulfillmentStatus": "NOT_STARTED",
+                    "buyer": { "username": "buyer_one" },
+                    "pricingSummary": { "total": { "value": "25.98", "currency": "USD" } },
+                    "lineItems": [{ "sku": "MUG-1", "title": "Coffee mug", "quantity": 2 }]
+                }]
+            })))
+            .mount(&server)
+            .await;
+
+        let etsy_receipt = UnifiedOrder {
+            id: "3012345678".to_string(),
+            platform: Platform::Etsy,
+            buyer: Some("etsy_buyer".to_string()),
+            items: vec![UnifiedOrderItem { sku: None, title: "Print".to_string(), quantity: 1 }],
+            total: Some("12.00".to_string()),
+            currency: Some("USD".to_string()),
+            status: UnifiedOrderStatus::Fulfilled,
+            created: Some("2024-06-03T08:00:00.000Z".to_string()),
+        };
+        let ebay = FulfillmentClient::new(config).unwrap();
+        let etsy = FixedOrders(vec![etsy_receipt.clone()]);
+
+        let mut orders: Vec<UnifiedOrder> = unified_orders(&[&ebay, &etsy], "2024-06-01T00:00:00.000Z")
+            .map(Result::unwrap)
+            .collect()
+            .await;
+        orders.sort_by_key(|order| order.platform.as_str());
+
+        assert_eq!(orders.len(), 2);
+        assert_eq!(
+            orders[0],
+            UnifiedOrder {
+                id: "12-34567-89012".to_string(),
+                platform: Platform::Ebay,
+                buyer: Some("buyer_one".to_string()),
+                items: vec![UnifiedOrderItem {
+                    sku: Some("MUG-1".to_string()),
+                    title: "Coffee mug".to_string(),
+                    quantity: 2,
+                }],
+                total: Some("25.98".to_string()),
+                currency: Some("USD".to_string()),
+                status: UnifiedOrderStatus::Open,
+                created: Some("2024-06-02T10:00:00.000Z".to_string()),
+            }
+        );
+        assert_eq!(orders[1], etsy_receipt);
+    }
+}